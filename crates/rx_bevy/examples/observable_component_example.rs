@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use bevy::{
+	input::common_conditions::input_just_pressed, platform::collections::HashMap, prelude::*,
+	time::common_conditions::on_timer,
+};
+use bevy_egui::EguiPlugin;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+use examples_common::{
+	SubscriptionMapResource, print_notification_observer, send_message, toggle_subscription_system,
+};
+use rx_bevy::prelude::*;
+use rx_bevy_observable_component::observable::{
+	ComponentChange, ComponentObservable, ComponentObservableOptions,
+};
+
+fn main() -> AppExit {
+	App::new()
+		.add_plugins((
+			DefaultPlugins,
+			EguiPlugin::default(),
+			WorldInspectorPlugin::new(),
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+			RxSchedulerPlugin::<PostUpdate, Virtual>::default(),
+		))
+		.register_type::<ExampleEntities>()
+		.add_systems(Startup, setup)
+		.add_systems(
+			Update,
+			(
+				toggle_subscription_system::<
+					ExampleEntities,
+					ComponentChange<usize>,
+					Never,
+					PostUpdate,
+					Virtual,
+				>(
+					KeyCode::Space,
+					|res| res.component_observable,
+					|res| res.dummy_destination,
+				),
+				(
+					dummy_component_mutator.run_if(on_timer(Duration::from_millis(500))),
+					toggle_dummy_component.run_if(input_just_pressed(KeyCode::KeyR)),
+				)
+					.chain(),
+				send_message(AppExit::Success).run_if(input_just_pressed(KeyCode::Escape)),
+			),
+		)
+		.run()
+}
+
+#[derive(Resource, Reflect)]
+struct ExampleEntities {
+	subscriptions: HashMap<(Entity, Entity), Entity>,
+	component_observable: Entity,
+	dummy_destination: Entity,
+	dummy_entity: Entity,
+}
+
+#[derive(Component, Default, Debug, Clone)]
+pub struct DummyComponent {
+	pub count: usize,
+}
+
+fn toggle_dummy_component(
+	mut commands: Commands,
+	example_entities: Res<ExampleEntities>,
+	dummy_component_query: Query<&DummyComponent>,
+) {
+	let mut dummy_entity = commands.entity(example_entities.dummy_entity);
+	if dummy_component_query
+		.get(example_entities.dummy_entity)
+		.is_ok()
+	{
+		dummy_entity.remove::<DummyComponent>();
+	} else {
+		dummy_entity.insert(DummyComponent { count: 0 });
+	}
+}
+
+fn dummy_component_mutator(mut dummy_component_query: Query<&mut DummyComponent>) {
+	for mut dummy_component in dummy_component_query.iter_mut() {
+		dummy_component.count += 1;
+		println!("Incrementing count to {dummy_component:?}");
+	}
+}
+
+impl SubscriptionMapResource for ExampleEntities {
+	fn insert(
+		&mut self,
+		observable_destination_key: (Entity, Entity),
+		subscription_entity: Entity,
+	) {
+		self.subscriptions
+			.insert(observable_destination_key, subscription_entity);
+	}
+
+	fn remove(&mut self, observable_destination_key: (Entity, Entity)) -> Option<Entity> {
+		self.subscriptions.remove(&observable_destination_key)
+	}
+}
+
+fn setup(mut commands: Commands, rx_schedule_update_virtual: RxSchedule<Update, Virtual>) {
+	println!("Press R to add or remove the component!");
+	println!("Press Space to subscribe!");
+	commands.spawn((
+		Camera3d::default(),
+		Transform::from_xyz(2., 6., 8.).looking_at(Vec3::ZERO, Vec3::Y),
+	));
+
+	let dummy_entity = commands.spawn(Name::new("DummyEntity")).id();
+
+	let dummy_destination = commands
+		.spawn(Name::new("ExampleObserver"))
+		.observe(print_notification_observer::<ComponentChange<usize>, Never, Virtual>)
+		.id();
+
+	let component_observable = commands
+		.spawn((
+			Name::new("ComponentObservable"),
+			ComponentObservable::<DummyComponent, _, usize>::new(
+				dummy_entity,
+				|dummy_component| dummy_component.count,
+				ComponentObservableOptions {
+					trigger_on_is_added: true,
+					trigger_on_is_changed: true,
+					trigger_on_is_removed: true,
+				},
+				rx_schedule_update_virtual.handle(),
+			)
+			.into_component(),
+		))
+		.id();
+
+	commands.insert_resource(ExampleEntities {
+		subscriptions: HashMap::new(),
+		component_observable,
+		dummy_destination,
+		dummy_entity,
+	});
+}
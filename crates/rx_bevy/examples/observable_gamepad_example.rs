@@ -0,0 +1,51 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::EguiPlugin;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+use examples_common::send_message;
+use rx_bevy::prelude::*;
+
+fn main() -> AppExit {
+	App::new()
+		.add_plugins((
+			DefaultPlugins,
+			EguiPlugin::default(),
+			WorldInspectorPlugin::new(),
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		))
+		.add_systems(Startup, setup)
+		.add_systems(
+			Update,
+			(
+				send_message(AppExit::Success).run_if(input_just_pressed(KeyCode::Escape)),
+				unsubscribe.run_if(input_just_pressed(KeyCode::Space)),
+			),
+		)
+		.run()
+}
+
+fn unsubscribe(mut example_entities: ResMut<MySubscriptions>) {
+	example_entities.button_subscription.unsubscribe();
+	example_entities.axis_subscription.unsubscribe();
+}
+
+#[derive(Resource)]
+struct MySubscriptions {
+	button_subscription: SharedSubscription,
+	axis_subscription: SharedSubscription,
+}
+
+fn setup(mut commands: Commands, rx_schedule_update_virtual: RxSchedule<Update, Virtual>) {
+	let button_subscription =
+		GamepadButtonObservable::new(default(), rx_schedule_update_virtual.handle())
+			.subscribe(PrintObserver::new("gamepad_button"));
+
+	let axis_subscription =
+		GamepadAxisObservable::new(default(), rx_schedule_update_virtual.handle())
+			.subscribe(PrintObserver::new("gamepad_axis"));
+
+	commands.insert_resource(MySubscriptions {
+		button_subscription: SharedSubscription::new(button_subscription),
+		axis_subscription: SharedSubscription::new(axis_subscription),
+	});
+}
@@ -0,0 +1,82 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::EguiPlugin;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+use examples_common::send_message;
+use rx_bevy::prelude::*;
+
+fn main() -> AppExit {
+	App::new()
+		.add_plugins((
+			DefaultPlugins,
+			EguiPlugin::default(),
+			WorldInspectorPlugin::new(),
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		))
+		.add_systems(Startup, setup)
+		.add_systems(
+			Update,
+			(
+				send_message(AppExit::Success).run_if(input_just_pressed(KeyCode::Escape)),
+				spawn_enemy.run_if(input_just_pressed(KeyCode::Space)),
+				despawn_enemy.run_if(input_just_pressed(KeyCode::KeyD)),
+				unsubscribe.run_if(input_just_pressed(KeyCode::KeyU)),
+			),
+		)
+		.run()
+}
+
+#[derive(Component)]
+struct Enemy;
+
+#[derive(Component)]
+struct EnemyWave;
+
+fn spawn_enemy(mut commands: Commands, enemy_wave_query: Query<Entity, With<EnemyWave>>) {
+	if let Ok(enemy_wave) = enemy_wave_query.single() {
+		commands.spawn((Enemy, Name::new("Enemy"), ChildOf(enemy_wave)));
+	}
+}
+
+fn despawn_enemy(mut commands: Commands, enemy_query: Query<Entity, With<Enemy>>) {
+	if let Some(enemy) = enemy_query.iter().next() {
+		commands.entity(enemy).despawn();
+	}
+}
+
+fn unsubscribe(mut example_entities: ResMut<MySubscriptions>) {
+	for subscription in example_entities.subscriptions.iter_mut() {
+		subscription.unsubscribe();
+	}
+}
+
+#[derive(Resource)]
+struct MySubscriptions {
+	subscriptions: Vec<SharedSubscription>,
+}
+
+fn setup(mut commands: Commands, rx_schedule_update_virtual: RxSchedule<Update, Virtual>) {
+	println!("Press Space to spawn an enemy, D to despawn one, U to unsubscribe!");
+
+	let enemy_wave = commands.spawn((EnemyWave, Name::new("Enemy Wave"))).id();
+
+	let spawn_subscription =
+		EntitySpawnObservable::<Enemy>::new(rx_schedule_update_virtual.handle())
+			.subscribe(PrintObserver::new("enemy_spawned"));
+
+	let despawn_subscription =
+		EntityDespawnObservable::<Enemy>::new(rx_schedule_update_virtual.handle())
+			.subscribe(PrintObserver::new("enemy_despawned"));
+
+	let children_subscription =
+		ChildrenObservable::new(enemy_wave, rx_schedule_update_virtual.handle())
+			.subscribe(PrintObserver::new("enemy_wave_children"));
+
+	commands.insert_resource(MySubscriptions {
+		subscriptions: vec![
+			SharedSubscription::new(spawn_subscription),
+			SharedSubscription::new(despawn_subscription),
+			SharedSubscription::new(children_subscription),
+		],
+	});
+}
@@ -0,0 +1,78 @@
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::EguiPlugin;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+use examples_common::send_message;
+use rx_bevy::prelude::*;
+
+fn main() -> AppExit {
+	App::new()
+		.add_plugins((
+			DefaultPlugins,
+			EguiPlugin::default(),
+			WorldInspectorPlugin::new(),
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		))
+		.init_state::<GameState>()
+		.add_systems(Startup, setup)
+		.add_systems(
+			Update,
+			(
+				send_message(AppExit::Success).run_if(input_just_pressed(KeyCode::Escape)),
+				start_game.run_if(input_just_pressed(KeyCode::Space)),
+				unsubscribe.run_if(input_just_pressed(KeyCode::KeyU)),
+			),
+		)
+		.run()
+}
+
+#[derive(States, Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum GameState {
+	#[default]
+	Menu,
+	Playing,
+	Paused,
+}
+
+fn start_game(mut next_state: ResMut<NextState<GameState>>) {
+	next_state.set(GameState::Playing);
+}
+
+fn unsubscribe(mut example_entities: ResMut<MySubscriptions>) {
+	for subscription in example_entities.subscriptions.iter_mut() {
+		subscription.unsubscribe();
+	}
+}
+
+#[derive(Resource)]
+struct MySubscriptions {
+	subscriptions: Vec<SharedSubscription>,
+}
+
+fn setup(mut commands: Commands, rx_schedule_update_virtual: RxSchedule<Update, Virtual>) {
+	println!("Press Space to start, U to unsubscribe!");
+
+	let transitions_subscription =
+		StateObservable::<GameState>::new(default(), rx_schedule_update_virtual.handle())
+			.subscribe(PrintObserver::new("game_state"));
+
+	// Leaving the menu always starts the game paused
+	let menu_exit_subscription = StateObservable::<GameState>::new(
+		StateObservableOptions {
+			emit: StateObservableEmit::OnExit(GameState::Menu),
+		},
+		rx_schedule_update_virtual.handle(),
+	)
+	.filter(|(_exited, entered), _| entered == &GameState::Playing)
+	.map(|_| GameState::Paused)
+	.subscribe(NextStateDestination::new(
+		rx_schedule_update_virtual.handle(),
+	));
+
+	commands.insert_resource(MySubscriptions {
+		subscriptions: vec![
+			SharedSubscription::new(transitions_subscription),
+			SharedSubscription::new(menu_exit_subscription),
+		],
+	});
+}
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy_egui::EguiPlugin;
+use bevy_inspector_egui::quick::WorldInspectorPlugin;
+use examples_common::send_message;
+use rx_bevy::prelude::*;
+
+/// Scheduler Clocks
+///
+/// Every `RxSchedulerPlugin` has its own executor, driven by its own clock:
+/// - `Virtual` stops when the executor is paused, press P to pause and resume
+///   it. Only this executor is paused, `Time<Virtual>` keeps running.
+/// - `Real` keeps going regardless, perfect for pause menus.
+/// - `Fixed` is ticked in `FixedUpdate`, catching up with multiple ticks
+///   within a single frame when the frame took longer than the timestep.
+/// - `GameTurn` is a custom clock, press Space to end the turn.
+fn main() -> AppExit {
+	App::new()
+		.add_plugins((
+			DefaultPlugins,
+			EguiPlugin::default(),
+			WorldInspectorPlugin::new(),
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+			RxSchedulerPlugin::<Update, Real>::default(),
+			RxSchedulerPlugin::<FixedUpdate, Fixed>::default(),
+			RxSchedulerPlugin::<Update, GameTurn>::default(),
+		))
+		.init_resource::<GameTurn>()
+		.init_resource::<MySubscriptions>()
+		.insert_resource(Time::<Fixed>::from_hz(4.0))
+		.add_systems(Startup, setup)
+		.add_systems(
+			Update,
+			(
+				send_message(AppExit::Success).run_if(input_just_pressed(KeyCode::Escape)),
+				toggle_pause.run_if(input_just_pressed(KeyCode::KeyP)),
+				end_turn.run_if(input_just_pressed(KeyCode::Space)),
+			),
+		)
+		.run()
+}
+
+/// A turn is treated as a second, so an `interval` of a second emits once
+/// per turn.
+#[derive(Resource, Default)]
+struct GameTurn(u32);
+
+impl Clock for GameTurn {
+	fn tick(world: &World) -> Tick {
+		Tick::new(Duration::from_secs(world.resource::<GameTurn>().0 as u64))
+	}
+}
+
+fn end_turn(mut game_turn: ResMut<GameTurn>) {
+	game_turn.0 += 1;
+}
+
+fn toggle_pause(mut gameplay_executor: ResMut<RxBevyExecutor<Update, Virtual>>) {
+	if gameplay_executor.is_paused() {
+		println!("Resuming the gameplay executor");
+		gameplay_executor.unpause();
+	} else {
+		println!("Pausing the gameplay executor");
+		gameplay_executor.pause();
+	}
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct MySubscriptions(SharedSubscription);
+
+fn every_second(
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+) -> IntervalObservable<RxBevyScheduler> {
+	IntervalObservable::new(
+		IntervalObservableOptions {
+			duration: Duration::from_secs(1),
+			start_on_subscribe: false,
+			max_emissions_per_tick: 10,
+		},
+		scheduler,
+	)
+}
+
+fn setup(
+	rx_schedule_update_virtual: RxSchedule<Update, Virtual>,
+	rx_schedule_update_real: RxSchedule<Update, Real>,
+	rx_schedule_fixed_update_fixed: RxSchedule<FixedUpdate, Fixed>,
+	rx_schedule_update_game_turn: RxSchedule<Update, GameTurn>,
+	mut my_subscriptions: ResMut<MySubscriptions>,
+) {
+	println!("Press P to pause the virtual executor, Space to end the turn!");
+
+	my_subscriptions.add(
+		every_second(rx_schedule_update_virtual.handle()).subscribe(PrintObserver::new("virtual")),
+	);
+	my_subscriptions
+		.add(every_second(rx_schedule_update_real.handle()).subscribe(PrintObserver::new("real")));
+	my_subscriptions.add(
+		every_second(rx_schedule_fixed_update_fixed.handle())
+			.subscribe(PrintObserver::new("fixed")),
+	);
+	my_subscriptions.add(
+		every_second(rx_schedule_update_game_turn.handle())
+			.subscribe(PrintObserver::new("game_turn")),
+	);
+}
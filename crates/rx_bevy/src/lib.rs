@@ -3,16 +3,28 @@ pub use rx_bevy_common::*;
 pub mod observable {
 	pub use rx_core::observable::*;
 
+	#[cfg(feature = "observable_component")]
+	pub use rx_bevy_observable_component::observable::*;
 	#[cfg(feature = "observable_event")]
 	pub use rx_bevy_observable_event::observable::*;
+	#[cfg(feature = "observable_gamepad")]
+	pub use rx_bevy_observable_gamepad::observable::*;
 	#[cfg(feature = "observable_keyboard")]
 	pub use rx_bevy_observable_keyboard::observable::*;
+	#[cfg(feature = "observable_lifecycle")]
+	pub use rx_bevy_observable_lifecycle::observable::*;
 	#[cfg(feature = "observable_message")]
 	pub use rx_bevy_observable_message::observable::*;
+	#[cfg(feature = "observable_mouse")]
+	pub use rx_bevy_observable_mouse::observable::*;
 	#[cfg(feature = "observable_proxy")]
 	pub use rx_bevy_observable_proxy::observable::*;
 	#[cfg(feature = "observable_resource")]
 	pub use rx_bevy_observable_resource::observable::*;
+	#[cfg(feature = "observable_state")]
+	pub use rx_bevy_observable_state::observable::*;
+	#[cfg(feature = "observable_touch")]
+	pub use rx_bevy_observable_touch::observable::*;
 }
 
 #[cfg(feature = "observable_fn")]
@@ -36,6 +48,9 @@ pub mod extension_pipe {
 
 pub mod observer {
 	pub use rx_core::observer::*;
+
+	#[cfg(feature = "observable_state")]
+	pub use rx_bevy_observable_state::observer::*;
 }
 
 pub mod subject {
@@ -0,0 +1,199 @@
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use rx_bevy::prelude::*;
+
+#[derive(Component, Clone, Debug, PartialEq)]
+struct Health(usize);
+
+mod insert {
+	use super::*;
+
+	#[test]
+	fn should_insert_the_component_only_if_it_does_not_exist_yet() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity = app.world_mut().spawn_empty().id();
+
+		let mut destination =
+			ComponentDestination::<Health>::insert(entity, scheduler_handle).upgrade();
+
+		destination.next(Health(1));
+		app.update();
+		assert_eq!(app.world().get::<Health>(entity), Some(&Health(1)));
+
+		destination.next(Health(2));
+		app.update();
+		assert_eq!(app.world().get::<Health>(entity), Some(&Health(1)));
+	}
+}
+
+mod replace {
+	use super::*;
+
+	#[test]
+	fn should_replace_the_component_with_each_value() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity = app.world_mut().spawn(Health(0)).id();
+
+		let mut destination =
+			ComponentDestination::<Health>::replace(entity, scheduler_handle).upgrade();
+
+		destination.next(Health(1));
+		app.update();
+		assert_eq!(app.world().get::<Health>(entity), Some(&Health(1)));
+
+		destination.next(Health(2));
+		app.update();
+		assert_eq!(app.world().get::<Health>(entity), Some(&Health(2)));
+	}
+
+	#[test]
+	fn should_remove_the_component_on_complete_when_enabled() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity = app.world_mut().spawn_empty().id();
+
+		let mut destination = ComponentDestination::<Health>::replace(entity, scheduler_handle)
+			.with_remove_on_complete(true)
+			.upgrade();
+
+		destination.next(Health(1));
+		app.update();
+		assert_eq!(app.world().get::<Health>(entity), Some(&Health(1)));
+
+		destination.complete();
+		app.update();
+		assert_eq!(app.world().get::<Health>(entity), None);
+		assert!(destination.is_closed(), "rx_verify_closed");
+	}
+
+	#[test]
+	fn should_keep_the_component_on_complete_by_default() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity = app.world_mut().spawn_empty().id();
+
+		let mut destination =
+			ComponentDestination::<Health>::replace(entity, scheduler_handle).upgrade();
+
+		destination.next(Health(1));
+		destination.complete();
+		app.update();
+		assert_eq!(app.world().get::<Health>(entity), Some(&Health(1)));
+	}
+
+	#[test]
+	fn should_not_fail_when_the_entity_is_despawned() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity = app.world_mut().spawn_empty().id();
+
+		let mut destination = ComponentDestination::<Health>::replace(entity, scheduler_handle)
+			.with_remove_on_complete(true)
+			.upgrade();
+
+		app.world_mut().despawn(entity);
+
+		destination.next(Health(1));
+		destination.complete();
+		app.update();
+	}
+}
+
+mod mutate {
+	use super::*;
+
+	#[test]
+	fn should_mutate_the_existing_component() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity = app.world_mut().spawn(Health(10)).id();
+
+		let mut destination = ComponentDestination::<Health, usize>::mutate(
+			entity,
+			|mut health, damage| health.0 -= damage,
+			scheduler_handle,
+		)
+		.upgrade();
+
+		destination.next(3);
+		destination.next(2);
+		app.update();
+		assert_eq!(app.world().get::<Health>(entity), Some(&Health(5)));
+	}
+
+	#[test]
+	fn should_do_nothing_when_the_component_does_not_exist() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity = app.world_mut().spawn_empty().id();
+
+		let mut destination = ComponentDestination::<Health, usize>::mutate(
+			entity,
+			|mut health, damage| health.0 -= damage,
+			scheduler_handle,
+		)
+		.upgrade();
+
+		destination.next(3);
+		app.update();
+		assert_eq!(app.world().get::<Health>(entity), None);
+	}
+}
@@ -0,0 +1,757 @@
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use rx_bevy::prelude::*;
+use rx_core_testing::prelude::*;
+
+#[derive(Component, Clone, Debug, PartialEq, Default)]
+struct TestComponent {
+	pub value: usize,
+}
+
+#[derive(Component, Default)]
+struct TestMarker;
+
+mod component_observable {
+	use super::*;
+
+	#[test]
+	fn should_observe_additions_changes_and_removals_by_default() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity = app.world_mut().spawn(TestComponent::default()).id();
+
+		let mut component_observable = ComponentObservable::<TestComponent, _, usize>::new(
+			entity,
+			|test_component: &TestComponent| test_component.value,
+			ComponentObservableOptions::default(),
+			scheduler_handle,
+		);
+
+		let destination = MockObserver::<ComponentChange<usize>, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = component_observable.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("component_observable");
+
+		app.update();
+
+		app.world_mut()
+			.get_mut::<TestComponent>(entity)
+			.unwrap()
+			.value += 1;
+		app.update();
+
+		app.world_mut().entity_mut(entity).remove::<TestComponent>();
+		app.update();
+
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(TestComponent { value: 10 });
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"component_observable",
+			0,
+			[
+				SubscriberNotification::Next(ComponentChange::Added(0)),
+				SubscriberNotification::Next(ComponentChange::Changed(1)),
+				SubscriberNotification::Next(ComponentChange::Removed),
+				SubscriberNotification::Next(ComponentChange::Added(10)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+	}
+
+	#[test]
+	fn should_only_observe_changes_when_the_other_triggers_are_disabled() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity = app.world_mut().spawn(TestComponent::default()).id();
+
+		let mut component_observable = ComponentObservable::<TestComponent, _, usize>::new(
+			entity,
+			|test_component: &TestComponent| test_component.value,
+			ComponentObservableOptions {
+				trigger_on_is_added: false,
+				trigger_on_is_changed: true,
+				trigger_on_is_removed: false,
+			},
+			scheduler_handle,
+		);
+
+		let destination = MockObserver::<ComponentChange<usize>, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = component_observable.subscribe(destination);
+
+		app.update();
+		app.update();
+
+		app.world_mut()
+			.get_mut::<TestComponent>(entity)
+			.unwrap()
+			.value += 1;
+		app.update();
+
+		app.world_mut().entity_mut(entity).remove::<TestComponent>();
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"component_observable",
+			0,
+			[
+				SubscriberNotification::Next(ComponentChange::Changed(1)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_emit_a_removal_and_complete_when_the_entity_is_despawned() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity = app.world_mut().spawn(TestComponent::default()).id();
+
+		let mut component_observable = ComponentObservable::<TestComponent, _, usize>::new(
+			entity,
+			|test_component: &TestComponent| test_component.value,
+			ComponentObservableOptions::default(),
+			scheduler_handle,
+		);
+
+		let destination = MockObserver::<ComponentChange<usize>, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = component_observable.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("component_observable");
+
+		app.update();
+
+		app.world_mut().despawn(entity);
+		app.update();
+
+		notification_collector.lock().assert_notifications(
+			"component_observable",
+			0,
+			[
+				SubscriberNotification::Next(ComponentChange::Added(0)),
+				SubscriberNotification::Next(ComponentChange::Removed),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		app.update();
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+	}
+}
+
+mod component_query_observable {
+	use super::*;
+
+	#[test]
+	fn should_emit_every_added_and_changed_component_with_its_entity() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let entity_a = app.world_mut().spawn(TestComponent { value: 1 }).id();
+		let entity_b = app.world_mut().spawn(TestComponent { value: 2 }).id();
+
+		let mut component_query_observable = ComponentQueryObservable::<TestComponent>::new(
+			ComponentQueryObservableOptions::default(),
+			scheduler_handle,
+		);
+
+		let destination = MockObserver::<(Entity, TestComponent), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = component_query_observable.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("component_query_observable");
+
+		app.update();
+		assert_eq!(notification_collector.lock().count_observed_nexts(), 2);
+
+		app.update();
+		assert_eq!(notification_collector.lock().count_observed_nexts(), 2);
+
+		app.world_mut()
+			.get_mut::<TestComponent>(entity_b)
+			.unwrap()
+			.value += 1;
+		let entity_c = app.world_mut().spawn(TestComponent { value: 5 }).id();
+		app.update();
+
+		subscription.unsubscribe();
+
+		// The iteration order of the query is not guaranteed within a tick
+		let values = notification_collector.lock().all_observed_values();
+		assert_eq!(values.len(), 4);
+		assert!(values[..2].contains(&(entity_a, TestComponent { value: 1 })));
+		assert!(values[..2].contains(&(entity_b, TestComponent { value: 2 })));
+		assert!(values[2..].contains(&(entity_b, TestComponent { value: 3 })));
+		assert!(values[2..].contains(&(entity_c, TestComponent { value: 5 })));
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+	}
+
+	#[test]
+	fn should_only_emit_for_entities_matching_the_filter() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		app.world_mut().spawn(TestComponent { value: 1 });
+		let marked_entity = app
+			.world_mut()
+			.spawn((TestComponent { value: 2 }, TestMarker))
+			.id();
+
+		let mut component_query_observable =
+			ComponentQueryObservable::<TestComponent, With<TestMarker>>::new(
+				ComponentQueryObservableOptions {
+					trigger_on_is_added: false,
+					trigger_on_is_changed: true,
+				},
+				scheduler_handle,
+			);
+
+		let destination = MockObserver::<(Entity, TestComponent), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = component_query_observable.subscribe(destination);
+
+		app.update();
+
+		let world = app.world_mut();
+		let mut test_component_query = world.query::<&mut TestComponent>();
+		for mut test_component in test_component_query.iter_mut(world) {
+			test_component.value += 1;
+		}
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"component_query_observable",
+			0,
+			[
+				SubscriberNotification::Next((marked_entity, TestComponent { value: 3 })),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		app.update();
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+	}
+}
+
+/// Non Applicable:
+/// - rx_contract_closed_after_complete - Can't Complete
+/// - rx_contract_closed_after_error - Can't Error
+mod contracts {
+	use super::*;
+
+	/// Only changes are observed, so nothing is emitted when subscribing.
+	mod component_observable {
+		use super::*;
+
+		#[test]
+		fn rx_contract_closed_after_unsubscribe() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let entity = app.world_mut().spawn(TestComponent::default()).id();
+
+			let mut observable = ComponentObservable::<TestComponent, _, usize>::new(
+				entity,
+				|test_component: &TestComponent| test_component.value,
+				ComponentObservableOptions {
+					trigger_on_is_added: false,
+					trigger_on_is_changed: true,
+					trigger_on_is_removed: false,
+				},
+				scheduler_handle,
+			);
+
+			let destination = MockObserver::<ComponentChange<usize>, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("component_observable");
+
+			app.update();
+
+			app.world_mut()
+				.get_mut::<TestComponent>(entity)
+				.unwrap()
+				.value += 1;
+			app.update();
+
+			app.world_mut()
+				.get_mut::<TestComponent>(entity)
+				.unwrap()
+				.value += 1;
+			app.update();
+
+			subscription.unsubscribe();
+
+			notification_collector.lock().assert_notifications(
+				"component_observable",
+				0,
+				[
+					SubscriberNotification::Next(ComponentChange::Changed(1)),
+					SubscriberNotification::Next(ComponentChange::Changed(2)),
+					SubscriberNotification::Unsubscribe,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"component_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_early() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let entity = app.world_mut().spawn(TestComponent::default()).id();
+
+			let mut observable = ComponentObservable::<TestComponent, _, usize>::new(
+				entity,
+				|test_component: &TestComponent| test_component.value,
+				ComponentObservableOptions {
+					trigger_on_is_added: false,
+					trigger_on_is_changed: true,
+					trigger_on_is_removed: false,
+				},
+				scheduler_handle,
+			)
+			.take(2);
+
+			let destination = MockObserver::<ComponentChange<usize>, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("component_observable");
+
+			app.update();
+
+			app.world_mut()
+				.get_mut::<TestComponent>(entity)
+				.unwrap()
+				.value += 1;
+			app.update();
+
+			app.world_mut()
+				.get_mut::<TestComponent>(entity)
+				.unwrap()
+				.value += 1;
+			app.update();
+
+			app.world_mut()
+				.get_mut::<TestComponent>(entity)
+				.unwrap()
+				.value += 1;
+			app.update();
+
+			notification_collector.lock().assert_notifications(
+				"component_observable",
+				0,
+				[
+					SubscriberNotification::Next(ComponentChange::Changed(1)),
+					SubscriberNotification::Next(ComponentChange::Changed(2)),
+					SubscriberNotification::Complete,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"component_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_immediately() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let entity = app.world_mut().spawn(TestComponent::default()).id();
+
+			let mut observable = ComponentObservable::<TestComponent, _, usize>::new(
+				entity,
+				|test_component: &TestComponent| test_component.value,
+				ComponentObservableOptions {
+					trigger_on_is_added: false,
+					trigger_on_is_changed: true,
+					trigger_on_is_removed: false,
+				},
+				scheduler_handle,
+			)
+			.take(0);
+
+			let destination = MockObserver::<ComponentChange<usize>, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("component_observable");
+
+			notification_collector.lock().assert_notifications(
+				"component_observable",
+				0,
+				[SubscriberNotification::Complete],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"component_observable - rx_verify_no_new_notification_after_closed",
+					0,
+				);
+		}
+	}
+
+	/// Only changes are observed, so nothing is emitted when subscribing.
+	mod component_query_observable {
+		use super::*;
+
+		#[test]
+		fn rx_contract_closed_after_unsubscribe() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let entity = app.world_mut().spawn(TestComponent::default()).id();
+
+			let mut observable = ComponentQueryObservable::<TestComponent>::new(
+				ComponentQueryObservableOptions {
+					trigger_on_is_added: false,
+					trigger_on_is_changed: true,
+				},
+				scheduler_handle,
+			);
+
+			let destination = MockObserver::<(Entity, TestComponent), Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("component_query_observable");
+
+			app.update();
+
+			app.world_mut()
+				.get_mut::<TestComponent>(entity)
+				.unwrap()
+				.value += 1;
+			app.update();
+
+			app.world_mut()
+				.get_mut::<TestComponent>(entity)
+				.unwrap()
+				.value += 1;
+			app.update();
+
+			subscription.unsubscribe();
+
+			notification_collector.lock().assert_notifications(
+				"component_query_observable",
+				0,
+				[
+					SubscriberNotification::Next((entity, TestComponent { value: 1 })),
+					SubscriberNotification::Next((entity, TestComponent { value: 2 })),
+					SubscriberNotification::Unsubscribe,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"component_query_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_early() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let entity = app.world_mut().spawn(TestComponent::default()).id();
+
+			let mut observable = ComponentQueryObservable::<TestComponent>::new(
+				ComponentQueryObservableOptions {
+					trigger_on_is_added: false,
+					trigger_on_is_changed: true,
+				},
+				scheduler_handle,
+			)
+			.take(2);
+
+			let destination = MockObserver::<(Entity, TestComponent), Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("component_query_observable");
+
+			app.update();
+
+			app.world_mut()
+				.get_mut::<TestComponent>(entity)
+				.unwrap()
+				.value += 1;
+			app.update();
+
+			app.world_mut()
+				.get_mut::<TestComponent>(entity)
+				.unwrap()
+				.value += 1;
+			app.update();
+
+			app.world_mut()
+				.get_mut::<TestComponent>(entity)
+				.unwrap()
+				.value += 1;
+			app.update();
+
+			notification_collector.lock().assert_notifications(
+				"component_query_observable",
+				0,
+				[
+					SubscriberNotification::Next((entity, TestComponent { value: 1 })),
+					SubscriberNotification::Next((entity, TestComponent { value: 2 })),
+					SubscriberNotification::Complete,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"component_query_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_immediately() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = ComponentQueryObservable::<TestComponent>::new(
+				ComponentQueryObservableOptions {
+					trigger_on_is_added: false,
+					trigger_on_is_changed: true,
+				},
+				scheduler_handle,
+			)
+			.take(0);
+
+			let destination = MockObserver::<(Entity, TestComponent), Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("component_query_observable");
+
+			notification_collector.lock().assert_notifications(
+				"component_query_observable",
+				0,
+				[SubscriberNotification::Complete],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"component_query_observable - rx_verify_no_new_notification_after_closed",
+					0,
+				);
+		}
+	}
+}
@@ -0,0 +1,668 @@
+use bevy::ecs::system::SystemState;
+use bevy::input::gamepad::Gamepad;
+use bevy::prelude::*;
+use rx_bevy::prelude::*;
+use rx_core_testing::prelude::*;
+
+mod gamepad_button_observable {
+	use super::*;
+
+	#[test]
+	fn should_observe_just_pressed_buttons_of_all_gamepads() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+
+		let destination = MockObserver::<(Entity, GamepadButton), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = GamepadButtonObservable::new(
+			GamepadButtonObservableOptions::default(),
+			scheduler_handle,
+		)
+		.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("gamepad_button_observable");
+
+		app.update();
+
+		app.world_mut()
+			.get_mut::<Gamepad>(gamepad)
+			.unwrap()
+			.digital_mut()
+			.press(GamepadButton::South);
+		app.update();
+
+		app.world_mut()
+			.get_mut::<Gamepad>(gamepad)
+			.unwrap()
+			.digital_mut()
+			.clear_just_pressed(GamepadButton::South);
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"gamepad_button_observable",
+			0,
+			[
+				SubscriberNotification::Next((gamepad, GamepadButton::South)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		app.update();
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty()
+		);
+	}
+
+	#[test]
+	fn should_only_observe_the_selected_gamepad() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let gamepad_a = app.world_mut().spawn(Gamepad::default()).id();
+		let gamepad_b = app.world_mut().spawn(Gamepad::default()).id();
+
+		let destination = MockObserver::<(Entity, GamepadButton), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = GamepadButtonObservable::new(
+			GamepadButtonObservableOptions {
+				emit: GamepadButtonObservableEmit::JustPressed,
+				gamepad: Some(gamepad_b),
+			},
+			scheduler_handle,
+		)
+		.subscribe(destination);
+
+		for gamepad in [gamepad_a, gamepad_b] {
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.digital_mut()
+				.press(GamepadButton::East);
+		}
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"gamepad_button_observable",
+			0,
+			[
+				SubscriberNotification::Next((gamepad_b, GamepadButton::East)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+	}
+}
+
+mod gamepad_axis_observable {
+	use super::*;
+
+	#[test]
+	fn should_observe_changes_and_snap_the_dead_zone_to_zero() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+
+		let destination = MockObserver::<(Entity, GamepadAxis, f32), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription =
+			GamepadAxisObservable::new(GamepadAxisObservableOptions::default(), scheduler_handle)
+				.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("gamepad_axis_observable");
+
+		app.update();
+
+		app.world_mut()
+			.get_mut::<Gamepad>(gamepad)
+			.unwrap()
+			.analog_mut()
+			.set(GamepadAxis::LeftStickX, 0.5);
+		app.update();
+		app.update(); // Unchanged
+
+		app.world_mut()
+			.get_mut::<Gamepad>(gamepad)
+			.unwrap()
+			.analog_mut()
+			.set(GamepadAxis::LeftStickX, 0.05);
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"gamepad_axis_observable",
+			0,
+			[
+				SubscriberNotification::Next((gamepad, GamepadAxis::LeftStickX, 0.5)),
+				SubscriberNotification::Next((gamepad, GamepadAxis::LeftStickX, 0.0)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+	}
+
+	#[test]
+	fn should_observe_active_axes_every_frame() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+
+		let destination = MockObserver::<(Entity, GamepadAxis, f32), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = GamepadAxisObservable::new(
+			GamepadAxisObservableOptions {
+				emit: GamepadAxisObservableEmit::WhileActive,
+				..Default::default()
+			},
+			scheduler_handle,
+		)
+		.subscribe(destination);
+
+		app.world_mut()
+			.get_mut::<Gamepad>(gamepad)
+			.unwrap()
+			.analog_mut()
+			.set(GamepadAxis::RightStickY, -1.0);
+		app.update();
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"gamepad_axis_observable",
+			0,
+			[
+				SubscriberNotification::Next((gamepad, GamepadAxis::RightStickY, -1.0)),
+				SubscriberNotification::Next((gamepad, GamepadAxis::RightStickY, -1.0)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+	}
+}
+
+/// Non Applicable:
+/// - rx_contract_closed_after_complete - Can't Complete
+/// - rx_contract_closed_after_error - Can't Error
+mod contracts {
+	use super::*;
+
+	mod gamepad_button_observable {
+		use super::*;
+
+		#[test]
+		fn rx_contract_closed_after_unsubscribe() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+
+			let mut observable = GamepadButtonObservable::new(
+				GamepadButtonObservableOptions::default(),
+				scheduler_handle,
+			);
+
+			let destination = MockObserver::<(Entity, GamepadButton), Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("gamepad_button_observable");
+
+			app.update();
+
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.digital_mut()
+				.press(GamepadButton::South);
+			app.update();
+
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.digital_mut()
+				.clear_just_pressed(GamepadButton::South);
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.digital_mut()
+				.press(GamepadButton::East);
+			app.update();
+
+			subscription.unsubscribe();
+
+			notification_collector.lock().assert_notifications(
+				"gamepad_button_observable",
+				0,
+				[
+					SubscriberNotification::Next((gamepad, GamepadButton::South)),
+					SubscriberNotification::Next((gamepad, GamepadButton::East)),
+					SubscriberNotification::Unsubscribe,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"gamepad_button_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_early() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+
+			let mut observable = GamepadButtonObservable::new(
+				GamepadButtonObservableOptions::default(),
+				scheduler_handle,
+			)
+			.take(2);
+
+			let destination = MockObserver::<(Entity, GamepadButton), Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("gamepad_button_observable");
+
+			app.update();
+
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.digital_mut()
+				.press(GamepadButton::South);
+			app.update();
+
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.digital_mut()
+				.clear_just_pressed(GamepadButton::South);
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.digital_mut()
+				.press(GamepadButton::East);
+			app.update();
+
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.digital_mut()
+				.clear_just_pressed(GamepadButton::East);
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.digital_mut()
+				.press(GamepadButton::North);
+			app.update();
+
+			notification_collector.lock().assert_notifications(
+				"gamepad_button_observable",
+				0,
+				[
+					SubscriberNotification::Next((gamepad, GamepadButton::South)),
+					SubscriberNotification::Next((gamepad, GamepadButton::East)),
+					SubscriberNotification::Complete,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"gamepad_button_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_immediately() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = GamepadButtonObservable::new(
+				GamepadButtonObservableOptions::default(),
+				scheduler_handle,
+			)
+			.take(0);
+
+			let destination = MockObserver::<(Entity, GamepadButton), Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("gamepad_button_observable");
+
+			notification_collector.lock().assert_notifications(
+				"gamepad_button_observable",
+				0,
+				[SubscriberNotification::Complete],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"gamepad_button_observable - rx_verify_no_new_notification_after_closed",
+					0,
+				);
+		}
+	}
+
+	mod gamepad_axis_observable {
+		use super::*;
+
+		#[test]
+		fn rx_contract_closed_after_unsubscribe() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+
+			let mut observable = GamepadAxisObservable::new(
+				GamepadAxisObservableOptions::default(),
+				scheduler_handle,
+			);
+
+			let destination = MockObserver::<(Entity, GamepadAxis, f32), Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("gamepad_axis_observable");
+
+			app.update();
+
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.analog_mut()
+				.set(GamepadAxis::LeftStickX, 0.5);
+			app.update();
+
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.analog_mut()
+				.set(GamepadAxis::LeftStickX, 0.75);
+			app.update();
+
+			subscription.unsubscribe();
+
+			notification_collector.lock().assert_notifications(
+				"gamepad_axis_observable",
+				0,
+				[
+					SubscriberNotification::Next((gamepad, GamepadAxis::LeftStickX, 0.5)),
+					SubscriberNotification::Next((gamepad, GamepadAxis::LeftStickX, 0.75)),
+					SubscriberNotification::Unsubscribe,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"gamepad_axis_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_early() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+
+			let mut observable = GamepadAxisObservable::new(
+				GamepadAxisObservableOptions::default(),
+				scheduler_handle,
+			)
+			.take(2);
+
+			let destination = MockObserver::<(Entity, GamepadAxis, f32), Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("gamepad_axis_observable");
+
+			app.update();
+
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.analog_mut()
+				.set(GamepadAxis::LeftStickX, 0.5);
+			app.update();
+
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.analog_mut()
+				.set(GamepadAxis::LeftStickX, 0.75);
+			app.update();
+
+			app.world_mut()
+				.get_mut::<Gamepad>(gamepad)
+				.unwrap()
+				.analog_mut()
+				.set(GamepadAxis::LeftStickX, 1.0);
+			app.update();
+
+			notification_collector.lock().assert_notifications(
+				"gamepad_axis_observable",
+				0,
+				[
+					SubscriberNotification::Next((gamepad, GamepadAxis::LeftStickX, 0.5)),
+					SubscriberNotification::Next((gamepad, GamepadAxis::LeftStickX, 0.75)),
+					SubscriberNotification::Complete,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"gamepad_axis_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_immediately() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = GamepadAxisObservable::new(
+				GamepadAxisObservableOptions::default(),
+				scheduler_handle,
+			)
+			.take(0);
+
+			let destination = MockObserver::<(Entity, GamepadAxis, f32), Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("gamepad_axis_observable");
+
+			notification_collector.lock().assert_notifications(
+				"gamepad_axis_observable",
+				0,
+				[SubscriberNotification::Complete],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"gamepad_axis_observable - rx_verify_no_new_notification_after_closed",
+					0,
+				);
+		}
+	}
+}
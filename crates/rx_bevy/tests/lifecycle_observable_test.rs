@@ -0,0 +1,439 @@
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use rx_bevy::prelude::*;
+use rx_core_testing::prelude::*;
+
+#[derive(Component)]
+struct Enemy;
+
+#[derive(Component)]
+struct Boss;
+
+mod entity_spawn_observable {
+	use super::*;
+
+	#[test]
+	fn should_emit_entities_spawned_with_the_component_matching_the_filter() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<Entity, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = EntitySpawnObservable::<Enemy, Without<Boss>>::new(scheduler_handle)
+			.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("entity_spawn_observable");
+
+		app.update();
+
+		let enemy = app.world_mut().spawn(Enemy).id();
+		let _boss = app.world_mut().spawn((Enemy, Boss)).id();
+		let _other = app.world_mut().spawn_empty().id();
+		let late_enemy = app.world_mut().spawn_empty().insert(Enemy).id();
+
+		subscription.unsubscribe();
+		app.update();
+
+		let _unobserved_enemy = app.world_mut().spawn(Enemy).id();
+
+		notification_collector.lock().assert_notifications(
+			"entity_spawn_observable",
+			0,
+			[
+				SubscriberNotification::Next(enemy),
+				SubscriberNotification::Next(late_enemy),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+	}
+
+	#[test]
+	fn should_despawn_its_observer_when_unsubscribed() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let mut subscription = EntitySpawnObservable::<Enemy>::new(scheduler_handle)
+			.subscribe(MockObserver::<Entity, Never>::default());
+
+		app.update();
+		let observer_count = app
+			.world_mut()
+			.query::<&Observer>()
+			.iter(app.world())
+			.count();
+
+		subscription.unsubscribe();
+		app.update();
+
+		assert_eq!(
+			app.world_mut()
+				.query::<&Observer>()
+				.iter(app.world())
+				.count(),
+			observer_count - 1
+		);
+	}
+}
+
+mod entity_despawn_observable {
+	use super::*;
+
+	#[test]
+	fn should_emit_entities_despawned_with_the_component_matching_the_filter() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<Entity, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription =
+			EntityDespawnObservable::<Enemy, Without<Boss>>::new(scheduler_handle)
+				.subscribe(destination);
+
+		app.update();
+
+		let enemy = app.world_mut().spawn(Enemy).id();
+		let boss = app.world_mut().spawn((Enemy, Boss)).id();
+		let removed_enemy = app.world_mut().spawn(Enemy).id();
+
+		app.world_mut().entity_mut(removed_enemy).remove::<Enemy>();
+		app.world_mut().despawn(boss);
+		app.world_mut().despawn(enemy);
+		app.world_mut().despawn(removed_enemy);
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"entity_despawn_observable",
+			0,
+			[
+				SubscriberNotification::Next(enemy),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+	}
+}
+
+mod children_observable {
+	use super::*;
+
+	#[test]
+	fn should_emit_children_added_to_and_removed_from_the_parent() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let parent = app.world_mut().spawn_empty().id();
+		let other_parent = app.world_mut().spawn_empty().id();
+
+		let destination = MockObserver::<ChildrenChange, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription =
+			ChildrenObservable::new(parent, scheduler_handle).subscribe(destination);
+
+		app.update();
+
+		let child_a = app.world_mut().spawn(ChildOf(parent)).id();
+		let child_b = app.world_mut().spawn(ChildOf(parent)).id();
+		let _unrelated_child = app.world_mut().spawn(ChildOf(other_parent)).id();
+
+		app.world_mut().entity_mut(child_a).remove::<ChildOf>();
+		app.world_mut()
+			.entity_mut(child_b)
+			.insert(ChildOf(other_parent));
+		app.world_mut().entity_mut(child_b).insert(ChildOf(parent));
+		app.world_mut().despawn(child_b);
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"children_observable",
+			0,
+			[
+				SubscriberNotification::Next(ChildrenChange::Added(child_a)),
+				SubscriberNotification::Next(ChildrenChange::Added(child_b)),
+				SubscriberNotification::Next(ChildrenChange::Removed(child_a)),
+				SubscriberNotification::Next(ChildrenChange::Removed(child_b)),
+				SubscriberNotification::Next(ChildrenChange::Added(child_b)),
+				SubscriberNotification::Next(ChildrenChange::Removed(child_b)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_complete_when_the_parent_is_despawned() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let parent = app.world_mut().spawn_empty().id();
+
+		let observer_count = app
+			.world_mut()
+			.query::<&Observer>()
+			.iter(app.world())
+			.count();
+
+		let destination = MockObserver::<ChildrenChange, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let subscription = ChildrenObservable::new(parent, scheduler_handle).subscribe(destination);
+
+		app.update();
+		app.world_mut().despawn(parent);
+		app.update();
+
+		notification_collector.lock().assert_notifications(
+			"children_observable",
+			0,
+			[SubscriberNotification::Complete],
+			true,
+		);
+
+		assert!(subscription.is_closed());
+		assert_eq!(
+			app.world_mut()
+				.query::<&Observer>()
+				.iter(app.world())
+				.count(),
+			observer_count,
+			"The observers should be despawned once completed"
+		);
+	}
+}
+
+/// Non Applicable:
+/// - rx_contract_closed_after_error - Can't Error
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let parent = app.world_mut().spawn_empty().id();
+
+		let destination = MockObserver::<ChildrenChange, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription =
+			ChildrenObservable::new(parent, scheduler_handle).subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("children_observable");
+
+		app.update();
+		app.world_mut().despawn(parent);
+		app.update();
+
+		notification_collector.lock().assert_notifications(
+			"children_observable",
+			0,
+			[SubscriberNotification::Complete],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<Entity, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription =
+			EntitySpawnObservable::<Enemy>::new(scheduler_handle).subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("entity_spawn_observable");
+
+		app.update();
+		let enemy = app.world_mut().spawn(Enemy).id();
+		subscription.unsubscribe();
+		app.update();
+		app.world_mut().spawn(Enemy);
+
+		notification_collector.lock().assert_notifications(
+			"entity_spawn_observable",
+			0,
+			[
+				SubscriberNotification::Next(enemy),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+	}
+
+	#[test]
+	fn rx_contract_closed_if_downstream_closes_early() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<Entity, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = EntitySpawnObservable::<Enemy>::new(scheduler_handle)
+			.take(1)
+			.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("entity_spawn_observable");
+
+		app.update();
+		let enemy = app.world_mut().spawn(Enemy).id();
+		app.update();
+		app.world_mut().spawn(Enemy);
+
+		notification_collector.lock().assert_notifications(
+			"entity_spawn_observable",
+			0,
+			[
+				SubscriberNotification::Next(enemy),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+
+		subscription.unsubscribe();
+		notification_collector
+			.lock()
+			.assert_nth_notification_is_last(
+				"entity_spawn_observable - rx_verify_no_new_notification_after_closed",
+				1,
+			);
+	}
+
+	#[test]
+	fn rx_contract_closed_if_downstream_closes_immediately() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<Entity, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = EntitySpawnObservable::<Enemy>::new(scheduler_handle)
+			.take(0)
+			.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("entity_spawn_observable");
+
+		notification_collector.lock().assert_notifications(
+			"entity_spawn_observable",
+			0,
+			[SubscriberNotification::Complete],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+
+		subscription.unsubscribe();
+		notification_collector
+			.lock()
+			.assert_nth_notification_is_last(
+				"entity_spawn_observable - rx_verify_no_new_notification_after_closed",
+				0,
+			);
+	}
+}
@@ -0,0 +1,72 @@
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use rx_bevy::prelude::*;
+
+#[derive(Message, Clone, Debug, PartialEq)]
+struct TestMessage {
+	pub value: usize,
+}
+
+fn read_messages(app: &mut App) -> Vec<TestMessage> {
+	let mut messages = SystemState::<MessageReader<TestMessage>>::new(app.world_mut());
+	messages.get_mut(app.world_mut()).read().cloned().collect()
+}
+
+#[test]
+fn should_write_every_observed_value_as_a_message() {
+	let mut app = App::new();
+	app.init_resource::<Time<Virtual>>();
+	app.add_message::<TestMessage>();
+	app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+	let scheduler_handle = {
+		let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+			.get_mut(app.world_mut());
+		scheduler.handle()
+	};
+
+	let mut destination = MessageDestination::<TestMessage>::new(scheduler_handle).upgrade();
+
+	destination.next(TestMessage { value: 1 });
+	destination.next(TestMessage { value: 2 });
+	destination.complete();
+
+	app.update();
+
+	assert_eq!(
+		read_messages(&mut app),
+		vec![TestMessage { value: 1 }, TestMessage { value: 2 }]
+	);
+	assert!(destination.is_closed(), "rx_verify_closed");
+}
+
+#[test]
+fn should_write_the_messages_of_a_pipeline() {
+	let mut app = App::new();
+	app.init_resource::<Time<Virtual>>();
+	app.add_message::<TestMessage>();
+	app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+	let scheduler_handle = {
+		let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+			.get_mut(app.world_mut());
+		scheduler.handle()
+	};
+
+	let subscription = (1..=3)
+		.into_observable()
+		.map(|value| TestMessage { value })
+		.subscribe(MessageDestination::new(scheduler_handle));
+
+	app.update();
+
+	assert_eq!(
+		read_messages(&mut app),
+		vec![
+			TestMessage { value: 1 },
+			TestMessage { value: 2 },
+			TestMessage { value: 3 }
+		]
+	);
+	assert!(subscription.is_closed());
+}
@@ -0,0 +1,839 @@
+use bevy::ecs::system::SystemState;
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll};
+use bevy::prelude::*;
+use rx_bevy::prelude::*;
+use rx_core_testing::prelude::*;
+
+mod mouse_button_observable {
+	use super::*;
+
+	#[test]
+	fn should_observe_just_pressed_buttons() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.init_resource::<ButtonInput<MouseButton>>();
+		app.init_resource::<AccumulatedMouseMotion>();
+		app.init_resource::<AccumulatedMouseScroll>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<MouseButton, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = MouseButtonObservable::new(
+			MouseButtonObservableOptions {
+				emit: MouseButtonObservableEmit::JustPressed,
+			},
+			scheduler_handle,
+		)
+		.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("mouse_button_observable");
+
+		app.update();
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<MouseButton>>()
+			.press(MouseButton::Left);
+		app.update();
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<MouseButton>>()
+			.clear_just_pressed(MouseButton::Left);
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"mouse_button_observable",
+			0,
+			[
+				SubscriberNotification::Next(MouseButton::Left),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		app.update();
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty()
+		);
+	}
+
+	#[test]
+	fn should_observe_held_buttons_every_frame() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.init_resource::<ButtonInput<MouseButton>>();
+		app.init_resource::<AccumulatedMouseMotion>();
+		app.init_resource::<AccumulatedMouseScroll>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<MouseButton, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = MouseButtonObservable::new(
+			MouseButtonObservableOptions {
+				emit: MouseButtonObservableEmit::WhilePressed,
+			},
+			scheduler_handle,
+		)
+		.subscribe(destination);
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<MouseButton>>()
+			.press(MouseButton::Right);
+		app.update();
+		app.update();
+
+		app.world_mut()
+			.resource_mut::<ButtonInput<MouseButton>>()
+			.release(MouseButton::Right);
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"mouse_button_observable",
+			0,
+			[
+				SubscriberNotification::Next(MouseButton::Right),
+				SubscriberNotification::Next(MouseButton::Right),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+	}
+}
+
+mod mouse_motion_observable {
+	use super::*;
+
+	#[test]
+	fn should_only_observe_non_zero_motion() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.init_resource::<ButtonInput<MouseButton>>();
+		app.init_resource::<AccumulatedMouseMotion>();
+		app.init_resource::<AccumulatedMouseScroll>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<Vec2, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = MouseMotionObservable::new(scheduler_handle).subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("mouse_motion_observable");
+
+		app.update();
+
+		app.world_mut()
+			.resource_mut::<AccumulatedMouseMotion>()
+			.delta = Vec2::new(1.0, 2.0);
+		app.update();
+
+		app.world_mut()
+			.resource_mut::<AccumulatedMouseMotion>()
+			.delta = Vec2::ZERO;
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"mouse_motion_observable",
+			0,
+			[
+				SubscriberNotification::Next(Vec2::new(1.0, 2.0)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+	}
+}
+
+mod mouse_wheel_observable {
+	use super::*;
+
+	#[test]
+	fn should_only_observe_non_zero_scrolling() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.init_resource::<ButtonInput<MouseButton>>();
+		app.init_resource::<AccumulatedMouseMotion>();
+		app.init_resource::<AccumulatedMouseScroll>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<AccumulatedMouseScroll, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = MouseWheelObservable::new(scheduler_handle).subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("mouse_wheel_observable");
+
+		app.update();
+
+		app.world_mut()
+			.resource_mut::<AccumulatedMouseScroll>()
+			.delta = Vec2::new(0.0, -1.0);
+		app.update();
+
+		app.world_mut()
+			.resource_mut::<AccumulatedMouseScroll>()
+			.delta = Vec2::ZERO;
+		app.update();
+
+		subscription.unsubscribe();
+
+		let observed = notification_collector.lock().all_observed_values();
+		assert_eq!(observed.len(), 1);
+		assert_eq!(observed[0].delta, Vec2::new(0.0, -1.0));
+
+		tracked_teardown.assert_was_torn_down();
+	}
+}
+
+/// Non Applicable:
+/// - rx_contract_closed_after_complete - Can't Complete
+/// - rx_contract_closed_after_error - Can't Error
+mod contracts {
+	use super::*;
+
+	mod mouse_button_observable {
+		use super::*;
+
+		#[test]
+		fn rx_contract_closed_after_unsubscribe() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.init_resource::<ButtonInput<MouseButton>>();
+			app.init_resource::<AccumulatedMouseMotion>();
+			app.init_resource::<AccumulatedMouseScroll>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = MouseButtonObservable::new(
+				MouseButtonObservableOptions {
+					emit: MouseButtonObservableEmit::JustPressed,
+				},
+				scheduler_handle,
+			);
+
+			let destination = MockObserver::<MouseButton, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("mouse_button_observable");
+
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<ButtonInput<MouseButton>>()
+				.press(MouseButton::Left);
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<ButtonInput<MouseButton>>()
+				.clear_just_pressed(MouseButton::Left);
+			app.world_mut()
+				.resource_mut::<ButtonInput<MouseButton>>()
+				.press(MouseButton::Right);
+			app.update();
+
+			subscription.unsubscribe();
+
+			notification_collector.lock().assert_notifications(
+				"mouse_button_observable",
+				0,
+				[
+					SubscriberNotification::Next(MouseButton::Left),
+					SubscriberNotification::Next(MouseButton::Right),
+					SubscriberNotification::Unsubscribe,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"mouse_button_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_early() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.init_resource::<ButtonInput<MouseButton>>();
+			app.init_resource::<AccumulatedMouseMotion>();
+			app.init_resource::<AccumulatedMouseScroll>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = MouseButtonObservable::new(
+				MouseButtonObservableOptions {
+					emit: MouseButtonObservableEmit::JustPressed,
+				},
+				scheduler_handle,
+			)
+			.take(2);
+
+			let destination = MockObserver::<MouseButton, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("mouse_button_observable");
+
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<ButtonInput<MouseButton>>()
+				.press(MouseButton::Left);
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<ButtonInput<MouseButton>>()
+				.clear_just_pressed(MouseButton::Left);
+			app.world_mut()
+				.resource_mut::<ButtonInput<MouseButton>>()
+				.press(MouseButton::Right);
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<ButtonInput<MouseButton>>()
+				.clear_just_pressed(MouseButton::Right);
+			app.world_mut()
+				.resource_mut::<ButtonInput<MouseButton>>()
+				.press(MouseButton::Middle);
+			app.update();
+
+			notification_collector.lock().assert_notifications(
+				"mouse_button_observable",
+				0,
+				[
+					SubscriberNotification::Next(MouseButton::Left),
+					SubscriberNotification::Next(MouseButton::Right),
+					SubscriberNotification::Complete,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"mouse_button_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_immediately() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.init_resource::<ButtonInput<MouseButton>>();
+			app.init_resource::<AccumulatedMouseMotion>();
+			app.init_resource::<AccumulatedMouseScroll>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = MouseButtonObservable::new(
+				MouseButtonObservableOptions {
+					emit: MouseButtonObservableEmit::JustPressed,
+				},
+				scheduler_handle,
+			)
+			.take(0);
+
+			let destination = MockObserver::<MouseButton, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("mouse_button_observable");
+
+			notification_collector.lock().assert_notifications(
+				"mouse_button_observable",
+				0,
+				[SubscriberNotification::Complete],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"mouse_button_observable - rx_verify_no_new_notification_after_closed",
+					0,
+				);
+		}
+	}
+
+	mod mouse_motion_observable {
+		use super::*;
+
+		#[test]
+		fn rx_contract_closed_after_unsubscribe() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.init_resource::<ButtonInput<MouseButton>>();
+			app.init_resource::<AccumulatedMouseMotion>();
+			app.init_resource::<AccumulatedMouseScroll>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = MouseMotionObservable::new(scheduler_handle);
+
+			let destination = MockObserver::<Vec2, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("mouse_motion_observable");
+
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<AccumulatedMouseMotion>()
+				.delta = Vec2::new(1.0, 0.0);
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<AccumulatedMouseMotion>()
+				.delta = Vec2::new(2.0, 0.0);
+			app.update();
+
+			subscription.unsubscribe();
+
+			notification_collector.lock().assert_notifications(
+				"mouse_motion_observable",
+				0,
+				[
+					SubscriberNotification::Next(Vec2::new(1.0, 0.0)),
+					SubscriberNotification::Next(Vec2::new(2.0, 0.0)),
+					SubscriberNotification::Unsubscribe,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"mouse_motion_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_early() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.init_resource::<ButtonInput<MouseButton>>();
+			app.init_resource::<AccumulatedMouseMotion>();
+			app.init_resource::<AccumulatedMouseScroll>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = MouseMotionObservable::new(scheduler_handle).take(2);
+
+			let destination = MockObserver::<Vec2, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("mouse_motion_observable");
+
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<AccumulatedMouseMotion>()
+				.delta = Vec2::new(1.0, 0.0);
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<AccumulatedMouseMotion>()
+				.delta = Vec2::new(2.0, 0.0);
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<AccumulatedMouseMotion>()
+				.delta = Vec2::new(3.0, 0.0);
+			app.update();
+
+			notification_collector.lock().assert_notifications(
+				"mouse_motion_observable",
+				0,
+				[
+					SubscriberNotification::Next(Vec2::new(1.0, 0.0)),
+					SubscriberNotification::Next(Vec2::new(2.0, 0.0)),
+					SubscriberNotification::Complete,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"mouse_motion_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_immediately() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.init_resource::<ButtonInput<MouseButton>>();
+			app.init_resource::<AccumulatedMouseMotion>();
+			app.init_resource::<AccumulatedMouseScroll>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = MouseMotionObservable::new(scheduler_handle).take(0);
+
+			let destination = MockObserver::<Vec2, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("mouse_motion_observable");
+
+			notification_collector.lock().assert_notifications(
+				"mouse_motion_observable",
+				0,
+				[SubscriberNotification::Complete],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"mouse_motion_observable - rx_verify_no_new_notification_after_closed",
+					0,
+				);
+		}
+	}
+
+	/// Scrolls are mapped to their delta, as they can't be compared.
+	mod mouse_wheel_observable {
+		use super::*;
+
+		#[test]
+		fn rx_contract_closed_after_unsubscribe() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.init_resource::<ButtonInput<MouseButton>>();
+			app.init_resource::<AccumulatedMouseMotion>();
+			app.init_resource::<AccumulatedMouseScroll>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable =
+				MouseWheelObservable::new(scheduler_handle).map(|scroll| scroll.delta);
+
+			let destination = MockObserver::<Vec2, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("mouse_wheel_observable");
+
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<AccumulatedMouseScroll>()
+				.delta = Vec2::new(1.0, 0.0);
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<AccumulatedMouseScroll>()
+				.delta = Vec2::new(2.0, 0.0);
+			app.update();
+
+			subscription.unsubscribe();
+
+			notification_collector.lock().assert_notifications(
+				"mouse_wheel_observable",
+				0,
+				[
+					SubscriberNotification::Next(Vec2::new(1.0, 0.0)),
+					SubscriberNotification::Next(Vec2::new(2.0, 0.0)),
+					SubscriberNotification::Unsubscribe,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"mouse_wheel_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_early() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.init_resource::<ButtonInput<MouseButton>>();
+			app.init_resource::<AccumulatedMouseMotion>();
+			app.init_resource::<AccumulatedMouseScroll>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = MouseWheelObservable::new(scheduler_handle)
+				.map(|scroll| scroll.delta)
+				.take(2);
+
+			let destination = MockObserver::<Vec2, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("mouse_wheel_observable");
+
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<AccumulatedMouseScroll>()
+				.delta = Vec2::new(1.0, 0.0);
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<AccumulatedMouseScroll>()
+				.delta = Vec2::new(2.0, 0.0);
+			app.update();
+
+			app.world_mut()
+				.resource_mut::<AccumulatedMouseScroll>()
+				.delta = Vec2::new(3.0, 0.0);
+			app.update();
+
+			notification_collector.lock().assert_notifications(
+				"mouse_wheel_observable",
+				0,
+				[
+					SubscriberNotification::Next(Vec2::new(1.0, 0.0)),
+					SubscriberNotification::Next(Vec2::new(2.0, 0.0)),
+					SubscriberNotification::Complete,
+				],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			app.update();
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"mouse_wheel_observable - rx_verify_no_new_notification_after_closed",
+					2,
+				);
+		}
+
+		#[test]
+		fn rx_contract_closed_if_downstream_closes_immediately() {
+			let mut app = App::new();
+			app.init_resource::<Time<Virtual>>();
+			app.init_resource::<ButtonInput<MouseButton>>();
+			app.init_resource::<AccumulatedMouseMotion>();
+			app.init_resource::<AccumulatedMouseScroll>();
+			app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+			let scheduler_handle = {
+				let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+					.get_mut(app.world_mut());
+				scheduler.handle()
+			};
+
+			let mut observable = MouseWheelObservable::new(scheduler_handle)
+				.map(|scroll| scroll.delta)
+				.take(0);
+
+			let destination = MockObserver::<Vec2, Never>::default();
+			let notification_collector = destination.get_notification_collector();
+
+			let mut subscription = observable.subscribe(destination);
+			let tracked_teardown = subscription.add_tracked_teardown("mouse_wheel_observable");
+
+			notification_collector.lock().assert_notifications(
+				"mouse_wheel_observable",
+				0,
+				[SubscriberNotification::Complete],
+				true,
+			);
+
+			tracked_teardown.assert_was_torn_down();
+			assert!(subscription.is_closed());
+
+			assert!(
+				app.world()
+					.resource::<RxBevyExecutor<Update, Virtual>>()
+					.is_empty(),
+				"No work should remain in the executor"
+			);
+
+			subscription.unsubscribe();
+			notification_collector
+				.lock()
+				.assert_nth_notification_is_last(
+					"mouse_wheel_observable - rx_verify_no_new_notification_after_closed",
+					0,
+				);
+		}
+	}
+}
@@ -406,3 +406,323 @@ mod cleanup_on_exit {
 		);
 	}
 }
+
+mod diagnostics {
+	use bevy::diagnostic::DiagnosticsStore;
+
+	use super::*;
+
+	#[test]
+	fn should_collect_the_work_held_by_the_executor() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+			RxSchedulerDiagnosticsPlugin::<Update, Virtual>::default(),
+		));
+
+		let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+			.get_mut(app.world_mut());
+		let scheduler_handle = scheduler.handle();
+
+		let repeated_cancellation_id = {
+			let mut scheduler = scheduler_handle.lock();
+
+			let repeated_cancellation_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_repeated_work(
+				|_, _| WorkResult::Pending,
+				Duration::from_millis(100),
+				false,
+				1,
+				repeated_cancellation_id,
+			);
+			let continuous_cancellation_id = scheduler.generate_cancellation_id();
+			scheduler
+				.schedule_continuous_work(|_, _| WorkResult::Pending, continuous_cancellation_id);
+			repeated_cancellation_id
+		};
+
+		app.update();
+
+		let executor_diagnostics = app
+			.world()
+			.resource::<RxExecutorDiagnostics<Update, Virtual>>();
+		assert_eq!(executor_diagnostics.work.len(), 2);
+		assert_eq!(executor_diagnostics.count_of_kind(WorkKind::Repeated), 1);
+		assert_eq!(executor_diagnostics.count_of_kind(WorkKind::Continuous), 1);
+
+		let continuous_info = executor_diagnostics
+			.work
+			.iter()
+			.find(|info| info.kind == WorkKind::Continuous)
+			.unwrap();
+		assert_eq!(continuous_info.stats.run_count, 1);
+
+		let repeated_work_path = executor_diagnostics.paths.repeated_work.clone();
+		assert_eq!(
+			repeated_work_path.as_str(),
+			"rx_executor/Update/Virtual/repeated_work"
+		);
+		assert_eq!(
+			app.world()
+				.resource::<DiagnosticsStore>()
+				.get_measurement(&repeated_work_path)
+				.map(|measurement| measurement.value),
+			Some(1.0)
+		);
+
+		scheduler_handle.lock().cancel(repeated_cancellation_id);
+		app.update();
+
+		assert_eq!(
+			app.world()
+				.resource::<RxExecutorDiagnostics<Update, Virtual>>()
+				.count_of_kind(WorkKind::Repeated),
+			0
+		);
+		assert_eq!(
+			app.world()
+				.resource::<DiagnosticsStore>()
+				.get_measurement(&repeated_work_path)
+				.map(|measurement| measurement.value),
+			Some(0.0)
+		);
+	}
+}
+
+mod clocks {
+	use bevy::time::{TimePlugin, TimeUpdateStrategy};
+
+	use super::*;
+
+	fn schedule_counting_work(
+		scheduler_handle: SchedulerHandle<RxBevyScheduler>,
+		period: Duration,
+	) -> Arc<AtomicUsize> {
+		let counter = Arc::new(AtomicUsize::default());
+		let counter_work = counter.clone();
+		let mut scheduler = scheduler_handle.lock();
+		let cancellation_id = scheduler.generate_cancellation_id();
+		scheduler.schedule_repeated_work(
+			move |_, _| {
+				counter_work.fetch_add(1, Ordering::Relaxed);
+				WorkResult::Pending
+			},
+			period,
+			false,
+			10,
+			cancellation_id,
+		);
+		counter
+	}
+
+	fn advance_virtual_time(app: &mut App, duration: Duration) {
+		app.world_mut()
+			.resource_mut::<Time<Virtual>>()
+			.advance_by(duration);
+		app.update();
+	}
+
+	#[test]
+	fn should_not_pass_time_for_a_paused_executor() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+			.get_mut(app.world_mut());
+		let counter = schedule_counting_work(scheduler.handle(), Duration::from_millis(100));
+
+		advance_virtual_time(&mut app, Duration::from_millis(100));
+		assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+		app.world_mut()
+			.resource_mut::<RxBevyExecutor<Update, Virtual>>()
+			.pause();
+		advance_virtual_time(&mut app, Duration::from_millis(500));
+		assert_eq!(counter.load(Ordering::Relaxed), 1);
+
+		// Time passed on the clock while paused is skipped
+		app.world_mut()
+			.resource_mut::<RxBevyExecutor<Update, Virtual>>()
+			.unpause();
+		advance_virtual_time(&mut app, Duration::from_millis(100));
+		assert_eq!(counter.load(Ordering::Relaxed), 2);
+		assert_eq!(
+			*app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.get_current_tick(),
+			Duration::from_millis(200)
+		);
+	}
+
+	#[test]
+	fn should_only_pause_the_executor_that_was_paused() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+			RxSchedulerPlugin::<PostUpdate, Virtual>::default(),
+		));
+
+		let update_scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+			.get_mut(app.world_mut());
+		let update_counter =
+			schedule_counting_work(update_scheduler.handle(), Duration::from_millis(100));
+		let post_update_scheduler =
+			SystemState::<RxSchedule<PostUpdate, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+		let post_update_counter =
+			schedule_counting_work(post_update_scheduler.handle(), Duration::from_millis(100));
+
+		app.world_mut()
+			.resource_mut::<RxBevyExecutor<Update, Virtual>>()
+			.pause();
+		advance_virtual_time(&mut app, Duration::from_millis(100));
+
+		assert_eq!(update_counter.load(Ordering::Relaxed), 0);
+		assert_eq!(post_update_counter.load(Ordering::Relaxed), 1);
+	}
+
+	#[test]
+	fn should_scale_the_time_of_the_executor_by_its_relative_speed() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+			.get_mut(app.world_mut());
+		let counter = schedule_counting_work(scheduler.handle(), Duration::from_millis(100));
+
+		app.world_mut()
+			.resource_mut::<RxBevyExecutor<Update, Virtual>>()
+			.set_relative_speed(2.0);
+		advance_virtual_time(&mut app, Duration::from_millis(100));
+		assert_eq!(counter.load(Ordering::Relaxed), 2);
+
+		app.world_mut()
+			.resource_mut::<RxBevyExecutor<Update, Virtual>>()
+			.set_relative_speed(0.5);
+		advance_virtual_time(&mut app, Duration::from_millis(100));
+		assert_eq!(counter.load(Ordering::Relaxed), 2);
+		advance_virtual_time(&mut app, Duration::from_millis(100));
+		assert_eq!(counter.load(Ordering::Relaxed), 3);
+	}
+
+	#[test]
+	#[should_panic]
+	fn should_panic_on_a_negative_relative_speed() {
+		RxBevyExecutor::<Update, Virtual>::default().set_relative_speed(-1.0);
+	}
+
+	#[test]
+	fn should_tick_the_executor_for_each_fixed_timestep_when_catching_up() {
+		let mut app = App::new();
+		app.add_plugins((
+			TimePlugin,
+			RxPlugin,
+			RxSchedulerPlugin::<FixedUpdate, Fixed>::default(),
+		));
+		app.insert_resource(Time::<Fixed>::from_duration(Duration::from_millis(100)));
+		app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+			300,
+		)));
+		app.world_mut()
+			.resource_mut::<Time<Virtual>>()
+			.set_max_delta(Duration::from_secs(1));
+
+		let scheduler = SystemState::<RxSchedule<FixedUpdate, Fixed>>::new(app.world_mut())
+			.get_mut(app.world_mut());
+		let scheduler_handle = scheduler.handle();
+
+		let ticks = Arc::new(std::sync::Mutex::new(Vec::<Duration>::new()));
+		let ticks_work = ticks.clone();
+		{
+			let mut scheduler = scheduler_handle.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_continuous_work(
+				move |tick, _| {
+					ticks_work.lock().unwrap().push(*tick);
+					WorkResult::Pending
+				},
+				cancellation_id,
+			);
+		}
+
+		// The first update only starts the clocks
+		app.update();
+		app.update();
+
+		assert_eq!(
+			*ticks.lock().unwrap(),
+			vec![
+				Duration::from_millis(100),
+				Duration::from_millis(200),
+				Duration::from_millis(300),
+			]
+		);
+	}
+
+	#[derive(Resource, Default)]
+	struct GameTurn(u64);
+
+	impl Clock for GameTurn {
+		fn tick(world: &World) -> Tick {
+			Tick::new(Duration::from_secs(world.resource::<GameTurn>().0))
+		}
+	}
+
+	#[test]
+	fn should_be_able_to_use_a_custom_clock() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.init_resource::<GameTurn>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, GameTurn>::default()));
+
+		let scheduler = SystemState::<RxSchedule<Update, GameTurn>>::new(app.world_mut())
+			.get_mut(app.world_mut());
+		let counter = schedule_counting_work(scheduler.handle(), Duration::from_secs(2));
+
+		app.update();
+		app.world_mut().resource_mut::<GameTurn>().0 += 1;
+		app.update();
+		assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+		app.world_mut().resource_mut::<GameTurn>().0 += 1;
+		app.update();
+		assert_eq!(counter.load(Ordering::Relaxed), 1);
+	}
+
+	#[derive(Default, Clone, Copy)]
+	struct CustomMarker;
+
+	#[test]
+	fn should_be_able_to_use_a_custom_time_as_the_clock() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.init_resource::<Time<CustomMarker>>();
+		app.add_plugins((
+			RxPlugin,
+			RxSchedulerPlugin::<Update, CustomMarker>::default(),
+		));
+
+		let scheduler = SystemState::<RxSchedule<Update, CustomMarker>>::new(app.world_mut())
+			.get_mut(app.world_mut());
+		let counter = schedule_counting_work(scheduler.handle(), Duration::from_secs(2));
+
+		app.update();
+		app.world_mut()
+			.resource_mut::<Time<CustomMarker>>()
+			.advance_by(Duration::from_secs(1));
+		app.update();
+		assert_eq!(counter.load(Ordering::Relaxed), 0);
+
+		app.world_mut()
+			.resource_mut::<Time<CustomMarker>>()
+			.advance_by(Duration::from_secs(1));
+		app.update();
+		assert_eq!(counter.load(Ordering::Relaxed), 1);
+	}
+}
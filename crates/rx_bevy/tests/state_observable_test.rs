@@ -0,0 +1,419 @@
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use rx_bevy::prelude::*;
+use rx_core_testing::prelude::*;
+
+#[derive(States, Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum TestState {
+	#[default]
+	Menu,
+	Playing,
+	Paused,
+}
+
+fn set_next_state(app: &mut App, state: TestState) {
+	app.world_mut()
+		.resource_mut::<NextState<TestState>>()
+		.set(state);
+}
+
+mod state_observable {
+	use super::*;
+
+	#[test]
+	fn should_emit_every_transition_including_the_initial_one() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((
+			StatesPlugin,
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		));
+		app.init_state::<TestState>();
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<(Option<TestState>, TestState), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription =
+			StateObservable::<TestState>::new(default(), scheduler_handle).subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("state_observable");
+
+		app.update();
+		set_next_state(&mut app, TestState::Playing);
+		app.update();
+		// Identity transitions are emitted too
+		set_next_state(&mut app, TestState::Playing);
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"state_observable",
+			0,
+			[
+				SubscriberNotification::Next((None, TestState::Menu)),
+				SubscriberNotification::Next((Some(TestState::Menu), TestState::Playing)),
+				SubscriberNotification::Next((Some(TestState::Playing), TestState::Playing)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+	}
+
+	#[test]
+	fn should_only_emit_when_entering_the_state_when_emitting_on_enter() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((
+			StatesPlugin,
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		));
+		app.init_state::<TestState>();
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<(Option<TestState>, TestState), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = StateObservable::<TestState>::new(
+			StateObservableOptions {
+				emit: StateObservableEmit::OnEnter(TestState::Playing),
+			},
+			scheduler_handle,
+		)
+		.subscribe(destination);
+
+		app.update();
+		set_next_state(&mut app, TestState::Playing);
+		app.update();
+		set_next_state(&mut app, TestState::Paused);
+		app.update();
+		set_next_state(&mut app, TestState::Playing);
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"state_observable",
+			0,
+			[
+				SubscriberNotification::Next((Some(TestState::Menu), TestState::Playing)),
+				SubscriberNotification::Next((Some(TestState::Paused), TestState::Playing)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_only_emit_when_exiting_the_state_when_emitting_on_exit() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((
+			StatesPlugin,
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		));
+		app.init_state::<TestState>();
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<(Option<TestState>, TestState), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = StateObservable::<TestState>::new(
+			StateObservableOptions {
+				emit: StateObservableEmit::OnExit(TestState::Playing),
+			},
+			scheduler_handle,
+		)
+		.subscribe(destination);
+
+		app.update();
+		set_next_state(&mut app, TestState::Playing);
+		app.update();
+		set_next_state(&mut app, TestState::Paused);
+		app.update();
+		set_next_state(&mut app, TestState::Menu);
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"state_observable",
+			0,
+			[
+				SubscriberNotification::Next((Some(TestState::Playing), TestState::Paused)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+	}
+}
+
+mod next_state_destination {
+	use super::*;
+
+	#[test]
+	fn should_set_the_next_state_for_each_observed_value() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((
+			StatesPlugin,
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		));
+		app.init_state::<TestState>();
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let mut destination =
+			NextStateDestination::<TestState>::new(scheduler_handle.clone()).upgrade();
+
+		app.update();
+		destination.next(TestState::Paused);
+		// The next state is set by the executor, and is applied by the
+		// following state transition.
+		app.update();
+		app.update();
+
+		assert_eq!(
+			app.world().resource::<State<TestState>>().get(),
+			&TestState::Paused
+		);
+	}
+
+	#[test]
+	fn should_drive_the_state_from_its_own_transitions() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((
+			StatesPlugin,
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		));
+		app.init_state::<TestState>();
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let _subscription = StateObservable::<TestState>::new(
+			StateObservableOptions {
+				emit: StateObservableEmit::OnEnter(TestState::Playing),
+			},
+			scheduler_handle.clone(),
+		)
+		.map(|_| TestState::Paused)
+		.subscribe(NextStateDestination::new(scheduler_handle));
+
+		app.update();
+		set_next_state(&mut app, TestState::Playing);
+		app.update();
+		assert_eq!(
+			app.world().resource::<State<TestState>>().get(),
+			&TestState::Playing
+		);
+
+		app.update();
+		assert_eq!(
+			app.world().resource::<State<TestState>>().get(),
+			&TestState::Paused
+		);
+	}
+}
+
+/// Non Applicable:
+/// - rx_contract_closed_after_complete - Can't Complete
+/// - rx_contract_closed_after_error - Can't Error
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((
+			StatesPlugin,
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		));
+		app.init_state::<TestState>();
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<(Option<TestState>, TestState), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription =
+			StateObservable::<TestState>::new(default(), scheduler_handle).subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("state_observable");
+
+		app.update();
+		subscription.unsubscribe();
+
+		set_next_state(&mut app, TestState::Playing);
+		app.update();
+
+		notification_collector.lock().assert_notifications(
+			"state_observable",
+			0,
+			[
+				SubscriberNotification::Next((None, TestState::Menu)),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+	}
+
+	#[test]
+	fn rx_contract_closed_if_downstream_closes_early() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((
+			StatesPlugin,
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		));
+		app.init_state::<TestState>();
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<(Option<TestState>, TestState), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = StateObservable::<TestState>::new(default(), scheduler_handle)
+			.take(2)
+			.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("state_observable");
+
+		app.update();
+		set_next_state(&mut app, TestState::Playing);
+		app.update();
+		set_next_state(&mut app, TestState::Paused);
+		app.update();
+
+		notification_collector.lock().assert_notifications(
+			"state_observable",
+			0,
+			[
+				SubscriberNotification::Next((None, TestState::Menu)),
+				SubscriberNotification::Next((Some(TestState::Menu), TestState::Playing)),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+
+		subscription.unsubscribe();
+		notification_collector
+			.lock()
+			.assert_nth_notification_is_last(
+				"state_observable - rx_verify_no_new_notification_after_closed",
+				2,
+			);
+	}
+
+	#[test]
+	fn rx_contract_closed_if_downstream_closes_immediately() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((
+			StatesPlugin,
+			RxPlugin,
+			RxSchedulerPlugin::<Update, Virtual>::default(),
+		));
+		app.init_state::<TestState>();
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let destination = MockObserver::<(Option<TestState>, TestState), Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = StateObservable::<TestState>::new(default(), scheduler_handle)
+			.take(0)
+			.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("state_observable");
+
+		notification_collector.lock().assert_notifications(
+			"state_observable",
+			0,
+			[SubscriberNotification::Complete],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+
+		subscription.unsubscribe();
+		notification_collector
+			.lock()
+			.assert_nth_notification_is_last(
+				"state_observable - rx_verify_no_new_notification_after_closed",
+				0,
+			);
+	}
+}
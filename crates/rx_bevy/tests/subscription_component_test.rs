@@ -71,3 +71,79 @@ mod new_despawn_on_unsubscribe {
 		);
 	}
 }
+
+#[cfg(feature = "debug_subscriptions")]
+mod debug_subscriptions {
+	use rx_core_common::{Never, SubscriptionRegistry};
+
+	use super::*;
+
+	#[test]
+	fn should_annotate_the_subscription_with_the_entity_owning_it() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let subscription = SharedSubscription::default();
+		let debug_id = subscription.debug_id().unwrap();
+
+		let entity = app
+			.world_mut()
+			.spawn(SubscriptionComponent::new(subscription))
+			.id();
+
+		assert!(
+			SubscriptionRegistry::get(debug_id)
+				.unwrap()
+				.annotations
+				.contains(&format!("entity {entity}"))
+		);
+
+		app.world_mut().despawn(entity);
+		assert!(!SubscriptionRegistry::is_open(debug_id));
+	}
+
+	#[test]
+	fn should_annotate_the_subscription_with_the_observable_entity_it_subscribed_to() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let observable_entity = app
+			.world_mut()
+			.spawn(PublishSubject::<usize>::default().into_component())
+			.id();
+		let destination_entity = app.world_mut().spawn_empty().id();
+
+		let subscription_entity = app
+			.world_mut()
+			.commands()
+			.entity(destination_entity)
+			.subscribes_to_observable_entity::<usize, Never>(observable_entity, scheduler_handle);
+
+		app.update();
+
+		let debug_id = app
+			.world()
+			.get::<SubscriptionComponent>(subscription_entity)
+			.expect("should exist on a subscription entity")
+			.debug_id()
+			.unwrap();
+		let annotations = SubscriptionRegistry::get(debug_id).unwrap().annotations;
+
+		assert!(annotations.contains(&format!("entity {subscription_entity}")));
+		assert!(annotations.iter().any(|annotation| {
+			annotation.starts_with(&format!("subscription of {observable_entity} ("))
+		}));
+
+		app.world_mut().despawn(subscription_entity);
+		app.update();
+		assert!(!SubscriptionRegistry::is_open(debug_id));
+	}
+}
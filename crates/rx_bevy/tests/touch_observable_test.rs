@@ -0,0 +1,309 @@
+use bevy::ecs::system::SystemState;
+use bevy::input::touch::{Touch, TouchInput, TouchPhase, Touches, touch_screen_input_system};
+use bevy::prelude::*;
+use rx_bevy::prelude::*;
+use rx_core_testing::prelude::*;
+
+fn write_touch(app: &mut App, id: u64, phase: TouchPhase) {
+	app.world_mut().write_message(TouchInput {
+		phase,
+		position: Vec2::ZERO,
+		window: Entity::PLACEHOLDER,
+		force: None,
+		id,
+	});
+}
+
+fn observe_touch_ids(emit: TouchObservableEmit) -> Vec<u64> {
+	let mut app = App::new();
+	app.init_resource::<Time<Virtual>>();
+	app.init_resource::<Touches>();
+	app.add_message::<TouchInput>();
+	app.add_systems(PreUpdate, touch_screen_input_system);
+	app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+	let scheduler_handle = {
+		let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+			.get_mut(app.world_mut());
+		scheduler.handle()
+	};
+
+	let destination = MockObserver::<Touch, Never>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut subscription = TouchObservable::new(TouchObservableOptions { emit }, scheduler_handle)
+		.subscribe(destination);
+	let tracked_teardown = subscription.add_tracked_teardown("touch_observable");
+
+	app.update();
+
+	write_touch(&mut app, 1, TouchPhase::Started);
+	app.update();
+
+	write_touch(&mut app, 2, TouchPhase::Started);
+	app.update();
+
+	write_touch(&mut app, 1, TouchPhase::Ended);
+	write_touch(&mut app, 2, TouchPhase::Canceled);
+	app.update();
+
+	app.update();
+
+	subscription.unsubscribe();
+	tracked_teardown.assert_was_torn_down();
+
+	app.update();
+	assert!(
+		app.world()
+			.resource::<RxBevyExecutor<Update, Virtual>>()
+			.is_empty()
+	);
+
+	notification_collector
+		.lock()
+		.all_observed_values()
+		.iter()
+		.map(|touch| touch.id())
+		.collect()
+}
+
+#[test]
+fn should_observe_just_pressed_touches() {
+	assert_eq!(
+		observe_touch_ids(TouchObservableEmit::JustPressed),
+		vec![1, 2]
+	);
+}
+
+#[test]
+fn should_observe_held_touches_every_frame() {
+	let mut observed = observe_touch_ids(TouchObservableEmit::WhilePressed);
+	// The order of simultaneous touches is not defined
+	observed[1..3].sort();
+	assert_eq!(observed, vec![1, 1, 2]);
+}
+
+#[test]
+fn should_observe_just_released_touches() {
+	assert_eq!(
+		observe_touch_ids(TouchObservableEmit::JustReleased),
+		vec![1]
+	);
+}
+
+#[test]
+fn should_observe_just_canceled_touches() {
+	assert_eq!(
+		observe_touch_ids(TouchObservableEmit::JustCanceled),
+		vec![2]
+	);
+}
+
+/// Non Applicable:
+/// - rx_contract_closed_after_complete - Can't Complete
+/// - rx_contract_closed_after_error - Can't Error
+///
+/// Touches are mapped to their id, as they can't be compared.
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.init_resource::<Touches>();
+		app.add_message::<TouchInput>();
+		app.add_systems(PreUpdate, touch_screen_input_system);
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let mut observable = TouchObservable::new(
+			TouchObservableOptions {
+				emit: TouchObservableEmit::JustPressed,
+			},
+			scheduler_handle,
+		)
+		.map(|touch| touch.id());
+
+		let destination = MockObserver::<u64, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = observable.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("touch_observable");
+
+		app.update();
+
+		write_touch(&mut app, 1, TouchPhase::Started);
+		app.update();
+
+		write_touch(&mut app, 2, TouchPhase::Started);
+		app.update();
+
+		subscription.unsubscribe();
+
+		notification_collector.lock().assert_notifications(
+			"touch_observable",
+			0,
+			[
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Next(2),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		app.update();
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+
+		subscription.unsubscribe();
+		notification_collector
+			.lock()
+			.assert_nth_notification_is_last(
+				"touch_observable - rx_verify_no_new_notification_after_closed",
+				2,
+			);
+	}
+
+	#[test]
+	fn rx_contract_closed_if_downstream_closes_early() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.init_resource::<Touches>();
+		app.add_message::<TouchInput>();
+		app.add_systems(PreUpdate, touch_screen_input_system);
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let mut observable = TouchObservable::new(
+			TouchObservableOptions {
+				emit: TouchObservableEmit::JustPressed,
+			},
+			scheduler_handle,
+		)
+		.map(|touch| touch.id())
+		.take(2);
+
+		let destination = MockObserver::<u64, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = observable.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("touch_observable");
+
+		app.update();
+
+		write_touch(&mut app, 1, TouchPhase::Started);
+		app.update();
+
+		write_touch(&mut app, 2, TouchPhase::Started);
+		app.update();
+
+		write_touch(&mut app, 3, TouchPhase::Started);
+		app.update();
+
+		notification_collector.lock().assert_notifications(
+			"touch_observable",
+			0,
+			[
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Next(2),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		app.update();
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+
+		subscription.unsubscribe();
+		notification_collector
+			.lock()
+			.assert_nth_notification_is_last(
+				"touch_observable - rx_verify_no_new_notification_after_closed",
+				2,
+			);
+	}
+
+	#[test]
+	fn rx_contract_closed_if_downstream_closes_immediately() {
+		let mut app = App::new();
+		app.init_resource::<Time<Virtual>>();
+		app.init_resource::<Touches>();
+		app.add_message::<TouchInput>();
+		app.add_systems(PreUpdate, touch_screen_input_system);
+		app.add_plugins((RxPlugin, RxSchedulerPlugin::<Update, Virtual>::default()));
+
+		let scheduler_handle = {
+			let scheduler = SystemState::<RxSchedule<Update, Virtual>>::new(app.world_mut())
+				.get_mut(app.world_mut());
+			scheduler.handle()
+		};
+
+		let mut observable = TouchObservable::new(
+			TouchObservableOptions {
+				emit: TouchObservableEmit::JustPressed,
+			},
+			scheduler_handle,
+		)
+		.map(|touch| touch.id())
+		.take(0);
+
+		let destination = MockObserver::<u64, Never>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = observable.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("touch_observable");
+
+		notification_collector.lock().assert_notifications(
+			"touch_observable",
+			0,
+			[SubscriberNotification::Complete],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+
+		assert!(
+			app.world()
+				.resource::<RxBevyExecutor<Update, Virtual>>()
+				.is_empty(),
+			"No work should remain in the executor"
+		);
+
+		subscription.unsubscribe();
+		notification_collector
+			.lock()
+			.assert_nth_notification_is_last(
+				"touch_observable - rx_verify_no_new_notification_after_closed",
+				0,
+			);
+	}
+}
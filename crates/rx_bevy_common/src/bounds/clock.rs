@@ -1,3 +1,52 @@
-pub trait Clock: Default + Copy + Clone + Send + Sync + 'static {}
+use bevy_ecs::world::World;
+use bevy_time::Time;
+use rx_core_scheduler_ticking::Tick;
 
-impl<T> Clock for T where T: Default + Copy + Clone + Send + Sync + 'static {}
+/// # [Clock]
+///
+/// Provides the current [`Tick`] for an
+/// [`RxBevyExecutor`][crate::RxBevyExecutor], and with that, the notion of
+/// time for all scheduled work executed by it.
+///
+/// Implemented for every `Copy` marker type of a Bevy [`Time`] resource,
+/// reading the elapsed time of `Time<C>`:
+/// - `Virtual` - Pausable and scalable game time, the default.
+/// - `Real` - Wall clock time, unaffected by pausing the game.
+/// - `Fixed` - Advanced in fixed timesteps, use it with the `FixedUpdate`
+///   schedule where it catches up with `Virtual` time by running multiple
+///   times per frame when needed, ticking the executor for each step.
+/// - `()` - The generic `Time`, which is `Time<Fixed>` within `FixedUpdate`
+///   and `Time<Virtual>` everywhere else.
+/// - Your own `Time<CustomMarker>` clocks.
+///
+/// To drive scheduled work with your own notion of time, like turns in a
+/// turn-based game, implement it for a resource that is not `Copy`:
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use bevy_ecs::{resource::Resource, world::World};
+/// # use rx_bevy_common::{Clock, Tick};
+/// #[derive(Resource, Default)]
+/// struct GameTurn(u32);
+///
+/// impl Clock for GameTurn {
+///     // A turn is treated as a second, an `interval` of 2 seconds now
+///     // emits every other turn.
+///     fn tick(world: &World) -> Tick {
+///         Tick::new(Duration::from_secs(world.resource::<GameTurn>().0 as u64))
+///     }
+/// }
+/// ```
+pub trait Clock: Default + Send + Sync + 'static {
+	fn tick(world: &World) -> Tick;
+}
+
+impl<T> Clock for T
+where
+	T: Default + Copy + Clone + Send + Sync + 'static,
+{
+	#[inline]
+	fn tick(world: &World) -> Tick {
+		Tick::new(world.resource::<Time<T>>().elapsed())
+	}
+}
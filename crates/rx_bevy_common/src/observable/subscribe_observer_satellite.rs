@@ -91,9 +91,25 @@ where
 		// already has access to.
 		// It also already contains the [SubscriptionSchedule] component.
 
+		let shared_subscription = subscription.into_shared();
+
+		// Marks which observable entity the subscription belongs to, the
+		// subscription entity itself is annotated by [SubscriptionComponent].
+		#[cfg(feature = "debug_subscriptions")]
+		if let Some(debug_id) = shared_subscription.debug_id() {
+			rx_core_common::SubscriptionRegistry::annotate(
+				debug_id,
+				format!(
+					"subscription of {} ({})",
+					event.observable_entity,
+					ShortName::of::<O>()
+				),
+			);
+		}
+
 		subscription_entity_commands.insert((
 			SubscriptionComponent::new_despawn_on_unsubscribe(
-				subscription.into_shared(),
+				shared_subscription,
 				event.subscription_entity,
 				rx_schedule_despawn.handle(),
 			),
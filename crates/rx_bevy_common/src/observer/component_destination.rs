@@ -0,0 +1,191 @@
+use core::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use bevy_ecs::{
+	component::{Component, Mutable},
+	entity::Entity,
+	world::{DeferredWorld, Mut},
+};
+use rx_core_common::{
+	Never, PhantomInvariant, RxObserver, Scheduler, SchedulerHandle,
+	SchedulerScheduleWorkExtension, Signal, WorkCancellationId,
+};
+use rx_core_macro_observer_derive::RxObserver;
+
+use crate::RxBevyScheduler;
+
+type ComponentWriter<In> = dyn FnMut(&mut DeferredWorld, Entity, In) + Send + Sync;
+
+/// A destination that writes the observed values into a component of an
+/// entity.
+///
+/// - [ComponentDestination::insert]: Inserts the observed value as the
+///   component, only if the entity doesn't have one yet.
+/// - [ComponentDestination::replace]: Inserts the observed value as the
+///   component, replacing the existing one.
+/// - [ComponentDestination::mutate]: Mutates the existing component with the
+///   observed value, does nothing if the entity doesn't have one.
+///
+/// Inserting is done through commands, so the component is only available
+/// once they are applied, while mutations are applied immediately.
+///
+/// Just like the `NoopObserver`, it panics in dev mode when an error is
+/// observed.
+#[derive(RxObserver)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+pub struct ComponentDestination<C, In = C, InError = Never>
+where
+	C: Component,
+	In: Signal,
+	InError: Signal,
+{
+	destination: Entity,
+	writer: Arc<Mutex<ComponentWriter<In>>>,
+	remove_on_complete: bool,
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+	cancellation_id: WorkCancellationId,
+	_phantom_data: PhantomInvariant<(C, InError)>,
+}
+
+impl<C, InError> ComponentDestination<C, C, InError>
+where
+	C: Component + Signal,
+	InError: Signal,
+{
+	pub fn insert(destination: Entity, scheduler: SchedulerHandle<RxBevyScheduler>) -> Self {
+		Self::new(
+			destination,
+			Arc::new(Mutex::new(
+				|deferred_world: &mut DeferredWorld, destination: Entity, next: C| {
+					deferred_world
+						.commands()
+						.entity(destination)
+						.try_insert_if_new(next);
+				},
+			)),
+			scheduler,
+		)
+	}
+
+	pub fn replace(destination: Entity, scheduler: SchedulerHandle<RxBevyScheduler>) -> Self {
+		Self::new(
+			destination,
+			Arc::new(Mutex::new(
+				|deferred_world: &mut DeferredWorld, destination: Entity, next: C| {
+					deferred_world
+						.commands()
+						.entity(destination)
+						.try_insert(next);
+				},
+			)),
+			scheduler,
+		)
+	}
+}
+
+impl<C, In, InError> ComponentDestination<C, In, InError>
+where
+	C: Component<Mutability = Mutable>,
+	In: Signal,
+	InError: Signal,
+{
+	pub fn mutate<Mutator>(
+		destination: Entity,
+		mut mutator: Mutator,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self
+	where
+		Mutator: 'static + FnMut(Mut<'_, C>, In) + Send + Sync,
+	{
+		Self::new(
+			destination,
+			Arc::new(Mutex::new(
+				move |deferred_world: &mut DeferredWorld, destination: Entity, next: In| {
+					if let Some(component) = deferred_world.get_mut::<C>(destination) {
+						(mutator)(component, next);
+					}
+				},
+			)),
+			scheduler,
+		)
+	}
+}
+
+impl<C, In, InError> ComponentDestination<C, In, InError>
+where
+	C: Component,
+	In: Signal,
+	InError: Signal,
+{
+	fn new(
+		destination: Entity,
+		writer: Arc<Mutex<ComponentWriter<In>>>,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		let cancellation_id = scheduler.lock().generate_cancellation_id();
+		Self {
+			destination,
+			writer,
+			remove_on_complete: false,
+			scheduler,
+			cancellation_id,
+			_phantom_data: PhantomData,
+		}
+	}
+
+	/// Removes the component from the entity when the destination completes.
+	///
+	/// Default value: false
+	pub fn with_remove_on_complete(self, remove_on_complete: bool) -> Self {
+		Self {
+			remove_on_complete,
+			..self
+		}
+	}
+}
+
+impl<C, In, InError> RxObserver for ComponentDestination<C, In, InError>
+where
+	C: Component,
+	In: Signal,
+	InError: Signal,
+{
+	fn next(&mut self, next: Self::In) {
+		let destination = self.destination;
+		let writer = self.writer.clone();
+		self.scheduler.lock().schedule_immediate_work(
+			move |_, context| {
+				if context.deferred_world.get_entity(destination).is_ok()
+					&& let Ok(mut writer) = writer.lock()
+				{
+					(writer)(&mut context.deferred_world, destination, next);
+				}
+			},
+			self.cancellation_id,
+		);
+	}
+
+	#[inline]
+	fn error(&mut self, _error: Self::InError) {
+		debug_assert!(false, "ComponentDestination observed an uncaught error!")
+	}
+
+	fn complete(&mut self) {
+		if !self.remove_on_complete {
+			return;
+		}
+
+		let destination = self.destination;
+		self.scheduler.lock().schedule_immediate_work(
+			move |_, context| {
+				context
+					.deferred_world
+					.commands()
+					.entity(destination)
+					.try_remove::<C>();
+			},
+			self.cancellation_id,
+		);
+	}
+}
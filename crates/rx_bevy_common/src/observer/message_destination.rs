@@ -0,0 +1,69 @@
+use core::marker::PhantomData;
+
+use bevy_ecs::message::Message;
+use rx_core_common::{
+	Never, PhantomInvariant, RxObserver, Scheduler, SchedulerHandle,
+	SchedulerScheduleWorkExtension, Signal, WorkCancellationId,
+};
+use rx_core_macro_observer_derive::RxObserver;
+
+use crate::RxBevyScheduler;
+
+/// A destination that writes every observed value as a message, just like a
+/// `MessageWriter` would.
+///
+/// The message has to be registered using `add_message`, otherwise the
+/// observed values are dropped.
+///
+/// Just like the `NoopObserver`, it panics in dev mode when an error is
+/// observed.
+#[derive(RxObserver, Debug)]
+#[rx_in(M)]
+#[rx_in_error(InError)]
+pub struct MessageDestination<M, InError = Never>
+where
+	M: Message + Signal,
+	InError: Signal,
+{
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+	cancellation_id: WorkCancellationId,
+	_phantom_data: PhantomInvariant<(M, InError)>,
+}
+
+impl<M, InError> MessageDestination<M, InError>
+where
+	M: Message + Signal,
+	InError: Signal,
+{
+	pub fn new(scheduler: SchedulerHandle<RxBevyScheduler>) -> Self {
+		let cancellation_id = scheduler.lock().generate_cancellation_id();
+		Self {
+			scheduler,
+			cancellation_id,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<M, InError> RxObserver for MessageDestination<M, InError>
+where
+	M: Message + Signal,
+	InError: Signal,
+{
+	fn next(&mut self, next: Self::In) {
+		self.scheduler.lock().schedule_immediate_work(
+			move |_, context| {
+				context.deferred_world.write_message(next);
+			},
+			self.cancellation_id,
+		);
+	}
+
+	#[inline]
+	fn error(&mut self, _error: Self::InError) {
+		debug_assert!(false, "MessageDestination observed an uncaught error!")
+	}
+
+	#[inline]
+	fn complete(&mut self) {}
+}
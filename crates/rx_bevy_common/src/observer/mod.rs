@@ -1,5 +1,9 @@
+mod component_destination;
 mod entity_destination;
+mod message_destination;
 mod resource_destination;
 
+pub use component_destination::*;
 pub use entity_destination::*;
+pub use message_destination::*;
 pub use resource_destination::*;
@@ -1,6 +1,7 @@
 mod rx_bevy_executor;
 mod rx_bevy_scheduler;
 mod rx_plugin;
+mod rx_scheduler_diagnostics_plugin;
 mod rx_scheduler_plugin;
 mod rx_scheduler_system_param;
 mod subscribe_retry_plugin;
@@ -9,7 +10,12 @@ mod work;
 pub use rx_bevy_executor::*;
 pub use rx_bevy_scheduler::*;
 pub use rx_plugin::*;
+pub use rx_scheduler_diagnostics_plugin::*;
 pub use rx_scheduler_plugin::*;
 pub use rx_scheduler_system_param::*;
 pub(crate) use subscribe_retry_plugin::*;
 pub use work::*;
+
+pub use rx_core_scheduler_ticking::{
+	ScheduledWorkId, ScheduledWorkInfo, Tick, TickBudget, TickingExecutorMetrics, WorkStats,
+};
@@ -2,9 +2,12 @@ use std::marker::PhantomData;
 
 use bevy_ecs::{resource::Resource, schedule::ScheduleLabel};
 use bevy_time::Virtual;
-use rx_core_common::PhantomInvariant;
+use rx_core_common::{PhantomInvariant, WorkExecutor};
 use rx_core_macro_executor_derive::RxExecutor;
-use rx_core_scheduler_ticking::{Tick, TickingSchedulerExecutor};
+use rx_core_scheduler_ticking::{
+	ScheduledWorkInfo, SchedulerForTickingExecutor, Tick, TickBudget, TickingExecutorMetrics,
+	TickingSchedulerExecutor,
+};
 
 use crate::{Clock, RxBevyContext, RxBevyContextItem, RxBevyScheduler};
 
@@ -19,6 +22,10 @@ where
 {
 	#[scheduler_handle]
 	ticking_executor: TickingSchedulerExecutor<RxBevyScheduler, RxBevyContext>,
+	/// The last tick read from the clock `C`.
+	clock_tick: Tick,
+	paused: bool,
+	relative_speed: f64,
 	_phantom_data: PhantomInvariant<(S, C)>,
 }
 
@@ -31,6 +38,95 @@ where
 	pub fn is_empty(&self) -> bool {
 		self.ticking_executor.is_empty()
 	}
+
+	/// Limits how much work is executed in a single frame, the rest spills
+	/// over into the next frame, lowest priority first.
+	#[inline]
+	pub fn set_tick_budget(&mut self, tick_budget: TickBudget) {
+		self.ticking_executor.set_tick_budget(tick_budget);
+	}
+
+	#[inline]
+	pub fn get_tick_budget(&self) -> TickBudget {
+		self.ticking_executor.get_tick_budget()
+	}
+
+	#[inline]
+	pub fn get_metrics(&self) -> TickingExecutorMetrics {
+		self.ticking_executor.get_metrics()
+	}
+
+	/// Measures the real time spent executing each work.
+	#[inline]
+	pub fn set_execution_time_tracking(&mut self, track_execution_time: bool) {
+		self.ticking_executor
+			.set_execution_time_tracking(track_execution_time);
+	}
+
+	/// Lists all work received by the executor.
+	#[inline]
+	pub fn get_work_info(&self) -> impl Iterator<Item = ScheduledWorkInfo> + '_ {
+		self.ticking_executor.get_work_info()
+	}
+
+	/// Stops the time of this executor only, without affecting the clock `C`
+	/// or any other executor. Like when opening a pause menu, while the
+	/// executor driving the menu itself keeps running.
+	///
+	/// Work is still executed while paused, but no time passes for it, so
+	/// timers like `interval` or `delay` are frozen.
+	#[inline]
+	pub fn pause(&mut self) {
+		self.paused = true;
+	}
+
+	/// Resumes the time of this executor from where it was paused, the time
+	/// passed on the clock while paused is skipped.
+	#[inline]
+	pub fn unpause(&mut self) {
+		self.paused = false;
+	}
+
+	#[inline]
+	pub fn is_paused(&self) -> bool {
+		self.paused
+	}
+
+	/// Scales the time passing for this executor, relative to its clock `C`.
+	///
+	/// Default value: 1.0
+	///
+	/// # Panics
+	///
+	/// Panics if `relative_speed` is negative or not finite.
+	pub fn set_relative_speed(&mut self, relative_speed: f64) {
+		assert!(
+			relative_speed.is_finite() && relative_speed >= 0.0,
+			"The relative speed of an executor must be finite and non-negative!"
+		);
+		self.relative_speed = relative_speed;
+	}
+
+	#[inline]
+	pub fn get_relative_speed(&self) -> f64 {
+		self.relative_speed
+	}
+
+	/// The current time of this executor, which is only the same as the time
+	/// of its clock `C` if it was never paused or scaled.
+	#[inline]
+	pub fn get_current_tick(&self) -> Tick {
+		self.ticking_executor.get_current_tick()
+	}
+
+	/// Lists work scheduled but not yet received by the executor, it will be
+	/// on its next tick.
+	pub fn get_queued_work_info(&self) -> Vec<ScheduledWorkInfo> {
+		self.get_scheduler_handle()
+			.lock()
+			.get_queued_work_info()
+			.collect()
+	}
 }
 
 impl<S, C> Default for RxBevyExecutor<S, C>
@@ -41,6 +137,9 @@ where
 	fn default() -> Self {
 		Self {
 			ticking_executor: TickingSchedulerExecutor::new(RxBevyScheduler::default()),
+			clock_tick: Tick::default(),
+			paused: false,
+			relative_speed: 1.0,
 			_phantom_data: PhantomData,
 		}
 	}
@@ -54,4 +153,17 @@ where
 	pub fn tick_to<'a>(&mut self, tick: Tick, context: &mut RxBevyContextItem<'a>) {
 		self.ticking_executor.tick_to(tick, context);
 	}
+
+	/// Returns the tick the executor should advance to, based on how much
+	/// time has passed on its clock since it was last read.
+	pub(crate) fn advance_clock(&mut self, clock_tick: Tick) -> Tick {
+		let clock_delta = clock_tick.saturating_sub(*self.clock_tick);
+		self.clock_tick = clock_tick;
+
+		if self.paused {
+			self.get_current_tick()
+		} else {
+			self.get_current_tick() + clock_delta.mul_f64(self.relative_speed)
+		}
+	}
 }
@@ -1,5 +1,5 @@
 use rx_core_common::{
-	ScheduledWork, ScheduledWorkAction, Scheduler, WorkCancellationId, WorkInvokeId,
+	ScheduledWork, ScheduledWorkAction, Scheduler, SchedulerClock, WorkCancellationId, WorkInvokeId,
 };
 use rx_core_macro_scheduler_derive::RxScheduler;
 use rx_core_scheduler_ticking::{
@@ -28,6 +28,11 @@ impl SchedulerForTickingExecutor for RxBevyScheduler {
 		self.ticking_scheduler.has_actions()
 	}
 
+	#[inline]
+	fn get_queued_actions(&self) -> &[ScheduledWorkAction<Tick, RxBevyContext>] {
+		self.ticking_scheduler.get_queued_actions()
+	}
+
 	#[inline]
 	fn update_tick(&mut self, tick: Tick) {
 		self.ticking_scheduler.update_tick(tick);
@@ -89,3 +94,10 @@ impl Scheduler for RxBevyScheduler {
 		self.ticking_scheduler.generate_invoke_id()
 	}
 }
+
+impl SchedulerClock for RxBevyScheduler {
+	#[inline]
+	fn current_tick(&self) -> Self::Tick {
+		self.ticking_scheduler.current_tick()
+	}
+}
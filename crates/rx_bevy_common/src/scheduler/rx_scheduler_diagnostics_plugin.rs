@@ -0,0 +1,228 @@
+use std::marker::PhantomData;
+
+use bevy_app::{App, Plugin};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::{
+	resource::Resource,
+	schedule::{IntoScheduleConfigs, ScheduleLabel},
+	system::{Res, ResMut},
+};
+use derive_where::derive_where;
+use disqualified::ShortName;
+use rx_core_common::{PhantomInvariant, WorkKind};
+use rx_core_scheduler_ticking::{ScheduledWorkInfo, TickingExecutorMetrics};
+
+use crate::{Clock, RxBevyExecutor, rx_executor};
+
+/// # [RxSchedulerDiagnosticsPlugin]
+///
+/// Collects the work held by the executor of the `S` bevy schedule and `C`
+/// clock combination into the [`RxExecutorDiagnostics`] resource after every
+/// tick, and feeds the work counts into `bevy_diagnostic`.
+///
+/// Useful to find leaked work, like intervals that are never unsubscribed.
+///
+/// Requires the matching [`RxSchedulerPlugin`][crate::RxSchedulerPlugin].
+#[derive_where(Default)]
+pub struct RxSchedulerDiagnosticsPlugin<S, C>
+where
+	S: ScheduleLabel + Default + Clone,
+	C: Clock,
+{
+	track_execution_time: bool,
+	_phantom_data: PhantomInvariant<(S, C)>,
+}
+
+impl<S, C> RxSchedulerDiagnosticsPlugin<S, C>
+where
+	S: ScheduleLabel + Default + Clone,
+	C: Clock,
+{
+	/// Enables measuring the real time spent executing each work on the
+	/// executor, reported in the `execution_time` of their stats. This costs
+	/// a clock read for every work on every tick.
+	///
+	/// Not available on the web, where it's always disabled.
+	///
+	/// Default value: false
+	pub fn with_execution_time_tracking(self, track_execution_time: bool) -> Self {
+		Self {
+			track_execution_time,
+			..self
+		}
+	}
+}
+
+impl<S, C> Plugin for RxSchedulerDiagnosticsPlugin<S, C>
+where
+	S: ScheduleLabel + Default + Clone,
+	C: Clock,
+{
+	fn build(&self, app: &mut App) {
+		let executor_diagnostics = RxExecutorDiagnostics::<S, C>::default();
+
+		for path in executor_diagnostics.paths.iter() {
+			app.register_diagnostic(Diagnostic::new(path.clone()));
+		}
+
+		app.insert_resource(executor_diagnostics);
+
+		app.init_resource::<RxBevyExecutor<S, C>>();
+		// `std::time::Instant` is not available on the web.
+		if self.track_execution_time && !cfg!(target_arch = "wasm32") {
+			app.world_mut()
+				.resource_mut::<RxBevyExecutor<S, C>>()
+				.set_execution_time_tracking(true);
+		}
+
+		app.add_systems(
+			S::default(),
+			collect_rx_executor_diagnostics::<S, C>.after(rx_executor::<S, C>),
+		);
+	}
+}
+
+/// The [`DiagnosticPath`]s of an executor, each prefixed with
+/// `rx_executor/<schedule>/<clock>`.
+#[derive(Debug, Clone)]
+pub struct RxExecutorDiagnosticPaths {
+	/// All work held by the executor, invokable work included.
+	pub work: DiagnosticPath,
+	pub immediate_work: DiagnosticPath,
+	pub delayed_work: DiagnosticPath,
+	pub repeated_work: DiagnosticPath,
+	pub continuous_work: DiagnosticPath,
+	pub invoked_work: DiagnosticPath,
+	pub other_work: DiagnosticPath,
+	/// Work ticked during the last tick.
+	pub ticked_work: DiagnosticPath,
+	/// Work deferred to the next tick during the last tick.
+	pub spilled_over_work: DiagnosticPath,
+}
+
+impl RxExecutorDiagnosticPaths {
+	fn new<S, C>() -> Self
+	where
+		S: ScheduleLabel,
+		C: Clock,
+	{
+		let schedule = ShortName::of::<S>().to_string();
+		let clock = ShortName::of::<C>().to_string();
+		let path =
+			|name: &str| DiagnosticPath::from_components(["rx_executor", &schedule, &clock, name]);
+
+		Self {
+			work: path("work"),
+			immediate_work: path("immediate_work"),
+			delayed_work: path("delayed_work"),
+			repeated_work: path("repeated_work"),
+			continuous_work: path("continuous_work"),
+			invoked_work: path("invoked_work"),
+			other_work: path("other_work"),
+			ticked_work: path("ticked_work"),
+			spilled_over_work: path("spilled_over_work"),
+		}
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &DiagnosticPath> {
+		[
+			&self.work,
+			&self.immediate_work,
+			&self.delayed_work,
+			&self.repeated_work,
+			&self.continuous_work,
+			&self.invoked_work,
+			&self.other_work,
+			&self.ticked_work,
+			&self.spilled_over_work,
+		]
+		.into_iter()
+	}
+
+	pub fn of_kind(&self, kind: WorkKind) -> &DiagnosticPath {
+		match kind {
+			WorkKind::Immediate => &self.immediate_work,
+			WorkKind::Delayed => &self.delayed_work,
+			WorkKind::Repeated => &self.repeated_work,
+			WorkKind::Continuous => &self.continuous_work,
+			WorkKind::Invoked => &self.invoked_work,
+			WorkKind::Other => &self.other_work,
+		}
+	}
+}
+
+/// A snapshot of the work held by the executor of the `S` bevy schedule and
+/// `C` clock combination, taken after its last tick.
+///
+/// Added by the [`RxSchedulerDiagnosticsPlugin`].
+#[derive(Resource)]
+pub struct RxExecutorDiagnostics<S, C>
+where
+	S: ScheduleLabel,
+	C: Clock,
+{
+	pub work: Vec<ScheduledWorkInfo>,
+	pub metrics: TickingExecutorMetrics,
+	pub paths: RxExecutorDiagnosticPaths,
+	_phantom_data: PhantomInvariant<(S, C)>,
+}
+
+impl<S, C> RxExecutorDiagnostics<S, C>
+where
+	S: ScheduleLabel,
+	C: Clock,
+{
+	pub fn count_of_kind(&self, kind: WorkKind) -> usize {
+		self.work.iter().filter(|info| info.kind == kind).count()
+	}
+}
+
+impl<S, C> Default for RxExecutorDiagnostics<S, C>
+where
+	S: ScheduleLabel,
+	C: Clock,
+{
+	fn default() -> Self {
+		Self {
+			work: Vec::new(),
+			metrics: TickingExecutorMetrics::default(),
+			paths: RxExecutorDiagnosticPaths::new::<S, C>(),
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+pub fn collect_rx_executor_diagnostics<S, C>(
+	executor: Res<RxBevyExecutor<S, C>>,
+	mut executor_diagnostics: ResMut<RxExecutorDiagnostics<S, C>>,
+	mut diagnostics: Diagnostics,
+) where
+	S: ScheduleLabel,
+	C: Clock,
+{
+	let executor_diagnostics = &mut *executor_diagnostics;
+	executor_diagnostics.work.clear();
+	executor_diagnostics.work.extend(executor.get_work_info());
+	executor_diagnostics.metrics = executor.get_metrics();
+
+	let paths = &executor_diagnostics.paths;
+	diagnostics.add_measurement(&paths.work, || executor_diagnostics.work.len() as f64);
+	for kind in [
+		WorkKind::Immediate,
+		WorkKind::Delayed,
+		WorkKind::Repeated,
+		WorkKind::Continuous,
+		WorkKind::Invoked,
+		WorkKind::Other,
+	] {
+		diagnostics.add_measurement(paths.of_kind(kind), || {
+			executor_diagnostics.count_of_kind(kind) as f64
+		});
+	}
+	diagnostics.add_measurement(&paths.ticked_work, || {
+		executor_diagnostics.metrics.ticked_work as f64
+	});
+	diagnostics.add_measurement(&paths.spilled_over_work, || {
+		executor_diagnostics.metrics.spilled_over_work as f64
+	});
+}
@@ -3,10 +3,8 @@ use bevy_ecs::{
 	schedule::ScheduleLabel,
 	world::{DeferredWorld, Mut, World},
 };
-use bevy_time::Time;
 use derive_where::derive_where;
 use rx_core_common::PhantomInvariant;
-use rx_core_scheduler_ticking::Tick;
 
 use crate::{Clock, RxBevyExecutor, SubscribeRetryPlugin};
 
@@ -14,8 +12,9 @@ use crate::{Clock, RxBevyExecutor, SubscribeRetryPlugin};
 ///
 /// Executes scheduled work issued by subscriptions.
 /// You need to add this plugin for every `S` bevy schedule (`Update`,
-/// `PostUpdate`) and `C` clock (`Virtual`, `Real`) combination you're using
-/// for [`RxSchedule`][crate::RxSchedule]s within your app.
+/// `PostUpdate`, `FixedUpdate`) and `C` [`Clock`] (`Virtual`, `Real`,
+/// `Fixed` or your own) combination you're using for
+/// [`RxSchedule`][crate::RxSchedule]s within your app.
 ///
 /// Don't forget to add the base [`RxPlugin`][crate::RxPlugin] too!
 ///
@@ -86,9 +85,10 @@ where
 	S: ScheduleLabel,
 	C: Clock,
 {
-	let tick = Tick::new(world.resource::<Time<C>>().elapsed());
+	let clock_tick = C::tick(world);
 
 	world.resource_scope(|world, mut executor: Mut<RxBevyExecutor<S, C>>| {
+		let tick = executor.advance_clock(clock_tick);
 		let deferred_world = DeferredWorld::from(world);
 		let mut context = deferred_world.into();
 		executor.tick_to(tick, &mut context);
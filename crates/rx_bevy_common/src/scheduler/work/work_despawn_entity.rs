@@ -1,5 +1,7 @@
 use bevy_ecs::entity::Entity;
-use rx_core_common::{ScheduledWork, Scheduler, WorkCancellationId, WorkInvokeId, WorkResult};
+use rx_core_common::{
+	ScheduledWork, Scheduler, WorkCancellationId, WorkInvokeId, WorkKind, WorkResult,
+};
 use rx_core_macro_work_derive::RxWork;
 use rx_core_scheduler_ticking::Tick;
 
@@ -21,6 +23,10 @@ impl ScheduledWorkDespawnEntity {
 impl ScheduledWork for ScheduledWorkDespawnEntity {
 	fn on_scheduled_hook(&mut self, _tick_input: Self::Tick) {}
 
+	fn kind(&self) -> WorkKind {
+		WorkKind::Immediate
+	}
+
 	fn tick(
 		&mut self,
 		_input: Self::Tick,
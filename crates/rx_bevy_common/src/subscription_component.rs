@@ -12,6 +12,10 @@ use crate::{RxBevyScheduler, RxBevySchedulerDespawnEntityExtension};
 
 #[derive(Component, RxSubscription, Clone, Deref, DerefMut, Default)]
 #[component(on_remove=subscription_unsubscribe_on_remove)]
+#[cfg_attr(
+	feature = "debug_subscriptions",
+	component(on_add=subscription_annotate_entity_on_add)
+)]
 #[rx_delegate_subscription_like_to_destination]
 #[rx_delegate_teardown_collection]
 #[require(Name::new(format!("{}", ShortName::of::<Self>())))]
@@ -59,3 +63,19 @@ fn subscription_unsubscribe_on_remove(
 
 	subscription_component.unsubscribe();
 }
+
+/// Marks the entity owning the subscription in the
+/// [`SubscriptionRegistry`][rx_core_common::SubscriptionRegistry].
+#[cfg(feature = "debug_subscriptions")]
+fn subscription_annotate_entity_on_add(deferred_world: DeferredWorld, hook_context: HookContext) {
+	let subscription_component = deferred_world
+		.get::<SubscriptionComponent>(hook_context.entity)
+		.unwrap();
+
+	if let Some(debug_id) = subscription_component.debug_id() {
+		rx_core_common::SubscriptionRegistry::annotate(
+			debug_id,
+			format!("entity {}", hook_context.entity),
+		);
+	}
+}
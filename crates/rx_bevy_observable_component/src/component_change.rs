@@ -0,0 +1,13 @@
+/// The signal emitted by a `ComponentObservable`, describing what happened
+/// to the observed component.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ComponentChange<Out> {
+	/// The component was just added to the entity, contains the result of the
+	/// reader function.
+	Added(Out),
+	/// The component was accessed mutably, contains the result of the reader
+	/// function.
+	Changed(Out),
+	/// The component was removed from the entity, or the entity was despawned.
+	Removed,
+}
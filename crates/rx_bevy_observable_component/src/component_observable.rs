@@ -0,0 +1,97 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{component::Component, entity::Entity};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{
+	Never, Observable, PhantomInvariant, SchedulerHandle, Signal, Subscriber, UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{
+	ComponentSubscription,
+	observable::{ComponentChange, ComponentObservableOptions},
+};
+
+/// # [ComponentObservable]
+///
+/// The `ComponentObservable` call a "reader" function on a component of an
+/// entity every time it is added or mutated, emitting the result to
+/// subscribers. Removals are emitted too, and once the entity is despawned,
+/// the observable completes.
+///
+/// ## Options
+///
+/// - `trigger_on_is_added`: Emit also when the component was just added.
+///   (Default: true)
+/// - `trigger_on_is_changed`: Emit on each tick where the component was
+///   accessed mutably, except when the component was just added.
+///   (Default: true)
+/// - `trigger_on_is_removed`: Emit when the component was removed, or the
+///   entity was despawned.
+///   (Default: true)
+#[derive(RxObservable)]
+#[rx_out(ComponentChange<Out>)]
+#[rx_out_error(Never)]
+pub struct ComponentObservable<C, Reader, Out>
+where
+	C: Component,
+	Reader: 'static + Fn(&C) -> Out + Clone + Send + Sync,
+	Out: Signal,
+{
+	entity: Entity,
+	reader: Reader,
+	options: ComponentObservableOptions,
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+	_phantom_data: PhantomInvariant<C>,
+}
+
+impl<C, Reader, Out> ComponentObservable<C, Reader, Out>
+where
+	C: Component,
+	Reader: 'static + Fn(&C) -> Out + Clone + Send + Sync,
+	Out: Signal,
+{
+	pub fn new(
+		entity: Entity,
+		reader: Reader,
+		options: ComponentObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		Self {
+			entity,
+			reader,
+			options,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<C, Reader, Out> Observable for ComponentObservable<C, Reader, Out>
+where
+	C: Component,
+	Reader: 'static + Fn(&C) -> Out + Clone + Send + Sync,
+	Out: Signal,
+{
+	type Subscription<Destination>
+		= ComponentSubscription<C, Reader, Out, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		ComponentSubscription::new(
+			destination.upgrade(),
+			self.entity,
+			self.reader.clone(),
+			self.options.clone(),
+			self.scheduler.clone(),
+		)
+	}
+}
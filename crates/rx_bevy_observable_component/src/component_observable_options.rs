@@ -0,0 +1,29 @@
+#[derive(Clone)]
+pub struct ComponentObservableOptions {
+	/// Emit also when the component was just added. Note that the observable
+	/// does **NOT** trigger immediately when the component is added, but on the
+	/// schedule the subscription was made for when it was first observed as
+	/// added.
+	///
+	/// Default: true
+	pub trigger_on_is_added: bool,
+	/// Emit on each tick where the component was accessed mutably, except when
+	/// the component was just added.
+	///
+	/// Default: true
+	pub trigger_on_is_changed: bool,
+	/// Emit when the component was removed, or the entity was despawned.
+	///
+	/// Default: true
+	pub trigger_on_is_removed: bool,
+}
+
+impl Default for ComponentObservableOptions {
+	fn default() -> Self {
+		Self {
+			trigger_on_is_added: true,
+			trigger_on_is_changed: true,
+			trigger_on_is_removed: true,
+		}
+	}
+}
@@ -0,0 +1,81 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{component::Component, entity::Entity, query::QueryFilter};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{
+	Never, Observable, PhantomInvariant, SchedulerHandle, Subscriber, UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{ComponentQuerySubscription, observable::ComponentQueryObservableOptions};
+
+/// # [ComponentQueryObservable]
+///
+/// The `ComponentQueryObservable` emits a clone of the component, along with
+/// its entity, for every entity matching the query where the component was
+/// added or mutated. An additional `QueryFilter` can be used to further narrow
+/// down which entities are observed.
+///
+/// ## Options
+///
+/// - `trigger_on_is_added`: Emit also for entities where the component was
+///   just added, or that just started matching the query.
+///   (Default: true)
+/// - `trigger_on_is_changed`: Emit on each tick where the component of an
+///   entity was accessed mutably, except when the component was just added.
+///   (Default: true)
+#[derive(RxObservable)]
+#[rx_out((Entity, C))]
+#[rx_out_error(Never)]
+pub struct ComponentQueryObservable<C, F = ()>
+where
+	C: Component + Clone,
+	F: 'static + QueryFilter + Send + Sync,
+{
+	options: ComponentQueryObservableOptions,
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+	_phantom_data: PhantomInvariant<(C, F)>,
+}
+
+impl<C, F> ComponentQueryObservable<C, F>
+where
+	C: Component + Clone,
+	F: 'static + QueryFilter + Send + Sync,
+{
+	pub fn new(
+		options: ComponentQueryObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		Self {
+			options,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<C, F> Observable for ComponentQueryObservable<C, F>
+where
+	C: Component + Clone,
+	F: 'static + QueryFilter + Send + Sync,
+{
+	type Subscription<Destination>
+		= ComponentQuerySubscription<C, F, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		ComponentQuerySubscription::new(
+			destination.upgrade(),
+			self.options.clone(),
+			self.scheduler.clone(),
+		)
+	}
+}
@@ -0,0 +1,22 @@
+#[derive(Clone)]
+pub struct ComponentQueryObservableOptions {
+	/// Emit also for entities where the component was just added, or that just
+	/// started matching the query.
+	///
+	/// Default: true
+	pub trigger_on_is_added: bool,
+	/// Emit on each tick where the component of an entity was accessed
+	/// mutably, except when the component was just added.
+	///
+	/// Default: true
+	pub trigger_on_is_changed: bool,
+}
+
+impl Default for ComponentQueryObservableOptions {
+	fn default() -> Self {
+		Self {
+			trigger_on_is_added: true,
+			trigger_on_is_changed: true,
+		}
+	}
+}
@@ -0,0 +1,106 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+	change_detection::{DetectChanges, Ref},
+	component::Component,
+	entity::{Entity, EntityHashSet},
+	query::{QueryFilter, QueryState},
+};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{
+	PhantomInvariant, RxObserver, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension,
+	SharedSubscriber, Subscriber, SubscriptionLike, Teardown, TeardownCollectionExtension,
+	WorkResult,
+};
+use rx_core_macro_subscription_derive::RxSubscription;
+
+use crate::observable::ComponentQueryObservableOptions;
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct ComponentQuerySubscription<C, F, Destination>
+where
+	C: Component + Clone,
+	F: 'static + QueryFilter + Send + Sync,
+	Destination: 'static + Subscriber<In = (Entity, C)>,
+{
+	#[destination]
+	shared_destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<(C, F)>,
+}
+
+impl<C, F, Destination> ComponentQuerySubscription<C, F, Destination>
+where
+	C: Component + Clone,
+	F: 'static + QueryFilter + Send + Sync,
+	Destination: 'static + Subscriber<In = (Entity, C)>,
+{
+	pub fn new(
+		destination: Destination,
+		options: ComponentQueryObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		let mut shared_destination = SharedSubscriber::new(destination);
+
+		let cancellation_id = {
+			let mut scheduler_lock = scheduler.lock();
+			let cancellation_id = scheduler_lock.generate_cancellation_id();
+
+			// Created lazily, as the component may not be registered yet
+			let mut query_state: Option<QueryState<(Entity, Ref<C>), F>> = None;
+			let mut entities_matched_in_the_previous_tick = EntityHashSet::default();
+			let mut shared_destination_clone = shared_destination.clone();
+			scheduler_lock.schedule_continuous_work(
+				move |_tick, context| {
+					if query_state.is_none() {
+						query_state = QueryState::try_new(&context.deferred_world);
+					}
+
+					let Some(query_state) = query_state.as_mut() else {
+						return WorkResult::Pending;
+					};
+
+					let mut entities_matched_this_tick = EntityHashSet::default();
+					let query = context.deferred_world.query(query_state);
+
+					for (entity, component) in query.iter() {
+						entities_matched_this_tick.insert(entity);
+						let is_added = !entities_matched_in_the_previous_tick.contains(&entity);
+
+						// is_changed is always true when is_added is true
+						let is_changed_condition =
+							options.trigger_on_is_changed && component.is_changed() && !is_added;
+						let is_added_condition = options.trigger_on_is_added && is_added;
+
+						if is_changed_condition || is_added_condition {
+							shared_destination_clone.next((entity, component.clone()));
+
+							if shared_destination_clone.is_closed() {
+								return WorkResult::Done;
+							}
+						}
+					}
+
+					entities_matched_in_the_previous_tick = entities_matched_this_tick;
+
+					if shared_destination_clone.is_closed() {
+						WorkResult::Done
+					} else {
+						WorkResult::Pending
+					}
+				},
+				cancellation_id,
+			);
+
+			cancellation_id
+		};
+
+		shared_destination.add(Teardown::new_work_cancellation(cancellation_id, scheduler));
+
+		Self {
+			shared_destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{change_detection::DetectChanges, component::Component, entity::Entity};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{
+	PhantomInvariant, RxObserver, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension,
+	SharedSubscriber, Signal, Subscriber, SubscriptionLike, Teardown, TeardownCollectionExtension,
+	WorkResult,
+};
+use rx_core_macro_subscription_derive::RxSubscription;
+
+use crate::observable::{ComponentChange, ComponentObservableOptions};
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct ComponentSubscription<C, Reader, Out, Destination>
+where
+	C: Component,
+	Reader: 'static + Fn(&C) -> Out + Clone + Send + Sync,
+	Out: Signal,
+	Destination: 'static + Subscriber<In = ComponentChange<Out>>,
+{
+	#[destination]
+	shared_destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<(C, Reader)>,
+}
+
+impl<C, Reader, Out, Destination> ComponentSubscription<C, Reader, Out, Destination>
+where
+	C: Component,
+	Reader: 'static + Fn(&C) -> Out + Clone + Send + Sync,
+	Out: Signal,
+	Destination: 'static + Subscriber<In = ComponentChange<Out>>,
+{
+	pub fn new(
+		destination: Destination,
+		entity: Entity,
+		reader: Reader,
+		options: ComponentObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		let mut shared_destination = SharedSubscriber::new(destination);
+
+		let cancellation_id = {
+			let mut scheduler_lock = scheduler.lock();
+			let cancellation_id = scheduler_lock.generate_cancellation_id();
+
+			let mut component_existed_in_the_previous_tick = false;
+			let mut shared_destination_clone = shared_destination.clone();
+			scheduler_lock.schedule_continuous_work(
+				move |_tick, context| {
+					let Ok(entity_ref) = context.deferred_world.get_entity(entity) else {
+						if options.trigger_on_is_removed && component_existed_in_the_previous_tick {
+							shared_destination_clone.next(ComponentChange::Removed);
+						}
+						shared_destination_clone.complete();
+						return WorkResult::Done;
+					};
+
+					let component_option = entity_ref.get_ref::<C>();
+
+					let is_added = {
+						let component_exists_this_tick = component_option.is_some();
+						let is_added =
+							!component_existed_in_the_previous_tick && component_exists_this_tick;
+						let is_removed =
+							component_existed_in_the_previous_tick && !component_exists_this_tick;
+						component_existed_in_the_previous_tick = component_exists_this_tick;
+
+						if options.trigger_on_is_removed && is_removed {
+							shared_destination_clone.next(ComponentChange::Removed);
+						}
+
+						is_added
+					};
+
+					if let Some(component) = component_option {
+						// is_changed is always true when is_added is true
+						if options.trigger_on_is_added && is_added {
+							let next = (reader)(&component);
+							shared_destination_clone.next(ComponentChange::Added(next));
+						} else if options.trigger_on_is_changed
+							&& component.is_changed()
+							&& !is_added
+						{
+							let next = (reader)(&component);
+							shared_destination_clone.next(ComponentChange::Changed(next));
+						}
+					}
+
+					if shared_destination_clone.is_closed() {
+						WorkResult::Done
+					} else {
+						WorkResult::Pending
+					}
+				},
+				cancellation_id,
+			);
+
+			cancellation_id
+		};
+
+		shared_destination.add(Teardown::new_work_cancellation(cancellation_id, scheduler));
+
+		Self {
+			shared_destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
@@ -0,0 +1,18 @@
+mod component_change;
+mod component_observable;
+mod component_observable_options;
+mod component_query_observable;
+mod component_query_observable_options;
+mod component_query_subscription;
+mod component_subscription;
+
+pub use component_query_subscription::*;
+pub use component_subscription::*;
+
+pub mod observable {
+	pub use super::component_change::*;
+	pub use super::component_observable::*;
+	pub use super::component_observable_options::*;
+	pub use super::component_query_observable::*;
+	pub use super::component_query_observable_options::*;
+}
@@ -0,0 +1,64 @@
+use bevy_ecs::entity::Entity;
+use bevy_input::gamepad::GamepadAxis;
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{Never, Observable, SchedulerHandle, Subscriber, UpgradeableObserver};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{GamepadAxisObservableOptions, GamepadAxisSubscription};
+
+/// # [GamepadAxisObservable]
+///
+/// The `GamepadAxisObservable` turns Bevy gamepad axis input into signals,
+/// along with the entity of the gamepad and the axis. The input is sourced
+/// from the `Gamepad` components.
+///
+/// Values within the `dead_zone` are treated as zero.
+///
+/// ## Options
+///
+/// Axis signals can be observed in multiple modes:
+///
+/// - `GamepadAxisObservableEmit::OnChange` - emits once when the value of the
+///   axis changes, including when it returns into the dead zone.
+/// - `GamepadAxisObservableEmit::WhileActive` - emits continuously while the
+///   axis is outside of the dead zone.
+///
+/// And can be limited to a single gamepad using the `gamepad` option.
+#[derive(RxObservable)]
+#[rx_out((Entity, GamepadAxis, f32))]
+#[rx_out_error(Never)]
+pub struct GamepadAxisObservable {
+	options: GamepadAxisObservableOptions,
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+}
+
+impl GamepadAxisObservable {
+	pub fn new(
+		options: GamepadAxisObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		Self { options, scheduler }
+	}
+}
+
+impl Observable for GamepadAxisObservable {
+	type Subscription<Destination>
+		= GamepadAxisSubscription<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		GamepadAxisSubscription::new(
+			destination.upgrade(),
+			self.options.clone(),
+			self.scheduler.clone(),
+		)
+	}
+}
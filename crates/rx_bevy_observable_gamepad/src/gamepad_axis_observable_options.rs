@@ -0,0 +1,35 @@
+use bevy_ecs::entity::Entity;
+
+#[derive(Clone)]
+pub struct GamepadAxisObservableOptions {
+	pub emit: GamepadAxisObservableEmit,
+	/// Axis values closer to zero than this are treated as zero, filtering
+	/// out the noise of sticks at rest.
+	///
+	/// Default: 0.1
+	pub dead_zone: f32,
+	/// Only observe this gamepad entity, or all of them when `None`.
+	///
+	/// Default: None
+	pub gamepad: Option<Entity>,
+}
+
+impl Default for GamepadAxisObservableOptions {
+	fn default() -> Self {
+		Self {
+			emit: GamepadAxisObservableEmit::default(),
+			dead_zone: 0.1,
+			gamepad: None,
+		}
+	}
+}
+
+#[derive(Default, Clone)]
+pub enum GamepadAxisObservableEmit {
+	/// Emits once when the value of the axis changes, including when it
+	/// returns into the dead zone.
+	#[default]
+	OnChange,
+	/// Emits continuously while the axis is outside of the dead zone.
+	WhileActive,
+}
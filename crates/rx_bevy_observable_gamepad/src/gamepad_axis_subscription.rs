@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use bevy_ecs::{entity::Entity, query::QueryState};
+use bevy_input::gamepad::{Gamepad, GamepadAxis, GamepadInput};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::*;
+use rx_core_macro_subscription_derive::RxSubscription;
+
+use crate::{GamepadAxisObservableEmit, GamepadAxisObservableOptions};
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct GamepadAxisSubscription<Destination>
+where
+	Destination: 'static + Subscriber<In = (Entity, GamepadAxis, f32)>,
+{
+	#[destination]
+	shared_destination: SharedSubscriber<Destination>,
+}
+
+impl<Destination> GamepadAxisSubscription<Destination>
+where
+	Destination: 'static + Subscriber<In = (Entity, GamepadAxis, f32)>,
+{
+	pub fn new(
+		destination: Destination,
+		options: GamepadAxisObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		let mut shared_destination = SharedSubscriber::new(destination);
+
+		let cancellation_id = {
+			let mut scheduler_lock = scheduler.lock();
+			let cancellation_id = scheduler_lock.generate_cancellation_id();
+
+			// Created lazily, as no gamepad may have been connected yet
+			let mut gamepad_query_state: Option<QueryState<(Entity, &Gamepad)>> = None;
+			let mut last_values = HashMap::<(Entity, GamepadAxis), f32>::new();
+			let mut shared_destination_clone = shared_destination.clone();
+			scheduler_lock.schedule_continuous_work(
+				move |_tick, context| {
+					if gamepad_query_state.is_none() {
+						gamepad_query_state = QueryState::try_new(&context.deferred_world);
+					}
+
+					let Some(gamepad_query_state) = gamepad_query_state.as_mut() else {
+						return WorkResult::Pending;
+					};
+
+					let gamepad_query = context.deferred_world.query(gamepad_query_state);
+
+					for (entity, gamepad) in gamepad_query.iter() {
+						if options.gamepad.is_some_and(|gamepad| gamepad != entity) {
+							continue;
+						}
+
+						for (input, value) in gamepad.analog().all_axes_and_values() {
+							let GamepadInput::Axis(axis) = *input else {
+								continue;
+							};
+
+							let value = if value.abs() < options.dead_zone {
+								0.0
+							} else {
+								value
+							};
+
+							let should_emit = match options.emit {
+								GamepadAxisObservableEmit::OnChange => {
+									let last_value =
+										last_values.insert((entity, axis), value).unwrap_or(0.0);
+									last_value != value
+								}
+								GamepadAxisObservableEmit::WhileActive => value != 0.0,
+							};
+
+							if should_emit {
+								shared_destination_clone.next((entity, axis, value));
+
+								if shared_destination_clone.is_closed() {
+									return WorkResult::Done;
+								}
+							}
+						}
+					}
+
+					if shared_destination_clone.is_closed() {
+						WorkResult::Done
+					} else {
+						WorkResult::Pending
+					}
+				},
+				cancellation_id,
+			);
+
+			cancellation_id
+		};
+
+		shared_destination.add(Teardown::new_work_cancellation(cancellation_id, scheduler));
+
+		Self { shared_destination }
+	}
+}
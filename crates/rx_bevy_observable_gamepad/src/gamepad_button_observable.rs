@@ -0,0 +1,61 @@
+use bevy_ecs::entity::Entity;
+use bevy_input::gamepad::GamepadButton;
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{Never, Observable, SchedulerHandle, Subscriber, UpgradeableObserver};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{GamepadButtonObservableOptions, GamepadButtonSubscription};
+
+/// # [GamepadButtonObservable]
+///
+/// The `GamepadButtonObservable` turns Bevy gamepad button input into
+/// signals, along with the entity of the gamepad. The input is sourced from
+/// the `Gamepad` components.
+///
+/// ## Options
+///
+/// `GamepadButton` signals can be observed in multiple modes:
+///
+/// - `GamepadButtonObservableEmit::JustPressed` - emits once when the button is pressed down.
+/// - `GamepadButtonObservableEmit::JustReleased` - emits once when the button is released.
+/// - `GamepadButtonObservableEmit::WhilePressed` - emits continuously while the button is held down.
+///
+/// And can be limited to a single gamepad using the `gamepad` option.
+#[derive(RxObservable)]
+#[rx_out((Entity, GamepadButton))]
+#[rx_out_error(Never)]
+pub struct GamepadButtonObservable {
+	options: GamepadButtonObservableOptions,
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+}
+
+impl GamepadButtonObservable {
+	pub fn new(
+		options: GamepadButtonObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		Self { options, scheduler }
+	}
+}
+
+impl Observable for GamepadButtonObservable {
+	type Subscription<Destination>
+		= GamepadButtonSubscription<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		GamepadButtonSubscription::new(
+			destination.upgrade(),
+			self.options.clone(),
+			self.scheduler.clone(),
+		)
+	}
+}
@@ -0,0 +1,18 @@
+use bevy_ecs::entity::Entity;
+
+#[derive(Default, Clone)]
+pub struct GamepadButtonObservableOptions {
+	pub emit: GamepadButtonObservableEmit,
+	/// Only observe this gamepad entity, or all of them when `None`.
+	///
+	/// Default: None
+	pub gamepad: Option<Entity>,
+}
+
+#[derive(Default, Clone)]
+pub enum GamepadButtonObservableEmit {
+	#[default]
+	JustPressed,
+	WhilePressed,
+	JustReleased,
+}
@@ -0,0 +1,88 @@
+use bevy_ecs::{entity::Entity, query::QueryState};
+use bevy_input::gamepad::{Gamepad, GamepadButton};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::*;
+use rx_core_macro_subscription_derive::RxSubscription;
+
+use crate::{GamepadButtonObservableEmit, GamepadButtonObservableOptions};
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct GamepadButtonSubscription<Destination>
+where
+	Destination: 'static + Subscriber<In = (Entity, GamepadButton)>,
+{
+	#[destination]
+	shared_destination: SharedSubscriber<Destination>,
+}
+
+impl<Destination> GamepadButtonSubscription<Destination>
+where
+	Destination: 'static + Subscriber<In = (Entity, GamepadButton)>,
+{
+	pub fn new(
+		destination: Destination,
+		options: GamepadButtonObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		let mut shared_destination = SharedSubscriber::new(destination);
+
+		let cancellation_id = {
+			let mut scheduler_lock = scheduler.lock();
+			let cancellation_id = scheduler_lock.generate_cancellation_id();
+
+			// Created lazily, as no gamepad may have been connected yet
+			let mut gamepad_query_state: Option<QueryState<(Entity, &Gamepad)>> = None;
+			let mut shared_destination_clone = shared_destination.clone();
+			scheduler_lock.schedule_continuous_work(
+				move |_tick, context| {
+					if gamepad_query_state.is_none() {
+						gamepad_query_state = QueryState::try_new(&context.deferred_world);
+					}
+
+					let Some(gamepad_query_state) = gamepad_query_state.as_mut() else {
+						return WorkResult::Pending;
+					};
+
+					let gamepad_query = context.deferred_world.query(gamepad_query_state);
+
+					for (entity, gamepad) in gamepad_query.iter() {
+						if options.gamepad.is_some_and(|gamepad| gamepad != entity) {
+							continue;
+						}
+
+						let gamepad_button_iterator: &mut dyn Iterator<Item = GamepadButton> =
+							match options.emit {
+								GamepadButtonObservableEmit::JustPressed => {
+									&mut gamepad.get_just_pressed().copied()
+								}
+								GamepadButtonObservableEmit::JustReleased => {
+									&mut gamepad.get_just_released().copied()
+								}
+								GamepadButtonObservableEmit::WhilePressed => {
+									&mut gamepad.get_pressed().copied()
+								}
+							};
+
+						for gamepad_button in gamepad_button_iterator {
+							if !shared_destination_clone.is_closed() {
+								shared_destination_clone.next((entity, gamepad_button));
+							} else {
+								return WorkResult::Done;
+							}
+						}
+					}
+					WorkResult::Pending
+				},
+				cancellation_id,
+			);
+
+			cancellation_id
+		};
+
+		shared_destination.add(Teardown::new_work_cancellation(cancellation_id, scheduler));
+
+		Self { shared_destination }
+	}
+}
@@ -0,0 +1,18 @@
+mod gamepad_axis_observable;
+mod gamepad_axis_observable_options;
+mod gamepad_axis_subscription;
+mod gamepad_button_observable;
+mod gamepad_button_observable_options;
+mod gamepad_button_subscription;
+
+pub use gamepad_axis_observable_options::*;
+pub use gamepad_axis_subscription::*;
+pub use gamepad_button_observable_options::*;
+pub use gamepad_button_subscription::*;
+
+pub mod observable {
+	pub use super::gamepad_axis_observable::*;
+	pub use super::gamepad_axis_observable_options::*;
+	pub use super::gamepad_button_observable::*;
+	pub use super::gamepad_button_observable_options::*;
+}
@@ -0,0 +1,21 @@
+use bevy_ecs::entity::Entity;
+
+/// A change in the children of the entity observed by a
+/// [`ChildrenObservable`][crate::observable::ChildrenObservable].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ChildrenChange {
+	/// The child was added to the parent.
+	Added(Entity),
+	/// The child was removed from the parent, by either despawning it, or
+	/// removing or replacing its `ChildOf` component.
+	Removed(Entity),
+}
+
+impl ChildrenChange {
+	/// Returns the child entity that was added or removed.
+	pub fn child(&self) -> Entity {
+		match self {
+			Self::Added(child) | Self::Removed(child) => *child,
+		}
+	}
+}
@@ -0,0 +1,90 @@
+use bevy_ecs::{
+	entity::Entity,
+	hierarchy::ChildOf,
+	lifecycle::{Insert, Replace},
+	observer::{Observer, On},
+	system::Query,
+};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{
+	Never, Observable, RxObserver, SchedulerHandle, Subscriber, UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{ChildrenChange, LifecycleSubscription};
+
+/// # [ChildrenObservable]
+///
+/// The `ChildrenObservable` emits when children are added to or removed from
+/// the observed parent entity, and completes once the parent is despawned.
+///
+/// Moving a child to another parent is observed as a removal, and moving it
+/// to the observed parent is observed as an addition.
+#[derive(RxObservable)]
+#[rx_out(ChildrenChange)]
+#[rx_out_error(Never)]
+pub struct ChildrenObservable {
+	parent_entity: Entity,
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+}
+
+impl ChildrenObservable {
+	pub fn new(parent_entity: Entity, scheduler: SchedulerHandle<RxBevyScheduler>) -> Self {
+		Self {
+			parent_entity,
+			scheduler,
+		}
+	}
+}
+
+impl Observable for ChildrenObservable {
+	type Subscription<Destination>
+		= LifecycleSubscription<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		let parent_entity = self.parent_entity;
+		LifecycleSubscription::new(
+			destination.upgrade(),
+			|destination| {
+				let mut added_destination = destination.clone();
+				let mut removed_destination = destination;
+				vec![
+					Observer::new(
+						move |on_insert: On<Insert, ChildOf>, child_of_query: Query<&ChildOf>| {
+							if child_of_query
+								.get(on_insert.entity)
+								.is_ok_and(|child_of| child_of.parent() == parent_entity)
+							{
+								added_destination.next(ChildrenChange::Added(on_insert.entity));
+							}
+						},
+					),
+					// Replace runs before the component is removed, so the
+					// previous parent is still readable.
+					Observer::new(
+						move |on_replace: On<Replace, ChildOf>, child_of_query: Query<&ChildOf>| {
+							if child_of_query
+								.get(on_replace.entity)
+								.is_ok_and(|child_of| child_of.parent() == parent_entity)
+							{
+								removed_destination
+									.next(ChildrenChange::Removed(on_replace.entity));
+							}
+						},
+					),
+				]
+			},
+			Some(parent_entity),
+			self.scheduler.clone(),
+		)
+	}
+}
@@ -0,0 +1,85 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+	component::Component,
+	entity::Entity,
+	lifecycle::Despawn,
+	observer::{Observer, On},
+	query::QueryFilter,
+	system::Query,
+};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{
+	Never, Observable, PhantomInvariant, RxObserver, SchedulerHandle, Subscriber,
+	UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::LifecycleSubscription;
+
+/// # [EntityDespawnObservable]
+///
+/// The `EntityDespawnObservable` emits the entities that are despawned while
+/// having the component `C`, and also matching the query filter `F`.
+///
+/// It's built on the `Despawn` lifecycle event of `C`, so merely removing `C`
+/// from an entity is not observed.
+#[derive(RxObservable)]
+#[rx_out(Entity)]
+#[rx_out_error(Never)]
+pub struct EntityDespawnObservable<C, F = ()>
+where
+	C: Component,
+	F: 'static + QueryFilter,
+{
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+	_phantom_data: PhantomInvariant<(C, F)>,
+}
+
+impl<C, F> EntityDespawnObservable<C, F>
+where
+	C: Component,
+	F: 'static + QueryFilter,
+{
+	pub fn new(scheduler: SchedulerHandle<RxBevyScheduler>) -> Self {
+		Self {
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<C, F> Observable for EntityDespawnObservable<C, F>
+where
+	C: Component,
+	F: 'static + QueryFilter,
+{
+	type Subscription<Destination>
+		= LifecycleSubscription<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		LifecycleSubscription::new(
+			destination.upgrade(),
+			|mut destination| {
+				vec![Observer::new(
+					move |on_despawn: On<Despawn, C>, filter: Query<(), F>| {
+						if filter.contains(on_despawn.entity) {
+							destination.next(on_despawn.entity);
+						}
+					},
+				)]
+			},
+			None,
+			self.scheduler.clone(),
+		)
+	}
+}
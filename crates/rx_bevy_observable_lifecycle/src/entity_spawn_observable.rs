@@ -0,0 +1,86 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+	component::Component,
+	entity::Entity,
+	lifecycle::Add,
+	observer::{Observer, On},
+	query::QueryFilter,
+	system::Query,
+};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{
+	Never, Observable, PhantomInvariant, RxObserver, SchedulerHandle, Subscriber,
+	UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::LifecycleSubscription;
+
+/// # [EntitySpawnObservable]
+///
+/// The `EntitySpawnObservable` emits the entities that are spawned with the
+/// component `C`, and also match the query filter `F`.
+///
+/// It's built on the `Add` lifecycle event of `C`, so inserting `C` into an
+/// already existing entity that didn't have it before is also observed as a
+/// spawn.
+#[derive(RxObservable)]
+#[rx_out(Entity)]
+#[rx_out_error(Never)]
+pub struct EntitySpawnObservable<C, F = ()>
+where
+	C: Component,
+	F: 'static + QueryFilter,
+{
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+	_phantom_data: PhantomInvariant<(C, F)>,
+}
+
+impl<C, F> EntitySpawnObservable<C, F>
+where
+	C: Component,
+	F: 'static + QueryFilter,
+{
+	pub fn new(scheduler: SchedulerHandle<RxBevyScheduler>) -> Self {
+		Self {
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<C, F> Observable for EntitySpawnObservable<C, F>
+where
+	C: Component,
+	F: 'static + QueryFilter,
+{
+	type Subscription<Destination>
+		= LifecycleSubscription<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		LifecycleSubscription::new(
+			destination.upgrade(),
+			|mut destination| {
+				vec![Observer::new(
+					move |on_add: On<Add, C>, filter: Query<(), F>| {
+						if filter.contains(on_add.entity) {
+							destination.next(on_add.entity);
+						}
+					},
+				)]
+			},
+			None,
+			self.scheduler.clone(),
+		)
+	}
+}
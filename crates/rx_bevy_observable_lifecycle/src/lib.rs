@@ -0,0 +1,15 @@
+mod children_change;
+mod children_observable;
+mod entity_despawn_observable;
+mod entity_spawn_observable;
+mod lifecycle_subscription;
+
+pub use children_change::*;
+pub use lifecycle_subscription::*;
+
+pub mod observable {
+	pub use super::children_change::*;
+	pub use super::children_observable::*;
+	pub use super::entity_despawn_observable::*;
+	pub use super::entity_spawn_observable::*;
+}
@@ -0,0 +1,99 @@
+use bevy_ecs::{entity::Entity, name::Name, observer::Observer};
+use disqualified::ShortName;
+use rx_bevy_common::{
+	RxBevyScheduler, RxBevySchedulerDespawnEntityExtension, SubscriptionSatellite,
+};
+use rx_core_common::{
+	RxObserver, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension, SharedSubscriber,
+	Subscriber, Teardown, TeardownCollectionExtension,
+};
+use rx_core_macro_subscription_derive::RxSubscription;
+
+/// Spawns the observers created for the destination each on its own entity,
+/// and despawns them once unsubscribed.
+///
+/// When a `completing_entity` is defined, the destination is completed once
+/// that entity is despawned.
+#[derive(RxSubscription)]
+#[rx_delegate_subscription_like_to_destination]
+#[rx_delegate_teardown_collection]
+pub struct LifecycleSubscription<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	#[destination]
+	shared_destination: SharedSubscriber<Destination>,
+}
+
+impl<Destination> LifecycleSubscription<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	pub fn new(
+		destination: Destination,
+		create_observers: impl FnOnce(SharedSubscriber<Destination>) -> Vec<Observer>,
+		completing_entity: Option<Entity>,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		let mut shared_destination = SharedSubscriber::new(destination);
+		let observers = create_observers(shared_destination.clone());
+
+		let (cancellation_id, despawn_invoke_ids) = {
+			let mut scheduler_lock = scheduler.lock();
+			let shared_destination_despawn_clone = shared_destination.clone();
+
+			let cancellation_id = scheduler_lock.generate_cancellation_id();
+			let despawn_observer_invoke_ids = observers
+				.iter()
+				.map(|_| scheduler_lock.generate_invoke_id())
+				.collect::<Vec<_>>();
+
+			let despawn_observer_invoke_ids_clone = despawn_observer_invoke_ids.clone();
+			let scheduler_schedule_clone = scheduler.clone();
+			scheduler_lock.schedule_immediate_work(
+				move |_, context| {
+					let mut commands = context.deferred_world.commands();
+					for (observer, despawn_observer_invoke_id) in
+						observers.into_iter().zip(despawn_observer_invoke_ids_clone)
+					{
+						let mut observer_entity = commands.spawn((
+							Name::new(format!("Lifecycle Observer of {}", ShortName::of::<Self>())),
+							observer.with_error_handler(bevy_ecs::error::error),
+						));
+
+						if let Some(completing_entity) = completing_entity {
+							let mut shared_destination_despawn_clone =
+								shared_destination_despawn_clone.clone();
+							observer_entity.insert(SubscriptionSatellite::new_with_teardown(
+								completing_entity,
+								Teardown::new(move || {
+									shared_destination_despawn_clone.complete();
+								}),
+							));
+						}
+
+						scheduler_schedule_clone
+							.lock()
+							.schedule_invoked_despawn_entity(
+								observer_entity.id(),
+								despawn_observer_invoke_id,
+							);
+					}
+				},
+				cancellation_id,
+			);
+
+			(cancellation_id, despawn_observer_invoke_ids)
+		};
+
+		shared_destination.add(Teardown::new(move || {
+			let mut scheduler = scheduler.lock();
+			for despawn_invoke_id in despawn_invoke_ids {
+				scheduler.invoke(despawn_invoke_id);
+			}
+			scheduler.cancel(cancellation_id);
+		}));
+
+		Self { shared_destination }
+	}
+}
@@ -0,0 +1,19 @@
+mod mouse_button_observable;
+mod mouse_button_observable_options;
+mod mouse_button_subscription;
+mod mouse_motion_observable;
+mod mouse_motion_subscription;
+mod mouse_wheel_observable;
+mod mouse_wheel_subscription;
+
+pub use mouse_button_observable_options::*;
+pub use mouse_button_subscription::*;
+pub use mouse_motion_subscription::*;
+pub use mouse_wheel_subscription::*;
+
+pub mod observable {
+	pub use super::mouse_button_observable::*;
+	pub use super::mouse_button_observable_options::*;
+	pub use super::mouse_motion_observable::*;
+	pub use super::mouse_wheel_observable::*;
+}
@@ -0,0 +1,58 @@
+use bevy_input::mouse::MouseButton;
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{Never, Observable, SchedulerHandle, Subscriber, UpgradeableObserver};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{MouseButtonObservableOptions, MouseButtonSubscription};
+
+/// # [MouseButtonObservable]
+///
+/// The `MouseButtonObservable` turns Bevy mouse button input events into
+/// signals. The events are sourced from the `ButtonInput<MouseButton>`
+/// resource.
+///
+/// ## Options
+///
+/// `MouseButton` signals can be observed in multiple modes:
+///
+/// - `MouseButtonObservableEmit::JustPressed` - emits once when the button is pressed down.
+/// - `MouseButtonObservableEmit::JustReleased` - emits once when the button is released.
+/// - `MouseButtonObservableEmit::WhilePressed` - emits continuously while the button is held down.
+#[derive(RxObservable)]
+#[rx_out(MouseButton)]
+#[rx_out_error(Never)]
+pub struct MouseButtonObservable {
+	options: MouseButtonObservableOptions,
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+}
+
+impl MouseButtonObservable {
+	pub fn new(
+		options: MouseButtonObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		Self { options, scheduler }
+	}
+}
+
+impl Observable for MouseButtonObservable {
+	type Subscription<Destination>
+		= MouseButtonSubscription<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		MouseButtonSubscription::new(
+			destination.upgrade(),
+			self.options.clone(),
+			self.scheduler.clone(),
+		)
+	}
+}
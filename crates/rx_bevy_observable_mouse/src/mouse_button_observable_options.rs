@@ -0,0 +1,12 @@
+#[derive(Default, Clone)]
+pub struct MouseButtonObservableOptions {
+	pub emit: MouseButtonObservableEmit,
+}
+
+#[derive(Default, Clone)]
+pub enum MouseButtonObservableEmit {
+	#[default]
+	JustPressed,
+	WhilePressed,
+	JustReleased,
+}
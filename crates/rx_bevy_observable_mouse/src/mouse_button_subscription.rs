@@ -0,0 +1,72 @@
+use bevy_input::{ButtonInput, mouse::MouseButton};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::*;
+use rx_core_macro_subscription_derive::RxSubscription;
+
+use crate::{MouseButtonObservableEmit, MouseButtonObservableOptions};
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct MouseButtonSubscription<Destination>
+where
+	Destination: 'static + Subscriber<In = MouseButton>,
+{
+	#[destination]
+	shared_destination: SharedSubscriber<Destination>,
+}
+
+impl<Destination> MouseButtonSubscription<Destination>
+where
+	Destination: 'static + Subscriber<In = MouseButton>,
+{
+	pub fn new(
+		destination: Destination,
+		options: MouseButtonObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		let mut shared_destination = SharedSubscriber::new(destination);
+
+		let cancellation_id = {
+			let mut scheduler_lock = scheduler.lock();
+			let cancellation_id = scheduler_lock.generate_cancellation_id();
+
+			let mut shared_destination_clone = shared_destination.clone();
+			scheduler_lock.schedule_continuous_work(
+				move |_tick, context| {
+					let button_input = context
+						.deferred_world
+						.resource::<ButtonInput<MouseButton>>();
+					let mouse_button_iterator: &mut dyn Iterator<Item = MouseButton> =
+						match options.emit {
+							MouseButtonObservableEmit::JustPressed => {
+								&mut button_input.get_just_pressed().copied()
+							}
+							MouseButtonObservableEmit::JustReleased => {
+								&mut button_input.get_just_released().copied()
+							}
+							MouseButtonObservableEmit::WhilePressed => {
+								&mut button_input.get_pressed().copied()
+							}
+						};
+
+					for mouse_button in mouse_button_iterator {
+						if !shared_destination_clone.is_closed() {
+							shared_destination_clone.next(mouse_button);
+						} else {
+							return WorkResult::Done;
+						}
+					}
+					WorkResult::Pending
+				},
+				cancellation_id,
+			);
+
+			cancellation_id
+		};
+
+		shared_destination.add(Teardown::new_work_cancellation(cancellation_id, scheduler));
+
+		Self { shared_destination }
+	}
+}
@@ -0,0 +1,42 @@
+use bevy_math::Vec2;
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{Never, Observable, SchedulerHandle, Subscriber, UpgradeableObserver};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::MouseMotionSubscription;
+
+/// # [MouseMotionObservable]
+///
+/// The `MouseMotionObservable` emits how much the mouse has moved, on every
+/// tick where it did move. The deltas are sourced from the
+/// `AccumulatedMouseMotion` resource.
+#[derive(RxObservable)]
+#[rx_out(Vec2)]
+#[rx_out_error(Never)]
+pub struct MouseMotionObservable {
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+}
+
+impl MouseMotionObservable {
+	pub fn new(scheduler: SchedulerHandle<RxBevyScheduler>) -> Self {
+		Self { scheduler }
+	}
+}
+
+impl Observable for MouseMotionObservable {
+	type Subscription<Destination>
+		= MouseMotionSubscription<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		MouseMotionSubscription::new(destination.upgrade(), self.scheduler.clone())
+	}
+}
@@ -0,0 +1,54 @@
+use bevy_input::mouse::AccumulatedMouseMotion;
+use bevy_math::Vec2;
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::*;
+use rx_core_macro_subscription_derive::RxSubscription;
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct MouseMotionSubscription<Destination>
+where
+	Destination: 'static + Subscriber<In = Vec2>,
+{
+	#[destination]
+	shared_destination: SharedSubscriber<Destination>,
+}
+
+impl<Destination> MouseMotionSubscription<Destination>
+where
+	Destination: 'static + Subscriber<In = Vec2>,
+{
+	pub fn new(destination: Destination, scheduler: SchedulerHandle<RxBevyScheduler>) -> Self {
+		let mut shared_destination = SharedSubscriber::new(destination);
+
+		let cancellation_id = {
+			let mut scheduler_lock = scheduler.lock();
+			let cancellation_id = scheduler_lock.generate_cancellation_id();
+
+			let mut shared_destination_clone = shared_destination.clone();
+			scheduler_lock.schedule_continuous_work(
+				move |_tick, context| {
+					let mouse_motion = context.deferred_world.resource::<AccumulatedMouseMotion>();
+
+					if mouse_motion.delta != Vec2::ZERO {
+						shared_destination_clone.next(mouse_motion.delta);
+					}
+
+					if shared_destination_clone.is_closed() {
+						WorkResult::Done
+					} else {
+						WorkResult::Pending
+					}
+				},
+				cancellation_id,
+			);
+
+			cancellation_id
+		};
+
+		shared_destination.add(Teardown::new_work_cancellation(cancellation_id, scheduler));
+
+		Self { shared_destination }
+	}
+}
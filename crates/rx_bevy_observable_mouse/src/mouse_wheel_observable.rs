@@ -0,0 +1,42 @@
+use bevy_input::mouse::AccumulatedMouseScroll;
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{Never, Observable, SchedulerHandle, Subscriber, UpgradeableObserver};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::MouseWheelSubscription;
+
+/// # [MouseWheelObservable]
+///
+/// The `MouseWheelObservable` emits how much the mouse wheel has scrolled,
+/// along with the unit of the scroll, on every tick where it did scroll. The
+/// deltas are sourced from the `AccumulatedMouseScroll` resource.
+#[derive(RxObservable)]
+#[rx_out(AccumulatedMouseScroll)]
+#[rx_out_error(Never)]
+pub struct MouseWheelObservable {
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+}
+
+impl MouseWheelObservable {
+	pub fn new(scheduler: SchedulerHandle<RxBevyScheduler>) -> Self {
+		Self { scheduler }
+	}
+}
+
+impl Observable for MouseWheelObservable {
+	type Subscription<Destination>
+		= MouseWheelSubscription<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		MouseWheelSubscription::new(destination.upgrade(), self.scheduler.clone())
+	}
+}
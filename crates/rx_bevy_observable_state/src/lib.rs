@@ -0,0 +1,16 @@
+mod next_state_destination;
+mod state_observable;
+mod state_observable_options;
+mod state_subscription;
+
+pub use state_observable_options::*;
+pub use state_subscription::*;
+
+pub mod observable {
+	pub use super::state_observable::*;
+	pub use super::state_observable_options::*;
+}
+
+pub mod observer {
+	pub use super::next_state_destination::*;
+}
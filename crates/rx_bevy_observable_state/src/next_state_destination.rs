@@ -0,0 +1,72 @@
+use core::marker::PhantomData;
+
+use bevy_state::state::{FreelyMutableState, NextState};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{
+	Never, PhantomInvariant, RxObserver, Scheduler, SchedulerHandle,
+	SchedulerScheduleWorkExtension, Signal, WorkCancellationId,
+};
+use rx_core_macro_observer_derive::RxObserver;
+
+/// A destination that requests a transition into every state it observes by
+/// calling [NextState::set].
+///
+/// Together with the [StateObservable][crate::observable::StateObservable]
+/// it allows driving state machines reactively.
+///
+/// Just like the `NoopObserver`, it panics in dev mode when an error is
+/// observed.
+#[derive(RxObserver, Debug)]
+#[rx_in(S)]
+#[rx_in_error(InError)]
+pub struct NextStateDestination<S, InError = Never>
+where
+	S: FreelyMutableState,
+	InError: Signal,
+{
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+	cancellation_id: WorkCancellationId,
+	_phantom_data: PhantomInvariant<(S, InError)>,
+}
+
+impl<S, InError> NextStateDestination<S, InError>
+where
+	S: FreelyMutableState,
+	InError: Signal,
+{
+	pub fn new(scheduler: SchedulerHandle<RxBevyScheduler>) -> Self {
+		let cancellation_id = scheduler.lock().generate_cancellation_id();
+		Self {
+			scheduler,
+			cancellation_id,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<S, InError> RxObserver for NextStateDestination<S, InError>
+where
+	S: FreelyMutableState,
+	InError: Signal,
+{
+	fn next(&mut self, next: Self::In) {
+		self.scheduler.lock().schedule_immediate_work(
+			move |_, context| {
+				if let Some(mut next_state) =
+					context.deferred_world.get_resource_mut::<NextState<S>>()
+				{
+					next_state.set(next);
+				}
+			},
+			self.cancellation_id,
+		);
+	}
+
+	#[inline]
+	fn error(&mut self, _error: Self::InError) {
+		debug_assert!(false, "NextStateDestination observed an uncaught error!")
+	}
+
+	#[inline]
+	fn complete(&mut self) {}
+}
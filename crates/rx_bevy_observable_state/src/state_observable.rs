@@ -0,0 +1,69 @@
+use bevy_state::state::States;
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{Never, Observable, SchedulerHandle, Subscriber, UpgradeableObserver};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{StateSubscription, observable::StateObservableOptions};
+
+/// # [StateObservable]
+///
+/// The `StateObservable` emits the transitions of a Bevy state as
+/// `(exited, entered)` pairs. The transitions are sourced from the
+/// `StateTransitionEvent` messages of the state.
+///
+/// The very first transition into the initial state has no exited state.
+/// Transitions where the state is removed are not emitted.
+///
+/// ## Options
+///
+/// - `emit`: Which transitions to emit. (Default: `StateObservableEmit::Transitions`)
+///   - `StateObservableEmit::Transitions` - emits every transition.
+///   - `StateObservableEmit::OnEnter(state)` - emits when `state` is entered.
+///   - `StateObservableEmit::OnExit(state)` - emits when `state` is exited.
+#[derive(RxObservable)]
+#[rx_out((Option<S>, S))]
+#[rx_out_error(Never)]
+pub struct StateObservable<S>
+where
+	S: States,
+{
+	options: StateObservableOptions<S>,
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+}
+
+impl<S> StateObservable<S>
+where
+	S: States,
+{
+	pub fn new(
+		options: StateObservableOptions<S>,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		Self { options, scheduler }
+	}
+}
+
+impl<S> Observable for StateObservable<S>
+where
+	S: States,
+{
+	type Subscription<Destination>
+		= StateSubscription<S, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		StateSubscription::new(
+			destination.upgrade(),
+			self.options.clone(),
+			self.scheduler.clone(),
+		)
+	}
+}
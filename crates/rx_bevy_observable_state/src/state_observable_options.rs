@@ -0,0 +1,49 @@
+use bevy_state::state::States;
+
+#[derive(Default, Clone)]
+pub struct StateObservableOptions<S>
+where
+	S: States,
+{
+	pub emit: StateObservableEmit<S>,
+}
+
+#[derive(Default, Clone)]
+pub enum StateObservableEmit<S>
+where
+	S: States,
+{
+	/// Emits every transition, including identity transitions where the
+	/// exited and entered states are the same.
+	#[default]
+	Transitions,
+	/// Emits only when the given state is entered, just like when the
+	/// `OnEnter` schedule runs.
+	OnEnter(S),
+	/// Emits only when the given state is exited, just like when the
+	/// `OnExit` schedule runs.
+	OnExit(S),
+}
+
+impl<S> StateObservableEmit<S>
+where
+	S: States,
+{
+	pub(crate) fn matches(
+		&self,
+		exited: Option<&S>,
+		entered: &S,
+		is_identity_allowed: bool,
+	) -> bool {
+		let is_identity_transition = exited == Some(entered);
+		match self {
+			Self::Transitions => true,
+			Self::OnEnter(state) => {
+				entered == state && (!is_identity_transition || is_identity_allowed)
+			}
+			Self::OnExit(state) => {
+				exited == Some(state) && (!is_identity_transition || is_identity_allowed)
+			}
+		}
+	}
+}
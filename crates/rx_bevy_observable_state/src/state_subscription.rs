@@ -0,0 +1,86 @@
+use bevy_ecs::message::{MessageCursor, Messages};
+use bevy_state::state::{StateTransitionEvent, States};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{
+	Never, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension, SharedSubscriber,
+	Subscriber, Teardown, TeardownCollectionExtension, WorkResult,
+};
+use rx_core_macro_subscription_derive::RxSubscription;
+
+use crate::observable::StateObservableOptions;
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct StateSubscription<S, Destination>
+where
+	S: States,
+	Destination: 'static + Subscriber<In = (Option<S>, S), InError = Never>,
+{
+	#[destination]
+	shared_destination: SharedSubscriber<Destination>,
+}
+
+impl<S, Destination> StateSubscription<S, Destination>
+where
+	S: States,
+	Destination: 'static + Subscriber<In = (Option<S>, S), InError = Never>,
+{
+	pub fn new(
+		destination: Destination,
+		options: StateObservableOptions<S>,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		let mut shared_destination = SharedSubscriber::new(destination);
+
+		let cancellation_id = {
+			let mut scheduler_lock = scheduler.lock();
+			let cancellation_id = scheduler_lock.generate_cancellation_id();
+
+			let mut message_cursor = MessageCursor::<StateTransitionEvent<S>>::default();
+			let shared_destination_clone = shared_destination.clone();
+			scheduler_lock.schedule_continuous_work(
+				move |_, context| {
+					let mut destination = shared_destination_clone.lock();
+					if destination.is_closed() {
+						return WorkResult::Done;
+					}
+
+					// The messages only exist once the state is initialized
+					let Some(transitions) = context
+						.deferred_world
+						.get_resource::<Messages<StateTransitionEvent<S>>>()
+					else {
+						return WorkResult::Pending;
+					};
+
+					for transition in message_cursor.read(transitions) {
+						let Some(entered) = transition.entered.as_ref() else {
+							continue;
+						};
+
+						if options.emit.matches(
+							transition.exited.as_ref(),
+							entered,
+							transition.allow_same_state_transitions,
+						) {
+							destination.next((transition.exited.clone(), entered.clone()));
+
+							if destination.is_closed() {
+								return WorkResult::Done;
+							}
+						}
+					}
+
+					WorkResult::Pending
+				},
+				cancellation_id,
+			);
+			cancellation_id
+		};
+
+		shared_destination.add(Teardown::new_work_cancellation(cancellation_id, scheduler));
+
+		Self { shared_destination }
+	}
+}
@@ -0,0 +1,11 @@
+mod touch_observable;
+mod touch_observable_options;
+mod touch_subscription;
+
+pub use touch_observable_options::*;
+pub use touch_subscription::*;
+
+pub mod observable {
+	pub use super::touch_observable::*;
+	pub use super::touch_observable_options::*;
+}
@@ -0,0 +1,58 @@
+use bevy_input::touch::Touch;
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::{Never, Observable, SchedulerHandle, Subscriber, UpgradeableObserver};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{TouchObservableOptions, TouchSubscription};
+
+/// # [TouchObservable]
+///
+/// The `TouchObservable` turns Bevy touch input into signals. The touches are
+/// sourced from the `Touches` resource.
+///
+/// ## Options
+///
+/// `Touch` signals can be observed in multiple modes:
+///
+/// - `TouchObservableEmit::JustPressed` - emits once when a finger touches the screen.
+/// - `TouchObservableEmit::JustReleased` - emits once when a finger is lifted.
+/// - `TouchObservableEmit::JustCanceled` - emits once when a touch is canceled by the system.
+/// - `TouchObservableEmit::WhilePressed` - emits continuously while a finger is on the screen.
+#[derive(RxObservable)]
+#[rx_out(Touch)]
+#[rx_out_error(Never)]
+pub struct TouchObservable {
+	options: TouchObservableOptions,
+	scheduler: SchedulerHandle<RxBevyScheduler>,
+}
+
+impl TouchObservable {
+	pub fn new(
+		options: TouchObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		Self { options, scheduler }
+	}
+}
+
+impl Observable for TouchObservable {
+	type Subscription<Destination>
+		= TouchSubscription<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		TouchSubscription::new(
+			destination.upgrade(),
+			self.options.clone(),
+			self.scheduler.clone(),
+		)
+	}
+}
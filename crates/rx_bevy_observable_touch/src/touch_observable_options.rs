@@ -0,0 +1,13 @@
+#[derive(Default, Clone)]
+pub struct TouchObservableOptions {
+	pub emit: TouchObservableEmit,
+}
+
+#[derive(Default, Clone)]
+pub enum TouchObservableEmit {
+	#[default]
+	JustPressed,
+	WhilePressed,
+	JustReleased,
+	JustCanceled,
+}
@@ -0,0 +1,70 @@
+use bevy_input::touch::{Touch, Touches};
+use rx_bevy_common::RxBevyScheduler;
+use rx_core_common::*;
+use rx_core_macro_subscription_derive::RxSubscription;
+
+use crate::{TouchObservableEmit, TouchObservableOptions};
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct TouchSubscription<Destination>
+where
+	Destination: 'static + Subscriber<In = Touch>,
+{
+	#[destination]
+	shared_destination: SharedSubscriber<Destination>,
+}
+
+impl<Destination> TouchSubscription<Destination>
+where
+	Destination: 'static + Subscriber<In = Touch>,
+{
+	pub fn new(
+		destination: Destination,
+		options: TouchObservableOptions,
+		scheduler: SchedulerHandle<RxBevyScheduler>,
+	) -> Self {
+		let mut shared_destination = SharedSubscriber::new(destination);
+
+		let cancellation_id = {
+			let mut scheduler_lock = scheduler.lock();
+			let cancellation_id = scheduler_lock.generate_cancellation_id();
+
+			let mut shared_destination_clone = shared_destination.clone();
+			scheduler_lock.schedule_continuous_work(
+				move |_tick, context| {
+					let touches = context.deferred_world.resource::<Touches>();
+					let touch_iterator: &mut dyn Iterator<Item = Touch> = match options.emit {
+						TouchObservableEmit::JustPressed => {
+							&mut touches.iter_just_pressed().copied()
+						}
+						TouchObservableEmit::JustReleased => {
+							&mut touches.iter_just_released().copied()
+						}
+						TouchObservableEmit::JustCanceled => {
+							&mut touches.iter_just_canceled().copied()
+						}
+						TouchObservableEmit::WhilePressed => &mut touches.iter().copied(),
+					};
+
+					for touch in touch_iterator {
+						if !shared_destination_clone.is_closed() {
+							shared_destination_clone.next(touch);
+						} else {
+							return WorkResult::Done;
+						}
+					}
+					WorkResult::Pending
+				},
+				cancellation_id,
+			);
+
+			cancellation_id
+		};
+
+		shared_destination.add(Teardown::new_work_cancellation(cancellation_id, scheduler));
+
+		Self { shared_destination }
+	}
+}
@@ -0,0 +1,10 @@
+use rx_core::prelude::*;
+
+fn main() {
+	let observable_1 = (1..=3).into_observable();
+	let observable_2 = (4..=6).into_observable().take(2);
+	let observable_3 = just(7);
+
+	let _subscription = fork_join((observable_1, observable_2, observable_3))
+		.subscribe(PrintObserver::<[i32; 3]>::new("fork_join_observable"));
+}
@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+use futures_lite::future;
+use rx_core::prelude::*;
+use rx_core_scheduler_async::AsyncExecutor;
+
+/// The future observable polls a future using a scheduler, and emits its
+/// output once it resolves.
+fn main() {
+	let executor = AsyncExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let _subscription = from_future(|| future::ready("resolved"), scheduler.clone())
+		.delay(Duration::from_millis(100), scheduler)
+		.subscribe(PrintObserver::new("from_future"));
+
+	executor.block_until_idle();
+}
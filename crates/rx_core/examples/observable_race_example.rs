@@ -0,0 +1,14 @@
+use rx_core::prelude::*;
+
+fn main() {
+	let mut slow = PublishSubject::<&'static str>::default();
+	let mut fast = PublishSubject::<&'static str>::default();
+
+	let _subscription = race((slow.clone(), fast.clone()))
+		.subscribe(PrintObserver::<&'static str>::new("race_observable"));
+
+	fast.next("fast 1");
+	slow.next("slow 1");
+	fast.next("fast 2");
+	fast.complete();
+}
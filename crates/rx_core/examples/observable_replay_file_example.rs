@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let recording =
+		"[0,{\"Next\":\"jump\"}]\n[200000000,{\"Next\":\"duck\"}]\n[300000000,\"Complete\"]\n";
+
+	let _subscription =
+		ReplayFileObservable::<String, (), _>::from_reader(recording.as_bytes(), scheduler)
+			.unwrap()
+			.subscribe(PrintObserver::new("replay_file_observable"));
+
+	for _ in 0..4 {
+		executor.tick(Duration::from_millis(100));
+	}
+}
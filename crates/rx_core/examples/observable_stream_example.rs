@@ -0,0 +1,25 @@
+use futures_lite::{StreamExt, future, stream};
+use rx_core::prelude::*;
+use rx_core_scheduler_async::AsyncExecutor;
+
+/// The stream observable polls a stream using a scheduler, and emits its
+/// items. Any observable can also be turned into a stream using `into_stream`.
+fn main() {
+	let executor = AsyncExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let _subscription = from_stream(|| stream::iter(1..=3), scheduler)
+		.map(|i| i * 10)
+		.subscribe(PrintObserver::new("from_stream"));
+
+	executor.block_until_idle();
+
+	let items = future::block_on(
+		(1..=3)
+			.into_observable()
+			.map(|i| i * 100)
+			.into_stream()
+			.collect::<Vec<_>>(),
+	);
+	println!("into_stream: {items:?}");
+}
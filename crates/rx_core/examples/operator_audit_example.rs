@@ -0,0 +1,20 @@
+use rx_core::prelude::*;
+
+/// The [AuditOperator] starts a duration observable on the first value, and
+/// emits the latest value once it emits
+fn main() {
+	let mut source = PublishSubject::<usize>::default();
+	let mut duration = PublishSubject::<()>::default();
+	let duration_clone = duration.clone();
+
+	let _s = source
+		.clone()
+		.audit(move |_next| duration_clone.clone())
+		.subscribe(PrintObserver::new("audit_operator"));
+
+	source.next(1);
+	source.next(2);
+	duration.next(());
+	source.next(3);
+	duration.next(());
+}
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+/// The [AuditTimeOperator] starts a timer on the first value, and emits the
+/// latest value once it elapses
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut subject = PublishSubject::<usize>::default();
+
+	let _subscription = subject
+		.clone()
+		.audit_time(Duration::from_millis(1000), scheduler)
+		.subscribe(PrintObserver::new("audit_time_operator"));
+
+	subject.next(1);
+	executor.tick(Duration::from_millis(500));
+	subject.next(2);
+	executor.tick(Duration::from_millis(500));
+	subject.next(3);
+	subject.complete();
+	executor.tick(Duration::from_millis(1000));
+}
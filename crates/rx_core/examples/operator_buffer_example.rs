@@ -0,0 +1,20 @@
+use rx_core::prelude::*;
+
+fn main() {
+	let mut subject = PublishSubject::<usize>::default();
+	let mut notifier = PublishSubject::<()>::default();
+
+	let _subscription = subject
+		.clone()
+		.buffer(notifier.clone())
+		.subscribe(PrintObserver::new("buffer_operator"));
+
+	subject.next(1);
+	subject.next(2);
+	notifier.next(());
+	subject.next(3);
+	notifier.next(());
+	notifier.next(());
+	subject.next(4);
+	subject.complete();
+}
@@ -0,0 +1,23 @@
+use rx_core::prelude::*;
+
+fn main() {
+	let mut subject = PublishSubject::<usize>::default();
+	let mut openings = PublishSubject::<()>::default();
+	let mut closings = PublishSubject::<()>::default();
+
+	let closings_clone = closings.clone();
+	let _subscription = subject
+		.clone()
+		.buffer_toggle(openings.clone(), move |_| closings_clone.clone())
+		.subscribe(PrintObserver::new("buffer_toggle_operator"));
+
+	subject.next(1);
+	openings.next(());
+	subject.next(2);
+	subject.next(3);
+	closings.next(());
+	subject.next(4);
+	openings.next(());
+	subject.next(5);
+	subject.complete();
+}
@@ -0,0 +1,20 @@
+use rx_core::prelude::*;
+
+/// The [DistinctOperator] only emits values with keys it has never seen
+/// before, the `flush` notifier of `distinct_with_flush` forgets seen keys
+fn main() {
+	let mut source = PublishSubject::<usize>::default();
+	let mut flush = PublishSubject::<()>::default();
+
+	let _s = source
+		.clone()
+		.distinct_with_flush(|next| *next, flush.clone())
+		.subscribe(PrintObserver::new("distinct_operator"));
+
+	source.next(1);
+	source.next(2);
+	source.next(1);
+	flush.next(());
+	source.next(1);
+	source.next(2);
+}
@@ -0,0 +1,10 @@
+use rx_core::prelude::*;
+
+/// The [DistinctUntilChangedOperator] skips values equal to the last emitted
+/// one, use `distinct_until_changed_by` to compare with a custom comparator
+fn main() {
+	let _s = [1, 1, 2, 2, 2, 1, 3]
+		.into_observable()
+		.distinct_until_changed()
+		.subscribe(PrintObserver::new("distinct_until_changed_operator"));
+}
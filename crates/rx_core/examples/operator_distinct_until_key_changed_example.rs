@@ -0,0 +1,10 @@
+use rx_core::prelude::*;
+
+/// The [DistinctUntilKeyChangedOperator] skips values whose key is equal to
+/// the key of the last emitted value
+fn main() {
+	let _s = [(1, "a"), (1, "b"), (2, "c"), (1, "d")]
+		.into_observable()
+		.distinct_until_key_changed(|(key, _)| *key)
+		.subscribe(PrintObserver::new("distinct_until_key_changed_operator"));
+}
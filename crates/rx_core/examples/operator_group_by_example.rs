@@ -0,0 +1,48 @@
+use rx_core::prelude::*;
+
+#[derive(Clone, Debug)]
+struct PlayerInput {
+	player: usize,
+	input: char,
+}
+
+fn main() {
+	let mut inputs = PublishSubject::<PlayerInput>::default();
+
+	// Routes the merged input stream into one stream per player
+	let _subscription = inputs
+		.clone()
+		.group_by(|next: &PlayerInput| next.player)
+		.merge_map(
+			|group| {
+				let player = *group.get_key();
+				group
+					.scan(
+						|keys: &String, next| format!("{keys}{}", next.input),
+						String::new(),
+					)
+					.map(move |keys| format!("player {player}: {keys}"))
+			},
+			usize::MAX,
+			Never::map_into(),
+		)
+		.subscribe(PrintObserver::new("group_by_operator"));
+
+	inputs.next(PlayerInput {
+		player: 1,
+		input: 'w',
+	});
+	inputs.next(PlayerInput {
+		player: 2,
+		input: 'a',
+	});
+	inputs.next(PlayerInput {
+		player: 1,
+		input: 'd',
+	});
+	inputs.next(PlayerInput {
+		player: 2,
+		input: 's',
+	});
+	inputs.complete();
+}
@@ -0,0 +1,20 @@
+use rx_core::prelude::*;
+
+fn main() {
+	let mut source = PublishSubject::<usize>::default();
+	let mut published = source.clone().publish_behavior(0);
+
+	let _s1 = published.subscribe(PrintObserver::new("publish_behavior_1"));
+
+	// Nothing reaches the subscribers until the connection is made
+	source.next(1);
+	published.connect();
+
+	source.next(2);
+
+	// Late subscribers immediately receive the latest value
+	let _s2 = published.subscribe(PrintObserver::new("publish_behavior_2"));
+
+	source.next(3);
+	source.complete();
+}
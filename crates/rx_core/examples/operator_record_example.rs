@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+	let path = std::env::temp_dir().join("operator_record_example.jsonl");
+
+	let _subscription = interval(
+		IntervalObservableOptions {
+			duration: Duration::from_millis(100),
+			..Default::default()
+		},
+		scheduler.clone(),
+	)
+	.take(3)
+	.map_never::<()>()
+	.record(&path, scheduler)
+	.subscribe(PrintObserver::new("record_operator"));
+
+	for _ in 0..4 {
+		executor.tick(Duration::from_millis(100));
+	}
+
+	println!("{}", std::fs::read_to_string(path).unwrap());
+}
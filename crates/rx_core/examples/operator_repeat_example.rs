@@ -0,0 +1,7 @@
+use rx_core::prelude::*;
+
+fn main() {
+	let mut repeated = (1..=3).into_observable().repeat(2);
+
+	let _s1 = repeated.subscribe(PrintObserver::new("repeat_operator"));
+}
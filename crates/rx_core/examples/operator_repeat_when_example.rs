@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut repeated = (1..=2).into_observable().repeat_when(move |completions| {
+		completions
+			.delay(Duration::from_millis(1000), scheduler.clone())
+			.take(2)
+	});
+
+	let _subscription = repeated.subscribe(PrintObserver::new("repeat_when_operator"));
+
+	executor.tick(Duration::from_millis(1000));
+	executor.tick(Duration::from_millis(1000));
+}
@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut retried = concat((
+		(0..=1).into_observable().map_never(),
+		throw("error").map_never(),
+	))
+	.retry_when(move |errors| errors.delay(Duration::from_millis(1000), scheduler.clone()));
+
+	let mut subscription = retried.subscribe(PrintObserver::new("retry_when_operator"));
+
+	executor.tick(Duration::from_millis(1000));
+	executor.tick(Duration::from_millis(1000));
+	subscription.unsubscribe();
+}
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut retried = concat((
+		(0..=1).into_observable().map_never(),
+		throw("error").map_never(),
+	))
+	.retry_with(
+		RetryConfig::new(2).with_delay(RetryDelay::Exponential {
+			initial: Duration::from_millis(500),
+			max: Duration::from_secs(10),
+		}),
+		scheduler,
+	);
+
+	let _subscription = retried.subscribe(PrintObserver::new("retry_with_operator"));
+
+	executor.tick(Duration::from_millis(500));
+	executor.tick(Duration::from_millis(1000));
+}
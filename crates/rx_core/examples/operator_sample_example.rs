@@ -0,0 +1,20 @@
+use rx_core::prelude::*;
+
+/// The [SampleOperator] emits the latest upstream value every time the
+/// notifier emits, if there was a new one
+fn main() {
+	let mut source = PublishSubject::<usize>::default();
+	let mut notifier = PublishSubject::<()>::default();
+
+	let _s = source
+		.clone()
+		.sample(notifier.clone())
+		.subscribe(PrintObserver::new("sample_operator"));
+
+	source.next(1);
+	source.next(2);
+	notifier.next(());
+	notifier.next(());
+	source.next(3);
+	notifier.next(());
+}
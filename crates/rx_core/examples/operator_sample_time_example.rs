@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+/// The [SampleTimeOperator] emits the latest upstream value once every
+/// period, if there was a new one
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut subject = PublishSubject::<usize>::default();
+
+	let _subscription = subject
+		.clone()
+		.sample_time(Duration::from_millis(1000), scheduler)
+		.subscribe(PrintObserver::new("sample_time_operator"));
+
+	subject.next(1);
+	subject.next(2);
+	executor.tick(Duration::from_millis(1000));
+	executor.tick(Duration::from_millis(1000));
+	subject.next(3);
+	executor.tick(Duration::from_millis(1000));
+}
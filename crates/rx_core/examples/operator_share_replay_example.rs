@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut source = PublishSubject::<usize>::default();
+	let mut shared = source
+		.clone()
+		.finalize(|| println!("source: unsubscribed"))
+		.share_replay(
+			ShareReplayOptions::new(2).with_window_time(Duration::from_secs(2)),
+			scheduler,
+		);
+
+	let _s1 = shared.subscribe(PrintObserver::new("share_replay_1"));
+
+	source.next(1);
+	executor.tick(Duration::from_secs(1));
+	source.next(2);
+	source.next(3);
+
+	// Replays the last 2 values
+	let _s2 = shared.subscribe(PrintObserver::new("share_replay_2"));
+
+	executor.tick(Duration::from_secs(2));
+
+	// Values older than 2 seconds are no longer replayed
+	let _s3 = shared.subscribe(PrintObserver::new("share_replay_3"));
+
+	source.complete();
+
+	// A completed source is not subscribed to again, the completion is replayed
+	let _s4 = shared.subscribe(PrintObserver::new("share_replay_4"));
+}
@@ -0,0 +1,18 @@
+use rx_core::prelude::*;
+
+/// The [SkipUntilOperator] ignores upstream values until the notifier emits
+fn main() {
+	let mut source = PublishSubject::<usize>::default();
+	let mut ready = PublishSubject::<()>::default();
+
+	let _s = source
+		.clone()
+		.skip_until(ready.clone())
+		.subscribe(PrintObserver::new("skip_until_operator"));
+
+	source.next(1);
+	ready.next(());
+	source.next(2);
+	source.next(3);
+	source.complete();
+}
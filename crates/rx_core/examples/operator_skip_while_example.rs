@@ -0,0 +1,10 @@
+use rx_core::prelude::*;
+
+/// The [SkipWhileOperator] skips values until the first one failing the
+/// predicate, then lets everything through
+fn main() {
+	let _s = [1, 2, 3, 1, 2]
+		.into_observable()
+		.skip_while(|next, _index| *next < 3)
+		.subscribe(PrintObserver::new("skip_while_operator"));
+}
@@ -0,0 +1,18 @@
+use rx_core::prelude::*;
+
+/// The [TakeUntilOperator] completes the stream once the notifier emits, for
+/// example when the entity or UI element the subscription belongs to is gone
+fn main() {
+	let mut source = PublishSubject::<usize>::default();
+	let mut destroyed = PublishSubject::<()>::default();
+
+	let _s = source
+		.clone()
+		.take_until(destroyed.clone())
+		.subscribe(PrintObserver::new("take_until_operator"));
+
+	source.next(1);
+	source.next(2);
+	destroyed.next(());
+	source.next(3);
+}
@@ -0,0 +1,10 @@
+use rx_core::prelude::*;
+
+/// The [TakeWhileOperator] completes the stream on the first value failing
+/// the predicate, `inclusive` decides if that value is emitted too
+fn main() {
+	let _s = (1..=5)
+		.into_observable()
+		.take_while(|next, _index| *next < 3, true)
+		.subscribe(PrintObserver::new("take_while_operator"));
+}
@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+/// The [TimeoutOperator] errors, or switches to a fallback observable, when
+/// upstream does not emit in time
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut response = PublishSubject::<&'static str>::default();
+
+	let _subscription = response
+		.clone()
+		.timeout(
+			TimeoutConfig {
+				first: Some(Duration::from_millis(1000)),
+				each: None,
+				with: just("fallback answer"),
+			},
+			scheduler,
+		)
+		.subscribe(PrintObserver::new("timeout_operator"));
+
+	executor.tick(Duration::from_millis(1000));
+	response.next("late answer");
+}
@@ -0,0 +1,13 @@
+use rx_core::prelude::*;
+
+fn main() {
+	let _s = (1..=7)
+		.into_observable()
+		.window_count(3)
+		.merge_map(
+			|window| window.reduce(|sum: &usize, next| sum + next, 0),
+			usize::MAX,
+			Never::map_into(),
+		)
+		.subscribe(PrintObserver::new("window_count_operator"));
+}
@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut subject = PublishSubject::<usize>::default();
+
+	let _subscription = subject
+		.clone()
+		.window_time(Duration::from_millis(1000), scheduler)
+		.merge_map(
+			|window| window.reduce(|sum: &usize, next| sum + next, 0),
+			usize::MAX,
+			Never::map_into(),
+		)
+		.subscribe(PrintObserver::new("window_time_operator"));
+
+	subject.next(1);
+	executor.tick(Duration::from_millis(500));
+	subject.next(2);
+	executor.tick(Duration::from_millis(500));
+	subject.next(3);
+	executor.tick(Duration::from_millis(1000));
+	subject.complete();
+}
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut subject = PublishSubject::<usize>::default();
+	let mut openings = PublishSubject::<u64>::default();
+
+	let _subscription = subject
+		.clone()
+		.window_toggle(openings.clone(), move |window_length| {
+			timer(Duration::from_millis(window_length), scheduler.clone())
+		})
+		.merge_map(
+			|window| window.reduce(|sum: &usize, next| sum + next, 0),
+			usize::MAX,
+			Never::map_into(),
+		)
+		.subscribe(PrintObserver::new("window_toggle_operator"));
+
+	subject.next(1);
+	openings.next(1000);
+	subject.next(2);
+	executor.tick(Duration::from_millis(500));
+	openings.next(200);
+	subject.next(3);
+	executor.tick(Duration::from_millis(200));
+	subject.next(4);
+	executor.tick(Duration::from_millis(300));
+	subject.next(5);
+	subject.complete();
+}
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_scheduler_async::AsyncExecutor;
+
+fn main() {
+	let executor = AsyncExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let _subscription = IntervalObservable::new(
+		IntervalObservableOptions {
+			duration: Duration::from_millis(100),
+			max_emissions_per_tick: 1,
+			start_on_subscribe: false,
+		},
+		scheduler.clone(),
+	)
+	.take(3)
+	.delay(Duration::from_millis(50), scheduler)
+	.subscribe(PrintObserver::new("async_executor"));
+
+	executor.block_until_idle();
+}
@@ -0,0 +1,24 @@
+use rx_core::prelude::*;
+
+fn main() {
+	let capacity = 3;
+	let mut subject = ReplayBufferSubject::<i32>::new(capacity);
+
+	for i in 1..=5 {
+		subject.next(i);
+	}
+
+	// Only the last three values are printed out, as decided at runtime
+	let _s = subject
+		.clone()
+		.subscribe(PrintObserver::<i32>::new("hello"));
+
+	let mut unbounded = ReplayBufferSubject::<i32>::unbounded();
+
+	for i in 1..=5 {
+		unbounded.next(i);
+	}
+
+	// Every value is printed out
+	let _s2 = unbounded.clone().subscribe(PrintObserver::<i32>::new("hi"));
+}
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::MockExecutor;
+
+fn main() {
+	let mut executor = MockExecutor::new_with_logging();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut damage_events = ReplayWindowSubject::<i32, Never, _>::new(
+		ReplayWindowOptions::new(Duration::from_secs(2)),
+		scheduler,
+	);
+
+	damage_events.next(10);
+	executor.tick(Duration::from_millis(1500));
+	damage_events.next(20);
+	executor.tick(Duration::from_millis(1000));
+
+	// Only the damage dealt within the last 2 seconds is replayed
+	let _s = damage_events
+		.clone()
+		.subscribe(PrintObserver::<i32>::new("late_panel"));
+
+	damage_events.next(30);
+}
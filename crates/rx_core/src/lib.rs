@@ -19,6 +19,10 @@ pub mod observable {
 	pub use rx_core_observable_deferred::observable::*;
 	#[cfg(feature = "observable_empty")]
 	pub use rx_core_observable_empty::observable::*;
+	#[cfg(feature = "observable_fork_join")]
+	pub use rx_core_observable_fork_join::observable::*;
+	#[cfg(feature = "observable_future")]
+	pub use rx_core_observable_future::observable::*;
 	#[cfg(feature = "observable_interval")]
 	pub use rx_core_observable_interval::observable::*;
 	#[cfg(feature = "observable_iterator")]
@@ -33,6 +37,12 @@ pub mod observable {
 	pub use rx_core_observable_merge::observable::*;
 	#[cfg(feature = "observable_never")]
 	pub use rx_core_observable_never::observable::*;
+	#[cfg(feature = "observable_race")]
+	pub use rx_core_observable_race::observable::*;
+	#[cfg(feature = "observable_replay_file")]
+	pub use rx_core_observable_replay_file::observable::*;
+	#[cfg(feature = "observable_stream")]
+	pub use rx_core_observable_stream::observable::*;
 	#[cfg(feature = "observable_throw")]
 	pub use rx_core_observable_throw::observable::*;
 	#[cfg(feature = "observable_timer")]
@@ -57,6 +67,10 @@ pub mod observable_fn {
 	pub use rx_core_observable_deferred::observable_fn::*;
 	#[cfg(feature = "observable_empty")]
 	pub use rx_core_observable_empty::observable_fn::*;
+	#[cfg(feature = "observable_fork_join")]
+	pub use rx_core_observable_fork_join::observable_fn::*;
+	#[cfg(feature = "observable_future")]
+	pub use rx_core_observable_future::observable_fn::*;
 	#[cfg(feature = "observable_interval")]
 	pub use rx_core_observable_interval::observable_fn::*;
 	#[cfg(feature = "observable_join")]
@@ -67,6 +81,10 @@ pub mod observable_fn {
 	pub use rx_core_observable_merge::observable_fn::*;
 	#[cfg(feature = "observable_never")]
 	pub use rx_core_observable_never::observable_fn::*;
+	#[cfg(feature = "observable_race")]
+	pub use rx_core_observable_race::observable_fn::*;
+	#[cfg(feature = "observable_stream")]
+	pub use rx_core_observable_stream::observable_fn::*;
 	#[cfg(feature = "observable_throw")]
 	pub use rx_core_observable_throw::observable_fn::*;
 	#[cfg(feature = "observable_timer")]
@@ -78,8 +96,18 @@ pub mod observable_fn {
 pub mod operator {
 	#[cfg(feature = "operator_adsr")]
 	pub use rx_core_operator_adsr::operator::*;
+	#[cfg(feature = "operator_audit")]
+	pub use rx_core_operator_audit::operator::*;
+	#[cfg(feature = "operator_audit_time")]
+	pub use rx_core_operator_audit_time::operator::*;
+	#[cfg(feature = "operator_buffer")]
+	pub use rx_core_operator_buffer::operator::*;
 	#[cfg(feature = "operator_buffer_count")]
 	pub use rx_core_operator_buffer_count::operator::*;
+	#[cfg(feature = "operator_buffer_time")]
+	pub use rx_core_operator_buffer_time::operator::*;
+	#[cfg(feature = "operator_buffer_toggle")]
+	pub use rx_core_operator_buffer_toggle::operator::*;
 	#[cfg(feature = "operator_catch")]
 	pub use rx_core_operator_catch::operator::*;
 	#[cfg(feature = "operator_composite")]
@@ -96,6 +124,12 @@ pub mod operator {
 	pub use rx_core_operator_delay::operator::*;
 	#[cfg(feature = "operator_dematerialize")]
 	pub use rx_core_operator_dematerialize::operator::*;
+	#[cfg(feature = "operator_distinct")]
+	pub use rx_core_operator_distinct::operator::*;
+	#[cfg(feature = "operator_distinct_until_changed")]
+	pub use rx_core_operator_distinct_until_changed::operator::*;
+	#[cfg(feature = "operator_distinct_until_key_changed")]
+	pub use rx_core_operator_distinct_until_key_changed::operator::*;
 	#[cfg(feature = "operator_element_at")]
 	pub use rx_core_operator_element_at::operator::*;
 	#[cfg(feature = "operator_end_with")]
@@ -122,6 +156,8 @@ pub mod operator {
 	pub use rx_core_operator_find_index::operator::*;
 	#[cfg(feature = "operator_first")]
 	pub use rx_core_operator_first::operator::*;
+	#[cfg(feature = "operator_group_by")]
+	pub use rx_core_operator_group_by::operator::*;
 	#[cfg(feature = "operator_identity")]
 	pub use rx_core_operator_identity::operator::*;
 	#[cfg(feature = "operator_into_result")]
@@ -154,16 +190,36 @@ pub mod operator {
 	pub use rx_core_operator_on_subscribe::operator::*;
 	#[cfg(feature = "operator_pairwise")]
 	pub use rx_core_operator_pairwise::operator::*;
+	#[cfg(feature = "operator_publish")]
+	pub use rx_core_operator_publish::operator::*;
+	#[cfg(feature = "operator_record")]
+	pub use rx_core_operator_record::operator::*;
 	#[cfg(feature = "operator_reduce")]
 	pub use rx_core_operator_reduce::operator::*;
+	#[cfg(feature = "operator_repeat")]
+	pub use rx_core_operator_repeat::operator::*;
+	#[cfg(feature = "operator_repeat_when")]
+	pub use rx_core_operator_repeat_when::operator::*;
 	#[cfg(feature = "operator_retry")]
 	pub use rx_core_operator_retry::operator::*;
+	#[cfg(feature = "operator_retry_when")]
+	pub use rx_core_operator_retry_when::operator::*;
+	#[cfg(feature = "operator_sample")]
+	pub use rx_core_operator_sample::operator::*;
+	#[cfg(feature = "operator_sample_time")]
+	pub use rx_core_operator_sample_time::operator::*;
 	#[cfg(feature = "operator_scan")]
 	pub use rx_core_operator_scan::operator::*;
 	#[cfg(feature = "operator_share")]
 	pub use rx_core_operator_share::operator::*;
+	#[cfg(feature = "operator_share_replay")]
+	pub use rx_core_operator_share_replay::operator::*;
 	#[cfg(feature = "operator_skip")]
 	pub use rx_core_operator_skip::operator::*;
+	#[cfg(feature = "operator_skip_until")]
+	pub use rx_core_operator_skip_until::operator::*;
+	#[cfg(feature = "operator_skip_while")]
+	pub use rx_core_operator_skip_while::operator::*;
 	#[cfg(feature = "operator_start_with")]
 	pub use rx_core_operator_start_with::operator::*;
 	#[cfg(feature = "operator_subscribe_on")]
@@ -174,12 +230,24 @@ pub mod operator {
 	pub use rx_core_operator_switch_map::operator::*;
 	#[cfg(feature = "operator_take")]
 	pub use rx_core_operator_take::operator::*;
+	#[cfg(feature = "operator_take_until")]
+	pub use rx_core_operator_take_until::operator::*;
+	#[cfg(feature = "operator_take_while")]
+	pub use rx_core_operator_take_while::operator::*;
 	#[cfg(feature = "operator_tap")]
 	pub use rx_core_operator_tap::operator::*;
 	#[cfg(feature = "operator_tap_next")]
 	pub use rx_core_operator_tap_next::operator::*;
 	#[cfg(feature = "operator_throttle_time")]
 	pub use rx_core_operator_throttle_time::operator::*;
+	#[cfg(feature = "operator_timeout")]
+	pub use rx_core_operator_timeout::operator::*;
+	#[cfg(feature = "operator_window_count")]
+	pub use rx_core_operator_window_count::operator::*;
+	#[cfg(feature = "operator_window_time")]
+	pub use rx_core_operator_window_time::operator::*;
+	#[cfg(feature = "operator_window_toggle")]
+	pub use rx_core_operator_window_toggle::operator::*;
 	#[cfg(feature = "operator_with_latest_from")]
 	pub use rx_core_operator_with_latest_from::operator::*;
 }
@@ -188,8 +256,18 @@ pub mod operator {
 pub mod extension_compose {
 	#[cfg(feature = "operator_adsr")]
 	pub use rx_core_operator_adsr::extension_compose::*;
+	#[cfg(feature = "operator_audit")]
+	pub use rx_core_operator_audit::extension_compose::*;
+	#[cfg(feature = "operator_audit_time")]
+	pub use rx_core_operator_audit_time::extension_compose::*;
+	#[cfg(feature = "operator_buffer")]
+	pub use rx_core_operator_buffer::extension_compose::*;
 	#[cfg(feature = "operator_buffer_count")]
 	pub use rx_core_operator_buffer_count::extension_compose::*;
+	#[cfg(feature = "operator_buffer_time")]
+	pub use rx_core_operator_buffer_time::extension_compose::*;
+	#[cfg(feature = "operator_buffer_toggle")]
+	pub use rx_core_operator_buffer_toggle::extension_compose::*;
 	#[cfg(feature = "operator_catch")]
 	pub use rx_core_operator_catch::extension_compose::*;
 	#[cfg(feature = "operator_composite")]
@@ -206,6 +284,12 @@ pub mod extension_compose {
 	pub use rx_core_operator_delay::extension_compose::*;
 	#[cfg(feature = "operator_dematerialize")]
 	pub use rx_core_operator_dematerialize::extension_compose::*;
+	#[cfg(feature = "operator_distinct")]
+	pub use rx_core_operator_distinct::extension_compose::*;
+	#[cfg(feature = "operator_distinct_until_changed")]
+	pub use rx_core_operator_distinct_until_changed::extension_compose::*;
+	#[cfg(feature = "operator_distinct_until_key_changed")]
+	pub use rx_core_operator_distinct_until_key_changed::extension_compose::*;
 	#[cfg(feature = "operator_element_at")]
 	pub use rx_core_operator_element_at::extension_compose::*;
 	#[cfg(feature = "operator_end_with")]
@@ -232,6 +316,8 @@ pub mod extension_compose {
 	pub use rx_core_operator_find_index::extension_compose::*;
 	#[cfg(feature = "operator_first")]
 	pub use rx_core_operator_first::extension_compose::*;
+	#[cfg(feature = "operator_group_by")]
+	pub use rx_core_operator_group_by::extension_compose::*;
 	#[cfg(feature = "operator_into_result")]
 	pub use rx_core_operator_into_result::extension_compose::*;
 	#[cfg(feature = "operator_is_empty")]
@@ -262,12 +348,22 @@ pub mod extension_compose {
 	pub use rx_core_operator_on_subscribe::extension_compose::*;
 	#[cfg(feature = "operator_pairwise")]
 	pub use rx_core_operator_pairwise::extension_compose::*;
+	#[cfg(feature = "operator_record")]
+	pub use rx_core_operator_record::extension_compose::*;
 	#[cfg(feature = "operator_reduce")]
 	pub use rx_core_operator_reduce::extension_compose::*;
+	#[cfg(feature = "operator_sample")]
+	pub use rx_core_operator_sample::extension_compose::*;
+	#[cfg(feature = "operator_sample_time")]
+	pub use rx_core_operator_sample_time::extension_compose::*;
 	#[cfg(feature = "operator_scan")]
 	pub use rx_core_operator_scan::extension_compose::*;
 	#[cfg(feature = "operator_skip")]
 	pub use rx_core_operator_skip::extension_compose::*;
+	#[cfg(feature = "operator_skip_until")]
+	pub use rx_core_operator_skip_until::extension_compose::*;
+	#[cfg(feature = "operator_skip_while")]
+	pub use rx_core_operator_skip_while::extension_compose::*;
 	#[cfg(feature = "operator_start_with")]
 	pub use rx_core_operator_start_with::extension_compose::*;
 	#[cfg(feature = "operator_switch_all")]
@@ -276,12 +372,24 @@ pub mod extension_compose {
 	pub use rx_core_operator_switch_map::extension_compose::*;
 	#[cfg(feature = "operator_take")]
 	pub use rx_core_operator_take::extension_compose::*;
+	#[cfg(feature = "operator_take_until")]
+	pub use rx_core_operator_take_until::extension_compose::*;
+	#[cfg(feature = "operator_take_while")]
+	pub use rx_core_operator_take_while::extension_compose::*;
 	#[cfg(feature = "operator_tap")]
 	pub use rx_core_operator_tap::extension_compose::*;
 	#[cfg(feature = "operator_tap_next")]
 	pub use rx_core_operator_tap_next::extension_compose::*;
 	#[cfg(feature = "operator_throttle_time")]
 	pub use rx_core_operator_throttle_time::extension_compose::*;
+	#[cfg(feature = "operator_timeout")]
+	pub use rx_core_operator_timeout::extension_compose::*;
+	#[cfg(feature = "operator_window_count")]
+	pub use rx_core_operator_window_count::extension_compose::*;
+	#[cfg(feature = "operator_window_time")]
+	pub use rx_core_operator_window_time::extension_compose::*;
+	#[cfg(feature = "operator_window_toggle")]
+	pub use rx_core_operator_window_toggle::extension_compose::*;
 	#[cfg(feature = "operator_with_latest_from")]
 	pub use rx_core_operator_with_latest_from::extension_compose::*;
 }
@@ -290,8 +398,18 @@ pub mod extension_compose {
 pub mod extension_pipe {
 	#[cfg(feature = "operator_adsr")]
 	pub use rx_core_operator_adsr::extension_pipe::*;
+	#[cfg(feature = "operator_audit")]
+	pub use rx_core_operator_audit::extension_pipe::*;
+	#[cfg(feature = "operator_audit_time")]
+	pub use rx_core_operator_audit_time::extension_pipe::*;
+	#[cfg(feature = "operator_buffer")]
+	pub use rx_core_operator_buffer::extension_pipe::*;
 	#[cfg(feature = "operator_buffer_count")]
 	pub use rx_core_operator_buffer_count::extension_pipe::*;
+	#[cfg(feature = "operator_buffer_time")]
+	pub use rx_core_operator_buffer_time::extension_pipe::*;
+	#[cfg(feature = "operator_buffer_toggle")]
+	pub use rx_core_operator_buffer_toggle::extension_pipe::*;
 	#[cfg(feature = "operator_catch")]
 	pub use rx_core_operator_catch::extension_pipe::*;
 	#[cfg(feature = "operator_concat_all")]
@@ -306,6 +424,12 @@ pub mod extension_pipe {
 	pub use rx_core_operator_delay::extension_pipe::*;
 	#[cfg(feature = "operator_dematerialize")]
 	pub use rx_core_operator_dematerialize::extension_pipe::*;
+	#[cfg(feature = "operator_distinct")]
+	pub use rx_core_operator_distinct::extension_pipe::*;
+	#[cfg(feature = "operator_distinct_until_changed")]
+	pub use rx_core_operator_distinct_until_changed::extension_pipe::*;
+	#[cfg(feature = "operator_distinct_until_key_changed")]
+	pub use rx_core_operator_distinct_until_key_changed::extension_pipe::*;
 	#[cfg(feature = "operator_element_at")]
 	pub use rx_core_operator_element_at::extension_pipe::*;
 	#[cfg(feature = "operator_end_with")]
@@ -332,6 +456,8 @@ pub mod extension_pipe {
 	pub use rx_core_operator_find_index::extension_pipe::*;
 	#[cfg(feature = "operator_first")]
 	pub use rx_core_operator_first::extension_pipe::*;
+	#[cfg(feature = "operator_group_by")]
+	pub use rx_core_operator_group_by::extension_pipe::*;
 	#[cfg(feature = "operator_into_result")]
 	pub use rx_core_operator_into_result::extension_pipe::*;
 	#[cfg(feature = "operator_is_empty")]
@@ -362,16 +488,36 @@ pub mod extension_pipe {
 	pub use rx_core_operator_on_subscribe::extension_pipe::*;
 	#[cfg(feature = "operator_pairwise")]
 	pub use rx_core_operator_pairwise::extension_pipe::*;
+	#[cfg(feature = "operator_publish")]
+	pub use rx_core_operator_publish::extension_pipe::*;
+	#[cfg(feature = "operator_record")]
+	pub use rx_core_operator_record::extension_pipe::*;
 	#[cfg(feature = "operator_reduce")]
 	pub use rx_core_operator_reduce::extension_pipe::*;
+	#[cfg(feature = "operator_repeat")]
+	pub use rx_core_operator_repeat::extension_pipe::*;
+	#[cfg(feature = "operator_repeat_when")]
+	pub use rx_core_operator_repeat_when::extension_pipe::*;
 	#[cfg(feature = "operator_retry")]
 	pub use rx_core_operator_retry::extension_pipe::*;
+	#[cfg(feature = "operator_retry_when")]
+	pub use rx_core_operator_retry_when::extension_pipe::*;
+	#[cfg(feature = "operator_sample")]
+	pub use rx_core_operator_sample::extension_pipe::*;
+	#[cfg(feature = "operator_sample_time")]
+	pub use rx_core_operator_sample_time::extension_pipe::*;
 	#[cfg(feature = "operator_scan")]
 	pub use rx_core_operator_scan::extension_pipe::*;
 	#[cfg(feature = "operator_share")]
 	pub use rx_core_operator_share::extension_pipe::*;
+	#[cfg(feature = "operator_share_replay")]
+	pub use rx_core_operator_share_replay::extension_pipe::*;
 	#[cfg(feature = "operator_skip")]
 	pub use rx_core_operator_skip::extension_pipe::*;
+	#[cfg(feature = "operator_skip_until")]
+	pub use rx_core_operator_skip_until::extension_pipe::*;
+	#[cfg(feature = "operator_skip_while")]
+	pub use rx_core_operator_skip_while::extension_pipe::*;
 	#[cfg(feature = "operator_start_with")]
 	pub use rx_core_operator_start_with::extension_pipe::*;
 	#[cfg(feature = "operator_subscribe_on")]
@@ -382,12 +528,24 @@ pub mod extension_pipe {
 	pub use rx_core_operator_switch_map::extension_pipe::*;
 	#[cfg(feature = "operator_take")]
 	pub use rx_core_operator_take::extension_pipe::*;
+	#[cfg(feature = "operator_take_until")]
+	pub use rx_core_operator_take_until::extension_pipe::*;
+	#[cfg(feature = "operator_take_while")]
+	pub use rx_core_operator_take_while::extension_pipe::*;
 	#[cfg(feature = "operator_tap")]
 	pub use rx_core_operator_tap::extension_pipe::*;
 	#[cfg(feature = "operator_tap_next")]
 	pub use rx_core_operator_tap_next::extension_pipe::*;
 	#[cfg(feature = "operator_throttle_time")]
 	pub use rx_core_operator_throttle_time::extension_pipe::*;
+	#[cfg(feature = "operator_timeout")]
+	pub use rx_core_operator_timeout::extension_pipe::*;
+	#[cfg(feature = "operator_window_count")]
+	pub use rx_core_operator_window_count::extension_pipe::*;
+	#[cfg(feature = "operator_window_time")]
+	pub use rx_core_operator_window_time::extension_pipe::*;
+	#[cfg(feature = "operator_window_toggle")]
+	pub use rx_core_operator_window_toggle::extension_pipe::*;
 	#[cfg(feature = "operator_with_latest_from")]
 	pub use rx_core_operator_with_latest_from::extension_pipe::*;
 }
@@ -402,6 +560,8 @@ pub mod observer {
 }
 
 pub mod scheduler {
+	#[cfg(feature = "scheduler_async")]
+	pub use rx_core_scheduler_async::scheduler::*;
 	#[cfg(feature = "scheduler_ticking")]
 	pub use rx_core_scheduler_ticking::scheduler::*;
 }
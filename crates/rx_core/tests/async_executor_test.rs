@@ -0,0 +1,295 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicUsize, Ordering},
+	},
+	time::{Duration, Instant},
+};
+
+use rx_core::prelude::*;
+use rx_core_scheduler_async::{AsyncExecutor, AsyncExecutorOptions, AsyncScheduler, block_on};
+use rx_core_testing::prelude::*;
+
+mod delayed_work {
+	use super::*;
+
+	#[test]
+	fn should_execute_delayed_work_once_the_delay_had_elapsed() {
+		let executor = AsyncExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let executed_at = Arc::new(std::sync::Mutex::new(None::<Duration>));
+		let executed_at_clone = executed_at.clone();
+
+		let scheduled_at = {
+			let mut scheduler = scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			let scheduled_at = scheduler.now();
+			scheduler.schedule_delayed_work(
+				move |tick, _context| {
+					executed_at_clone.lock().unwrap().replace(tick.now());
+				},
+				Duration::from_millis(30),
+				cancellation_id,
+			);
+			scheduled_at
+		};
+
+		executor.block_until_idle();
+
+		let executed_at = executed_at
+			.lock()
+			.unwrap()
+			.expect("delayed work should have been executed");
+
+		assert!(executed_at >= scheduled_at.now() + Duration::from_millis(30));
+	}
+
+	#[test]
+	fn should_not_execute_cancelled_work() {
+		let executor = AsyncExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let counter = Arc::new(AtomicUsize::new(0));
+		let counter_clone = counter.clone();
+
+		{
+			let mut scheduler = scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_delayed_work(
+				move |_, _| {
+					counter_clone.fetch_add(1, Ordering::Relaxed);
+				},
+				Duration::from_millis(30),
+				cancellation_id,
+			);
+			scheduler.cancel(cancellation_id);
+		}
+
+		executor.block_until_idle();
+
+		assert_eq!(counter.load(Ordering::Relaxed), 0);
+	}
+}
+
+mod repeated_work {
+	use super::*;
+
+	#[test]
+	fn should_execute_repeated_work_until_it_is_done() {
+		let executor = AsyncExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let counter = Arc::new(AtomicUsize::new(0));
+		let counter_clone = counter.clone();
+
+		{
+			let mut scheduler = scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_repeated_work(
+				move |_, _| {
+					if counter_clone.fetch_add(1, Ordering::Relaxed) == 2 {
+						WorkResult::Done
+					} else {
+						WorkResult::Pending
+					}
+				},
+				Duration::from_millis(10),
+				false,
+				1,
+				cancellation_id,
+			);
+		}
+
+		executor.block_until_idle();
+
+		assert_eq!(counter.load(Ordering::Relaxed), 3);
+	}
+}
+
+mod continuous_work {
+	use super::*;
+
+	#[test]
+	fn should_execute_continuous_work_on_every_step() {
+		let executor = AsyncExecutor::new(AsyncExecutorOptions {
+			resolution: Duration::from_millis(2),
+			..Default::default()
+		});
+		let scheduler = executor.get_scheduler_handle();
+
+		let counter = Arc::new(AtomicUsize::new(0));
+		let counter_clone = counter.clone();
+
+		{
+			let mut scheduler = scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_continuous_work(
+				move |_, _| {
+					if counter_clone.fetch_add(1, Ordering::Relaxed) == 4 {
+						WorkResult::Done
+					} else {
+						WorkResult::Pending
+					}
+				},
+				cancellation_id,
+			);
+		}
+
+		executor.block_until_idle();
+
+		assert_eq!(counter.load(Ordering::Relaxed), 5);
+	}
+}
+
+mod invoked_work {
+	use super::*;
+
+	#[test]
+	fn should_execute_invoked_work_only_when_invoked() {
+		let executor = AsyncExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let counter = Arc::new(AtomicUsize::new(0));
+		let counter_clone = counter.clone();
+
+		let invoke_id = {
+			let mut scheduler = scheduler.lock();
+			let invoke_id = scheduler.generate_invoke_id();
+			scheduler.schedule_invoked_work(
+				<AsyncScheduler as Scheduler>::InvokedWorkFactory::new(move |_, _| {
+					counter_clone.fetch_add(1, Ordering::Relaxed);
+					WorkResult::Done
+				}),
+				invoke_id,
+			);
+			invoke_id
+		};
+
+		std::thread::sleep(Duration::from_millis(10));
+		assert_eq!(counter.load(Ordering::Relaxed), 0);
+		assert!(!executor.is_idle(), "invokable work should count as work");
+
+		scheduler.lock().invoke(invoke_id);
+		executor.block_until_idle();
+
+		assert_eq!(counter.load(Ordering::Relaxed), 1);
+	}
+}
+
+mod operators {
+	use super::*;
+
+	#[test]
+	fn should_drive_the_delay_operator() {
+		let executor = AsyncExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<usize>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let started_at = Instant::now();
+		let _subscription = (1..=3)
+			.into_observable()
+			.delay(Duration::from_millis(20), scheduler)
+			.subscribe(destination);
+
+		executor.block_until_idle();
+
+		assert!(started_at.elapsed() >= Duration::from_millis(20));
+		notification_collector.lock().assert_notifications(
+			"delay",
+			0,
+			[
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Next(2),
+				SubscriberNotification::Next(3),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_drive_the_debounce_time_operator() {
+		let executor = AsyncExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<usize>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize>::default();
+		let _subscription = source
+			.clone()
+			.debounce_time(Duration::from_millis(50), scheduler)
+			.subscribe(destination);
+
+		source.next(1);
+		source.next(2);
+		executor.block_until_idle();
+
+		notification_collector.lock().assert_notifications(
+			"debounce_time",
+			0,
+			[SubscriberNotification::Next(2)],
+			true,
+		);
+
+		source.complete();
+		executor.block_until_idle();
+	}
+
+	#[test]
+	fn should_drive_the_interval_observable() {
+		let executor = AsyncExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<usize>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let _subscription = IntervalObservable::new(
+			IntervalObservableOptions {
+				duration: Duration::from_millis(10),
+				max_emissions_per_tick: 1,
+				start_on_subscribe: false,
+			},
+			scheduler,
+		)
+		.take(3)
+		.subscribe(destination);
+
+		block_on(executor.until_idle());
+
+		notification_collector.lock().assert_notifications(
+			"interval",
+			0,
+			[
+				SubscriberNotification::Next(0),
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Next(2),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+}
+
+mod drop {
+	use super::*;
+
+	#[test]
+	fn should_resolve_idle_futures_when_dropped() {
+		let executor = AsyncExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		{
+			let mut scheduler = scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_delayed_work(|_, _| {}, Duration::from_secs(3600), cancellation_id);
+		}
+
+		let until_idle = executor.until_idle();
+		drop(executor);
+		block_on(until_idle);
+	}
+}
@@ -0,0 +1,225 @@
+use std::sync::{
+	Arc, Mutex,
+	atomic::{AtomicBool, Ordering},
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_emit_the_latest_value_when_the_duration_observable_emits() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let selected = Arc::new(Mutex::new(Vec::new()));
+	let selected_clone = selected.clone();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut duration = PublishSubject::<(), &'static str>::default();
+	let duration_clone = duration.clone();
+
+	let _subscription = source
+		.clone()
+		.audit(move |next| {
+			selected_clone.lock().unwrap().push(*next);
+			duration_clone.clone()
+		})
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	duration.next(());
+	// The audit already ended
+	duration.next(());
+	source.next(3);
+	duration.next(());
+
+	notification_collector.lock().assert_notifications(
+		"audit",
+		0,
+		[
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+	assert_eq!(*selected.lock().unwrap(), vec![1, 3]);
+}
+
+#[test]
+fn should_keep_the_value_pending_when_the_duration_observable_completes() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut durations = PublishSubject::<bool, &'static str>::default();
+	let durations_clone = durations.clone();
+
+	let _subscription = source
+		.clone()
+		.audit(move |_next| durations_clone.clone().take(1).filter(|emit, _index| *emit))
+		.subscribe(destination);
+
+	source.next(1);
+	durations.next(false);
+	notification_collector.lock().assert_is_empty("audit");
+
+	source.next(2);
+	durations.next(true);
+
+	notification_collector.lock().assert_notifications(
+		"audit",
+		0,
+		[SubscriberNotification::Next(2)],
+		true,
+	);
+}
+
+#[test]
+fn should_complete_once_the_active_duration_observable_ends() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut duration = PublishSubject::<(), &'static str>::default();
+	let duration_clone = duration.clone();
+
+	let _subscription = source
+		.clone()
+		.audit(move |_next| duration_clone.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	source.complete();
+	notification_collector.lock().assert_is_empty("audit");
+
+	duration.next(());
+
+	notification_collector.lock().assert_notifications(
+		"audit",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_forward_duration_observable_errors() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut duration = PublishSubject::<(), &'static str>::default();
+	let duration_clone = duration.clone();
+
+	let subscription = source
+		.clone()
+		.audit(move |_next| duration_clone.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	duration.error("duration error");
+
+	assert!(subscription.is_closed());
+	notification_collector.lock().assert_notifications(
+		"audit",
+		0,
+		[SubscriberNotification::Error("duration error")],
+		true,
+	);
+}
+
+#[test]
+fn should_unsubscribe_the_active_duration_observable_when_unsubscribed() {
+	let duration_finalized = Arc::new(AtomicBool::new(false));
+	let duration_finalized_clone = duration_finalized.clone();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let duration = PublishSubject::<(), &'static str>::default();
+
+	let mut subscription = source
+		.clone()
+		.audit(move |_next| {
+			let duration_finalized_clone = duration_finalized_clone.clone();
+			duration
+				.clone()
+				.finalize(move || duration_finalized_clone.store(true, Ordering::Relaxed))
+		})
+		.subscribe(MockObserver::default());
+
+	source.next(1);
+	assert!(!duration_finalized.load(Ordering::Relaxed));
+	subscription.unsubscribe();
+	assert!(duration_finalized.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_compose() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut duration = PublishSubject::<(), &'static str>::default();
+	let duration_clone = duration.clone();
+
+	let composed =
+		compose_operator::<usize, &'static str>().audit(move |_next| duration_clone.clone());
+
+	let _subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	duration.next(());
+
+	notification_collector.lock().assert_notifications(
+		"audit",
+		0,
+		[SubscriberNotification::Next(2)],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("audit");
+		let observable = harness
+			.create_harness_observable()
+			.audit(|_next| NeverObservable.map_never_both::<(), MockError>());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("audit");
+		let observable = harness
+			.create_harness_observable()
+			.audit(|_next| NeverObservable.map_never_both::<(), MockError>());
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("audit");
+		let observable = harness
+			.create_harness_observable()
+			.audit(|_next| NeverObservable.map_never_both::<(), MockError>());
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -0,0 +1,215 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicBool, Ordering},
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_emit_the_buffer_when_the_notifier_emits() {
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.buffer(notifier.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	notification_collector.lock().assert_is_empty("buffer");
+
+	notifier.next(());
+	notifier.next(());
+	source.next(3);
+
+	notification_collector.lock().assert_notifications(
+		"buffer",
+		0,
+		[
+			SubscriberNotification::Next(vec![1, 2]),
+			SubscriberNotification::Next(vec![]),
+		],
+		true,
+	);
+
+	source.complete();
+	notification_collector.lock().assert_notifications(
+		"buffer",
+		2,
+		[
+			SubscriberNotification::Next(vec![3]),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_keep_collecting_when_the_notifier_completes() {
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.buffer(notifier.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	notifier.complete();
+	source.next(2);
+
+	assert!(!subscription.is_closed());
+
+	source.complete();
+	notification_collector.lock().assert_notifications(
+		"buffer",
+		0,
+		[
+			SubscriberNotification::Next(vec![1, 2]),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+mod error {
+	use super::*;
+
+	#[test]
+	fn should_discard_the_buffer_when_upstream_errors() {
+		let destination = MockObserver::<Vec<usize>, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let notifier = PublishSubject::<(), &'static str>::default();
+		let notifier_finalized = Arc::new(AtomicBool::new(false));
+		let notifier_finalized_clone = notifier_finalized.clone();
+
+		let mut subscription = source
+			.clone()
+			.buffer(
+				notifier
+					.clone()
+					.finalize(move || notifier_finalized_clone.store(true, Ordering::Relaxed)),
+			)
+			.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("buffer");
+
+		source.next(1);
+		source.error("error");
+
+		notification_collector.lock().assert_notifications(
+			"buffer",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+		assert!(subscription.is_closed(), "rx_verify_closed");
+		tracked_teardown.assert_was_torn_down();
+		assert!(
+			notifier_finalized.load(Ordering::Relaxed),
+			"rx_verify_input_observable_teardowns_executed"
+		);
+	}
+
+	#[test]
+	fn should_discard_the_buffer_when_the_notifier_errors() {
+		let destination = MockObserver::<Vec<usize>, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut notifier = PublishSubject::<(), &'static str>::default();
+
+		let subscription = source
+			.clone()
+			.buffer(notifier.clone())
+			.subscribe(destination);
+
+		source.next(1);
+		notifier.error("error");
+		source.next(2);
+
+		notification_collector.lock().assert_notifications(
+			"buffer",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+		assert!(subscription.is_closed(), "rx_verify_closed");
+	}
+}
+
+#[test]
+fn should_unsubscribe_from_the_notifier_when_unsubscribed() {
+	let notifier_finalized = Arc::new(AtomicBool::new(false));
+	let notifier_finalized_clone = notifier_finalized.clone();
+
+	let mut subscription = PublishSubject::<usize>::default()
+		.buffer(
+			PublishSubject::<()>::default()
+				.finalize(move || notifier_finalized_clone.store(true, Ordering::Relaxed)),
+		)
+		.subscribe(MockObserver::default());
+
+	assert!(!notifier_finalized.load(Ordering::Relaxed));
+	subscription.unsubscribe();
+	assert!(notifier_finalized.load(Ordering::Relaxed));
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"buffer",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.buffer(PublishSubject::<(), &'static str>::default());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error"));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"buffer",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.buffer(PublishSubject::<(), &'static str>::default());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"buffer",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.buffer(PublishSubject::<(), &'static str>::default());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -0,0 +1,270 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_common::{SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_emit_the_buffer_every_duration() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut subscription = source
+		.clone()
+		.buffer_time(Duration::from_millis(1000), usize::MAX, scheduler)
+		.subscribe(destination);
+
+	source.next(1);
+	executor.tick(Duration::from_millis(500));
+	source.next(2);
+
+	notification_collector.lock().assert_is_empty("buffer_time");
+
+	executor.tick(Duration::from_millis(500));
+	notification_collector.lock().assert_notifications(
+		"buffer_time",
+		0,
+		[SubscriberNotification::Next(vec![1, 2])],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(1000));
+	notification_collector.lock().assert_notifications(
+		"buffer_time",
+		1,
+		[SubscriberNotification::Next(vec![])],
+		true,
+	);
+
+	subscription.unsubscribe();
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_emit_the_buffer_early_when_it_reaches_the_max_size() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.buffer_time(Duration::from_millis(1000), 2, scheduler)
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	source.next(3);
+
+	notification_collector.lock().assert_notifications(
+		"buffer_time",
+		0,
+		[SubscriberNotification::Next(vec![1, 2])],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(1000));
+	notification_collector.lock().assert_notifications(
+		"buffer_time",
+		1,
+		[SubscriberNotification::Next(vec![3])],
+		true,
+	);
+}
+
+#[test]
+fn should_start_a_new_period_after_emitting_a_full_buffer() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut subscription = source
+		.clone()
+		.buffer_time(Duration::from_millis(1000), 2, scheduler)
+		.subscribe(destination);
+
+	executor.tick(Duration::from_millis(600));
+	source.next(1);
+	source.next(2);
+	source.next(3);
+
+	notification_collector.lock().assert_notifications(
+		"buffer_time",
+		0,
+		[SubscriberNotification::Next(vec![1, 2])],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(400));
+	assert_eq!(
+		notification_collector.lock().count_observed_nexts(),
+		1,
+		"the period should have been restarted by the full buffer"
+	);
+
+	executor.tick(Duration::from_millis(600));
+	notification_collector.lock().assert_notifications(
+		"buffer_time",
+		1,
+		[SubscriberNotification::Next(vec![3])],
+		true,
+	);
+
+	subscription.unsubscribe();
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+mod complete {
+	use super::*;
+
+	#[test]
+	fn should_emit_the_remaining_buffer_and_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<Vec<usize>, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut subscription = source
+			.clone()
+			.buffer_time(Duration::from_millis(1000), usize::MAX, scheduler)
+			.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("buffer_time");
+
+		source.next(1);
+		executor.tick(Duration::from_millis(200));
+		source.complete();
+
+		notification_collector.lock().assert_notifications(
+			"buffer_time",
+			0,
+			[
+				SubscriberNotification::Next(vec![1]),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+
+		assert!(subscription.is_closed(), "rx_verify_closed");
+		tracked_teardown.assert_was_torn_down();
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+}
+
+mod error {
+	use super::*;
+
+	#[test]
+	fn should_discard_the_buffer_and_error() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<Vec<usize>, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut subscription = source
+			.clone()
+			.buffer_time(Duration::from_millis(1000), usize::MAX, scheduler)
+			.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("buffer_time");
+
+		source.next(1);
+		source.error("error");
+
+		notification_collector.lock().assert_notifications(
+			"buffer_time",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+
+		assert!(subscription.is_closed(), "rx_verify_closed");
+		tracked_teardown.assert_was_torn_down();
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"buffer_time",
+			);
+		let observable = harness.create_harness_observable().buffer_time(
+			Duration::from_millis(1000),
+			usize::MAX,
+			scheduler.clone(),
+		);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error"));
+
+		executor.tick(Duration::from_millis(2000));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"buffer_time",
+			);
+		let observable = harness.create_harness_observable().buffer_time(
+			Duration::from_millis(1000),
+			usize::MAX,
+			scheduler.clone(),
+		);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+
+		executor.tick(Duration::from_millis(2000));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"buffer_time",
+			);
+		let observable = harness.create_harness_observable().buffer_time(
+			Duration::from_millis(1000),
+			usize::MAX,
+			scheduler.clone(),
+		);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+
+		executor.tick(Duration::from_millis(2000));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+}
@@ -0,0 +1,323 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::Duration,
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_collect_into_overlapping_buffers_between_openings_and_closings() {
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut openings = PublishSubject::<usize, &'static str>::default();
+	let mut closings = [
+		PublishSubject::<(), &'static str>::default(),
+		PublishSubject::<(), &'static str>::default(),
+		PublishSubject::<(), &'static str>::default(),
+	];
+	let closings_clone = closings.clone();
+
+	let _subscription = source
+		.clone()
+		.buffer_toggle(openings.clone(), move |index: usize| {
+			closings_clone[index].clone()
+		})
+		.subscribe(destination);
+
+	source.next(0);
+	openings.next(0);
+	source.next(1);
+	openings.next(1);
+	source.next(2);
+
+	notification_collector
+		.lock()
+		.assert_is_empty("buffer_toggle");
+
+	closings[0].next(());
+	notification_collector.lock().assert_notifications(
+		"buffer_toggle",
+		0,
+		[SubscriberNotification::Next(vec![1, 2])],
+		true,
+	);
+
+	closings[1].next(());
+	source.next(3);
+	openings.next(2);
+	source.next(4);
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"buffer_toggle",
+		1,
+		[
+			SubscriberNotification::Next(vec![2]),
+			SubscriberNotification::Next(vec![4]),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_keep_the_buffer_open_when_its_closing_observable_completes() {
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut openings = PublishSubject::<(), &'static str>::default();
+	let mut closing = PublishSubject::<(), &'static str>::default();
+	let closing_clone = closing.clone();
+
+	let _subscription = source
+		.clone()
+		.buffer_toggle(openings.clone(), move |_| closing_clone.clone())
+		.subscribe(destination);
+
+	openings.next(());
+	source.next(1);
+	closing.complete();
+	source.next(2);
+
+	notification_collector
+		.lock()
+		.assert_is_empty("buffer_toggle");
+
+	source.complete();
+	notification_collector.lock().assert_notifications(
+		"buffer_toggle",
+		0,
+		[
+			SubscriberNotification::Next(vec![1, 2]),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_emit_buffers_when_their_timer_fires() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<Vec<usize>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize>::default();
+	let mut openings = PublishSubject::<u64>::default();
+
+	let mut subscription = source
+		.clone()
+		.buffer_toggle(openings.clone(), move |milliseconds| {
+			timer(Duration::from_millis(milliseconds), scheduler.clone())
+		})
+		.subscribe(destination);
+
+	openings.next(1000);
+	source.next(1);
+	executor.tick(Duration::from_millis(500));
+	openings.next(200);
+	source.next(2);
+	executor.tick(Duration::from_millis(200));
+
+	notification_collector.lock().assert_notifications(
+		"buffer_toggle",
+		0,
+		[SubscriberNotification::Next(vec![2])],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(300));
+	notification_collector.lock().assert_notifications(
+		"buffer_toggle",
+		1,
+		[SubscriberNotification::Next(vec![1, 2])],
+		true,
+	);
+
+	subscription.unsubscribe();
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+mod error {
+	use super::*;
+
+	#[test]
+	fn should_discard_every_buffer_when_the_openings_error() {
+		let destination = MockObserver::<Vec<usize>, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut openings = PublishSubject::<(), &'static str>::default();
+
+		let mut subscription = source
+			.clone()
+			.buffer_toggle(openings.clone(), |_| {
+				PublishSubject::<(), &'static str>::default()
+			})
+			.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("buffer_toggle");
+
+		openings.next(());
+		source.next(1);
+		openings.error("error");
+
+		notification_collector.lock().assert_notifications(
+			"buffer_toggle",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+		assert!(subscription.is_closed(), "rx_verify_closed");
+		tracked_teardown.assert_was_torn_down();
+	}
+
+	#[test]
+	fn should_discard_every_buffer_when_a_closing_observable_errors() {
+		let destination = MockObserver::<Vec<usize>, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut openings = PublishSubject::<(), &'static str>::default();
+		let mut closing = PublishSubject::<(), &'static str>::default();
+		let closing_clone = closing.clone();
+
+		let subscription = source
+			.clone()
+			.buffer_toggle(openings.clone(), move |_| closing_clone.clone())
+			.subscribe(destination);
+
+		openings.next(());
+		source.next(1);
+		closing.error("error");
+
+		notification_collector.lock().assert_notifications(
+			"buffer_toggle",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+		assert!(subscription.is_closed(), "rx_verify_closed");
+	}
+
+	#[test]
+	fn should_discard_every_buffer_when_upstream_errors() {
+		let destination = MockObserver::<Vec<usize>, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut openings = PublishSubject::<(), &'static str>::default();
+
+		let subscription = source
+			.clone()
+			.buffer_toggle(openings.clone(), |_| {
+				PublishSubject::<(), &'static str>::default()
+			})
+			.subscribe(destination);
+
+		openings.next(());
+		source.next(1);
+		source.error("error");
+
+		notification_collector.lock().assert_notifications(
+			"buffer_toggle",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+		assert!(subscription.is_closed(), "rx_verify_closed");
+	}
+}
+
+#[test]
+fn should_unsubscribe_the_openings_and_closings_when_unsubscribed() {
+	let openings_finalized = Arc::new(AtomicBool::new(false));
+	let openings_finalized_clone = openings_finalized.clone();
+	let closing_finalized = Arc::new(AtomicBool::new(false));
+	let closing_finalized_clone = closing_finalized.clone();
+
+	let mut openings = PublishSubject::<()>::default();
+
+	let mut subscription = PublishSubject::<usize>::default()
+		.buffer_toggle(
+			openings
+				.clone()
+				.finalize(move || openings_finalized_clone.store(true, Ordering::Relaxed)),
+			move |_| {
+				let closing_finalized = closing_finalized_clone.clone();
+				PublishSubject::<()>::default()
+					.finalize(move || closing_finalized.store(true, Ordering::Relaxed))
+			},
+		)
+		.subscribe(MockObserver::default());
+
+	openings.next(());
+	subscription.unsubscribe();
+
+	assert!(openings_finalized.load(Ordering::Relaxed));
+	assert!(closing_finalized.load(Ordering::Relaxed));
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"buffer_toggle",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.buffer_toggle(PublishSubject::<(), &'static str>::default(), |_| {
+				PublishSubject::<(), &'static str>::default()
+			});
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error"));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"buffer_toggle",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.buffer_toggle(PublishSubject::<(), &'static str>::default(), |_| {
+				PublishSubject::<(), &'static str>::default()
+			});
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"buffer_toggle",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.buffer_toggle(PublishSubject::<(), &'static str>::default(), |_| {
+				PublishSubject::<(), &'static str>::default()
+			});
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -396,3 +396,182 @@ mod errors {
 		);
 	}
 }
+
+mod variadic {
+	use super::*;
+
+	#[test]
+	fn should_only_emit_after_all_three_observables_emitted() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subject_1 = PublishSubject::<usize>::default();
+		let mut subject_2 = PublishSubject::<&'static str>::default();
+		let mut subject_3 = PublishSubject::<bool>::default();
+
+		let _s = combine_latest3(subject_1.clone(), subject_2.clone(), subject_3.clone())
+			.subscribe(destination);
+
+		subject_1.next(1);
+		subject_2.next("hello");
+		assert!(
+			notification_collector.lock().is_empty(),
+			"Nothing should've been emitted before all sources emitted"
+		);
+
+		subject_3.next(true);
+		subject_1.next(2);
+
+		notification_collector.lock().assert_notifications(
+			"combine_latest3",
+			0,
+			[
+				SubscriberNotification::Next((1, "hello", true)),
+				SubscriberNotification::Next((2, "hello", true)),
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_combine_eight_observables() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let _s = combine_latest8(
+			just(1),
+			just("2"),
+			just(3.0),
+			just('4'),
+			just(5u8),
+			just(true),
+			just(7i64),
+			(8..=9).into_observable(),
+		)
+		.subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"combine_latest8",
+			0,
+			[
+				SubscriberNotification::Next((1, "2", 3.0, '4', 5u8, true, 7i64, 8)),
+				SubscriberNotification::Next((1, "2", 3.0, '4', 5u8, true, 7i64, 9)),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_just_unsubscribe_when_one_completes_before_emitting() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let subject_1 = PublishSubject::<usize>::default();
+		let subject_2 = PublishSubject::<usize>::default();
+		let mut subject_3 = PublishSubject::<usize>::default();
+
+		let _s = combine_latest3(subject_1.clone(), subject_2.clone(), subject_3.clone())
+			.subscribe(destination);
+
+		subject_3.complete();
+
+		notification_collector.lock().assert_notifications(
+			"combine_latest3",
+			0,
+			[SubscriberNotification::Unsubscribe],
+			true,
+		);
+	}
+}
+
+mod array {
+	use super::*;
+
+	#[test]
+	fn should_emit_a_vec_of_the_latest_values_after_all_emitted() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subjects = [
+			PublishSubject::<usize>::default(),
+			PublishSubject::<usize>::default(),
+			PublishSubject::<usize>::default(),
+		];
+
+		let _s = combine_latest_array(subjects.clone()).subscribe(destination);
+
+		subjects[0].next(1);
+		subjects[2].next(3);
+		subjects[1].next(2);
+		subjects[0].next(10);
+
+		notification_collector.lock().assert_notifications(
+			"combine_latest_array",
+			0,
+			[
+				SubscriberNotification::Next(vec![1, 2, 3]),
+				SubscriberNotification::Next(vec![10, 2, 3]),
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_accept_a_vec_and_complete_once_all_completed() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let _s = combine_latest_array(vec![(1..=2).into_observable(), (3..=3).into_observable()])
+			.subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"combine_latest_array",
+			0,
+			[
+				SubscriberNotification::Next(vec![2, 3]),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_error_downstream_when_any_of_the_observables_errors() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subjects = [
+			PublishSubject::<usize, &'static str>::default(),
+			PublishSubject::<usize, &'static str>::default(),
+		];
+
+		let _s = combine_latest_array(subjects.clone()).subscribe(destination);
+
+		subjects[1].error("error");
+
+		notification_collector.lock().assert_notifications(
+			"combine_latest_array",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_complete_immediately_when_empty() {
+		let destination = MockObserver::<Vec<usize>>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let subscription =
+			combine_latest_array(Vec::<PublishSubject<usize>>::new()).subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"combine_latest_array",
+			0,
+			[SubscriberNotification::Complete],
+			false,
+		);
+		assert!(subscription.is_closed());
+	}
+}
@@ -0,0 +1,198 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_testing::prelude::*;
+
+mod reset_on_complete {
+	use super::*;
+
+	#[test]
+	fn should_only_reset_once_the_delay_elapsed() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut connectable_observable = ConnectableObservable::new_with_reset_delays(
+			source.clone(),
+			ConnectableOptions::new(ReplaySubject::<1, _, _>::default)
+				.reset_connector_on_complete(),
+			ConnectableResetDelays::default()
+				.on_complete(ResetDelay::new(Duration::from_millis(1000), scheduler)),
+		);
+
+		let _s = connectable_observable.subscribe(MockObserver::default());
+		connectable_observable.connect();
+
+		source.next(1);
+		source.complete();
+
+		executor.tick(Duration::from_millis(500));
+
+		let destination_2 = MockObserver::default();
+		let notification_collector_2 = destination_2.get_notification_collector();
+		let _s2 = connectable_observable.subscribe(destination_2);
+
+		notification_collector_2.lock().assert_notifications(
+			"connectable - reset_delay on_complete 2",
+			0,
+			[
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+
+		executor.tick(Duration::from_millis(500));
+
+		// Asserting reset by checking if the replay is still primed or not
+		let destination_3 = MockObserver::default();
+		let notification_collector_3 = destination_3.get_notification_collector();
+		let _s3 = connectable_observable.subscribe(destination_3);
+		notification_collector_3
+			.lock()
+			.assert_is_empty("connectable - reset_delay on_complete 3");
+	}
+
+	#[test]
+	fn should_cancel_the_pending_reset_when_reset_manually() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut connectable_observable = ConnectableObservable::new_with_reset_delays(
+			source.clone(),
+			ConnectableOptions::new(ReplaySubject::<1, _, _>::default)
+				.reset_connector_on_complete(),
+			ConnectableResetDelays::default()
+				.on_complete(ResetDelay::new(Duration::from_millis(1000), scheduler)),
+		);
+
+		connectable_observable.connect();
+		source.complete();
+		executor.tick(Duration::from_millis(0));
+		assert!(!executor.is_empty());
+
+		connectable_observable.reset();
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty());
+	}
+}
+
+mod reset_on_error {
+	use super::*;
+
+	#[test]
+	fn should_only_reset_once_the_delay_elapsed() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut connectable_observable = ConnectableObservable::new_with_reset_delays(
+			source.clone(),
+			ConnectableOptions::new(ReplaySubject::<1, _, _>::default).reset_connector_on_error(),
+			ConnectableResetDelays::default()
+				.on_error(ResetDelay::new(Duration::from_millis(1000), scheduler)),
+		);
+
+		let _s = connectable_observable.subscribe(MockObserver::default());
+		connectable_observable.connect();
+
+		source.next(1);
+		source.error("error");
+
+		executor.tick(Duration::from_millis(500));
+
+		let destination_2 = MockObserver::default();
+		let notification_collector_2 = destination_2.get_notification_collector();
+		let _s2 = connectable_observable.subscribe(destination_2);
+
+		notification_collector_2.lock().assert_notifications(
+			"connectable - reset_delay on_error 2",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+
+		executor.tick(Duration::from_millis(500));
+
+		let destination_3 = MockObserver::default();
+		let notification_collector_3 = destination_3.get_notification_collector();
+		let _s3 = connectable_observable.subscribe(destination_3);
+		notification_collector_3
+			.lock()
+			.assert_is_empty("connectable - reset_delay on_error 3");
+	}
+}
+
+mod reset_on_ref_count_zero {
+	use super::*;
+
+	#[test]
+	fn should_only_disconnect_once_the_delay_elapsed() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let source = PublishSubject::<usize, &'static str>::default();
+		let mut connectable_observable = ConnectableObservable::new_with_reset_delays(
+			source.clone(),
+			ConnectableOptions::new(ReplaySubject::<1, _, _>::default)
+				.disconnect_when_ref_count_zero(),
+			ConnectableResetDelays::default()
+				.on_ref_count_zero(ResetDelay::new(Duration::from_millis(1000), scheduler)),
+		);
+
+		connectable_observable.connect();
+		let mut subscription = connectable_observable.subscribe(MockObserver::default());
+		subscription.unsubscribe();
+
+		executor.tick(Duration::from_millis(999));
+		assert!(connectable_observable.is_connected());
+
+		executor.tick(Duration::from_millis(1));
+		assert!(
+			!connectable_observable.is_connected(),
+			"should've disconnected once the delay elapsed"
+		);
+	}
+
+	#[test]
+	fn should_not_disconnect_when_a_new_subscriber_arrives_within_the_delay() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut connectable_observable = ConnectableObservable::new_with_reset_delays(
+			source.clone(),
+			ConnectableOptions::new(ReplaySubject::<1, _, _>::default)
+				.disconnect_when_ref_count_zero()
+				.reset_connector_on_disconnect(),
+			ConnectableResetDelays::default()
+				.on_ref_count_zero(ResetDelay::new(Duration::from_millis(1000), scheduler)),
+		);
+
+		connectable_observable.connect();
+		let mut subscription = connectable_observable.subscribe(MockObserver::default());
+		source.next(1);
+		subscription.unsubscribe();
+
+		executor.tick(Duration::from_millis(500));
+		let _s2 = connectable_observable.subscribe(destination);
+
+		executor.tick(Duration::from_millis(1000));
+		assert!(connectable_observable.is_connected());
+
+		notification_collector.lock().assert_notifications(
+			"connectable - reset_delay on_ref_count_zero",
+			0,
+			[SubscriberNotification::Next(1)],
+			true,
+		);
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty());
+	}
+}
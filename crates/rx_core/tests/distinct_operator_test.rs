@@ -0,0 +1,212 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicBool, Ordering},
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_only_emit_values_with_unseen_keys() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let _subscription = source.clone().distinct(|next| *next).subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	source.next(1);
+	source.next(3);
+	source.next(2);
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"distinct",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_use_the_key_returned_by_the_key_fn() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.distinct(|next| next % 3)
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(4);
+	source.next(2);
+	source.next(3);
+	source.next(6);
+
+	notification_collector.lock().assert_notifications(
+		"distinct",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_forget_seen_keys_when_the_flush_notifier_emits() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut flush = PublishSubject::<(), &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.distinct_with_flush(|next| *next, flush.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(1);
+	flush.next(());
+	source.next(1);
+	source.next(1);
+
+	notification_collector.lock().assert_notifications(
+		"distinct",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(1),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_not_forward_flush_notifier_errors() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut flush = PublishSubject::<(), &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.distinct_with_flush(|next| *next, flush.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	flush.error("flush error");
+	source.next(1);
+	source.next(2);
+
+	assert!(!subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"distinct",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_unsubscribe_the_flush_notifier_when_unsubscribed() {
+	let flush_finalized = Arc::new(AtomicBool::new(false));
+	let flush_finalized_clone = flush_finalized.clone();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+	let flush = PublishSubject::<(), &'static str>::default();
+
+	let mut subscription = source
+		.clone()
+		.distinct_with_flush(
+			|next| *next,
+			flush
+				.clone()
+				.finalize(move || flush_finalized_clone.store(true, Ordering::Relaxed)),
+		)
+		.subscribe(MockObserver::default());
+
+	assert!(!flush_finalized.load(Ordering::Relaxed));
+	subscription.unsubscribe();
+	assert!(flush_finalized.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_compose() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let composed = compose_operator::<usize, &'static str>().distinct(|next| *next);
+
+	let _subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	source.next(1);
+	source.next(2);
+
+	notification_collector.lock().assert_notifications(
+		"distinct",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+		],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("distinct");
+		let observable = harness.create_harness_observable().distinct(|next| *next);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("distinct");
+		let observable = harness.create_harness_observable().distinct(|next| *next);
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("distinct");
+		let observable = harness.create_harness_observable().distinct(|next| *next);
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -0,0 +1,158 @@
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_skip_values_equal_to_the_previous_one() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.distinct_until_changed()
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(1);
+	source.next(2);
+	source.next(2);
+	source.next(1);
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"distinct_until_changed",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_use_the_custom_comparator() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.distinct_until_changed_by(|previous, next| previous / 10 == next / 10)
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(5);
+	source.next(12);
+	source.next(19);
+	source.next(3);
+
+	notification_collector.lock().assert_notifications(
+		"distinct_until_changed",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(12),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_compare_against_the_last_emitted_value() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	// A skipped value must not become the value compared against
+	let _subscription = source
+		.clone()
+		.distinct_until_changed_by(|previous, next| next.abs_diff(*previous) < 2)
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	source.next(3);
+	source.next(4);
+
+	notification_collector.lock().assert_notifications(
+		"distinct_until_changed",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_compose() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let composed = compose_operator::<usize, &'static str>().distinct_until_changed();
+
+	let _subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	source.next(1);
+	source.next(2);
+
+	notification_collector.lock().assert_notifications(
+		"distinct_until_changed",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+		],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness = TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new(
+			"distinct_until_changed",
+		);
+		let observable = harness.create_harness_observable().distinct_until_changed();
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness = TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new(
+			"distinct_until_changed",
+		);
+		let observable = harness.create_harness_observable().distinct_until_changed();
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness = TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new(
+			"distinct_until_changed",
+		);
+		let observable = harness.create_harness_observable().distinct_until_changed();
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -0,0 +1,135 @@
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification};
+use rx_core_testing::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Person {
+	name: &'static str,
+	age: usize,
+}
+
+#[test]
+fn should_skip_values_whose_key_equals_the_previous_key() {
+	let destination = MockObserver::<Person, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<Person, &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.distinct_until_key_changed(|person| person.name)
+		.subscribe(destination);
+
+	source.next(Person {
+		name: "Alice",
+		age: 20,
+	});
+	source.next(Person {
+		name: "Alice",
+		age: 21,
+	});
+	source.next(Person {
+		name: "Bob",
+		age: 30,
+	});
+	source.next(Person {
+		name: "Alice",
+		age: 22,
+	});
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"distinct_until_key_changed",
+		0,
+		[
+			SubscriberNotification::Next(Person {
+				name: "Alice",
+				age: 20,
+			}),
+			SubscriberNotification::Next(Person {
+				name: "Bob",
+				age: 30,
+			}),
+			SubscriberNotification::Next(Person {
+				name: "Alice",
+				age: 22,
+			}),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_compose() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let composed =
+		compose_operator::<usize, &'static str>().distinct_until_key_changed(|next| next % 2);
+
+	let _subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	source.next(3);
+	source.next(4);
+	source.next(6);
+	source.next(7);
+
+	notification_collector.lock().assert_notifications(
+		"distinct_until_key_changed",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(4),
+			SubscriberNotification::Next(7),
+		],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness = TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new(
+			"distinct_until_key_changed",
+		);
+		let observable = harness
+			.create_harness_observable()
+			.distinct_until_key_changed(|next| *next);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness = TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new(
+			"distinct_until_key_changed",
+		);
+		let observable = harness
+			.create_harness_observable()
+			.distinct_until_key_changed(|next| *next);
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness = TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new(
+			"distinct_until_key_changed",
+		);
+		let observable = harness
+			.create_harness_observable()
+			.distinct_until_key_changed(|next| *next);
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
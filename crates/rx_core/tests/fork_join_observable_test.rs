@@ -0,0 +1,419 @@
+use rx_core::prelude::*;
+use rx_core_common::SubscriberNotification;
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_emit_the_last_values_once_all_sources_completed() {
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source_1 = PublishSubject::<usize>::default();
+	let mut source_2 = PublishSubject::<usize>::default();
+	let mut source_3 = PublishSubject::<usize>::default();
+
+	let _subscription =
+		fork_join((source_1.clone(), source_2.clone(), source_3.clone())).subscribe(destination);
+
+	source_1.next(1);
+	source_2.next(2);
+	source_3.next(3);
+	source_1.next(4);
+	source_1.complete();
+	source_3.complete();
+
+	assert!(
+		notification_collector.lock().is_empty(),
+		"no emissions before all sources completed"
+	);
+
+	source_2.next(5);
+	source_2.complete();
+
+	notification_collector.lock().assert_notifications(
+		"fork_join",
+		0,
+		[
+			SubscriberNotification::Next([4, 5, 3]),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_complete_without_a_value_as_soon_as_a_source_completes_empty() {
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source_1 = PublishSubject::<usize>::default();
+	let mut source_1_finalized = SharedSubscription::default();
+	let source_1_tracked_teardown = source_1_finalized.add_tracked_teardown("fork_join - source_1");
+
+	let mut source_2 = PublishSubject::<usize>::default();
+	let mut source_2_finalized = SharedSubscription::default();
+	let source_2_tracked_teardown = source_2_finalized.add_tracked_teardown("fork_join - source_2");
+
+	let subscription = fork_join((
+		source_1
+			.clone()
+			.finalize(move || source_1_finalized.unsubscribe()),
+		source_2
+			.clone()
+			.finalize(move || source_2_finalized.unsubscribe()),
+	))
+	.subscribe(destination);
+
+	source_1.next(1);
+	source_2.complete();
+
+	notification_collector.lock().assert_notifications(
+		"fork_join",
+		0,
+		[SubscriberNotification::Complete],
+		true,
+	);
+	assert!(subscription.is_closed());
+
+	source_1_tracked_teardown.assert_was_torn_down();
+	source_2_tracked_teardown.assert_was_torn_down();
+}
+
+#[test]
+fn should_unsubscribe_when_a_source_unsubscribes_without_completing() {
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source_1 = PublishSubject::<usize>::default();
+	let mut source_2 = PublishSubject::<usize>::default();
+
+	let subscription = fork_join((source_1.clone(), source_2.clone())).subscribe(destination);
+
+	source_1.next(1);
+	source_1.complete();
+	source_2.next(2);
+	source_2.unsubscribe();
+
+	notification_collector.lock().assert_notifications(
+		"fork_join",
+		0,
+		[SubscriberNotification::Unsubscribe],
+		true,
+	);
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_emit_immediately_when_all_sources_complete_immediately() {
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let _subscription = fork_join((
+		(1..=3).into_observable(),
+		(4..=5).into_observable(),
+		just(6),
+	))
+	.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"fork_join",
+		0,
+		[
+			SubscriberNotification::Next([3, 5, 6]),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+mod errors {
+	use super::*;
+
+	#[test]
+	fn should_error_downstream_when_any_source_errors() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source_1 = PublishSubject::<usize, &'static str>::default();
+		let mut source_2 = PublishSubject::<usize, &'static str>::default();
+
+		let _subscription = fork_join((source_1.clone(), source_2.clone())).subscribe(destination);
+
+		source_1.next(1);
+		source_1.complete();
+		source_2.error("error");
+
+		notification_collector.lock().assert_notifications(
+			"fork_join",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+	}
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut source_1 = PublishSubject::<usize, MockError>::default();
+		let mut source_1_finalized = SharedSubscription::default();
+		let source_1_tracked_teardown =
+			source_1_finalized.add_tracked_teardown("fork_join - source_1");
+
+		let source_2 = PublishSubject::<usize, MockError>::default();
+		let mut source_2_finalized = SharedSubscription::default();
+		let source_2_tracked_teardown =
+			source_2_finalized.add_tracked_teardown("fork_join - source_2");
+
+		let mut harness = TestHarness::<_, [usize; 2], MockError>::new_with_source(
+			"fork_join",
+			fork_join((
+				source_1
+					.clone()
+					.finalize(move || source_1_finalized.unsubscribe()),
+				source_2
+					.clone()
+					.finalize(move || source_2_finalized.unsubscribe()),
+			)),
+		);
+		let observable = harness.create_harness_observable();
+		harness.subscribe_to(observable);
+
+		source_1.error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+
+		source_1_tracked_teardown.assert_was_torn_down();
+		source_2_tracked_teardown.assert_was_torn_down();
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut source_1 = PublishSubject::<usize, MockError>::default();
+		let mut source_1_finalized = SharedSubscription::default();
+		let source_1_tracked_teardown =
+			source_1_finalized.add_tracked_teardown("fork_join - source_1");
+
+		let mut source_2 = PublishSubject::<usize, MockError>::default();
+		let mut source_2_finalized = SharedSubscription::default();
+		let source_2_tracked_teardown =
+			source_2_finalized.add_tracked_teardown("fork_join - source_2");
+
+		let mut harness = TestHarness::<_, [usize; 2], MockError>::new_with_source(
+			"fork_join",
+			fork_join((
+				source_1
+					.clone()
+					.finalize(move || source_1_finalized.unsubscribe()),
+				source_2
+					.clone()
+					.finalize(move || source_2_finalized.unsubscribe()),
+			)),
+		);
+		let observable = harness.create_harness_observable();
+		harness.subscribe_to(observable);
+
+		source_1.next(1);
+		source_2.next(2);
+		source_1.complete();
+		source_2.complete();
+
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+
+		source_1_tracked_teardown.assert_was_torn_down();
+		source_2_tracked_teardown.assert_was_torn_down();
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let source_1 = PublishSubject::<usize, MockError>::default();
+		let mut source_1_finalized = SharedSubscription::default();
+		let source_1_tracked_teardown =
+			source_1_finalized.add_tracked_teardown("fork_join - source_1");
+
+		let source_2 = PublishSubject::<usize, MockError>::default();
+		let mut source_2_finalized = SharedSubscription::default();
+		let source_2_tracked_teardown =
+			source_2_finalized.add_tracked_teardown("fork_join - source_2");
+
+		let mut harness = TestHarness::<_, [usize; 2], MockError>::new_with_source(
+			"fork_join",
+			fork_join((
+				source_1
+					.clone()
+					.finalize(move || source_1_finalized.unsubscribe()),
+				source_2
+					.clone()
+					.finalize(move || source_2_finalized.unsubscribe()),
+			)),
+		);
+		let observable = harness.create_harness_observable();
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+
+		source_1_tracked_teardown.assert_was_torn_down();
+		source_2_tracked_teardown.assert_was_torn_down();
+	}
+}
+
+mod variadic {
+	use super::*;
+
+	#[test]
+	fn should_emit_a_tuple_of_the_last_values_once_all_sources_completed() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source_1 = PublishSubject::<u32>::default();
+		let mut source_2 = PublishSubject::<String>::default();
+
+		let _subscription = fork_join2(source_1.clone(), source_2.clone()).subscribe(destination);
+
+		source_1.next(1);
+		source_2.next("hello".to_string());
+		source_1.next(2);
+		source_1.complete();
+
+		assert!(
+			notification_collector.lock().is_empty(),
+			"no emissions before all sources completed"
+		);
+
+		source_2.complete();
+
+		notification_collector.lock().assert_notifications(
+			"fork_join2",
+			0,
+			[
+				SubscriberNotification::Next((2, "hello".to_string())),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_join_eight_observables() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let _subscription = fork_join8(
+			just(1),
+			just("2"),
+			just(3.0),
+			just('4'),
+			just(5u8),
+			just(true),
+			just(7i64),
+			(8..=9).into_observable(),
+		)
+		.subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"fork_join8",
+			0,
+			[
+				SubscriberNotification::Next((1, "2", 3.0, '4', 5u8, true, 7i64, 9)),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_complete_without_a_value_as_soon_as_a_source_completes_empty() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source_1 = PublishSubject::<usize>::default();
+		let source_2 = PublishSubject::<&'static str>::default();
+		let mut source_3 = PublishSubject::<bool>::default();
+
+		let subscription =
+			fork_join3(source_1.clone(), source_2.clone(), source_3.clone()).subscribe(destination);
+
+		source_1.next(1);
+		source_3.complete();
+
+		notification_collector.lock().assert_notifications(
+			"fork_join3",
+			0,
+			[SubscriberNotification::Complete],
+			true,
+		);
+		assert!(subscription.is_closed());
+	}
+
+	#[test]
+	fn should_error_downstream_when_any_source_errors() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source_1 = PublishSubject::<usize, &'static str>::default();
+		let mut source_2 = PublishSubject::<bool, &'static str>::default();
+
+		let subscription = fork_join2(source_1.clone(), source_2.clone()).subscribe(destination);
+
+		source_1.next(1);
+		source_1.complete();
+		source_2.error("error");
+
+		notification_collector.lock().assert_notifications(
+			"fork_join2",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+		assert!(subscription.is_closed());
+	}
+}
+
+mod array {
+	use super::*;
+
+	#[test]
+	fn should_emit_an_array_of_the_last_values_once_all_completed() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut sources = [
+			PublishSubject::<usize>::default(),
+			PublishSubject::<usize>::default(),
+		];
+
+		let _subscription = fork_join(sources.clone()).subscribe(destination);
+
+		sources[1].next(1);
+		sources[0].next(2);
+		sources[1].complete();
+		sources[0].complete();
+
+		notification_collector.lock().assert_notifications(
+			"fork_join",
+			0,
+			[
+				SubscriberNotification::Next([2, 1]),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_complete_immediately_when_empty() {
+		let destination = MockObserver::<[usize; 0]>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let subscription =
+			fork_join::<usize, Never, 0>([] as [PublishSubject<usize>; 0]).subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"fork_join",
+			0,
+			[SubscriberNotification::Complete],
+			true,
+		);
+		assert!(subscription.is_closed());
+	}
+}
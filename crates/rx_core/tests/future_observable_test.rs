@@ -0,0 +1,173 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	task::{Poll, Waker},
+	time::Duration,
+};
+
+use futures_lite::future;
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+/// A future that resolves once the returned flag was set, and its last waker
+/// woken.
+fn create_manual_future() -> (
+	impl Future<Output = usize> + Send,
+	Arc<AtomicBool>,
+	Arc<std::sync::Mutex<Option<Waker>>>,
+) {
+	let is_ready = Arc::new(AtomicBool::new(false));
+	let waker = Arc::new(std::sync::Mutex::new(None::<Waker>));
+
+	let is_ready_clone = is_ready.clone();
+	let waker_clone = waker.clone();
+	let manual_future = future::poll_fn(move |context| {
+		if is_ready_clone.load(Ordering::Relaxed) {
+			Poll::Ready(1)
+		} else {
+			waker_clone.lock().unwrap().replace(context.waker().clone());
+			Poll::Pending
+		}
+	});
+
+	(manual_future, is_ready, waker)
+}
+
+#[test]
+fn should_emit_the_output_of_a_ready_future_then_complete() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let _subscription = from_future(|| future::ready(1), scheduler).subscribe(destination);
+
+	assert!(
+		notification_collector.lock().is_empty(),
+		"the future should only be polled by the scheduler"
+	);
+
+	executor.tick(Duration::from_millis(0));
+
+	notification_collector.lock().assert_notifications(
+		"from_future",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_poll_the_future_again_when_woken() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let (manual_future, is_ready, waker) = create_manual_future();
+	let mut manual_future = Some(manual_future);
+
+	let _subscription = FutureObservable::new(move || manual_future.take().unwrap(), scheduler)
+		.subscribe(destination);
+
+	executor.tick(Duration::from_millis(0));
+	assert!(notification_collector.lock().is_empty());
+
+	is_ready.store(true, Ordering::Relaxed);
+	executor.tick(Duration::from_millis(0));
+	assert!(
+		notification_collector.lock().is_empty(),
+		"should not be polled until woken"
+	);
+
+	waker.lock().unwrap().take().unwrap().wake();
+	executor.tick(Duration::from_millis(0));
+
+	notification_collector.lock().assert_notifications(
+		"from_future",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_not_emit_when_unsubscribed_before_the_future_resolved() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let (manual_future, is_ready, waker) = create_manual_future();
+	let mut manual_future = Some(manual_future);
+
+	let mut subscription =
+		from_future(move || manual_future.take().unwrap(), scheduler).subscribe(destination);
+
+	executor.tick(Duration::from_millis(0));
+	subscription.unsubscribe();
+
+	is_ready.store(true, Ordering::Relaxed);
+	waker.lock().unwrap().take().unwrap().wake();
+	executor.tick(Duration::from_millis(0));
+
+	notification_collector.lock().assert_notifications(
+		"from_future",
+		0,
+		[SubscriberNotification::Unsubscribe],
+		true,
+	);
+	assert!(
+		executor.is_empty(),
+		"the polling work should have been cancelled"
+	);
+}
+
+/// rx_contract_closed_after_error - does not error
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness = TestHarness::<_, usize, Never>::new_with_source(
+			"from_future",
+			from_future(|| future::ready(1), scheduler),
+		);
+		let observable = harness.create_harness_observable();
+		harness.subscribe_to(observable);
+		executor.tick(Duration::from_millis(0));
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness = TestHarness::<_, usize, Never>::new_with_source(
+			"from_future",
+			from_future(future::pending::<usize>, scheduler),
+		);
+		let observable = harness.create_harness_observable();
+		harness.subscribe_to(observable);
+		executor.tick(Duration::from_millis(0));
+		harness.get_subscription_mut().unsubscribe();
+		executor.tick(Duration::from_millis(0));
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+}
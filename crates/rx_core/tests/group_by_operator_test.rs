@@ -0,0 +1,456 @@
+use std::{
+	sync::{
+		Arc, Mutex,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::Duration,
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, Signal, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[derive(Clone, Debug, PartialEq)]
+struct PlayerInput {
+	player: usize,
+	input: char,
+}
+
+fn input(player: usize, input: char) -> PlayerInput {
+	PlayerInput { player, input }
+}
+
+/// Collects each group into its key and every value it received, emitted
+/// once the group completes
+fn collect_group<Element: Signal + Clone>(
+	group: GroupedObservable<usize, Element, &'static str>,
+) -> impl Observable<Out = (usize, Vec<Element>), OutError = &'static str> + Signal {
+	let key = *group.get_key();
+	group.reduce(
+		move |accumulator: &(usize, Vec<Element>), next| {
+			let mut accumulator = accumulator.clone();
+			accumulator.1.push(next);
+			accumulator
+		},
+		(key, Vec::new()),
+	)
+}
+
+#[test]
+fn should_route_values_into_groups_by_key() {
+	let destination = MockObserver::<(usize, Vec<PlayerInput>), &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<PlayerInput, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.group_by(|next: &PlayerInput| next.player)
+		.merge_map(collect_group, usize::MAX, |error| error)
+		.subscribe(destination);
+
+	source.next(input(1, 'a'));
+	source.next(input(2, 'b'));
+	source.next(input(1, 'c'));
+
+	notification_collector.lock().assert_is_empty("group_by");
+
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"group_by",
+		0,
+		[
+			SubscriberNotification::Next((1, vec![input(1, 'a'), input(1, 'c')])),
+			SubscriberNotification::Next((2, vec![input(2, 'b')])),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_emit_a_group_before_its_first_value() {
+	let destination = MockObserver::<(usize, char), &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<PlayerInput, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.group_by(|next: &PlayerInput| next.player)
+		.merge_map(
+			|group| {
+				let key = *group.get_key();
+				group.map(move |next: PlayerInput| (key, next.input))
+			},
+			usize::MAX,
+			|error| error,
+		)
+		.subscribe(destination);
+
+	source.next(input(1, 'a'));
+	source.next(input(2, 'b'));
+
+	notification_collector.lock().assert_notifications(
+		"group_by",
+		0,
+		[
+			SubscriberNotification::Next((1, 'a')),
+			SubscriberNotification::Next((2, 'b')),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_map_values_with_the_element_selector() {
+	let destination = MockObserver::<(usize, Vec<char>), &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<PlayerInput, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.group_by_with(
+			|next: &PlayerInput| next.player,
+			|next: PlayerInput| next.input,
+			NoGroupDuration,
+		)
+		.merge_map(collect_group, usize::MAX, |error| error)
+		.subscribe(destination);
+
+	source.next(input(1, 'a'));
+	source.next(input(1, 'b'));
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"group_by",
+		0,
+		[
+			SubscriberNotification::Next((1, vec!['a', 'b'])),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+mod duration {
+	use super::*;
+
+	#[test]
+	fn should_expire_the_group_and_open_a_new_one_for_the_same_key() {
+		let destination = MockObserver::<(usize, Vec<char>), &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<PlayerInput, &'static str>::default();
+		let mut expire = PublishSubject::<(), &'static str>::default();
+		let expire_clone = expire.clone();
+
+		let _subscription = source
+			.clone()
+			.group_by_with(
+				|next: &PlayerInput| next.player,
+				|next: PlayerInput| next.input,
+				move |_group| expire_clone.clone(),
+			)
+			.merge_map(collect_group, usize::MAX, |error| error)
+			.subscribe(destination);
+
+		source.next(input(1, 'a'));
+		source.next(input(1, 'b'));
+		expire.next(());
+
+		notification_collector.lock().assert_notifications(
+			"group_by",
+			0,
+			[SubscriberNotification::Next((1, vec!['a', 'b']))],
+			true,
+		);
+
+		source.next(input(1, 'c'));
+		source.complete();
+
+		notification_collector.lock().assert_notifications(
+			"group_by",
+			1,
+			[
+				SubscriberNotification::Next((1, vec!['c'])),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_expire_groups_after_inactivity() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<(usize, Vec<char>)>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<PlayerInput>::default();
+
+		let mut subscription = source
+			.clone()
+			.group_by_with(
+				|next: &PlayerInput| next.player,
+				|next: PlayerInput| next.input,
+				move |group: GroupedObservable<usize, char, Never>| {
+					group.debounce_time(Duration::from_millis(1000), scheduler.clone())
+				},
+			)
+			.merge_map(
+				|group: GroupedObservable<usize, char, Never>| {
+					let key = *group.get_key();
+					group.reduce(
+						move |accumulator: &(usize, Vec<char>), next| {
+							let mut accumulator = accumulator.clone();
+							accumulator.1.push(next);
+							accumulator
+						},
+						(key, Vec::new()),
+					)
+				},
+				usize::MAX,
+				Never::map_into(),
+			)
+			.subscribe(destination);
+
+		source.next(input(1, 'a'));
+		source.next(input(2, 'b'));
+		executor.tick(Duration::from_millis(500));
+		source.next(input(1, 'c'));
+		executor.tick(Duration::from_millis(500));
+
+		notification_collector.lock().assert_notifications(
+			"group_by",
+			0,
+			[SubscriberNotification::Next((2, vec!['b']))],
+			true,
+		);
+
+		executor.tick(Duration::from_millis(500));
+		notification_collector.lock().assert_notifications(
+			"group_by",
+			1,
+			[SubscriberNotification::Next((1, vec!['a', 'c']))],
+			true,
+		);
+
+		subscription.unsubscribe();
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn should_keep_the_group_open_when_its_duration_completes() {
+		let destination = MockObserver::<(usize, Vec<char>), &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<PlayerInput, &'static str>::default();
+		let mut expire = PublishSubject::<(), &'static str>::default();
+		let expire_clone = expire.clone();
+
+		let _subscription = source
+			.clone()
+			.group_by_with(
+				|next: &PlayerInput| next.player,
+				|next: PlayerInput| next.input,
+				move |_group| expire_clone.clone(),
+			)
+			.merge_map(collect_group, usize::MAX, |error| error)
+			.subscribe(destination);
+
+		source.next(input(1, 'a'));
+		expire.complete();
+		source.next(input(1, 'b'));
+		source.complete();
+
+		notification_collector.lock().assert_notifications(
+			"group_by",
+			0,
+			[
+				SubscriberNotification::Next((1, vec!['a', 'b'])),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+}
+
+mod error {
+	use super::*;
+
+	#[test]
+	fn should_error_every_group_and_downstream() {
+		let destination = MockObserver::<
+			GroupedObservable<usize, PlayerInput, &'static str>,
+			&'static str,
+		>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<PlayerInput, &'static str>::default();
+		let mut subscription = source
+			.clone()
+			.group_by(|next: &PlayerInput| next.player)
+			.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("group_by");
+
+		source.next(input(1, 'a'));
+		source.next(input(2, 'b'));
+		source.error("error");
+
+		let notification_collector = notification_collector.lock();
+		assert!(
+			notification_collector
+				.nth_notification_as_next(0)
+				.is_closed()
+		);
+		assert!(
+			notification_collector
+				.nth_notification_as_next(1)
+				.is_closed()
+		);
+		assert_eq!(notification_collector.count_observed_errors(), 1);
+		assert!(subscription.is_closed(), "rx_verify_closed");
+		tracked_teardown.assert_was_torn_down();
+	}
+
+	#[test]
+	fn should_error_when_a_duration_observable_errors() {
+		let destination = MockObserver::<(usize, Vec<char>), &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<PlayerInput, &'static str>::default();
+		let mut expire = PublishSubject::<(), &'static str>::default();
+		let expire_clone = expire.clone();
+
+		let subscription = source
+			.clone()
+			.group_by_with(
+				|next: &PlayerInput| next.player,
+				|next: PlayerInput| next.input,
+				move |_group| expire_clone.clone(),
+			)
+			.merge_map(collect_group, usize::MAX, |error| error)
+			.subscribe(destination);
+
+		source.next(input(1, 'a'));
+		expire.error("error");
+
+		notification_collector.lock().assert_notifications(
+			"group_by",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+		assert!(subscription.is_closed(), "rx_verify_closed");
+	}
+}
+
+#[test]
+fn should_tear_down_every_group_when_unsubscribed() {
+	let groups = Arc::new(Mutex::new(
+		Vec::<GroupedObservable<usize, PlayerInput>>::new(),
+	));
+	let groups_clone = groups.clone();
+	let duration_finalized = Arc::new(AtomicBool::new(false));
+	let duration_finalized_clone = duration_finalized.clone();
+
+	let group_destination = MockObserver::<PlayerInput>::default();
+	let group_notification_collector = group_destination.get_notification_collector();
+	// Dropping an unused MockObserver would unsubscribe it
+	let group_destination = Arc::new(Mutex::new(Some(group_destination)));
+	let group_subscriptions = Arc::new(Mutex::new(Vec::new()));
+	let group_subscriptions_clone = group_subscriptions.clone();
+
+	let mut source = PublishSubject::<PlayerInput>::default();
+	let mut subscription = source
+		.clone()
+		.group_by_with(
+			|next: &PlayerInput| next.player,
+			|next: PlayerInput| next,
+			move |_group| {
+				let duration_finalized = duration_finalized_clone.clone();
+				PublishSubject::<()>::default()
+					.finalize(move || duration_finalized.store(true, Ordering::Relaxed))
+			},
+		)
+		.tap_next(move |group| {
+			let mut group = group.clone();
+			if let Some(group_destination) = group_destination.lock().unwrap().take() {
+				let group_subscription = group.subscribe(group_destination);
+				group_subscriptions_clone
+					.lock()
+					.unwrap()
+					.push(group_subscription);
+			}
+			groups_clone.lock().unwrap().push(group);
+		})
+		.subscribe(MockObserver::default());
+
+	source.next(input(1, 'a'));
+	source.next(input(2, 'b'));
+	subscription.unsubscribe();
+
+	let groups = groups.lock().unwrap();
+	assert_eq!(groups.len(), 2);
+	assert!(groups.iter().all(|group| group.is_closed()));
+	assert!(duration_finalized.load(Ordering::Relaxed));
+
+	group_notification_collector.lock().assert_notifications(
+		"group_by",
+		0,
+		[
+			SubscriberNotification::Next(input(1, 'a')),
+			SubscriberNotification::Unsubscribe,
+		],
+		true,
+	);
+}
+
+/// Groups are mapped to their keys for these, as they can't be compared.
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("group_by");
+		let observable = harness
+			.create_harness_observable()
+			.group_by(|next: &usize| next % 2)
+			.map(|group: GroupedObservable<usize, usize, &'static str>| *group.get_key());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error"));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("group_by");
+		let observable = harness
+			.create_harness_observable()
+			.group_by(|next: &usize| next % 2)
+			.map(|group: GroupedObservable<usize, usize, &'static str>| *group.get_key());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("group_by");
+		let observable = harness
+			.create_harness_observable()
+			.group_by(|next: &usize| next % 2)
+			.map(|group: GroupedObservable<usize, usize, &'static str>| *group.get_key());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -316,3 +316,138 @@ mod contracts {
 		source_2_tracked_teardown.assert_was_torn_down();
 	}
 }
+
+mod variadic {
+	use super::*;
+
+	#[test]
+	fn should_emit_the_last_values_of_three_observables_once_all_completed() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subject_1 = PublishSubject::<usize>::default();
+		let mut subject_2 = PublishSubject::<&'static str>::default();
+		let mut subject_3 = PublishSubject::<bool>::default();
+
+		let _s =
+			join3(subject_1.clone(), subject_2.clone(), subject_3.clone()).subscribe(destination);
+
+		subject_1.next(1);
+		subject_2.next("a");
+		subject_3.next(true);
+		subject_1.next(2);
+		subject_1.complete();
+		subject_2.complete();
+		assert!(
+			notification_collector.lock().is_empty(),
+			"Nothing should've been emitted before all sources completed"
+		);
+
+		subject_3.complete();
+
+		notification_collector.lock().assert_notifications(
+			"join3",
+			0,
+			[
+				SubscriberNotification::Next((2, "a", true)),
+				SubscriberNotification::Complete,
+			],
+			false,
+		);
+	}
+
+	#[test]
+	fn should_join_eight_observables() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let _s = join8(
+			just(1),
+			just(2),
+			just(3),
+			just(4),
+			just(5),
+			just(6),
+			just(7),
+			(8..=9).into_observable(),
+		)
+		.subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"join8",
+			0,
+			[
+				SubscriberNotification::Next((1, 2, 3, 4, 5, 6, 7, 9)),
+				SubscriberNotification::Complete,
+			],
+			false,
+		);
+	}
+}
+
+mod array {
+	use super::*;
+
+	#[test]
+	fn should_emit_a_vec_of_the_last_values_once_all_completed() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let _s = join_array([
+			(1..=3).into_observable(),
+			(4..=5).into_observable(),
+			(6..=6).into_observable(),
+		])
+		.subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"join_array",
+			0,
+			[
+				SubscriberNotification::Next(vec![3, 5, 6]),
+				SubscriberNotification::Complete,
+			],
+			false,
+		);
+	}
+
+	#[test]
+	fn should_only_complete_when_one_of_them_never_emitted() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subjects = vec![
+			PublishSubject::<usize>::default(),
+			PublishSubject::<usize>::default(),
+		];
+
+		let _s = join_array(subjects.clone()).subscribe(destination);
+
+		subjects[0].next(1);
+		subjects[0].complete();
+		subjects[1].complete();
+
+		notification_collector.lock().assert_notifications(
+			"join_array",
+			0,
+			[SubscriberNotification::Complete],
+			false,
+		);
+	}
+
+	#[test]
+	fn should_complete_immediately_when_empty() {
+		let destination = MockObserver::<Vec<usize>>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let subscription = join_array(Vec::<PublishSubject<usize>>::new()).subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"join_array",
+			0,
+			[SubscriberNotification::Complete],
+			false,
+		);
+		assert!(subscription.is_closed());
+	}
+}
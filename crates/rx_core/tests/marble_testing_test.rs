@@ -0,0 +1,231 @@
+use rx_core::prelude::*;
+use rx_core_testing::prelude::*;
+
+fn letters() -> MarbleValues<usize, &'static str> {
+	MarbleValues::new([('a', 1), ('b', 2), ('c', 3), ('d', 4), ('e', 5)]).with_error("error")
+}
+
+mod cold {
+	use super::*;
+
+	#[test]
+	fn should_emit_relative_to_the_subscription() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+
+		let source = tester.cold("-a-b-|", &values);
+		let recording = tester.subscribe_with("--^", source.clone());
+
+		tester.flush();
+
+		recording.assert_marble("---a-b-|", &values);
+		source.assert_subscriptions(["--^----!"]);
+	}
+
+	#[test]
+	fn should_replay_the_marble_for_every_subscriber() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+
+		let source = tester.cold("-a|", &values);
+		let first_recording = tester.subscribe(source.clone());
+		let second_recording = tester.subscribe_with("---^", source.clone());
+
+		tester.flush();
+
+		first_recording.assert_marble("-a|", &values);
+		second_recording.assert_marble("----a|", &values);
+		source.assert_subscriptions(["^-!", "---^-!"]);
+	}
+
+	#[test]
+	fn should_emit_errors() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+
+		let source = tester.cold("-a-#", &values);
+		let recording = tester.subscribe(source.clone());
+
+		tester.flush();
+
+		recording.assert_marble("-a-#", &values);
+		source.assert_subscriptions(["^--!"]);
+	}
+
+	#[test]
+	fn should_emit_groups_in_the_same_frame() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+
+		let source = tester.cold("-(ab)-(c|)", &values);
+		let recording = tester.subscribe(source);
+
+		tester.flush();
+
+		recording.assert_marble("-(ab)-(c|)", &values);
+	}
+
+	#[test]
+	fn should_stop_emitting_when_unsubscribed() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+
+		let source = tester.cold("-a-b-c-|", &values);
+		let recording = tester.subscribe_with("^--!", source.clone());
+
+		tester.flush();
+
+		recording.assert_marble("-a", &values);
+		source.assert_subscriptions(["^--!"]);
+	}
+
+	#[test]
+	fn should_record_when_a_downstream_operator_unsubscribes() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+
+		let source = tester.cold("-a-b-c-|", &values);
+		let recording = tester.subscribe(source.clone().take(2));
+
+		tester.flush();
+
+		recording.assert_marble("-a-(b|)", &values);
+		source.assert_subscriptions(["^--!"]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn should_not_accept_subscription_points() {
+		let tester = MarbleTester::default();
+		let _source = tester.cold("-^-a-|", &letters());
+	}
+}
+
+mod hot {
+	use super::*;
+
+	#[test]
+	fn should_only_emit_what_happened_after_subscribing() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+
+		let source = tester.hot("a-^-b-c-d-|", &values);
+		let early_recording = tester.subscribe(source.clone());
+		let late_recording = tester.subscribe_with("---^", source.clone());
+		let leaving_recording = tester.subscribe_with("-^---!", source.clone());
+
+		tester.flush();
+
+		early_recording.assert_marble("--b-c-d-|", &values);
+		late_recording.assert_marble("----c-d-|", &values);
+		leaving_recording.assert_marble("--b-c", &values);
+		source.assert_subscriptions(["^-------!", "-^---!", "---^----!"]);
+	}
+
+	#[test]
+	fn should_send_the_terminal_notification_to_late_subscribers() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+
+		let source = tester.hot("^-a-#", &values);
+		let recording = tester.subscribe_with("-----^", source.clone());
+
+		tester.flush();
+
+		recording.assert_marble("-----#", &values);
+		source.assert_subscriptions(["-----(^!)"]);
+	}
+}
+
+mod time_based_operators {
+	use super::*;
+
+	#[test]
+	fn debounce_time() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+		let scheduler = tester.get_scheduler_handle();
+
+		let source = tester.cold("-ab-----c---|", &values);
+		let recording = tester.subscribe(source.debounce_time(tester.frames(2), scheduler));
+
+		tester.flush();
+
+		recording.assert_marble("----b-----c-|", &values);
+	}
+
+	#[test]
+	fn throttle_time() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+		let scheduler = tester.get_scheduler_handle();
+
+		let source = tester.cold("-abc----d-|", &values);
+		let recording = tester.subscribe(
+			source.throttle_time(
+				ThrottleTimeOptions::new(tester.frames(3))
+					.with_output(ThrottleOutputBehavior::LeadingOnly),
+				scheduler,
+			),
+		);
+
+		tester.flush();
+
+		recording.assert_marble("-a------d-|", &values);
+	}
+
+	#[test]
+	fn delay() {
+		let mut tester = MarbleTester::default();
+		let values = letters();
+		let scheduler = tester.get_scheduler_handle();
+
+		let source = tester.cold("-a-b-|", &values);
+		let recording = tester.subscribe(source.delay(tester.frames(2), scheduler));
+
+		tester.flush();
+
+		// Completion is not delayed, but it waits for the delayed values
+		recording.assert_marble("---a-(b|)", &values);
+	}
+
+	#[test]
+	fn fallback_when_silent() {
+		let mut tester = MarbleTester::default();
+		let values = MarbleValues::<usize, &'static str>::new([('a', 1), ('b', 2), ('x', 0)]);
+		let scheduler = tester.get_scheduler_handle();
+
+		let source = tester.hot("^-a--b|", &values);
+		let recording = tester.subscribe(source.fallback_when_silent(|_, _, _| 0, scheduler));
+
+		tester.flush();
+
+		recording.assert_marble("xxaxxb|", &values);
+	}
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_the_notifications_do_not_match() {
+	let mut tester = MarbleTester::default();
+	let values = letters();
+
+	let recording = tester.subscribe(tester.cold("-a-b-|", &values));
+
+	tester.flush();
+
+	recording.assert_marble("-a-c-|", &values);
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_the_subscriptions_do_not_match() {
+	let mut tester = MarbleTester::default();
+
+	let source = tester.cold("-a-b-|", &letters());
+	let _recording = tester.subscribe(source.clone());
+
+	tester.flush();
+
+	source.assert_subscriptions(["^--!"]);
+}
@@ -0,0 +1,229 @@
+use rx_core::prelude::*;
+use rx_core_testing::prelude::*;
+
+mod publish {
+	use super::*;
+
+	#[test]
+	fn should_not_subscribe_to_the_source_until_connected() {
+		let destination = MockObserver::<usize, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut published = source.clone().publish();
+
+		let _s = published.subscribe(destination);
+		source.next(1);
+		assert!(!published.is_connected());
+
+		published.connect();
+		source.next(2);
+
+		notification_collector.lock().assert_notifications(
+			"publish",
+			0,
+			[SubscriberNotification::Next(2)],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_stay_connected_without_subscribers() {
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut published = source.clone().publish();
+
+		let mut subscription = published.subscribe(MockObserver::default());
+		published.connect();
+		subscription.unsubscribe();
+
+		assert!(published.is_connected());
+
+		published.disconnect();
+		assert!(!published.is_connected());
+		source.complete();
+	}
+}
+
+mod publish_behavior {
+	use super::*;
+
+	#[test]
+	fn should_emit_the_initial_value_then_the_latest_value_to_late_subscribers() {
+		let destination_1 = MockObserver::<usize, &'static str>::default();
+		let notification_collector_1 = destination_1.get_notification_collector();
+
+		let destination_2 = MockObserver::<usize, &'static str>::default();
+		let notification_collector_2 = destination_2.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut published = source.clone().publish_behavior(0);
+
+		let _s1 = published.subscribe(destination_1);
+		published.connect();
+		source.next(1);
+		source.next(2);
+		let _s2 = published.subscribe(destination_2);
+
+		notification_collector_1.lock().assert_notifications(
+			"publish_behavior - destination_1",
+			0,
+			[
+				SubscriberNotification::Next(0),
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Next(2),
+			],
+			true,
+		);
+
+		notification_collector_2.lock().assert_notifications(
+			"publish_behavior - destination_2",
+			0,
+			[SubscriberNotification::Next(2)],
+			true,
+		);
+	}
+}
+
+mod publish_replay {
+	use super::*;
+
+	#[test]
+	fn should_replay_the_last_values_to_late_subscribers() {
+		let destination = MockObserver::<usize, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut published = source.clone().publish_replay(Some(2));
+
+		published.connect();
+		source.next(1);
+		source.next(2);
+		source.next(3);
+		let _s = published.subscribe(destination);
+		source.next(4);
+
+		notification_collector.lock().assert_notifications(
+			"publish_replay",
+			0,
+			[
+				SubscriberNotification::Next(2),
+				SubscriberNotification::Next(3),
+				SubscriberNotification::Next(4),
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_replay_every_value_when_unbounded() {
+		let destination = MockObserver::<usize, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut published = source.clone().publish_replay(None);
+
+		published.connect();
+		source.next(1);
+		source.next(2);
+		source.complete();
+		let _s = published.subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"publish_replay",
+			0,
+			[
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Next(2),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+}
+
+mod publish_last {
+	use super::*;
+
+	#[test]
+	fn should_only_emit_the_last_value_on_completion() {
+		let destination_1 = MockObserver::<usize, &'static str>::default();
+		let notification_collector_1 = destination_1.get_notification_collector();
+
+		let destination_2 = MockObserver::<usize, &'static str>::default();
+		let notification_collector_2 = destination_2.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut published = source.clone().publish_last();
+
+		let _s1 = published.subscribe(destination_1);
+		published.connect();
+		source.next(1);
+		source.next(2);
+
+		notification_collector_1
+			.lock()
+			.assert_is_empty("publish_last - destination_1 before completion");
+
+		source.complete();
+		let _s2 = published.subscribe(destination_2);
+
+		notification_collector_1.lock().assert_notifications(
+			"publish_last - destination_1",
+			0,
+			[
+				SubscriberNotification::Next(2),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+
+		notification_collector_2.lock().assert_notifications(
+			"publish_last - destination_2",
+			0,
+			[
+				SubscriberNotification::Next(2),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("publish");
+		let mut observable = harness.create_harness_observable().publish();
+		observable.connect();
+		harness.subscribe_to(observable);
+
+		let error = "error";
+		harness.source().error(error);
+		harness.assert_terminal_notification(SubscriberNotification::Error(error));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("publish");
+		let mut observable = harness.create_harness_observable().publish();
+		observable.connect();
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("publish");
+		let mut observable = harness.create_harness_observable().publish();
+		observable.connect();
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -375,6 +375,31 @@ fn should_error_active_subscribers_but_not_unsubscribe_them() {
 	);
 }
 
+#[test]
+fn should_forward_values_to_subscribers_of_its_read_only_observable() {
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut subject = PublishSubject::<usize, &'static str>::default();
+	let mut observable = subject.as_observable();
+
+	let _subscription = observable.subscribe(destination);
+
+	subject.next(1);
+	subject.complete();
+
+	notification_collector.lock().assert_notifications(
+		"publish_subject destination",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+	assert!(observable.is_closed());
+}
+
 #[test]
 fn should_be_closed_after_completion() {
 	let mut subject = PublishSubject::<usize, &'static str>::default();
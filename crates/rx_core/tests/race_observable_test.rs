@@ -0,0 +1,455 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicBool, Ordering},
+};
+
+use rx_core::prelude::*;
+use rx_core_common::SubscriberNotification;
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_mirror_the_first_source_that_emits() {
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source_1 = PublishSubject::<usize>::default();
+	let mut source_2 = PublishSubject::<usize>::default();
+	let mut source_3 = PublishSubject::<usize>::default();
+
+	let _subscription =
+		race((source_1.clone(), source_2.clone(), source_3.clone())).subscribe(destination);
+
+	source_2.next(1);
+	source_1.next(2);
+	source_3.next(3);
+	source_2.next(4);
+	source_1.complete();
+	source_2.complete();
+
+	notification_collector.lock().assert_notifications(
+		"race",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(4),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_unsubscribe_the_losers_as_soon_as_there_is_a_winner() {
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source_1 = PublishSubject::<usize>::default();
+	let mut source_1_finalized = SharedSubscription::default();
+	let source_1_tracked_teardown = source_1_finalized.add_tracked_teardown("race - source_1");
+
+	let mut source_2 = PublishSubject::<usize>::default();
+	let mut source_2_finalized = SharedSubscription::default();
+	let source_2_tracked_teardown = source_2_finalized.add_tracked_teardown("race - source_2");
+
+	let _subscription = race((
+		source_1
+			.clone()
+			.finalize(move || source_1_finalized.unsubscribe()),
+		source_2
+			.clone()
+			.finalize(move || source_2_finalized.unsubscribe()),
+	))
+	.subscribe(destination);
+
+	source_1_tracked_teardown.assert_yet_to_be_torn_down();
+	source_2_tracked_teardown.assert_yet_to_be_torn_down();
+
+	source_2.next(1);
+
+	source_1_tracked_teardown.assert_was_torn_down();
+	source_2_tracked_teardown.assert_yet_to_be_torn_down();
+
+	source_1.next(2);
+	source_2.next(3);
+
+	notification_collector.lock().assert_notifications(
+		"race",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_let_the_first_completion_win_the_race() {
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source_1 = PublishSubject::<usize>::default();
+	let mut source_2 = PublishSubject::<usize>::default();
+
+	let subscription = race((source_1.clone(), source_2.clone())).subscribe(destination);
+
+	source_1.complete();
+	source_2.next(1);
+
+	notification_collector.lock().assert_notifications(
+		"race",
+		0,
+		[SubscriberNotification::Complete],
+		true,
+	);
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_not_subscribe_to_the_rest_when_a_source_emits_immediately() {
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let was_second_subscribed = Arc::new(AtomicBool::new(false));
+
+	let _subscription = race((
+		(1..=2).into_observable(),
+		deferred_observable({
+			let was_second_subscribed = was_second_subscribed.clone();
+			move || {
+				was_second_subscribed.store(true, Ordering::Relaxed);
+				(3..=4).into_observable()
+			}
+		}),
+	))
+	.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"race",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+	assert!(!was_second_subscribed.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_unsubscribe_when_all_sources_unsubscribed_without_emitting() {
+	let destination = MockObserver::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source_1 = PublishSubject::<usize>::default();
+	let mut source_2 = PublishSubject::<usize>::default();
+
+	let subscription = race((source_1.clone(), source_2.clone())).subscribe(destination);
+
+	source_1.unsubscribe();
+	assert!(!subscription.is_closed());
+
+	source_2.unsubscribe();
+
+	notification_collector.lock().assert_notifications(
+		"race",
+		0,
+		[SubscriberNotification::Unsubscribe],
+		true,
+	);
+}
+
+mod errors {
+	use super::*;
+
+	#[test]
+	fn should_let_the_first_error_win_the_race() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source_1 = PublishSubject::<usize, &'static str>::default();
+		let mut source_2 = PublishSubject::<usize, &'static str>::default();
+
+		let _subscription = race((source_1.clone(), source_2.clone())).subscribe(destination);
+
+		source_2.error("error");
+		source_1.next(1);
+
+		notification_collector.lock().assert_notifications(
+			"race",
+			0,
+			[SubscriberNotification::Error("error")],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_ignore_errors_of_the_losers() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source_1 = PublishSubject::<usize, &'static str>::default();
+		let mut source_2 = PublishSubject::<usize, &'static str>::default();
+
+		let _subscription = race((source_1.clone(), source_2.clone())).subscribe(destination);
+
+		source_1.next(1);
+		source_2.error("error");
+
+		notification_collector.lock().assert_notifications(
+			"race",
+			0,
+			[SubscriberNotification::Next(1)],
+			true,
+		);
+	}
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut source_1 = PublishSubject::<usize, MockError>::default();
+		let mut source_1_finalized = SharedSubscription::default();
+		let source_1_tracked_teardown = source_1_finalized.add_tracked_teardown("race - source_1");
+
+		let source_2 = PublishSubject::<usize, MockError>::default();
+		let mut source_2_finalized = SharedSubscription::default();
+		let source_2_tracked_teardown = source_2_finalized.add_tracked_teardown("race - source_2");
+
+		let mut harness = TestHarness::<_, usize, MockError>::new_with_source(
+			"race",
+			race((
+				source_1
+					.clone()
+					.finalize(move || source_1_finalized.unsubscribe()),
+				source_2
+					.clone()
+					.finalize(move || source_2_finalized.unsubscribe()),
+			)),
+		);
+		let observable = harness.create_harness_observable();
+		harness.subscribe_to(observable);
+
+		source_1.error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+
+		source_1_tracked_teardown.assert_was_torn_down();
+		source_2_tracked_teardown.assert_was_torn_down();
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut source_1 = PublishSubject::<usize, MockError>::default();
+		let mut source_1_finalized = SharedSubscription::default();
+		let source_1_tracked_teardown = source_1_finalized.add_tracked_teardown("race - source_1");
+
+		let source_2 = PublishSubject::<usize, MockError>::default();
+		let mut source_2_finalized = SharedSubscription::default();
+		let source_2_tracked_teardown = source_2_finalized.add_tracked_teardown("race - source_2");
+
+		let mut harness = TestHarness::<_, usize, MockError>::new_with_source(
+			"race",
+			race((
+				source_1
+					.clone()
+					.finalize(move || source_1_finalized.unsubscribe()),
+				source_2
+					.clone()
+					.finalize(move || source_2_finalized.unsubscribe()),
+			)),
+		);
+		let observable = harness.create_harness_observable();
+		harness.subscribe_to(observable);
+
+		source_1.next(1);
+		source_1.complete();
+
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+
+		source_1_tracked_teardown.assert_was_torn_down();
+		source_2_tracked_teardown.assert_was_torn_down();
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let source_1 = PublishSubject::<usize, MockError>::default();
+		let mut source_1_finalized = SharedSubscription::default();
+		let source_1_tracked_teardown = source_1_finalized.add_tracked_teardown("race - source_1");
+
+		let source_2 = PublishSubject::<usize, MockError>::default();
+		let mut source_2_finalized = SharedSubscription::default();
+		let source_2_tracked_teardown = source_2_finalized.add_tracked_teardown("race - source_2");
+
+		let mut harness = TestHarness::<_, usize, MockError>::new_with_source(
+			"race",
+			race((
+				source_1
+					.clone()
+					.finalize(move || source_1_finalized.unsubscribe()),
+				source_2
+					.clone()
+					.finalize(move || source_2_finalized.unsubscribe()),
+			)),
+		);
+		let observable = harness.create_harness_observable();
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+
+		source_1_tracked_teardown.assert_was_torn_down();
+		source_2_tracked_teardown.assert_was_torn_down();
+	}
+}
+
+mod variadic {
+	use super::*;
+
+	#[test]
+	fn should_mirror_the_first_source_that_emits_converted_into_the_first_ones_output() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source_1 = PublishSubject::<u32>::default();
+		let mut source_2 = PublishSubject::<u8>::default();
+
+		let _subscription = race2(source_1.clone(), source_2.clone()).subscribe(destination);
+
+		source_2.next(1);
+		source_1.next(2);
+		source_2.next(3);
+		source_2.complete();
+
+		notification_collector.lock().assert_notifications(
+			"race2",
+			0,
+			[
+				SubscriberNotification::Next(1u32),
+				SubscriberNotification::Next(3u32),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_unsubscribe_the_losers_as_soon_as_there_is_a_winner() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source_1 = PublishSubject::<usize>::default();
+		let mut source_1_finalized = SharedSubscription::default();
+		let source_1_tracked_teardown = source_1_finalized.add_tracked_teardown("race3 - source_1");
+
+		let mut source_3 = PublishSubject::<u16>::default();
+		let mut source_3_finalized = SharedSubscription::default();
+		let source_3_tracked_teardown = source_3_finalized.add_tracked_teardown("race3 - source_3");
+
+		let mut source_2 = PublishSubject::<u8>::default();
+
+		let _subscription = race3(
+			source_1
+				.clone()
+				.finalize(move || source_1_finalized.unsubscribe()),
+			source_2.clone(),
+			source_3
+				.clone()
+				.finalize(move || source_3_finalized.unsubscribe()),
+		)
+		.subscribe(destination);
+
+		source_2.next(1);
+
+		source_1_tracked_teardown.assert_was_torn_down();
+		source_3_tracked_teardown.assert_was_torn_down();
+
+		source_1.next(2);
+		source_3.next(3);
+
+		notification_collector.lock().assert_notifications(
+			"race3",
+			0,
+			[SubscriberNotification::Next(1)],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_race_eight_observables() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let _subscription = race8(
+			PublishSubject::<i64>::default(),
+			PublishSubject::<i32>::default(),
+			PublishSubject::<i16>::default(),
+			PublishSubject::<i8>::default(),
+			PublishSubject::<u32>::default(),
+			PublishSubject::<u16>::default(),
+			PublishSubject::<u8>::default(),
+			just(true),
+		)
+		.subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"race8",
+			0,
+			[
+				SubscriberNotification::Next(1i64),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+}
+
+mod array {
+	use super::*;
+
+	#[test]
+	fn should_mirror_the_first_source_that_emits() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut sources = [
+			PublishSubject::<usize>::default(),
+			PublishSubject::<usize>::default(),
+			PublishSubject::<usize>::default(),
+		];
+
+		let _subscription = race(sources.clone()).subscribe(destination);
+
+		sources[2].next(1);
+		sources[0].next(2);
+		sources[2].complete();
+
+		notification_collector.lock().assert_notifications(
+			"race",
+			0,
+			[
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_complete_immediately_when_empty() {
+		let destination = MockObserver::<usize>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let subscription =
+			race::<usize, Never, 0>([] as [PublishSubject<usize>; 0]).subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"race",
+			0,
+			[SubscriberNotification::Complete],
+			true,
+		);
+		assert!(subscription.is_closed());
+	}
+}
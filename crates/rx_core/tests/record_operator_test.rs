@@ -0,0 +1,234 @@
+use std::{fs, path::PathBuf, time::Duration};
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+fn recording_path(name: &str) -> PathBuf {
+	std::env::temp_dir().join(format!(
+		"rx_core_record_operator_test_{name}_{}.jsonl",
+		std::process::id()
+	))
+}
+
+#[test]
+fn should_pass_notifications_through_and_record_them_with_the_elapsed_time() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+	let path = recording_path("pass_through");
+
+	let destination = MockObserver::<usize, String>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.record(&path, scheduler)
+		.map_error(|error| error.to_string())
+		.subscribe(destination);
+
+	executor.tick(Duration::from_millis(10));
+	source.next(1);
+	executor.tick(Duration::from_millis(100));
+	source.next(2);
+	executor.tick(Duration::from_millis(50));
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"record",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert_eq!(
+		fs::read_to_string(&path).unwrap(),
+		"[0,{\"Next\":1}]\n[100000000,{\"Next\":2}]\n[150000000,\"Complete\"]\n"
+	);
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn should_record_upstream_errors_and_forward_them() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+	let path = recording_path("upstream_error");
+
+	let destination = MockObserver::<usize, String>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.record(&path, scheduler)
+		.map_error(|error| error.to_string())
+		.subscribe(destination);
+
+	executor.tick(Duration::from_millis(10));
+	source.next(1);
+	executor.tick(Duration::from_millis(20));
+	source.error("error");
+
+	notification_collector.lock().assert_notifications(
+		"record",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Error("error".to_string()),
+		],
+		true,
+	);
+
+	assert_eq!(
+		fs::read_to_string(&path).unwrap(),
+		"[0,{\"Next\":1}]\n[20000000,{\"Error\":\"error\"}]\n"
+	);
+	fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn should_record_never_erroring_streams_once_retyped() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+	let path = recording_path("map_never");
+
+	let destination = MockObserver::<usize, String>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let _subscription = (1..=2)
+		.into_observable()
+		.map_never::<()>()
+		.record(&path, scheduler)
+		.map_error(|error| format!("{error:?}"))
+		.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"record",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert_eq!(
+		fs::read_to_string(&path).unwrap(),
+		"[0,{\"Next\":1}]\n[0,{\"Next\":2}]\n[0,\"Complete\"]\n"
+	);
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn should_error_when_the_recording_cannot_be_created() {
+	let executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+	let path = recording_path("missing_directory").join("recording.jsonl");
+
+	let destination = MockObserver::<usize, String>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let subscription = source
+		.clone()
+		.record(&path, scheduler)
+		.map_error(|error| error.to_string())
+		.subscribe(destination);
+
+	source.next(1);
+
+	{
+		let notifications = notification_collector.lock();
+		assert!(
+			matches!(
+				notifications.nth_notification(0),
+				SubscriberNotification::Error(error) if error.starts_with("RecordOperatorError::Io")
+			),
+			"should have errored with an io error"
+		);
+		notifications.assert_nth_notification_is_last("record", 0);
+	}
+
+	assert!(subscription.is_closed());
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let path = recording_path("contract_error");
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, String>::new("record");
+
+		let observable = harness
+			.create_harness_observable()
+			.record(&path, scheduler)
+			.map_error(|error| error.to_string());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error".to_string()));
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+		fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let path = recording_path("contract_complete");
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, String>::new("record");
+
+		let observable = harness
+			.create_harness_observable()
+			.record(&path, scheduler)
+			.map_error(|error| error.to_string());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+		fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let path = recording_path("contract_unsubscribe");
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, String>::new("record");
+
+		let observable = harness
+			.create_harness_observable()
+			.record(&path, scheduler)
+			.map_error(|error| error.to_string());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+		assert_eq!(fs::read_to_string(&path).unwrap(), "[0,{\"Next\":1}]\n");
+		fs::remove_file(path).unwrap();
+	}
+}
@@ -0,0 +1,218 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicBool, Ordering},
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_subscribe_to_the_source_count_times() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut subscription = (1..=2)
+		.into_observable()
+		.map_never()
+		.repeat(3)
+		.subscribe(destination);
+	let teardown_tracker = subscription.add_tracked_teardown("repeat");
+
+	notification_collector.lock().assert_notifications(
+		"repeat",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+	teardown_tracker.assert_was_torn_down();
+}
+
+#[test]
+fn should_complete_immediately_when_the_count_is_zero() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let subscription = (1..=2)
+		.into_observable()
+		.map_never()
+		.repeat(0)
+		.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"repeat",
+		0,
+		[SubscriberNotification::Complete],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_resubscribe_on_later_completions() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let subscription = source.clone().take(1).repeat(3).subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	assert!(!subscription.is_closed());
+	source.next(3);
+	source.next(4);
+
+	notification_collector.lock().assert_notifications(
+		"repeat",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_forward_errors_without_repeating() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let error = "error";
+	let subscription = concat((
+		(0..=1).into_observable().map_never(),
+		throw(error).map_never(),
+	))
+	.repeat(3)
+	.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"repeat",
+		0,
+		[
+			SubscriberNotification::Next(0),
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Error(error),
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_not_overflow_the_stack_on_immediate_completions() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let subscription = (0..1)
+		.into_observable()
+		.map_never()
+		.repeat(10_000)
+		.subscribe(destination);
+
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 10_000);
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_stop_repeating_when_downstream_unsubscribes() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let subscription = (0..=1)
+		.into_observable()
+		.map_never()
+		.repeat(usize::MAX)
+		.take(3)
+		.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"repeat",
+		0,
+		[
+			SubscriberNotification::Next(0),
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(0),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_unsubscribe_from_the_source_when_unsubscribed() {
+	let destination = MockObserver::<usize, &'static str>::default();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let source_finalized = Arc::new(AtomicBool::new(false));
+	let source_finalized_clone = source_finalized.clone();
+
+	let mut subscription = source
+		.clone()
+		.take(1)
+		.finalize(move || source_finalized_clone.store(true, Ordering::Relaxed))
+		.repeat(3)
+		.subscribe(destination);
+
+	source.next(1);
+	// Only the first subscription of the source is finalized by its completion
+	assert!(source_finalized.swap(false, Ordering::Relaxed));
+
+	subscription.unsubscribe();
+	assert!(source_finalized.load(Ordering::Relaxed));
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("repeat");
+		let observable = harness.create_harness_observable().repeat(1);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error"));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("repeat");
+		let observable = harness.create_harness_observable().repeat(1);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("repeat");
+		let observable = harness.create_harness_observable().repeat(1);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -0,0 +1,287 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::Duration,
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_resubscribe_every_time_the_notifier_emits() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let notifier_clone = notifier.clone();
+	let subscription = source
+		.clone()
+		.take(1)
+		.repeat_when(move |_completions| notifier_clone.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(2); // Not subscribed until the notifier emits
+	notifier.next(());
+	source.next(3);
+
+	notification_collector.lock().assert_notifications(
+		"repeat_when",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+
+	assert!(!subscription.is_closed());
+}
+
+#[test]
+fn should_repeat_with_a_delayed_notifier() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut subscription = (0..=1)
+		.into_observable()
+		.map_never()
+		.repeat_when(move |completions| {
+			completions.delay(Duration::from_millis(1000), scheduler.clone())
+		})
+		.subscribe(destination);
+
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 2);
+
+	executor.tick(Duration::from_millis(999));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 2);
+
+	executor.tick(Duration::from_millis(1));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 4);
+
+	subscription.unsubscribe();
+	// The already delayed completion of the notifier still runs out
+	executor.tick(Duration::from_millis(1000));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 4);
+
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_not_overflow_the_stack_on_immediate_completions() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let subscription = (0..=1)
+		.into_observable()
+		.map_never()
+		.repeat_when(|completions| completions)
+		.take(2000)
+		.subscribe(destination);
+
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 2000);
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_complete_when_the_notifier_completes() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let notifier_clone = notifier.clone();
+	let subscription = source
+		.clone()
+		.take(1)
+		.repeat_when(move |_completions| notifier_clone.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	assert!(!subscription.is_closed());
+	notifier.complete();
+
+	notification_collector.lock().assert_notifications(
+		"repeat_when",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_error_when_the_notifier_errors() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let notifier_clone = notifier.clone();
+	let subscription = source
+		.clone()
+		.take(1)
+		.repeat_when(move |_completions| notifier_clone.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	notifier.error("error");
+
+	notification_collector.lock().assert_notifications(
+		"repeat_when",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Error("error"),
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_forward_errors_of_the_source_without_calling_the_notifier_fn() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let was_notifier_fn_called = Arc::new(AtomicBool::new(false));
+	let was_notifier_fn_called_clone = was_notifier_fn_called.clone();
+
+	let error = "error";
+	let subscription = concat((
+		(0..=1).into_observable().map_never(),
+		throw(error).map_never(),
+	))
+	.repeat_when(move |completions: PublishSubject<(), &'static str>| {
+		was_notifier_fn_called_clone.store(true, Ordering::Relaxed);
+		completions
+	})
+	.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"repeat_when",
+		0,
+		[
+			SubscriberNotification::Next(0),
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Error(error),
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+	assert!(!was_notifier_fn_called.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_unsubscribe_from_the_source_and_the_notifier_when_unsubscribed() {
+	let destination = MockObserver::<usize, &'static str>::default();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let source_finalized = Arc::new(AtomicBool::new(false));
+	let source_finalized_clone = source_finalized.clone();
+	let notifier_finalized = Arc::new(AtomicBool::new(false));
+	let notifier_finalized_clone = notifier_finalized.clone();
+
+	let notifier_clone = notifier.clone();
+	let mut subscription = source
+		.clone()
+		.take(1)
+		.finalize(move || source_finalized_clone.store(true, Ordering::Relaxed))
+		.repeat_when(move |_completions| {
+			let notifier_finalized = notifier_finalized_clone.clone();
+			notifier_clone
+				.clone()
+				.finalize(move || notifier_finalized.store(true, Ordering::Relaxed))
+		})
+		.subscribe(destination);
+
+	source.next(1);
+	notifier.next(());
+	// Only the first subscription of the source is finalized by its completion
+	assert!(source_finalized.swap(false, Ordering::Relaxed));
+	assert!(!notifier_finalized.load(Ordering::Relaxed));
+
+	subscription.unsubscribe();
+
+	assert!(source_finalized.load(Ordering::Relaxed));
+	assert!(notifier_finalized.load(Ordering::Relaxed));
+}
+
+/// The completion comes from the notifier, as the completions of the source
+/// never reach downstream directly.
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness = TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new(
+			"repeat_when",
+		);
+		let observable = harness
+			.create_harness_observable()
+			.repeat_when(|_completions| {
+				let mut notifier = PublishSubject::<(), &'static str>::default();
+				notifier.complete();
+				notifier
+			});
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error"));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness = TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new(
+			"repeat_when",
+		);
+		let observable = harness
+			.create_harness_observable()
+			.repeat_when(|_completions| {
+				let mut notifier = PublishSubject::<(), &'static str>::default();
+				notifier.complete();
+				notifier
+			});
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness = TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new(
+			"repeat_when",
+		);
+		let observable = harness
+			.create_harness_observable()
+			.repeat_when(|_completions| {
+				let mut notifier = PublishSubject::<(), &'static str>::default();
+				notifier.complete();
+				notifier
+			});
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -0,0 +1,104 @@
+use rx_core::prelude::*;
+use rx_core_common::{RxObserver, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_replay_the_last_capacity_values_to_new_subscribers() {
+	let destination = MockObserver::<usize>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut replay_subject = ReplayBufferSubject::<usize>::new(2);
+	replay_subject.next(1);
+	replay_subject.next(2);
+	replay_subject.next(3);
+	let _s = replay_subject.clone().subscribe(destination);
+	replay_subject.next(4);
+
+	notification_collector.lock().assert_notifications(
+		"replay_buffer_subject",
+		0,
+		[
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+			SubscriberNotification::Next(4),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_not_replay_anything_with_zero_capacity() {
+	let destination = MockObserver::<usize>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut replay_subject = ReplayBufferSubject::<usize>::new(0);
+	replay_subject.next(1);
+	let _s = replay_subject.clone().subscribe(destination);
+
+	assert!(
+		!notification_collector.lock().nth_notification_exists(0),
+		"destination received something when it shouldn't have"
+	);
+	assert!(replay_subject.values().is_empty());
+}
+
+#[test]
+fn should_replay_every_value_when_unbounded() {
+	let destination = MockObserver::<usize>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut replay_subject = ReplayBufferSubject::<usize>::unbounded();
+	for i in 0..100 {
+		replay_subject.next(i);
+	}
+	let _s = replay_subject.clone().subscribe(destination);
+
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 100);
+	assert_eq!(replay_subject.values(), (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn should_replay_its_values_to_new_subscribers_after_completed() {
+	let destination = MockObserver::<usize>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut replay_subject = ReplayBufferSubject::<usize>::unbounded();
+	replay_subject.next(1);
+	replay_subject.complete();
+	let _s = replay_subject.clone().subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"replay_buffer_subject",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_not_replay_its_values_to_new_subscribers_after_errored() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut replay_subject = ReplayBufferSubject::<usize, &'static str>::unbounded();
+	replay_subject.next(1);
+	replay_subject.error("error");
+	let _s = replay_subject.clone().subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"replay_buffer_subject",
+		0,
+		[SubscriberNotification::Error("error")],
+		true,
+	);
+}
+
+#[test]
+fn should_be_closed_after_unsubscribe() {
+	let mut replay_subject = ReplayBufferSubject::<usize>::new(1);
+	replay_subject.unsubscribe();
+	assert!(replay_subject.is_closed());
+}
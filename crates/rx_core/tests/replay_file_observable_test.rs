@@ -0,0 +1,301 @@
+use std::{fs, path::PathBuf, time::Duration};
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+fn recording_path(name: &str) -> PathBuf {
+	std::env::temp_dir().join(format!(
+		"rx_core_replay_file_observable_test_{name}_{}.jsonl",
+		std::process::id()
+	))
+}
+
+const RECORDING: &str = "[0,{\"Next\":1}]\n[100000000,{\"Next\":2}]\n\n[150000000,\"Complete\"]\n";
+
+#[test]
+fn should_replay_the_recording_with_its_original_timing() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, String>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut replay =
+		ReplayFileObservable::<usize, String, _>::from_reader(RECORDING.as_bytes(), scheduler)
+			.unwrap();
+	assert_eq!(replay.recording().len(), 3);
+
+	let subscription = replay.subscribe(destination);
+	assert!(notification_collector.lock().is_empty());
+
+	executor.tick(Duration::from_millis(10));
+	notification_collector.lock().assert_notifications(
+		"replay_file",
+		0,
+		[SubscriberNotification::Next(1)],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(99));
+	notification_collector
+		.lock()
+		.assert_nth_notification_is_last("replay_file", 0);
+
+	executor.tick(Duration::from_millis(1));
+	notification_collector.lock().assert_notifications(
+		"replay_file",
+		1,
+		[SubscriberNotification::Next(2)],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(100));
+	notification_collector.lock().assert_notifications(
+		"replay_file",
+		2,
+		[SubscriberNotification::Complete],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_replay_every_notification_due_within_a_single_tick() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, String>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let _subscription =
+		ReplayFileObservable::<usize, String, _>::from_reader(RECORDING.as_bytes(), scheduler)
+			.unwrap()
+			.subscribe(destination);
+
+	executor.tick(Duration::from_millis(0));
+	executor.tick(Duration::from_secs(1));
+
+	notification_collector.lock().assert_notifications(
+		"replay_file",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_replay_recorded_errors() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, String>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let _subscription = ReplayFileObservable::<usize, String, _>::from_reader(
+		"[0,{\"Next\":1}]\n[0,{\"Error\":\"error\"}]\n".as_bytes(),
+		scheduler,
+	)
+	.unwrap()
+	.subscribe(destination);
+
+	executor.tick(Duration::from_millis(0));
+
+	notification_collector.lock().assert_notifications(
+		"replay_file",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Error("error".to_string()),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_fail_to_open_a_missing_recording() {
+	let executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let result =
+		ReplayFileObservable::<usize, String, _>::open(recording_path("missing"), scheduler);
+
+	assert!(matches!(result, Err(ReplayFileObservableError::Io(_))));
+}
+
+#[test]
+fn should_fail_to_read_an_invalid_recording_with_the_line_number() {
+	let executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let result = ReplayFileObservable::<usize, String, _>::from_reader(
+		"[0,{\"Next\":1}]\n[0,{\"Next\":\"one\"}]\n".as_bytes(),
+		scheduler,
+	);
+
+	assert!(matches!(
+		result,
+		Err(ReplayFileObservableError::Parse { line: 2, .. })
+	));
+}
+
+#[test]
+fn should_replay_what_was_recorded() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+	let path = recording_path("round_trip");
+
+	let mut source = PublishSubject::<usize, String>::default();
+	let _recording_subscription = source
+		.clone()
+		.record(&path, scheduler.clone())
+		.subscribe(MockObserver::default());
+
+	executor.tick(Duration::from_millis(0));
+	source.next(1);
+	executor.tick(Duration::from_millis(30));
+	source.next(2);
+	source.next(3);
+	executor.tick(Duration::from_millis(20));
+	source.error("error".to_string());
+
+	let destination = MockObserver::<usize, String>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let _subscription = ReplayFileObservable::<usize, String, _>::open(&path, scheduler)
+		.unwrap()
+		.subscribe(destination);
+
+	executor.tick(Duration::from_millis(0));
+	notification_collector.lock().assert_notifications(
+		"replay_file",
+		0,
+		[SubscriberNotification::Next(1)],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(30));
+	notification_collector.lock().assert_notifications(
+		"replay_file",
+		1,
+		[
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(20));
+	notification_collector.lock().assert_notifications(
+		"replay_file",
+		3,
+		[SubscriberNotification::Error("error".to_string())],
+		true,
+	);
+
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	fs::remove_file(path).unwrap();
+}
+
+/// Non Applicable:
+/// - rx_contract_closed_if_downstream_closes_early - Same as after_unsubscribe
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<usize, String>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription = ReplayFileObservable::<usize, String, _>::from_reader(
+			"[0,{\"Error\":\"error\"}]\n".as_bytes(),
+			scheduler,
+		)
+		.unwrap()
+		.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("replay_file");
+
+		executor.tick(Duration::from_millis(0));
+
+		notification_collector.lock().assert_notifications(
+			"replay_file",
+			0,
+			[SubscriberNotification::Error("error".to_string())],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<usize, String>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription =
+			ReplayFileObservable::<usize, String, _>::from_reader(RECORDING.as_bytes(), scheduler)
+				.unwrap()
+				.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("replay_file");
+
+		executor.tick(Duration::from_millis(0));
+		executor.tick(Duration::from_millis(150));
+
+		notification_collector.lock().assert_nth_notification(
+			"replay_file",
+			2,
+			SubscriberNotification::Complete,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<usize, String>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subscription =
+			ReplayFileObservable::<usize, String, _>::from_reader(RECORDING.as_bytes(), scheduler)
+				.unwrap()
+				.subscribe(destination);
+		let tracked_teardown = subscription.add_tracked_teardown("replay_file");
+
+		executor.tick(Duration::from_millis(0));
+		subscription.unsubscribe();
+		executor.tick(Duration::from_millis(150));
+
+		notification_collector.lock().assert_notifications(
+			"replay_file",
+			0,
+			[
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Unsubscribe,
+			],
+			true,
+		);
+
+		tracked_teardown.assert_was_torn_down();
+		assert!(subscription.is_closed());
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+}
@@ -147,7 +147,7 @@ fn should_not_replay_its_last_value_to_new_subscribers_after_errored() {
 #[test]
 fn should_allow_its_buffer_to_be_read_without_subscribe() {
 	let mut replay_subject = ReplaySubject::<2, usize, &'static str>::default();
-	assert_eq!(replay_subject.values(), Vec::new());
+	assert_eq!(replay_subject.values(), Vec::<usize>::new());
 	replay_subject.next(10);
 	assert_eq!(replay_subject.values(), vec![10]);
 	replay_subject.next(20);
@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_common::RxObserver;
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_only_replay_values_observed_within_the_window() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut replay_subject = ReplayWindowSubject::<usize, Never, _>::new(
+		ReplayWindowOptions::new(Duration::from_millis(2000)),
+		scheduler,
+	);
+
+	replay_subject.next(1);
+	executor.tick(Duration::from_millis(1000));
+	replay_subject.next(2);
+	executor.tick(Duration::from_millis(999));
+	assert_eq!(replay_subject.values(), vec![1, 2]);
+
+	executor.tick(Duration::from_millis(1));
+	assert_eq!(replay_subject.values(), vec![2]);
+
+	let _s = replay_subject.clone().subscribe(destination);
+	replay_subject.next(3);
+
+	notification_collector.lock().assert_notifications(
+		"replay_window_subject",
+		0,
+		[
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(2000));
+	assert!(replay_subject.values().is_empty());
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_also_limit_the_replayed_values_by_capacity() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut replay_subject = ReplayWindowSubject::<usize, Never, _>::new(
+		ReplayWindowOptions::new(Duration::from_millis(1000)).with_capacity(2),
+		scheduler,
+	);
+
+	replay_subject.next(1);
+	replay_subject.next(2);
+	replay_subject.next(3);
+	let _s = replay_subject.clone().subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"replay_window_subject",
+		0,
+		[
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+
+	// Evictions of values already dropped by capacity don't affect newer ones
+	replay_subject.next(4);
+	executor.tick(Duration::from_millis(1000));
+	assert!(replay_subject.values().is_empty());
+
+	replay_subject.next(5);
+	executor.tick(Duration::from_millis(999));
+	assert_eq!(replay_subject.values(), vec![5]);
+
+	executor.tick(Duration::from_millis(1));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_keep_evicting_values_after_completion() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut replay_subject = ReplayWindowSubject::<usize, Never, _>::new(
+		ReplayWindowOptions::new(Duration::from_millis(1000)),
+		scheduler,
+	);
+
+	replay_subject.next(1);
+	replay_subject.complete();
+
+	let destination_1 = MockObserver::<usize>::default();
+	let notification_collector_1 = destination_1.get_notification_collector();
+	let _s1 = replay_subject.clone().subscribe(destination_1);
+
+	notification_collector_1.lock().assert_notifications(
+		"replay_window_subject - before the window",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(1000));
+
+	let destination_2 = MockObserver::<usize>::default();
+	let notification_collector_2 = destination_2.get_notification_collector();
+	let _s2 = replay_subject.clone().subscribe(destination_2);
+
+	notification_collector_2.lock().assert_notifications(
+		"replay_window_subject - after the window",
+		0,
+		[SubscriberNotification::Complete],
+		true,
+	);
+
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_not_replay_its_values_to_new_subscribers_after_errored() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut replay_subject = ReplayWindowSubject::<usize, &'static str, _>::new(
+		ReplayWindowOptions::new(Duration::from_millis(1000)),
+		scheduler,
+	);
+	replay_subject.next(1);
+	replay_subject.error("error");
+	let _s = replay_subject.clone().subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"replay_window_subject",
+		0,
+		[SubscriberNotification::Error("error")],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(1000));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_not_schedule_any_work_for_observed_values() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut replay_subject = ReplayWindowSubject::<usize, Never, _>::new(
+		ReplayWindowOptions::new(Duration::from_millis(1000)),
+		scheduler,
+	);
+
+	for i in 0..1000 {
+		replay_subject.next(i);
+	}
+
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	assert_eq!(replay_subject.values().len(), 1000);
+
+	executor.tick(Duration::from_millis(1000));
+	assert!(replay_subject.values().is_empty());
+}
@@ -0,0 +1,336 @@
+use std::{
+	sync::{
+		Arc, Mutex,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::Duration,
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+fn erroring_above_ten(
+	source: &PublishSubject<usize, &'static str>,
+	error: &'static str,
+) -> impl Observable<Out = usize, OutError = &'static str> + Send + Sync + 'static {
+	source.clone().on_next(move |next, destination| {
+		if *next > 10 {
+			destination.error(error);
+			false
+		} else {
+			true
+		}
+	})
+}
+
+#[test]
+fn should_resubscribe_every_time_the_notifier_emits() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let notifier_clone = notifier.clone();
+	let _subscription = erroring_above_ten(&source, "error")
+		.retry_when(move |_errors| notifier_clone.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(99);
+	source.next(2); // Not subscribed until the notifier emits
+	notifier.next(());
+	source.next(3);
+	notifier.next(()); // Resubscribes even without an error
+	source.next(4);
+
+	notification_collector.lock().assert_notifications(
+		"retry_when",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(3),
+			SubscriberNotification::Next(4),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_send_the_errors_to_the_notifier() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let error_destination = MockObserver::<&'static str, &'static str>::default();
+	let error_notification_collector = error_destination.get_notification_collector();
+	let error_destination = Arc::new(Mutex::new(Some(error_destination)));
+	let error_subscription = Arc::new(Mutex::new(None));
+	let error_subscription_clone = error_subscription.clone();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let _subscription = erroring_above_ten(&source, "error")
+		.retry_when(move |mut errors| {
+			if let Some(error_destination) = error_destination.lock_ignore_poison().take() {
+				error_subscription_clone
+					.lock_ignore_poison()
+					.replace(errors.subscribe(error_destination));
+			}
+			errors
+		})
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(99);
+	source.next(2);
+	source.next(99);
+
+	error_notification_collector.lock().assert_notifications(
+		"retry_when - errors",
+		0,
+		[
+			SubscriberNotification::Next("error"),
+			SubscriberNotification::Next("error"),
+		],
+		true,
+	);
+
+	notification_collector.lock().assert_notifications(
+		"retry_when",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_not_overflow_the_stack_on_immediate_errors() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut subscription = concat((
+		(0..=1).into_observable().map_never(),
+		throw("error").map_never(),
+	))
+	.retry_when(|errors| errors)
+	.take(2000)
+	.subscribe(destination);
+
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 2000);
+	assert!(subscription.is_closed());
+	subscription.unsubscribe();
+}
+
+#[test]
+fn should_retry_with_a_delayed_notifier() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut subscription = concat((
+		(0..=1).into_observable().map_never(),
+		throw("error").map_never(),
+	))
+	.retry_when(move |errors| errors.delay(Duration::from_millis(1000), scheduler.clone()))
+	.subscribe(destination);
+
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 2);
+
+	executor.tick(Duration::from_millis(999));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 2);
+
+	executor.tick(Duration::from_millis(1));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 4);
+
+	executor.tick(Duration::from_millis(1000));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 6);
+
+	subscription.unsubscribe();
+	// The already delayed error of the notifier still runs out
+	executor.tick(Duration::from_millis(1000));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 6);
+
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_error_when_the_notifier_errors() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let notifier_clone = notifier.clone();
+	let subscription = erroring_above_ten(&source, "error")
+		.retry_when(move |_errors| notifier_clone.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(99);
+	notifier.error("gave up");
+
+	notification_collector.lock().assert_notifications(
+		"retry_when",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Error("gave up"),
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_complete_when_the_notifier_completes() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let notifier_clone = notifier.clone();
+	let subscription = erroring_above_ten(&source, "error")
+		.retry_when(move |_errors| notifier_clone.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(99);
+	notifier.complete();
+
+	notification_collector.lock().assert_notifications(
+		"retry_when",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+}
+
+#[test]
+fn should_complete_when_the_source_completes_without_calling_the_notifier_fn() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let was_notifier_fn_called = Arc::new(AtomicBool::new(false));
+	let was_notifier_fn_called_clone = was_notifier_fn_called.clone();
+
+	let subscription = (1..=2)
+		.into_observable()
+		.map_never()
+		.retry_when(move |errors: PublishSubject<&'static str, &'static str>| {
+			was_notifier_fn_called_clone.store(true, Ordering::Relaxed);
+			errors
+		})
+		.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"retry_when",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+	assert!(!was_notifier_fn_called.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_unsubscribe_from_the_source_and_the_notifier_when_unsubscribed() {
+	let destination = MockObserver::<usize, &'static str>::default();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let source_finalized = Arc::new(AtomicBool::new(false));
+	let source_finalized_clone = source_finalized.clone();
+	let notifier_finalized = Arc::new(AtomicBool::new(false));
+	let notifier_finalized_clone = notifier_finalized.clone();
+
+	let notifier_clone = notifier.clone();
+	let mut subscription = erroring_above_ten(&source, "error")
+		.finalize(move || source_finalized_clone.store(true, Ordering::Relaxed))
+		.retry_when(move |_errors| {
+			let notifier_finalized = notifier_finalized_clone.clone();
+			notifier_clone
+				.clone()
+				.finalize(move || notifier_finalized.store(true, Ordering::Relaxed))
+		})
+		.subscribe(destination);
+
+	let mut source_clone = source.clone();
+	source_clone.next(99);
+	notifier.next(());
+	// Only the first subscription of the source is finalized by its error
+	assert!(source_finalized.swap(false, Ordering::Relaxed));
+
+	assert!(!source_finalized.load(Ordering::Relaxed));
+	assert!(!notifier_finalized.load(Ordering::Relaxed));
+
+	subscription.unsubscribe();
+
+	assert!(source_finalized.load(Ordering::Relaxed));
+	assert!(notifier_finalized.load(Ordering::Relaxed));
+}
+
+/// The error comes from the notifier, as the errors of the source never reach
+/// downstream directly.
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("retry_when");
+		let observable = harness
+			.create_harness_observable()
+			.retry_when(|_errors| throw("error"));
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error"));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("retry_when");
+		let observable = harness
+			.create_harness_observable()
+			.retry_when(|_errors| throw("error"));
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("retry_when");
+		let observable = harness
+			.create_harness_observable()
+			.retry_when(|_errors| throw("error"));
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
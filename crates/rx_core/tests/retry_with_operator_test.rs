@@ -0,0 +1,374 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_common::{SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+fn failing_source(error: &'static str) -> impl Observable<Out = usize, OutError = &'static str> {
+	concat((
+		(0..=1).into_observable().map_never(),
+		throw(error).map_never(),
+	))
+}
+
+fn erroring_above_ten(
+	source: &PublishSubject<usize, &'static str>,
+	error: &'static str,
+) -> impl Observable<Out = usize, OutError = &'static str> + Send + Sync + 'static {
+	source.clone().on_next(move |next, destination| {
+		if *next > 10 {
+			destination.error(error);
+			false
+		} else {
+			true
+		}
+	})
+}
+
+#[test]
+fn should_wait_for_the_delay_before_retrying() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let error = "error";
+	let mut subscription = failing_source(error)
+		.retry_with(
+			RetryConfig::new(2).with_delay(RetryDelay::Fixed(Duration::from_millis(1000))),
+			scheduler,
+		)
+		.subscribe(destination);
+	let teardown_tracker = subscription.add_tracked_teardown("retry_with");
+
+	notification_collector.lock().assert_notifications(
+		"retry_with",
+		0,
+		[
+			SubscriberNotification::Next(0),
+			SubscriberNotification::Next(1),
+		],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(999));
+	notification_collector.lock().assert_notifications(
+		"retry_with",
+		0,
+		[
+			SubscriberNotification::Next(0),
+			SubscriberNotification::Next(1),
+		],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(1));
+	notification_collector.lock().assert_notifications(
+		"retry_with",
+		2,
+		[
+			SubscriberNotification::Next(0),
+			SubscriberNotification::Next(1),
+		],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(1000));
+	notification_collector.lock().assert_notifications(
+		"retry_with",
+		4,
+		[
+			SubscriberNotification::Next(0),
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Error(error),
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+	teardown_tracker.assert_was_torn_down();
+
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_back_off_exponentially_up_to_the_max_delay() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut subscription = failing_source("error")
+		.retry_with(
+			RetryConfig::new(4).with_delay(RetryDelay::Exponential {
+				initial: Duration::from_millis(100),
+				max: Duration::from_millis(300),
+			}),
+			scheduler,
+		)
+		.subscribe(destination);
+
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 2);
+
+	executor.tick(Duration::from_millis(100));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 4);
+
+	executor.tick(Duration::from_millis(199));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 4);
+	executor.tick(Duration::from_millis(1));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 6);
+
+	// Would be 400ms, but it's capped
+	executor.tick(Duration::from_millis(300));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 8);
+
+	executor.tick(Duration::from_millis(300));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 10);
+	assert_eq!(notification_collector.lock().count_observed_errors(), 1);
+
+	subscription.unsubscribe();
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_only_count_consecutive_errors_when_reset_on_success_is_enabled() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let error = "error";
+
+	let _subscription = erroring_above_ten(&source, error)
+		.retry_with(
+			RetryConfig::new(1)
+				.with_delay(RetryDelay::Fixed(Duration::from_millis(100)))
+				.with_reset_on_success(true),
+			scheduler,
+		)
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(99); // First retry
+	executor.tick(Duration::from_millis(100));
+	source.next(2); // Resets the counter
+	source.next(99); // Still the first retry
+	executor.tick(Duration::from_millis(100));
+	source.next(3);
+	source.next(99); // First retry again
+	executor.tick(Duration::from_millis(100));
+	source.next(99); // No success since the last retry
+
+	notification_collector.lock().assert_notifications(
+		"retry_with",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+			SubscriberNotification::Error(error),
+		],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_count_every_error_when_reset_on_success_is_disabled() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let error = "error";
+
+	let _subscription = erroring_above_ten(&source, error)
+		.retry_with(RetryConfig::new(1), scheduler)
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(99);
+	executor.tick(Duration::from_millis(0));
+	source.next(2);
+	source.next(99);
+
+	notification_collector.lock().assert_notifications(
+		"retry_with",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Error(error),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_not_retry_after_unsubscribing_during_the_delay() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut subscription = failing_source("error")
+		.retry_with(
+			RetryConfig::new(2).with_delay(RetryDelay::Fixed(Duration::from_millis(1000))),
+			scheduler,
+		)
+		.subscribe(destination);
+	let teardown_tracker = subscription.add_tracked_teardown("retry_with");
+
+	executor.tick(Duration::from_millis(500));
+	subscription.unsubscribe();
+	teardown_tracker.assert_was_torn_down();
+
+	executor.tick(Duration::from_millis(1000));
+	notification_collector.lock().assert_notifications(
+		"retry_with",
+		0,
+		[
+			SubscriberNotification::Next(0),
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Unsubscribe,
+		],
+		true,
+	);
+
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_complete_when_the_source_completes() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let subscription = (1..=2)
+		.into_observable()
+		.map_never()
+		.retry_with(RetryConfig::default(), scheduler)
+		.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"retry_with",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+mod retry_delay {
+	use super::*;
+
+	#[test]
+	fn fixed_should_always_return_the_same_delay() {
+		let delay = RetryDelay::Fixed(Duration::from_millis(100));
+		assert_eq!(delay.delay_for(1), Duration::from_millis(100));
+		assert_eq!(delay.delay_for(10), Duration::from_millis(100));
+	}
+
+	#[test]
+	fn exponential_should_double_until_the_max() {
+		let delay = RetryDelay::Exponential {
+			initial: Duration::from_millis(100),
+			max: Duration::from_secs(10),
+		};
+		assert_eq!(delay.delay_for(1), Duration::from_millis(100));
+		assert_eq!(delay.delay_for(2), Duration::from_millis(200));
+		assert_eq!(delay.delay_for(3), Duration::from_millis(400));
+		assert_eq!(delay.delay_for(usize::MAX), Duration::from_secs(10));
+	}
+
+	#[test]
+	fn jittered_should_stay_below_the_exponential_delay() {
+		let delay = RetryDelay::Jittered {
+			initial: Duration::from_millis(100),
+			max: Duration::from_millis(300),
+		};
+		for retry in 1..=10 {
+			assert!(delay.delay_for(retry) <= Duration::from_millis(300));
+		}
+		assert!(delay.delay_for(1) <= Duration::from_millis(100));
+	}
+}
+
+/// With no retries left, the error of the source is let through.
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("retry_with");
+		let observable = harness
+			.create_harness_observable()
+			.retry_with(RetryConfig::new(0), scheduler.clone());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error"));
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("retry_with");
+		let observable = harness
+			.create_harness_observable()
+			.retry_with(RetryConfig::new(0), scheduler.clone());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new("retry_with");
+		let observable = harness
+			.create_harness_observable()
+			.retry_with(RetryConfig::new(0), scheduler.clone());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+}
@@ -0,0 +1,196 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicBool, Ordering},
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_emit_the_latest_value_when_the_notifier_emits() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.sample(notifier.clone())
+		.subscribe(destination);
+
+	notifier.next(());
+	source.next(1);
+	source.next(2);
+	notifier.next(());
+	notifier.next(());
+	source.next(3);
+	notifier.next(());
+
+	notification_collector.lock().assert_notifications(
+		"sample",
+		0,
+		[
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_not_complete_when_the_notifier_completes() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.sample(notifier.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	notifier.complete();
+	source.next(2);
+
+	assert!(!subscription.is_closed());
+	notification_collector.lock().assert_is_empty("sample");
+}
+
+#[test]
+fn should_forward_notifier_errors() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.sample(notifier.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	notifier.error("notifier error");
+
+	assert!(subscription.is_closed());
+	notification_collector.lock().assert_notifications(
+		"sample",
+		0,
+		[SubscriberNotification::Error("notifier error")],
+		true,
+	);
+}
+
+#[test]
+fn should_complete_without_emitting_the_pending_value() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let notifier = PublishSubject::<(), &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.sample(notifier.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"sample",
+		0,
+		[SubscriberNotification::Complete],
+		true,
+	);
+}
+
+#[test]
+fn should_unsubscribe_the_notifier_when_unsubscribed() {
+	let notifier_finalized = Arc::new(AtomicBool::new(false));
+	let notifier_finalized_clone = notifier_finalized.clone();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+	let notifier = PublishSubject::<(), &'static str>::default();
+
+	let mut subscription = source
+		.clone()
+		.sample(
+			notifier
+				.clone()
+				.finalize(move || notifier_finalized_clone.store(true, Ordering::Relaxed)),
+		)
+		.subscribe(MockObserver::default());
+
+	assert!(!notifier_finalized.load(Ordering::Relaxed));
+	subscription.unsubscribe();
+	assert!(notifier_finalized.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_compose() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let composed = compose_operator::<usize, &'static str>().sample(notifier.clone());
+
+	let _subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	notifier.next(());
+
+	notification_collector.lock().assert_notifications(
+		"sample",
+		0,
+		[SubscriberNotification::Next(1)],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("sample");
+		let observable = harness
+			.create_harness_observable()
+			.sample(NeverObservable.map_never_both::<(), MockError>());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("sample");
+		let observable = harness
+			.create_harness_observable()
+			.sample(NeverObservable.map_never_both::<(), MockError>());
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("sample");
+		let observable = harness
+			.create_harness_observable()
+			.sample(NeverObservable.map_never_both::<(), MockError>());
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
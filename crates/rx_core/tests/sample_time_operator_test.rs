@@ -0,0 +1,181 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_emit_the_latest_value_every_period() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut subscription = source
+		.clone()
+		.sample_time(Duration::from_millis(100), scheduler)
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	executor.tick(Duration::from_millis(99));
+	notification_collector.lock().assert_is_empty("sample_time");
+
+	executor.tick(Duration::from_millis(1));
+	// Nothing new to sample
+	executor.tick(Duration::from_millis(100));
+	source.next(3);
+	executor.tick(Duration::from_millis(100));
+
+	notification_collector.lock().assert_notifications(
+		"sample_time",
+		0,
+		[
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+		],
+		true,
+	);
+
+	subscription.unsubscribe();
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_complete_without_emitting_the_pending_value() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.sample_time(Duration::from_millis(100), scheduler)
+		.subscribe(destination);
+
+	source.next(1);
+	source.complete();
+	executor.tick(Duration::from_millis(100));
+
+	notification_collector.lock().assert_notifications(
+		"sample_time",
+		0,
+		[SubscriberNotification::Complete],
+		true,
+	);
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_error_immediately_and_stop_sampling() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.sample_time(Duration::from_millis(100), scheduler)
+		.subscribe(destination);
+
+	source.next(1);
+	source.error("error");
+	executor.tick(Duration::from_millis(100));
+
+	notification_collector.lock().assert_notifications(
+		"sample_time",
+		0,
+		[SubscriberNotification::Error("error")],
+		true,
+	);
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_compose() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let composed = compose_operator::<usize, &'static str>()
+		.sample_time(Duration::from_millis(100), scheduler);
+
+	let _subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	executor.tick(Duration::from_millis(100));
+
+	notification_collector.lock().assert_notifications(
+		"sample_time",
+		0,
+		[SubscriberNotification::Next(1)],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("sample_time");
+		let observable = harness
+			.create_harness_observable()
+			.sample_time(Duration::from_millis(10), scheduler.clone());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("sample_time");
+		let observable = harness
+			.create_harness_observable()
+			.sample_time(Duration::from_millis(10), scheduler.clone());
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("sample_time");
+		let observable = harness
+			.create_harness_observable()
+			.sample_time(Duration::from_millis(10), scheduler.clone());
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use rx_core::prelude::*;
 use rx_core_common::{Observable, SubscriberNotification};
 use rx_core_testing::prelude::*;
@@ -117,6 +119,33 @@ fn should_disconnect_when_the_ref_count_is_zero() {
 	assert!(!shared.is_connected(), "should not stay connected");
 }
 
+#[test]
+fn should_disconnect_only_after_the_ref_count_zero_delay() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+
+	let mut shared = source.clone().share_with_reset_delays(
+		ConnectableOptions::<ProvideWithDefault<PublishSubject<usize, &'static str>>>::default()
+			.disconnect_when_ref_count_zero(),
+		ConnectableResetDelays::default()
+			.on_ref_count_zero(ResetDelay::new(Duration::from_millis(1000), scheduler)),
+	);
+
+	let mut subscription = shared.subscribe(MockObserver::default());
+	subscription.unsubscribe();
+
+	assert!(
+		shared.is_connected(),
+		"should stay connected during the delay"
+	);
+
+	executor.tick(Duration::from_millis(1000));
+
+	assert!(!shared.is_connected(), "should disconnect after the delay");
+}
+
 #[test]
 fn should_close_when_errored() {
 	let destination_1 = MockObserver::<usize, &'static str>::default();
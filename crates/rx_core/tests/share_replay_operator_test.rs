@@ -0,0 +1,277 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicUsize, Ordering},
+	},
+	time::Duration,
+};
+
+use rx_core::prelude::*;
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_replay_the_last_values_to_late_subscribers() {
+	let executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination_1 = MockObserver::<usize, &'static str>::default();
+	let notification_collector_1 = destination_1.get_notification_collector();
+
+	let destination_2 = MockObserver::<usize, &'static str>::default();
+	let notification_collector_2 = destination_2.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut shared = source
+		.clone()
+		.share_replay(ShareReplayOptions::new(2), scheduler);
+
+	let _s1 = shared.subscribe(destination_1);
+	source.next(1);
+	source.next(2);
+	source.next(3);
+	let _s2 = shared.subscribe(destination_2);
+	source.next(4);
+
+	notification_collector_1.lock().assert_notifications(
+		"share_replay - destination_1",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+			SubscriberNotification::Next(4),
+		],
+		true,
+	);
+
+	notification_collector_2.lock().assert_notifications(
+		"share_replay - destination_2",
+		0,
+		[
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Next(3),
+			SubscriberNotification::Next(4),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_only_replay_values_within_the_window_time() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut shared = source.clone().share_replay(
+		ShareReplayOptions::default().with_window_time(Duration::from_millis(1000)),
+		scheduler,
+	);
+
+	let _s1 = shared.subscribe(MockObserver::default());
+	source.next(1);
+	executor.tick(Duration::from_millis(600));
+	source.next(2);
+	executor.tick(Duration::from_millis(600));
+
+	let _s2 = shared.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"share_replay",
+		0,
+		[SubscriberNotification::Next(2)],
+		true,
+	);
+}
+
+#[test]
+fn should_not_resubscribe_to_the_source_after_it_completed() {
+	let executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let source_subscription_count = Arc::new(AtomicUsize::new(0));
+	let source_subscription_count_clone = source_subscription_count.clone();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut shared = source
+		.clone()
+		.on_subscribe(move |_| {
+			source_subscription_count_clone.fetch_add(1, Ordering::Relaxed);
+		})
+		.share_replay(ShareReplayOptions::default(), scheduler);
+
+	let _s1 = shared.subscribe(MockObserver::default());
+	source.next(1);
+	source.complete();
+
+	let _s2 = shared.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"share_replay",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert_eq!(source_subscription_count.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn should_reset_and_resubscribe_to_the_source_after_it_errored() {
+	let executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let source_subscription_count = Arc::new(AtomicUsize::new(0));
+	let source_subscription_count_clone = source_subscription_count.clone();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut shared = source
+		.clone()
+		.on_subscribe(move |_| {
+			source_subscription_count_clone.fetch_add(1, Ordering::Relaxed);
+		})
+		.share_replay(ShareReplayOptions::default(), scheduler);
+
+	let _s1 = shared.subscribe(MockObserver::default());
+	source.next(1);
+	source.error("error");
+
+	// The values before the error are no longer replayed, the source is
+	// subscribed to again, which errors again right away.
+	let _s2 = shared.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"share_replay",
+		0,
+		[SubscriberNotification::Error("error")],
+		true,
+	);
+
+	assert_eq!(source_subscription_count.load(Ordering::Relaxed), 2);
+}
+
+mod ref_count {
+	use super::*;
+
+	#[test]
+	fn should_keep_the_source_subscribed_without_subscribers_when_disabled() {
+		let executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<usize, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut shared = source
+			.clone()
+			.share_replay(ShareReplayOptions::new(1), scheduler);
+
+		let mut subscription = shared.subscribe(MockObserver::default());
+		source.next(1);
+		subscription.unsubscribe();
+
+		assert!(shared.is_connected());
+		source.next(2);
+
+		let _s2 = shared.subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"share_replay",
+			0,
+			[SubscriberNotification::Next(2)],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_unsubscribe_the_source_and_forget_the_values_when_enabled() {
+		let executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<usize, &'static str>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut shared = source
+			.clone()
+			.share_replay(ShareReplayOptions::new(1).with_ref_count(), scheduler);
+
+		let mut subscription = shared.subscribe(MockObserver::default());
+		source.next(1);
+		subscription.unsubscribe();
+
+		assert!(!shared.is_connected());
+
+		let _s2 = shared.subscribe(destination);
+		assert!(shared.is_connected());
+
+		notification_collector
+			.lock()
+			.assert_is_empty("share_replay");
+	}
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let mut harness = TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new(
+			"share_replay",
+		);
+		let observable = harness
+			.create_harness_observable()
+			.share_replay(ShareReplayOptions::new(1), scheduler);
+		harness.subscribe_to(observable);
+
+		let error = "error";
+		harness.source().error(error);
+		harness.assert_terminal_notification(SubscriberNotification::Error(error));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let mut harness = TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new(
+			"share_replay",
+		);
+		let observable = harness
+			.create_harness_observable()
+			.share_replay(ShareReplayOptions::new(1), scheduler);
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let mut harness = TestHarness::<TestSubject<usize, &'static str>, usize, &'static str>::new(
+			"share_replay",
+		);
+		let observable = harness
+			.create_harness_observable()
+			.share_replay(ShareReplayOptions::new(1), scheduler);
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -0,0 +1,257 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicBool, Ordering},
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_skip_upstream_until_the_notifier_emits() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.skip_until(notifier.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	notifier.next(());
+	source.next(3);
+	notifier.next(());
+	source.next(4);
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"skip_until",
+		0,
+		[
+			SubscriberNotification::Next(3),
+			SubscriberNotification::Next(4),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_keep_skipping_when_the_notifier_completes_without_emitting() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.skip_until(notifier.clone())
+		.subscribe(destination);
+
+	notifier.complete();
+	source.next(1);
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"skip_until",
+		0,
+		[SubscriberNotification::Complete],
+		true,
+	);
+}
+
+#[test]
+fn should_forward_notifier_errors() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.skip_until(notifier.clone())
+		.subscribe(destination);
+
+	notifier.error("error");
+
+	assert!(subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"skip_until",
+		0,
+		[SubscriberNotification::Error("error")],
+		true,
+	);
+}
+
+#[test]
+fn should_unsubscribe_the_notifier_when_unsubscribed() {
+	let notifier_finalized = Arc::new(AtomicBool::new(false));
+	let notifier_finalized_clone = notifier_finalized.clone();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+	let notifier = PublishSubject::<(), &'static str>::default();
+
+	let mut subscription = source
+		.clone()
+		.skip_until(
+			notifier
+				.clone()
+				.finalize(move || notifier_finalized_clone.store(true, Ordering::Relaxed)),
+		)
+		.subscribe(MockObserver::default());
+
+	assert!(!notifier_finalized.load(Ordering::Relaxed));
+
+	subscription.unsubscribe();
+
+	assert!(notifier_finalized.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_forward_notifier_errors_after_it_emitted() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.skip_until(create_observable::<_, &'static str, _>(|destination| {
+			destination.next(());
+			destination.error("error");
+		}))
+		.subscribe(destination);
+
+	assert!(subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"skip_until",
+		0,
+		[SubscriberNotification::Error("error")],
+		true,
+	);
+}
+
+#[test]
+fn should_not_receive_notifier_errors_once_unsubscribed_from_it() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.skip_until(notifier.clone())
+		.subscribe(destination);
+
+	notifier.next(());
+	notifier.error("error");
+	source.next(1);
+
+	assert!(!subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"skip_until",
+		0,
+		[SubscriberNotification::Next(1)],
+		true,
+	);
+}
+
+#[test]
+fn should_unsubscribe_the_notifier_once_it_emitted() {
+	let notifier_finalized = Arc::new(AtomicBool::new(false));
+	let notifier_finalized_clone = notifier_finalized.clone();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.skip_until(
+			notifier
+				.clone()
+				.finalize(move || notifier_finalized_clone.store(true, Ordering::Relaxed)),
+		)
+		.subscribe(MockObserver::default());
+
+	assert!(!notifier_finalized.load(Ordering::Relaxed));
+
+	notifier.next(());
+
+	assert!(notifier_finalized.load(Ordering::Relaxed));
+	assert!(!subscription.is_closed());
+}
+
+#[test]
+fn should_compose() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let composed = compose_operator::<usize, &'static str>().skip_until(notifier.clone());
+
+	let _subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	notifier.next(());
+	source.next(2);
+
+	notification_collector.lock().assert_notifications(
+		"skip_until",
+		0,
+		[SubscriberNotification::Next(2)],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("skip_until");
+		let observable = harness
+			.create_harness_observable()
+			.skip_until(PublishSubject::<(), MockError>::default());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("skip_until");
+		let observable = harness
+			.create_harness_observable()
+			.skip_until(PublishSubject::<(), MockError>::default());
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("skip_until");
+		let observable = harness
+			.create_harness_observable()
+			.skip_until(PublishSubject::<(), MockError>::default());
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
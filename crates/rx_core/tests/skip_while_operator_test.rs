@@ -0,0 +1,120 @@
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_skip_upstream_while_the_predicate_passes() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.skip_while(|next, _index| *next < 3)
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	source.next(3);
+	source.next(1);
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"skip_while",
+		0,
+		[
+			SubscriberNotification::Next(3),
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_pass_the_index_to_the_predicate() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.skip_while(|_next, index| index < 2)
+		.subscribe(destination);
+
+	source.next(10);
+	source.next(20);
+	source.next(30);
+
+	notification_collector.lock().assert_notifications(
+		"skip_while",
+		0,
+		[SubscriberNotification::Next(30)],
+		true,
+	);
+}
+
+#[test]
+fn should_compose() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let composed = compose_operator::<usize, &'static str>().skip_while(|next, _index| *next < 2);
+
+	let _subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+
+	notification_collector.lock().assert_notifications(
+		"skip_while",
+		0,
+		[SubscriberNotification::Next(2)],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("skip_while");
+		let observable = harness
+			.create_harness_observable()
+			.skip_while(|next, _index| *next < 10);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("skip_while");
+		let observable = harness
+			.create_harness_observable()
+			.skip_while(|next, _index| *next < 10);
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("skip_while");
+		let observable = harness
+			.create_harness_observable()
+			.skip_while(|next, _index| *next < 10);
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
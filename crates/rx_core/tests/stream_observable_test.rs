@@ -0,0 +1,246 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::Duration,
+};
+
+use futures_lite::{StreamExt, future, stream};
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification, SubscriptionLike};
+use rx_core_notification_store::{QueueOverflowBehavior, QueueOverflowOptions};
+use rx_core_testing::prelude::*;
+
+mod from_stream {
+	use super::*;
+
+	#[test]
+	fn should_emit_every_item_of_a_ready_stream_then_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let _subscription = from_stream(|| stream::iter(1..=3), scheduler).subscribe(destination);
+
+		assert!(
+			notification_collector.lock().is_empty(),
+			"the stream should only be polled by the scheduler"
+		);
+
+		executor.tick(Duration::from_millis(0));
+
+		notification_collector.lock().assert_notifications(
+			"from_stream",
+			0,
+			[
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Next(2),
+				SubscriberNotification::Next(3),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_poll_the_stream_again_when_woken() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let source_clone = source.clone();
+
+		let _subscription =
+			StreamObservable::new(move || source_clone.clone().into_stream(), scheduler)
+				.subscribe(destination);
+
+		executor.tick(Duration::from_millis(0));
+		assert!(notification_collector.lock().is_empty());
+
+		source.next(1);
+		source.next(2);
+		executor.tick(Duration::from_millis(0));
+
+		source.error("error");
+		executor.tick(Duration::from_millis(0));
+
+		notification_collector.lock().assert_notifications(
+			"from_stream",
+			0,
+			[
+				SubscriberNotification::Next(Ok(1)),
+				SubscriberNotification::Next(Ok(2)),
+				SubscriberNotification::Next(Err("error")),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_stop_polling_the_stream_when_unsubscribed() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize>::default();
+		let source_clone = source.clone();
+
+		let mut subscription = from_stream(move || source_clone.clone().into_stream(), scheduler)
+			.subscribe(destination);
+
+		executor.tick(Duration::from_millis(0));
+		subscription.unsubscribe();
+
+		source.next(1);
+		executor.tick(Duration::from_millis(0));
+
+		notification_collector.lock().assert_notifications(
+			"from_stream",
+			0,
+			[SubscriberNotification::Unsubscribe],
+			true,
+		);
+		assert!(
+			executor.is_empty(),
+			"the polling work should have been cancelled"
+		);
+	}
+}
+
+/// rx_contract_closed_after_error - does not error
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness = TestHarness::<_, usize, Never>::new_with_source(
+			"from_stream",
+			from_stream(|| stream::iter(1..=3), scheduler),
+		);
+		let observable = harness.create_harness_observable();
+		harness.subscribe_to(observable);
+		executor.tick(Duration::from_millis(0));
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness = TestHarness::<_, usize, Never>::new_with_source(
+			"from_stream",
+			from_stream(stream::pending::<usize>, scheduler),
+		);
+		let observable = harness.create_harness_observable();
+		harness.subscribe_to(observable);
+		executor.tick(Duration::from_millis(0));
+		harness.get_subscription_mut().unsubscribe();
+		executor.tick(Duration::from_millis(0));
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+}
+
+mod into_stream {
+	use super::*;
+
+	#[test]
+	fn should_yield_every_value_then_end_on_completion() {
+		let values = future::block_on((1..=3).into_observable().into_stream().collect::<Vec<_>>());
+
+		assert_eq!(values, vec![Ok::<_, Never>(1), Ok(2), Ok(3)]);
+	}
+
+	#[test]
+	fn should_yield_the_error_then_end() {
+		let values = future::block_on(throw("error").into_stream().collect::<Vec<_>>());
+
+		assert_eq!(values, vec![Err("error")]);
+	}
+
+	#[test]
+	fn should_yield_every_value_emitted_before_the_error_then_the_error() {
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let observable_stream = source.clone().into_stream();
+
+		source.next(1);
+		source.next(2);
+		source.next(3);
+		source.error("error");
+
+		let values = future::block_on(observable_stream.collect::<Vec<_>>());
+
+		assert_eq!(values, vec![Ok(1), Ok(2), Ok(3), Err("error")]);
+	}
+
+	#[test]
+	fn should_be_pending_until_the_observable_emits() {
+		let mut source = PublishSubject::<usize>::default();
+		let mut observable_stream = source.clone().into_stream();
+
+		assert_eq!(
+			future::block_on(future::poll_once(observable_stream.next())),
+			None,
+			"should be pending"
+		);
+
+		source.next(1);
+
+		assert_eq!(
+			future::block_on(future::poll_once(observable_stream.next())),
+			Some(Some(Ok(1)))
+		);
+
+		source.complete();
+
+		assert_eq!(
+			future::block_on(future::poll_once(observable_stream.next())),
+			Some(None)
+		);
+	}
+
+	#[test]
+	fn should_ignore_values_over_the_queue_limit_when_configured_to() {
+		let values = future::block_on(
+			(1..=5)
+				.into_observable()
+				.into_stream_with_options(QueueOverflowOptions {
+					max_queue_length: 2,
+					overflow_behavior: QueueOverflowBehavior::IgnoreNext,
+				})
+				.collect::<Vec<_>>(),
+		);
+
+		assert_eq!(values, vec![Ok::<_, Never>(1), Ok(2)]);
+	}
+
+	#[test]
+	fn should_unsubscribe_from_the_source_when_dropped() {
+		let source = PublishSubject::<usize>::default();
+		let finalized = Arc::new(AtomicBool::new(false));
+		let finalized_clone = finalized.clone();
+
+		let observable_stream = source
+			.clone()
+			.finalize(move || finalized_clone.store(true, Ordering::Relaxed))
+			.into_stream();
+
+		assert!(!finalized.load(Ordering::Relaxed));
+
+		drop(observable_stream);
+
+		assert!(finalized.load(Ordering::Relaxed));
+	}
+}
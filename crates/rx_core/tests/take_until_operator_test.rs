@@ -0,0 +1,209 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicBool, Ordering},
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_mirror_upstream_until_the_notifier_emits() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.take_until(notifier.clone())
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	notifier.next(());
+	source.next(3);
+
+	assert!(subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"take_until",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_unsubscribe_upstream_and_the_notifier_when_the_notifier_emits() {
+	let upstream_finalized = Arc::new(AtomicBool::new(false));
+	let upstream_finalized_clone = upstream_finalized.clone();
+	let notifier_finalized = Arc::new(AtomicBool::new(false));
+	let notifier_finalized_clone = notifier_finalized.clone();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.finalize(move || upstream_finalized_clone.store(true, Ordering::Relaxed))
+		.take_until(
+			notifier
+				.clone()
+				.finalize(move || notifier_finalized_clone.store(true, Ordering::Relaxed)),
+		)
+		.subscribe(MockObserver::default());
+
+	notifier.next(());
+
+	assert!(upstream_finalized.load(Ordering::Relaxed));
+	assert!(notifier_finalized.load(Ordering::Relaxed));
+}
+
+#[test]
+fn should_complete_immediately_when_the_notifier_emits_on_subscribe() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.take_until(just(()).map_never())
+		.subscribe(destination);
+
+	source.next(1);
+
+	assert!(subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"take_until",
+		0,
+		[SubscriberNotification::Complete],
+		true,
+	);
+}
+
+#[test]
+fn should_not_complete_when_the_notifier_completes_without_emitting() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.take_until(notifier.clone())
+		.subscribe(destination);
+
+	notifier.complete();
+	source.next(1);
+
+	assert!(!subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"take_until",
+		0,
+		[SubscriberNotification::Next(1)],
+		true,
+	);
+}
+
+#[test]
+fn should_forward_notifier_errors() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.take_until(notifier.clone())
+		.subscribe(destination);
+
+	notifier.error("error");
+
+	assert!(subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"take_until",
+		0,
+		[SubscriberNotification::Error("error")],
+		true,
+	);
+}
+
+#[test]
+fn should_compose() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut notifier = PublishSubject::<(), &'static str>::default();
+
+	let composed = compose_operator::<usize, &'static str>().take_until(notifier.clone());
+
+	let subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	notifier.next(());
+	assert!(subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"take_until",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("take_until");
+		let observable = harness
+			.create_harness_observable()
+			.take_until(PublishSubject::<(), MockError>::default());
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("take_until");
+		let observable = harness
+			.create_harness_observable()
+			.take_until(PublishSubject::<(), MockError>::default());
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("take_until");
+		let observable = harness
+			.create_harness_observable()
+			.take_until(PublishSubject::<(), MockError>::default());
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
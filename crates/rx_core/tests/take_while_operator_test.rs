@@ -0,0 +1,160 @@
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_mirror_upstream_while_the_predicate_passes_then_complete() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.take_while(|next, _index| *next < 3, false)
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	source.next(3);
+	source.next(1);
+
+	assert!(subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"take_while",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_also_emit_the_first_failing_value_when_inclusive() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let subscription = source
+		.clone()
+		.take_while(|next, _index| *next < 3, true)
+		.subscribe(destination);
+
+	source.next(1);
+	source.next(3);
+	source.next(1);
+
+	assert!(subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"take_while",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(3),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_pass_the_index_to_the_predicate() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.take_while(|_next, index| index < 2, false)
+		.subscribe(destination);
+
+	source.next(10);
+	source.next(20);
+	source.next(30);
+
+	notification_collector.lock().assert_notifications(
+		"take_while",
+		0,
+		[
+			SubscriberNotification::Next(10),
+			SubscriberNotification::Next(20),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_compose() {
+	let destination = MockObserver::<usize, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let composed =
+		compose_operator::<usize, &'static str>().take_while(|next, _index| *next < 2, false);
+
+	let subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	source.next(2);
+	assert!(subscription.is_closed());
+
+	notification_collector.lock().assert_notifications(
+		"take_while",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("take_while");
+		let observable = harness
+			.create_harness_observable()
+			.take_while(|next, _index| *next < 10, false);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(MockError));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("take_while");
+		let observable = harness
+			.create_harness_observable()
+			.take_while(|next, _index| *next < 10, false);
+		harness.subscribe_to(observable);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, MockError>::new("take_while");
+		let observable = harness
+			.create_harness_observable()
+			.take_while(|next, _index| *next < 10, false);
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
@@ -1,13 +1,16 @@
 use std::{
 	sync::{
-		Arc,
+		Arc, Mutex,
 		atomic::{AtomicBool, AtomicUsize, Ordering},
 	},
 	time::Duration,
 };
 
 use rx_core::prelude::*;
-use rx_core_scheduler_ticking::{Tick, TickingScheduler, TickingSchedulerExecutor};
+use rx_core_scheduler_ticking::{
+	ScheduledWorkId, SchedulerForTickingExecutor, Tick, TickBudget, TickingExecutorMetrics,
+	TickingScheduler, TickingSchedulerExecutor,
+};
 
 use rx_core_scheduler_ticking::TickedInvokedWorkFactory;
 
@@ -21,6 +24,27 @@ struct TestContext;
 
 impl WorkContext<'_> for TestContext {}
 
+fn schedule_recorded_continuous_work(
+	scheduler: &mut impl Scheduler<Tick = Tick, WorkContextProvider = TestContextProvider>,
+	name: &'static str,
+	record: &Arc<Mutex<Vec<&'static str>>>,
+) -> WorkCancellationId {
+	let record = record.clone();
+	let cancellation_id = scheduler.generate_cancellation_id();
+	scheduler.schedule_continuous_work(
+		move |_, _| {
+			record.lock().unwrap().push(name);
+			WorkResult::Pending
+		},
+		cancellation_id,
+	);
+	cancellation_id
+}
+
+fn take_record(record: &Arc<Mutex<Vec<&'static str>>>) -> Vec<&'static str> {
+	std::mem::take(&mut *record.lock().unwrap())
+}
+
 fn mute_panic<R>(fun: impl FnOnce() -> R) -> R {
 	let hook = std::panic::take_hook();
 	std::panic::set_hook(Box::new(|_| {}));
@@ -493,3 +517,466 @@ mod invoked_work {
 		);
 	}
 }
+
+mod priority {
+	use super::*;
+
+	#[test]
+	fn should_tick_higher_priority_work_first() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default());
+
+		let mut context = TestContext;
+		let record = Arc::new(Mutex::new(Vec::new()));
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			schedule_recorded_continuous_work(
+				&mut scheduler.with_priority(WorkPriority::Low),
+				"low",
+				&record,
+			);
+			schedule_recorded_continuous_work(&mut *scheduler, "normal", &record);
+			schedule_recorded_continuous_work(
+				&mut scheduler.with_priority(WorkPriority::Critical),
+				"critical",
+				&record,
+			);
+			schedule_recorded_continuous_work(
+				&mut scheduler.with_priority(WorkPriority::High),
+				"high",
+				&record,
+			);
+		}
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+
+		assert_eq!(
+			take_record(&record),
+			vec!["critical", "high", "normal", "low"]
+		);
+	}
+
+	#[test]
+	fn should_tick_work_of_the_same_priority_in_the_order_they_were_scheduled() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default());
+
+		let mut context = TestContext;
+		let record = Arc::new(Mutex::new(Vec::new()));
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			let mut scheduler = scheduler.with_priority(WorkPriority::High);
+			schedule_recorded_continuous_work(&mut scheduler, "a", &record);
+			schedule_recorded_continuous_work(&mut scheduler, "b", &record);
+			schedule_recorded_continuous_work(&mut scheduler, "c", &record);
+		}
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+
+		assert_eq!(take_record(&record), vec!["a", "b", "c"]);
+	}
+
+	#[test]
+	fn should_order_work_scheduled_during_a_tick_by_priority() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default());
+
+		let mut context = TestContext;
+		let record = Arc::new(Mutex::new(Vec::new()));
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			schedule_recorded_continuous_work(&mut *scheduler, "normal", &record);
+		}
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert_eq!(take_record(&record), vec!["normal"]);
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			schedule_recorded_continuous_work(
+				&mut scheduler.with_priority(WorkPriority::High),
+				"high",
+				&record,
+			);
+		}
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert_eq!(take_record(&record), vec!["high", "normal"]);
+	}
+}
+
+mod tick_budget {
+	use super::*;
+
+	#[test]
+	fn should_spill_work_over_into_the_next_tick_when_the_work_budget_is_exhausted() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default())
+		.with_tick_budget(TickBudget::new().with_max_work(2));
+
+		let mut context = TestContext;
+		let record = Arc::new(Mutex::new(Vec::new()));
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			for name in ["a", "b", "c", "d", "e"] {
+				schedule_recorded_continuous_work(&mut *scheduler, name, &record);
+			}
+		}
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert_eq!(take_record(&record), vec!["a", "b"]);
+		assert_eq!(ticking_executor.get_metrics().ticked_work, 2);
+		assert_eq!(ticking_executor.get_metrics().spilled_over_work, 3);
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert_eq!(
+			take_record(&record),
+			vec!["c", "d"],
+			"Spilled over work should be ticked first!"
+		);
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert_eq!(take_record(&record), vec!["e", "a"]);
+	}
+
+	#[test]
+	fn should_spill_lower_priority_work_over_first() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default())
+		.with_tick_budget(TickBudget::new().with_max_work(1));
+
+		let mut context = TestContext;
+		let record = Arc::new(Mutex::new(Vec::new()));
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			schedule_recorded_continuous_work(
+				&mut scheduler.with_priority(WorkPriority::Low),
+				"low",
+				&record,
+			);
+			schedule_recorded_continuous_work(&mut *scheduler, "normal", &record);
+		}
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert_eq!(take_record(&record), vec!["normal"]);
+
+		ticking_executor.set_tick_budget(TickBudget::default());
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert_eq!(
+			take_record(&record),
+			vec!["normal", "low"],
+			"Spilled over work should not overtake higher priority work!"
+		);
+	}
+
+	#[test]
+	fn should_always_tick_critical_work() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default())
+		.with_tick_budget(TickBudget::new().with_max_work(0));
+
+		let mut context = TestContext;
+		let record = Arc::new(Mutex::new(Vec::new()));
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			schedule_recorded_continuous_work(&mut *scheduler, "normal", &record);
+			schedule_recorded_continuous_work(
+				&mut scheduler.with_priority(WorkPriority::Critical),
+				"critical",
+				&record,
+			);
+		}
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert_eq!(take_record(&record), vec!["critical"]);
+	}
+
+	#[test]
+	fn should_spill_work_over_when_the_time_budget_is_exhausted() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default())
+		.with_tick_budget(TickBudget::new().with_max_duration(Duration::ZERO));
+
+		let mut context = TestContext;
+		let record = Arc::new(Mutex::new(Vec::new()));
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			schedule_recorded_continuous_work(&mut *scheduler, "normal", &record);
+		}
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert!(take_record(&record).is_empty());
+		assert_eq!(ticking_executor.get_metrics().spilled_over_work, 1);
+	}
+
+	#[test]
+	fn should_execute_spilled_over_immediate_work_on_the_next_tick() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default())
+		.with_tick_budget(TickBudget::new().with_max_work(1));
+
+		let mut context = TestContext;
+		let execution_counter = Arc::new(AtomicUsize::default());
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			for _ in 0..2 {
+				let execution_counter = execution_counter.clone();
+				let cancellation_id = scheduler.generate_cancellation_id();
+				scheduler.schedule_immediate_work(
+					move |_, _| {
+						execution_counter.fetch_add(1, Ordering::Relaxed);
+					},
+					cancellation_id,
+				);
+			}
+		}
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert_eq!(execution_counter.load(Ordering::Relaxed), 1);
+		assert!(!ticking_executor.is_empty());
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		assert_eq!(execution_counter.load(Ordering::Relaxed), 2);
+		assert!(ticking_executor.is_empty());
+	}
+
+	#[test]
+	fn should_accumulate_metrics_across_ticks() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default())
+		.with_tick_budget(TickBudget::new().with_max_work(2));
+
+		let mut context = TestContext;
+		let record = Arc::new(Mutex::new(Vec::new()));
+
+		let cancellation_id = {
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			schedule_recorded_continuous_work(&mut *scheduler, "a", &record);
+			schedule_recorded_continuous_work(&mut *scheduler, "b", &record);
+			schedule_recorded_continuous_work(&mut *scheduler, "c", &record)
+		};
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+
+		ticking_executor
+			.get_scheduler_handle()
+			.lock()
+			.cancel(cancellation_id);
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+
+		assert_eq!(
+			ticking_executor.get_metrics(),
+			TickingExecutorMetrics {
+				ticked_work: 2,
+				spilled_over_work: 0,
+				total_ticked_work: 6,
+				total_spilled_over_work: 2,
+				ticks_with_spill_over: 2,
+			}
+		);
+	}
+}
+
+mod introspection {
+	use super::*;
+
+	#[test]
+	fn should_list_work_with_their_kind_and_next_due_tick() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default());
+
+		let mut context = TestContext;
+		let scheduler = ticking_executor.get_scheduler_handle();
+
+		let (delayed_id, repeated_id, continuous_id, invoke_id) = {
+			let mut scheduler = scheduler.lock();
+			let delayed_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_delayed_work(|_, _| {}, Duration::from_millis(500), delayed_id);
+			let repeated_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_repeated_work(
+				|_, _| WorkResult::Pending,
+				Duration::from_millis(200),
+				false,
+				1,
+				repeated_id,
+			);
+			let continuous_id = scheduler.generate_cancellation_id();
+			scheduler
+				.with_priority(WorkPriority::High)
+				.schedule_continuous_work(|_, _| WorkResult::Pending, continuous_id);
+			let invoke_id = scheduler.generate_invoke_id();
+			scheduler.schedule_invoked_work(
+				TickedInvokedWorkFactory::new(|_, _| WorkResult::Done),
+				invoke_id,
+			);
+			(delayed_id, repeated_id, continuous_id, invoke_id)
+		};
+
+		assert_eq!(ticking_executor.get_work_info().count(), 0);
+		assert_eq!(
+			scheduler
+				.lock()
+				.get_queued_work_info()
+				.map(|info| (info.id, info.kind))
+				.collect::<Vec<_>>(),
+			vec![
+				(ScheduledWorkId::Cancellation(delayed_id), WorkKind::Delayed),
+				(
+					ScheduledWorkId::Cancellation(repeated_id),
+					WorkKind::Repeated
+				),
+				(
+					ScheduledWorkId::Cancellation(continuous_id),
+					WorkKind::Continuous
+				),
+				(ScheduledWorkId::Invoke(invoke_id), WorkKind::Invoked),
+			]
+		);
+
+		ticking_executor.tick(Duration::from_millis(100), &mut context);
+
+		assert_eq!(scheduler.lock().get_queued_work_info().count(), 0);
+		assert_eq!(
+			ticking_executor
+				.get_work_info()
+				.map(|info| (info.id, info.kind, info.priority, info.next_due))
+				.collect::<Vec<_>>(),
+			vec![
+				(
+					ScheduledWorkId::Cancellation(continuous_id),
+					WorkKind::Continuous,
+					WorkPriority::High,
+					None
+				),
+				(
+					ScheduledWorkId::Cancellation(delayed_id),
+					WorkKind::Delayed,
+					WorkPriority::Normal,
+					Some(Tick::new(Duration::from_millis(500)))
+				),
+				(
+					ScheduledWorkId::Cancellation(repeated_id),
+					WorkKind::Repeated,
+					WorkPriority::Normal,
+					Some(Tick::new(Duration::from_millis(200)))
+				),
+				(
+					ScheduledWorkId::Invoke(invoke_id),
+					WorkKind::Invoked,
+					WorkPriority::Normal,
+					None
+				),
+			]
+		);
+
+		ticking_executor.tick(Duration::from_millis(150), &mut context);
+
+		let repeated_info = ticking_executor
+			.get_work_info()
+			.find(|info| info.id == ScheduledWorkId::Cancellation(repeated_id))
+			.unwrap();
+		assert_eq!(
+			repeated_info.next_due,
+			Some(Tick::new(Duration::from_millis(400)))
+		);
+	}
+
+	#[test]
+	fn should_count_how_many_times_a_work_was_ticked() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default());
+
+		let mut context = TestContext;
+		let record = Arc::new(Mutex::new(Vec::new()));
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			schedule_recorded_continuous_work(&mut *scheduler.lock(), "a", &record);
+		}
+
+		for _ in 0..3 {
+			ticking_executor.tick(Duration::from_millis(1), &mut context);
+		}
+
+		let info = ticking_executor.get_work_info().next().unwrap();
+		assert_eq!(info.stats.run_count, 3);
+		assert_eq!(
+			info.stats.execution_time,
+			Duration::ZERO,
+			"Execution time should not be tracked by default!"
+		);
+	}
+
+	#[test]
+	fn should_accumulate_execution_time_when_tracking_is_enabled() {
+		let mut ticking_executor = TickingSchedulerExecutor::<
+			TickingScheduler<TestContextProvider>,
+			TestContextProvider,
+		>::new(TickingScheduler::<TestContextProvider>::default())
+		.with_execution_time_tracking(true);
+
+		let mut context = TestContext;
+
+		{
+			let scheduler = ticking_executor.get_scheduler_handle();
+			let mut scheduler = scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_continuous_work(
+				|_, _| {
+					std::thread::sleep(Duration::from_millis(2));
+					WorkResult::Pending
+				},
+				cancellation_id,
+			);
+		}
+
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+		ticking_executor.tick(Duration::from_millis(1), &mut context);
+
+		let info = ticking_executor.get_work_info().next().unwrap();
+		assert_eq!(info.stats.run_count, 2);
+		assert!(info.stats.execution_time >= Duration::from_millis(4));
+	}
+}
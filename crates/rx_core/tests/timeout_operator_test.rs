@@ -0,0 +1,466 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::Duration,
+};
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+#[test]
+fn should_error_when_the_first_value_does_not_arrive_in_time() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, TimeoutError<&'static str>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let source = PublishSubject::<usize, &'static str>::default();
+	let subscription = source
+		.clone()
+		.timeout(
+			TimeoutConfig {
+				each: Some(Duration::from_millis(1000)),
+				..Default::default()
+			},
+			scheduler,
+		)
+		.subscribe(destination);
+
+	executor.tick(Duration::from_millis(999));
+	notification_collector.lock().assert_is_empty("timeout");
+
+	executor.tick(Duration::from_millis(1));
+	notification_collector.lock().assert_notifications(
+		"timeout",
+		0,
+		[SubscriberNotification::Error(TimeoutError::Timeout)],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_restart_the_timer_on_each_value() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, TimeoutError<&'static str>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.timeout(
+			TimeoutConfig {
+				each: Some(Duration::from_millis(1000)),
+				..Default::default()
+			},
+			scheduler,
+		)
+		.subscribe(destination);
+
+	executor.tick(Duration::from_millis(600));
+	source.next(1);
+	executor.tick(Duration::from_millis(600));
+	source.next(2);
+	executor.tick(Duration::from_millis(999));
+
+	notification_collector.lock().assert_notifications(
+		"timeout",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+		],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(1));
+	notification_collector.lock().assert_notifications(
+		"timeout",
+		2,
+		[SubscriberNotification::Error(TimeoutError::Timeout)],
+		true,
+	);
+}
+
+#[test]
+fn should_use_first_for_the_first_value_only() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, TimeoutError<&'static str>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let subscription = source
+		.clone()
+		.timeout(
+			TimeoutConfig {
+				first: Some(Duration::from_millis(1000)),
+				..Default::default()
+			},
+			scheduler,
+		)
+		.subscribe(destination);
+
+	executor.tick(Duration::from_millis(900));
+	source.next(1);
+	executor.tick(Duration::from_millis(5000));
+	source.next(2);
+
+	assert!(!subscription.is_closed());
+	notification_collector.lock().assert_notifications(
+		"timeout",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Next(2),
+		],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_use_first_and_each_separately() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, TimeoutError<&'static str>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.timeout(
+			TimeoutConfig {
+				first: Some(Duration::from_millis(5000)),
+				each: Some(Duration::from_millis(100)),
+				..Default::default()
+			},
+			scheduler,
+		)
+		.subscribe(destination);
+
+	executor.tick(Duration::from_millis(4000));
+	source.next(1);
+	executor.tick(Duration::from_millis(100));
+
+	notification_collector.lock().assert_notifications(
+		"timeout",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Error(TimeoutError::Timeout),
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_never_time_out_without_durations() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, TimeoutError<&'static str>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.timeout(TimeoutConfig::default(), scheduler)
+		.subscribe(destination);
+
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+
+	source.next(1);
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"timeout",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_forward_upstream_errors_and_cancel_the_timer() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, TimeoutError<&'static str>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let _subscription = source
+		.clone()
+		.timeout(
+			TimeoutConfig {
+				each: Some(Duration::from_millis(1000)),
+				..Default::default()
+			},
+			scheduler,
+		)
+		.subscribe(destination);
+
+	source.error("error");
+	executor.tick(Duration::from_millis(2000));
+
+	notification_collector.lock().assert_notifications(
+		"timeout",
+		0,
+		[SubscriberNotification::Error(TimeoutError::Upstream(
+			"error",
+		))],
+		true,
+	);
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+mod fallback {
+	use super::*;
+
+	#[test]
+	fn should_switch_to_the_fallback_and_unsubscribe_upstream() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let upstream_finalized = Arc::new(AtomicBool::new(false));
+		let upstream_finalized_clone = upstream_finalized.clone();
+
+		let destination = MockObserver::<usize, TimeoutError<&'static str>>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut source = PublishSubject::<usize, &'static str>::default();
+		let mut fallback = PublishSubject::<usize, &'static str>::default();
+		let subscription = source
+			.clone()
+			.finalize(move || upstream_finalized_clone.store(true, Ordering::Relaxed))
+			.timeout(
+				TimeoutConfig {
+					first: None,
+					each: Some(Duration::from_millis(1000)),
+					with: fallback.clone(),
+				},
+				scheduler,
+			)
+			.subscribe(destination);
+
+		source.next(1);
+		executor.tick(Duration::from_millis(1000));
+
+		assert!(upstream_finalized.load(Ordering::Relaxed));
+		assert!(!subscription.is_closed());
+
+		source.next(2);
+		fallback.next(10);
+		fallback.complete();
+
+		notification_collector.lock().assert_notifications(
+			"timeout",
+			0,
+			[
+				SubscriberNotification::Next(1),
+				SubscriberNotification::Next(10),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+		assert!(subscription.is_closed());
+	}
+
+	#[test]
+	fn should_forward_fallback_errors_as_upstream_errors() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let destination = MockObserver::<usize, TimeoutError<&'static str>>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let source = PublishSubject::<usize, &'static str>::default();
+		let mut fallback = PublishSubject::<usize, &'static str>::default();
+		let _subscription = source
+			.clone()
+			.timeout(
+				TimeoutConfig {
+					first: Some(Duration::from_millis(1000)),
+					each: None,
+					with: fallback.clone(),
+				},
+				scheduler,
+			)
+			.subscribe(destination);
+
+		executor.tick(Duration::from_millis(1000));
+		fallback.error("fallback error");
+
+		notification_collector.lock().assert_notifications(
+			"timeout",
+			0,
+			[SubscriberNotification::Error(TimeoutError::Upstream(
+				"fallback error",
+			))],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_unsubscribe_the_fallback_when_unsubscribed() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+
+		let fallback_finalized = Arc::new(AtomicBool::new(false));
+		let fallback_finalized_clone = fallback_finalized.clone();
+
+		let source = PublishSubject::<usize, &'static str>::default();
+		let fallback = PublishSubject::<usize, &'static str>::default();
+		let mut subscription = source
+			.clone()
+			.timeout(
+				TimeoutConfig {
+					first: None,
+					each: Some(Duration::from_millis(1000)),
+					with: fallback
+						.clone()
+						.finalize(move || fallback_finalized_clone.store(true, Ordering::Relaxed)),
+				},
+				scheduler,
+			)
+			.subscribe(MockObserver::default());
+
+		executor.tick(Duration::from_millis(1000));
+		assert!(!fallback_finalized.load(Ordering::Relaxed));
+
+		subscription.unsubscribe();
+		assert!(fallback_finalized.load(Ordering::Relaxed));
+	}
+}
+
+#[test]
+fn should_compose() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<usize, TimeoutError<&'static str>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let composed = compose_operator::<usize, &'static str>().timeout(
+		TimeoutConfig {
+			each: Some(Duration::from_millis(1000)),
+			..Default::default()
+		},
+		scheduler,
+	);
+
+	let _subscription = source.clone().pipe(composed).subscribe(destination);
+
+	source.next(1);
+	executor.tick(Duration::from_millis(1000));
+
+	notification_collector.lock().assert_notifications(
+		"timeout",
+		0,
+		[
+			SubscriberNotification::Next(1),
+			SubscriberNotification::Error(TimeoutError::Timeout),
+		],
+		true,
+	);
+}
+
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, TimeoutError<MockError>>::new(
+				"timeout",
+			);
+
+		let observable = harness.create_harness_observable().timeout(
+			TimeoutConfig {
+				each: Some(Duration::from_millis(10)),
+				..Default::default()
+			},
+			scheduler,
+		);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error(MockError);
+		harness.assert_terminal_notification(SubscriberNotification::Error(
+			TimeoutError::Upstream(MockError),
+		));
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, TimeoutError<MockError>>::new(
+				"timeout",
+			);
+
+		let observable = harness.create_harness_observable().timeout(
+			TimeoutConfig {
+				each: Some(Duration::from_millis(10)),
+				..Default::default()
+			},
+			scheduler,
+		);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, MockError>, usize, TimeoutError<MockError>>::new(
+				"timeout",
+			);
+
+		let observable = harness.create_harness_observable().timeout(
+			TimeoutConfig {
+				each: Some(Duration::from_millis(10)),
+				..Default::default()
+			},
+			scheduler,
+		);
+		harness.subscribe_to(observable);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+
+		executor.tick(Duration::from_millis(0));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+}
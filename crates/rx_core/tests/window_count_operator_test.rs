@@ -0,0 +1,232 @@
+use std::sync::{Arc, Mutex};
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, Signal, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+/// Collects each window into a `Vec` emitted once the window closes
+fn collect_window(
+	window: PublishObservable<usize, &'static str>,
+) -> impl Observable<Out = Vec<usize>, OutError = &'static str> + Signal {
+	window.reduce(
+		|accumulator: &Vec<usize>, next| {
+			let mut accumulator = accumulator.clone();
+			accumulator.push(next);
+			accumulator
+		},
+		Vec::new(),
+	)
+}
+
+#[test]
+fn should_split_upstream_into_windows_of_the_window_size() {
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let _subscription = source
+		.clone()
+		.window_count(2)
+		.merge_map(collect_window, usize::MAX, |error| error)
+		.subscribe(destination);
+
+	for i in 1..=5 {
+		source.next(i);
+	}
+
+	notification_collector.lock().assert_notifications(
+		"window_count",
+		0,
+		[
+			SubscriberNotification::Next(vec![1, 2]),
+			SubscriberNotification::Next(vec![3, 4]),
+		],
+		true,
+	);
+
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"window_count",
+		2,
+		[
+			SubscriberNotification::Next(vec![5]),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+#[test]
+fn should_open_the_first_window_on_subscribe_and_the_next_one_when_full() {
+	let destination = MockObserver::<PublishObservable<usize>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize>::default();
+
+	let _subscription = source.clone().window_count(2).subscribe(destination);
+
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 1);
+
+	source.next(1);
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 1);
+
+	source.next(2);
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 2);
+	assert!(
+		notification_collector
+			.lock()
+			.nth_notification_as_next(0)
+			.is_closed(),
+		"the first window should be completed"
+	);
+
+	source.complete();
+	assert!(
+		notification_collector
+			.lock()
+			.nth_notification_as_next(1)
+			.is_closed(),
+		"the empty window should be completed too"
+	);
+}
+
+#[test]
+fn should_error_the_open_window_and_downstream() {
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+
+	let mut subscription = source
+		.clone()
+		.window_count(3)
+		.merge_map(collect_window, usize::MAX, |error| error)
+		.subscribe(destination);
+	let tracked_teardown = subscription.add_tracked_teardown("window_count");
+
+	source.next(1);
+	source.error("error");
+
+	assert!(subscription.is_closed());
+	tracked_teardown.assert_was_torn_down();
+
+	notification_collector.lock().assert_notifications(
+		"window_count",
+		0,
+		[SubscriberNotification::Error("error")],
+		true,
+	);
+}
+
+#[test]
+fn should_close_the_open_window_when_unsubscribed() {
+	let windows = Arc::new(Mutex::new(Vec::<PublishObservable<usize>>::new()));
+	let windows_clone = windows.clone();
+
+	let mut source = PublishSubject::<usize>::default();
+
+	let mut subscription = source
+		.clone()
+		.window_count(2)
+		.tap_next(move |window| windows_clone.lock().unwrap().push(window.clone()))
+		.subscribe(MockObserver::default());
+
+	source.next(1);
+	subscription.unsubscribe();
+
+	let windows = windows.lock().unwrap();
+	assert_eq!(windows.len(), 1);
+	assert!(windows[0].is_closed());
+}
+
+#[test]
+fn should_treat_a_window_size_of_zero_as_one() {
+	let destination = MockObserver::<Vec<usize>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let _subscription = (1..=2)
+		.into_observable()
+		.window_count(0)
+		.merge_map(
+			|window: PublishObservable<usize>| {
+				window.reduce(
+					|accumulator: &Vec<usize>, next| {
+						let mut accumulator = accumulator.clone();
+						accumulator.push(next);
+						accumulator
+					},
+					Vec::new(),
+				)
+			},
+			usize::MAX,
+			|error| error,
+		)
+		.subscribe(destination);
+
+	notification_collector.lock().assert_notifications(
+		"window_count",
+		0,
+		[
+			SubscriberNotification::Next(vec![1]),
+			SubscriberNotification::Next(vec![2]),
+			SubscriberNotification::Next(vec![]),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+}
+
+/// Windows are flattened for these, as they can't be compared.
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"window_count",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.window_count(2)
+			.merge_map(collect_window, usize::MAX, |error| error);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error"));
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"window_count",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.window_count(2)
+			.merge_map(collect_window, usize::MAX, |error| error);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"window_count",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.window_count(2)
+			.merge_map(collect_window, usize::MAX, |error| error);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+	}
+}
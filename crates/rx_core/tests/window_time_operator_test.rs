@@ -0,0 +1,245 @@
+use std::time::Duration;
+
+use rx_core::prelude::*;
+use rx_core_common::{Observable, Signal, SubscriberNotification, SubscriptionLike};
+use rx_core_testing::prelude::*;
+
+/// Collects each window into a `Vec` emitted once the window closes
+fn collect_window(
+	window: PublishObservable<usize, &'static str>,
+) -> impl Observable<Out = Vec<usize>, OutError = &'static str> + Signal {
+	window.reduce(
+		|accumulator: &Vec<usize>, next| {
+			let mut accumulator = accumulator.clone();
+			accumulator.push(next);
+			accumulator
+		},
+		Vec::new(),
+	)
+}
+
+#[test]
+fn should_close_and_reopen_the_window_every_duration() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut subscription = source
+		.clone()
+		.window_time(Duration::from_millis(1000), scheduler)
+		.merge_map(collect_window, usize::MAX, |error| error)
+		.subscribe(destination);
+
+	source.next(1);
+	executor.tick(Duration::from_millis(500));
+	source.next(2);
+
+	notification_collector.lock().assert_is_empty("window_time");
+
+	executor.tick(Duration::from_millis(500));
+	notification_collector.lock().assert_notifications(
+		"window_time",
+		0,
+		[SubscriberNotification::Next(vec![1, 2])],
+		true,
+	);
+
+	source.next(3);
+	executor.tick(Duration::from_millis(1000));
+	notification_collector.lock().assert_notifications(
+		"window_time",
+		1,
+		[SubscriberNotification::Next(vec![3])],
+		true,
+	);
+
+	executor.tick(Duration::from_millis(1000));
+	notification_collector.lock().assert_notifications(
+		"window_time",
+		2,
+		[SubscriberNotification::Next(vec![])],
+		true,
+	);
+
+	subscription.unsubscribe();
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_emit_the_first_window_on_subscribe() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<PublishObservable<usize>>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let _subscription = PublishSubject::<usize>::default()
+		.window_time(Duration::from_millis(1000), scheduler)
+		.subscribe(destination);
+
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 1);
+
+	executor.tick(Duration::from_millis(1000));
+	assert_eq!(notification_collector.lock().count_observed_nexts(), 2);
+	assert!(
+		notification_collector
+			.lock()
+			.nth_notification_as_next(0)
+			.is_closed()
+	);
+}
+
+#[test]
+fn should_complete_the_open_window_when_upstream_completes() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let subscription = source
+		.clone()
+		.window_time(Duration::from_millis(1000), scheduler)
+		.merge_map(collect_window, usize::MAX, |error| error)
+		.subscribe(destination);
+
+	source.next(1);
+	executor.tick(Duration::from_millis(200));
+	source.complete();
+
+	notification_collector.lock().assert_notifications(
+		"window_time",
+		0,
+		[
+			SubscriberNotification::Next(vec![1]),
+			SubscriberNotification::Complete,
+		],
+		true,
+	);
+
+	assert!(subscription.is_closed());
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_error_the_open_window_and_downstream() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let destination = MockObserver::<Vec<usize>, &'static str>::default();
+	let notification_collector = destination.get_notification_collector();
+
+	let mut source = PublishSubject::<usize, &'static str>::default();
+	let mut subscription = source
+		.clone()
+		.window_time(Duration::from_millis(1000), scheduler)
+		.merge_map(collect_window, usize::MAX, |error| error)
+		.subscribe(destination);
+	let tracked_teardown = subscription.add_tracked_teardown("window_time");
+
+	source.next(1);
+	source.error("error");
+
+	notification_collector.lock().assert_notifications(
+		"window_time",
+		0,
+		[SubscriberNotification::Error("error")],
+		true,
+	);
+
+	tracked_teardown.assert_was_torn_down();
+	executor.tick(Duration::from_millis(0));
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+#[test]
+fn should_cancel_the_scheduled_work_when_unsubscribed() {
+	let mut executor = MockExecutor::default();
+	let scheduler = executor.get_scheduler_handle();
+
+	let mut subscription = PublishSubject::<usize>::default()
+		.window_time(Duration::from_millis(1000), scheduler)
+		.subscribe(MockObserver::default());
+
+	executor.tick(Duration::from_millis(0));
+	assert!(!executor.is_empty());
+
+	subscription.unsubscribe();
+	executor.tick(Duration::from_millis(0));
+
+	assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+}
+
+/// Windows are flattened for these, as they can't be compared.
+mod contracts {
+	use super::*;
+
+	#[test]
+	fn rx_contract_closed_after_error() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"window_time",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.window_time(Duration::from_millis(1000), scheduler.clone())
+			.merge_map(collect_window, usize::MAX, |error| error);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().error("error");
+		harness.assert_terminal_notification(SubscriberNotification::Error("error"));
+
+		executor.tick(Duration::from_millis(2000));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_complete() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"window_time",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.window_time(Duration::from_millis(1000), scheduler.clone())
+			.merge_map(collect_window, usize::MAX, |error| error);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.source().complete();
+		harness.assert_terminal_notification(SubscriberNotification::Complete);
+
+		executor.tick(Duration::from_millis(2000));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+
+	#[test]
+	fn rx_contract_closed_after_unsubscribe() {
+		let mut executor = MockExecutor::default();
+		let scheduler = executor.get_scheduler_handle();
+		let mut harness =
+			TestHarness::<TestSubject<usize, &'static str>, Vec<usize>, &'static str>::new(
+				"window_time",
+			);
+		let observable = harness
+			.create_harness_observable()
+			.window_time(Duration::from_millis(1000), scheduler.clone())
+			.merge_map(collect_window, usize::MAX, |error| error);
+		harness.subscribe_to(observable);
+		harness.source().next(1);
+		harness.get_subscription_mut().unsubscribe();
+		harness.assert_terminal_notification(SubscriberNotification::Unsubscribe);
+
+		executor.tick(Duration::from_millis(2000));
+		assert!(executor.is_empty(), "rx_verify_scheduler_is_empty");
+	}
+}
@@ -415,3 +415,138 @@ mod errors {
 		);
 	}
 }
+
+mod variadic {
+	use super::*;
+
+	#[test]
+	fn should_pair_up_emissions_of_three_observables_in_order() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subject_1 = PublishSubject::<usize>::default();
+		let mut subject_2 = PublishSubject::<&'static str>::default();
+		let mut subject_3 = PublishSubject::<bool>::default();
+
+		let _s =
+			zip3(subject_1.clone(), subject_2.clone(), subject_3.clone()).subscribe(destination);
+
+		subject_1.next(1);
+		subject_1.next(2);
+		subject_2.next("a");
+		subject_3.next(true);
+		subject_2.next("b");
+		subject_3.next(false);
+
+		notification_collector.lock().assert_notifications(
+			"zip3",
+			0,
+			[
+				SubscriberNotification::Next((1, "a", true)),
+				SubscriberNotification::Next((2, "b", false)),
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_zip_eight_observables() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let _s = zip8(
+			(1..=3).into_observable(),
+			(1..=3).into_observable(),
+			(1..=3).into_observable(),
+			(1..=3).into_observable(),
+			(1..=3).into_observable(),
+			(1..=3).into_observable(),
+			(1..=3).into_observable(),
+			(1..=3).into_observable(),
+		)
+		.subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"zip8",
+			0,
+			[
+				SubscriberNotification::Next((1, 1, 1, 1, 1, 1, 1, 1)),
+				SubscriberNotification::Next((2, 2, 2, 2, 2, 2, 2, 2)),
+				SubscriberNotification::Next((3, 3, 3, 3, 3, 3, 3, 3)),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+}
+
+mod array {
+	use super::*;
+
+	#[test]
+	fn should_pair_up_emissions_into_a_vec_in_order() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let mut subjects = [
+			PublishSubject::<usize>::default(),
+			PublishSubject::<usize>::default(),
+			PublishSubject::<usize>::default(),
+		];
+
+		let _s = zip_array(subjects.clone()).subscribe(destination);
+
+		subjects[0].next(1);
+		subjects[0].next(2);
+		subjects[1].next(10);
+		subjects[2].next(100);
+		subjects[1].next(20);
+		subjects[2].next(200);
+
+		notification_collector.lock().assert_notifications(
+			"zip_array",
+			0,
+			[
+				SubscriberNotification::Next(vec![1, 10, 100]),
+				SubscriberNotification::Next(vec![2, 20, 200]),
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_accept_a_vec_and_complete_when_all_completed() {
+		let destination = MockObserver::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let _s = zip_array(vec![(1..=2).into_observable(), (4..=5).into_observable()])
+			.subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"zip_array",
+			0,
+			[
+				SubscriberNotification::Next(vec![1, 4]),
+				SubscriberNotification::Next(vec![2, 5]),
+				SubscriberNotification::Complete,
+			],
+			true,
+		);
+	}
+
+	#[test]
+	fn should_complete_immediately_when_empty() {
+		let destination = MockObserver::<Vec<usize>>::default();
+		let notification_collector = destination.get_notification_collector();
+
+		let subscription = zip_array(Vec::<PublishSubject<usize>>::new()).subscribe(destination);
+
+		notification_collector.lock().assert_notifications(
+			"zip_array",
+			0,
+			[SubscriberNotification::Complete],
+			false,
+		);
+		assert!(subscription.is_closed());
+	}
+}
@@ -1,17 +1,27 @@
 mod executor;
+mod prioritized_scheduler;
 mod scheduled_work;
 mod scheduler;
+mod scheduler_clock;
 mod scheduler_handle;
 mod work;
 mod work_cancellation_id;
 mod work_context;
 mod work_invoke_id;
+mod work_invoke_waker;
+mod work_kind;
+mod work_priority;
 
 pub use executor::*;
+pub use prioritized_scheduler::*;
 pub use scheduled_work::*;
 pub use scheduler::*;
+pub use scheduler_clock::*;
 pub use scheduler_handle::*;
 pub use work::*;
 pub use work_cancellation_id::*;
 pub use work_context::*;
 pub use work_invoke_id::*;
+pub use work_invoke_waker::*;
+pub use work_kind::*;
+pub use work_priority::*;
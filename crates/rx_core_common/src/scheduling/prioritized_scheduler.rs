@@ -0,0 +1,118 @@
+use crate::{
+	PrioritizedWork, ScheduledWork, Scheduler, SchedulerClock, WithWorkContextProvider,
+	WithWorkInputOutput, WorkCancellationId, WorkInvokeId, WorkPriority,
+};
+
+/// A view into a scheduler that schedules every work with the same
+/// [`WorkPriority`].
+///
+/// Acquire it using [`SchedulerPriorityExtension::with_priority`], then use
+/// it like the scheduler itself.
+#[derive(Debug)]
+pub struct PrioritizedScheduler<'s, S>
+where
+	S: Scheduler,
+{
+	scheduler: &'s mut S,
+	priority: WorkPriority,
+}
+
+impl<S> WithWorkInputOutput for PrioritizedScheduler<'_, S>
+where
+	S: Scheduler,
+{
+	type Tick = S::Tick;
+}
+
+impl<S> WithWorkContextProvider for PrioritizedScheduler<'_, S>
+where
+	S: Scheduler,
+{
+	type WorkContextProvider = S::WorkContextProvider;
+}
+
+impl<S> Scheduler for PrioritizedScheduler<'_, S>
+where
+	S: Scheduler,
+{
+	type DelayedWorkFactory = S::DelayedWorkFactory;
+	type RepeatedWorkFactory = S::RepeatedWorkFactory;
+	type ContinuousWorkFactory = S::ContinuousWorkFactory;
+	type ImmediateWorkFactory = S::ImmediateWorkFactory;
+	type InvokedWorkFactory = S::InvokedWorkFactory;
+
+	#[inline]
+	fn schedule_work<W>(&mut self, work: W, cancellation_id: WorkCancellationId)
+	where
+		W: 'static
+			+ ScheduledWork<Tick = Self::Tick, WorkContextProvider = Self::WorkContextProvider>
+			+ Send
+			+ Sync,
+	{
+		self.scheduler
+			.schedule_work(PrioritizedWork::new(work, self.priority), cancellation_id);
+	}
+
+	#[inline]
+	fn schedule_invoked_work<W>(&mut self, work: W, invoke_id: WorkInvokeId)
+	where
+		W: 'static
+			+ ScheduledWork<Tick = Self::Tick, WorkContextProvider = Self::WorkContextProvider>
+			+ Send
+			+ Sync,
+	{
+		self.scheduler
+			.schedule_invoked_work(PrioritizedWork::new(work, self.priority), invoke_id);
+	}
+
+	#[inline]
+	fn cancel(&mut self, cancellation_id: WorkCancellationId) {
+		self.scheduler.cancel(cancellation_id);
+	}
+
+	#[inline]
+	fn invoke(&mut self, invoke_id: WorkInvokeId) {
+		self.scheduler.invoke(invoke_id);
+	}
+
+	#[inline]
+	fn cancel_invoked(&mut self, invoke_id: WorkInvokeId) {
+		self.scheduler.cancel_invoked(invoke_id);
+	}
+
+	#[inline]
+	fn generate_cancellation_id(&mut self) -> WorkCancellationId {
+		self.scheduler.generate_cancellation_id()
+	}
+
+	#[inline]
+	fn generate_invoke_id(&mut self) -> WorkInvokeId {
+		self.scheduler.generate_invoke_id()
+	}
+}
+
+impl<S> SchedulerClock for PrioritizedScheduler<'_, S>
+where
+	S: SchedulerClock,
+{
+	#[inline]
+	fn current_tick(&self) -> Self::Tick {
+		self.scheduler.current_tick()
+	}
+}
+
+pub trait SchedulerPriorityExtension: Scheduler + Sized {
+	/// Returns a view of this scheduler that schedules every work with the
+	/// given `priority`.
+	///
+	/// All other [`SchedulerScheduleWorkExtension`][crate::SchedulerScheduleWorkExtension]
+	/// methods are available on it too.
+	fn with_priority(&mut self, priority: WorkPriority) -> PrioritizedScheduler<'_, Self> {
+		PrioritizedScheduler {
+			scheduler: self,
+			priority,
+		}
+	}
+}
+
+impl<S> SchedulerPriorityExtension for S where S: Scheduler {}
@@ -1,6 +1,8 @@
 use core::{fmt::Debug, ops::AddAssign};
 
-use crate::{WithWorkContextProvider, WithWorkInputOutput, WorkContextProvider};
+use crate::{
+	WithWorkContextProvider, WithWorkInputOutput, WorkContextProvider, WorkKind, WorkPriority,
+};
 
 pub trait ScheduledWork: WithWorkInputOutput + WithWorkContextProvider {
 	fn tick(
@@ -12,6 +14,27 @@ pub trait ScheduledWork: WithWorkInputOutput + WithWorkContextProvider {
 	/// This hook is called once when the work enters the executor during the
 	/// first tick after being scheduled.
 	fn on_scheduled_hook(&mut self, tick_input: Self::Tick);
+
+	/// Describes when this work gets executed, used for introspection only.
+	#[inline]
+	fn kind(&self) -> WorkKind {
+		WorkKind::Other
+	}
+
+	/// The earliest tick this work is expected to execute on. `None` when
+	/// it's simply executed on the next tick, or only when invoked.
+	#[inline]
+	fn next_due(&self) -> Option<Self::Tick> {
+		None
+	}
+
+	/// The priority of this work. Executors that support prioritization
+	/// tick higher priority work first, and may defer lower priority work to
+	/// a later tick when they run out of budget.
+	#[inline]
+	fn priority(&self) -> WorkPriority {
+		WorkPriority::default()
+	}
 }
 
 #[derive(Debug)]
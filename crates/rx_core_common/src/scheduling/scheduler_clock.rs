@@ -0,0 +1,8 @@
+use crate::Scheduler;
+
+/// Schedulers that know where their executor currently is in time. This lets
+/// time-based primitives measure elapsed time without scheduling any work.
+pub trait SchedulerClock: Scheduler {
+	/// The tick the executor is currently at.
+	fn current_tick(&self) -> Self::Tick;
+}
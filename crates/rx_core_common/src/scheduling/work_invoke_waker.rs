@@ -0,0 +1,50 @@
+use std::{
+	sync::Arc,
+	task::{Wake, Waker},
+};
+
+use crate::{Scheduler, SchedulerHandle, WorkInvokeId};
+
+/// # [WorkInvokeWaker]
+///
+/// A [Waker] that invokes an invoked work on its scheduler when woken.
+///
+/// Lets futures and streams be polled from scheduled work, without having to
+/// poll them on every tick: the work only runs again once whatever it's
+/// waiting for signals that it can make progress.
+pub struct WorkInvokeWaker<S>
+where
+	S: Scheduler,
+{
+	invoke_id: WorkInvokeId,
+	scheduler: SchedulerHandle<S>,
+}
+
+impl<S> WorkInvokeWaker<S>
+where
+	S: 'static + Scheduler,
+{
+	pub fn new(invoke_id: WorkInvokeId, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			invoke_id,
+			scheduler,
+		}
+	}
+
+	pub fn into_waker(self) -> Waker {
+		Waker::from(Arc::new(self))
+	}
+}
+
+impl<S> Wake for WorkInvokeWaker<S>
+where
+	S: 'static + Scheduler,
+{
+	fn wake(self: Arc<Self>) {
+		self.wake_by_ref();
+	}
+
+	fn wake_by_ref(self: &Arc<Self>) {
+		self.scheduler.lock().invoke(self.invoke_id);
+	}
+}
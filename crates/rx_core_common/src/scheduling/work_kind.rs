@@ -0,0 +1,16 @@
+/// Describes when a scheduled work gets executed.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub enum WorkKind {
+	/// Executed once, as soon as the executor receives it.
+	Immediate,
+	/// Executed once, after its delay had passed.
+	Delayed,
+	/// Executed each time its interval had passed.
+	Repeated,
+	/// Executed every time the executor ticks.
+	Continuous,
+	/// Executed only when invoked.
+	Invoked,
+	/// Custom work that doesn't describe when it gets executed.
+	Other,
+}
@@ -0,0 +1,109 @@
+use derive_where::derive_where;
+
+use crate::{
+	ScheduledWork, WithWorkContextProvider, WithWorkInputOutput, WorkContextProvider, WorkKind,
+	WorkResult,
+};
+
+/// The priority level of a scheduled work.
+///
+/// Executors supporting prioritization tick higher priority work first.
+/// Within the same priority level, work is ticked in the order it was
+/// scheduled.
+#[derive(Clone, Copy, Hash, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WorkPriority {
+	/// The first to spill over into the next tick when the executor runs out
+	/// of its per-tick budget.
+	Low,
+	#[default]
+	Normal,
+	High,
+	/// Critical work is always executed, even if the per-tick budget of the
+	/// executor is already exhausted.
+	Critical,
+}
+
+/// Overrides the [`WorkPriority`] of the wrapped work.
+#[derive_where(Debug)]
+pub struct PrioritizedWork<W>
+where
+	W: ScheduledWork,
+{
+	#[derive_where(skip(Debug))]
+	work: W,
+	priority: WorkPriority,
+}
+
+impl<W> PrioritizedWork<W>
+where
+	W: ScheduledWork,
+{
+	pub fn new(work: W, priority: WorkPriority) -> Self {
+		Self { work, priority }
+	}
+}
+
+impl<W> WithWorkInputOutput for PrioritizedWork<W>
+where
+	W: ScheduledWork,
+{
+	type Tick = W::Tick;
+}
+
+impl<W> WithWorkContextProvider for PrioritizedWork<W>
+where
+	W: ScheduledWork,
+{
+	type WorkContextProvider = W::WorkContextProvider;
+}
+
+impl<W> ScheduledWork for PrioritizedWork<W>
+where
+	W: ScheduledWork,
+{
+	#[inline]
+	fn tick(
+		&mut self,
+		input: Self::Tick,
+		context: &mut <Self::WorkContextProvider as WorkContextProvider>::Item<'_>,
+	) -> WorkResult {
+		self.work.tick(input, context)
+	}
+
+	#[inline]
+	fn on_scheduled_hook(&mut self, tick_input: Self::Tick) {
+		self.work.on_scheduled_hook(tick_input);
+	}
+
+	#[inline]
+	fn kind(&self) -> WorkKind {
+		self.work.kind()
+	}
+
+	#[inline]
+	fn next_due(&self) -> Option<Self::Tick> {
+		self.work.next_due()
+	}
+
+	#[inline]
+	fn priority(&self) -> WorkPriority {
+		self.priority
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::WorkPriority;
+
+	#[test]
+	fn should_order_priorities_from_low_to_critical() {
+		assert!(WorkPriority::Low < WorkPriority::Normal);
+		assert!(WorkPriority::Normal < WorkPriority::High);
+		assert!(WorkPriority::High < WorkPriority::Critical);
+	}
+
+	#[test]
+	fn should_default_to_normal() {
+		assert_eq!(WorkPriority::default(), WorkPriority::Normal);
+	}
+}
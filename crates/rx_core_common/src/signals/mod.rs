@@ -1,6 +1,7 @@
 mod never;
 mod notification_observer;
 mod notification_observer_terminal;
+mod notification_recorded;
 mod notification_subscriber;
 mod notification_subscription;
 mod signal;
@@ -8,6 +9,7 @@ mod signal;
 pub use never::*;
 pub use notification_observer::*;
 pub use notification_observer_terminal::*;
+pub use notification_recorded::*;
 pub use notification_subscriber::*;
 pub use notification_subscription::*;
 pub use signal::*;
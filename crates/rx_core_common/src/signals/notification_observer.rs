@@ -13,6 +13,7 @@ use crate::{Never, ObserverTerminalNotification, RxObserver, Signal, SubscriberN
 /// - Can try to convert from a [SubscriberNotification]
 ///
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObserverNotification<In, InError = Never>
 where
 	In: Signal,
@@ -14,6 +14,7 @@ use crate::{Never, ObserverNotification, RxObserver, Signal, SubscriberNotificat
 /// - Can try to convert from a [SubscriberNotification]
 ///
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObserverTerminalNotification<InError = Never>
 where
 	InError: Signal,
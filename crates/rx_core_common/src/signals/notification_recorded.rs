@@ -0,0 +1,88 @@
+use core::time::Duration;
+
+use crate::{Never, ObserverNotification, Signal};
+
+/// # [RecordedNotification]
+///
+/// An [ObserverNotification] paired with the time elapsed since the
+/// recording started, as written by the `record` operator and read by the
+/// `ReplayFileObservable`.
+///
+/// - With the `serde` feature it's serialized as a compact
+///   `[elapsed_nanos, notification]` pair.
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedNotification<In, InError = Never>(
+	#[cfg_attr(feature = "serde", serde(with = "elapsed_nanos"))] pub Duration,
+	pub ObserverNotification<In, InError>,
+)
+where
+	In: Signal,
+	InError: Signal;
+
+impl<In, InError> RecordedNotification<In, InError>
+where
+	In: Signal,
+	InError: Signal,
+{
+	pub fn new(elapsed: Duration, notification: ObserverNotification<In, InError>) -> Self {
+		Self(elapsed, notification)
+	}
+
+	#[inline]
+	pub fn elapsed(&self) -> Duration {
+		self.0
+	}
+
+	#[inline]
+	pub fn notification(&self) -> &ObserverNotification<In, InError> {
+		&self.1
+	}
+
+	#[inline]
+	pub fn into_notification(self) -> ObserverNotification<In, InError> {
+		self.1
+	}
+}
+
+/// Durations are stored as whole nanoseconds, which is plenty for about 584
+/// years of recording.
+#[cfg(feature = "serde")]
+mod elapsed_nanos {
+	use core::time::Duration;
+
+	use serde::{Deserialize, Deserializer, Serializer};
+
+	pub fn serialize<S>(elapsed: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.serialize_u64(elapsed.as_nanos() as u64)
+	}
+
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		u64::deserialize(deserializer).map(Duration::from_nanos)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use core::time::Duration;
+
+	use crate::{ObserverNotification, RecordedNotification};
+
+	#[test]
+	fn should_expose_its_parts() {
+		let recorded = RecordedNotification::<usize>::new(
+			Duration::from_millis(10),
+			ObserverNotification::Next(1),
+		);
+		assert_eq!(recorded.elapsed(), Duration::from_millis(10));
+		assert_eq!(recorded.notification(), &ObserverNotification::Next(1));
+		assert_eq!(recorded.into_notification(), ObserverNotification::Next(1));
+	}
+}
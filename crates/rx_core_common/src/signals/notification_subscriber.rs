@@ -16,6 +16,7 @@ use crate::{
 /// - Can try to convert into a [SubscriptionNotification]
 ///
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubscriberNotification<In, InError = Never>
 where
 	In: Signal,
@@ -12,6 +12,7 @@ use crate::{Signal, SubscriberNotification, SubscriptionLike};
 /// - Can try to convert from [SubscriberNotification]
 ///
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubscriptionNotification {
 	Unsubscribe,
 }
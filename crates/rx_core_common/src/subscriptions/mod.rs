@@ -1,10 +1,13 @@
 mod subscription;
 mod subscription_closed_flag;
 mod subscription_data;
+mod subscription_debug_id;
 mod subscription_erased;
 mod subscription_extension_share;
 mod subscription_like;
 mod subscription_option;
+#[cfg(feature = "debug_subscriptions")]
+mod subscription_registry;
 mod subscription_shared;
 mod subscription_with_teardown;
 mod teardown;
@@ -13,10 +16,13 @@ mod teardown_collection;
 pub use subscription::*;
 pub use subscription_closed_flag::*;
 pub use subscription_data::*;
+pub use subscription_debug_id::*;
 pub use subscription_erased::*;
 pub use subscription_extension_share::*;
 pub use subscription_like::*;
 pub use subscription_option::*;
+#[cfg(feature = "debug_subscriptions")]
+pub use subscription_registry::*;
 pub use subscription_shared::*;
 pub use subscription_with_teardown::*;
 pub use teardown::*;
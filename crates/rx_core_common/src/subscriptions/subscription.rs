@@ -20,10 +20,15 @@ impl<Destination> Subscription<Destination>
 where
 	Destination: Subscriber,
 {
+	#[track_caller]
 	pub fn new(destination: Destination) -> Self {
+		let teardown = SubscriptionData::default();
+		#[cfg(feature = "debug_subscriptions")]
+		teardown.register_as::<Self>();
+
 		Self {
 			destination,
-			teardown: SubscriptionData::default(),
+			teardown,
 		}
 	}
 }
@@ -1,7 +1,12 @@
 use disqualified::ShortName;
 
-use crate::{SubscriptionClosedFlag, SubscriptionLike, Teardown, TeardownCollection};
-use std::{fmt::Debug, vec};
+use crate::{
+	SubscriptionClosedFlag, SubscriptionDebugId, SubscriptionLike, Teardown, TeardownCollection,
+};
+use std::fmt::Debug;
+
+#[cfg(feature = "debug_subscriptions")]
+use crate::SubscriptionRegistry;
 
 /// The internal subscription implementation commonly used by other subscription
 /// implementations.
@@ -14,29 +19,49 @@ use std::{fmt::Debug, vec};
 /// upon which the collection is drained, and the closures are called,
 /// effectively dropping everything held by the subscription before the
 /// subscription itself is dropped.
+///
+/// With the `debug_subscriptions` feature enabled, every [SubscriptionData]
+/// is tracked in the [SubscriptionRegistry][crate::SubscriptionRegistry]
+/// until it's closed.
 pub struct SubscriptionData {
 	closed_flag: SubscriptionClosedFlag,
 	finalizers: Vec<Box<dyn FnOnce() + Send + Sync>>,
+	#[cfg(feature = "debug_subscriptions")]
+	debug_id: SubscriptionDebugId,
 }
 
 impl SubscriptionData {
+	#[track_caller]
 	pub fn new_with_teardown(teardown: Teardown) -> Self {
-		if let Some(teardown) = teardown.take() {
-			Self {
-				closed_flag: false.into(),
-				finalizers: vec![teardown],
-			}
-		} else {
-			Self::default()
-		}
+		let mut subscription = Self::default();
+		subscription.add_teardown(teardown);
+		subscription
+	}
+
+	/// Registers the subscription wrapping this [SubscriptionData] in its
+	/// place in the [SubscriptionRegistry].
+	#[cfg(feature = "debug_subscriptions")]
+	#[track_caller]
+	pub(crate) fn register_as<S>(&self) {
+		SubscriptionRegistry::relabel(
+			self.debug_id,
+			ShortName::of::<S>().to_string(),
+			std::panic::Location::caller(),
+		);
 	}
 }
 
 impl Default for SubscriptionData {
+	#[track_caller]
 	fn default() -> Self {
 		Self {
 			finalizers: Vec::new(),
 			closed_flag: false.into(),
+			#[cfg(feature = "debug_subscriptions")]
+			debug_id: SubscriptionRegistry::register(
+				ShortName::of::<Self>().to_string(),
+				std::panic::Location::caller(),
+			),
 		}
 	}
 }
@@ -51,11 +76,27 @@ impl SubscriptionLike for SubscriptionData {
 		if !self.is_closed() {
 			self.closed_flag.close();
 
+			#[cfg(feature = "debug_subscriptions")]
+			SubscriptionRegistry::unregister(self.debug_id);
+
 			for teardown in self.finalizers.drain(..) {
 				(teardown)();
 			}
 		}
 	}
+
+	#[inline]
+	fn debug_id(&self) -> Option<SubscriptionDebugId> {
+		#[cfg(feature = "debug_subscriptions")]
+		{
+			Some(self.debug_id)
+		}
+
+		#[cfg(not(feature = "debug_subscriptions"))]
+		{
+			None
+		}
+	}
 }
 
 impl TeardownCollection for SubscriptionData {
@@ -64,8 +105,15 @@ impl TeardownCollection for SubscriptionData {
 			// If this subscription is already closed, the newly added teardown
 			// is immediately executed.
 			teardown.execute();
-		} else if let Some(teardown_fn) = teardown.take() {
-			self.finalizers.push(teardown_fn);
+		} else {
+			#[cfg(feature = "debug_subscriptions")]
+			if let Some(child_debug_id) = teardown.debug_id() {
+				SubscriptionRegistry::adopt(self.debug_id, child_debug_id);
+			}
+
+			if let Some(teardown_fn) = teardown.take() {
+				self.finalizers.push(teardown_fn);
+			}
 		}
 	}
 }
@@ -0,0 +1,14 @@
+use std::fmt::Display;
+
+/// Identifies a subscription in the
+/// [`SubscriptionRegistry`][crate::SubscriptionRegistry].
+///
+/// Only ever created when the `debug_subscriptions` feature is enabled.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SubscriptionDebugId(pub(crate) usize);
+
+impl Display for SubscriptionDebugId {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "#{}", self.0)
+	}
+}
@@ -1,6 +1,6 @@
 use rx_core_macro_subscription_derive::RxSubscription;
 
-use crate::{SubscriptionLike, SubscriptionWithTeardown, TeardownCollection};
+use crate::{SubscriptionDebugId, SubscriptionLike, SubscriptionWithTeardown, TeardownCollection};
 
 pub trait EraseSubscriptionExtension {
 	fn erase(self) -> ErasedSubscription;
@@ -40,6 +40,10 @@ impl SubscriptionLike for ErasedSubscription {
 	fn unsubscribe(&mut self) {
 		self.subscription.unsubscribe();
 	}
+
+	fn debug_id(&self) -> Option<SubscriptionDebugId> {
+		self.subscription.debug_id()
+	}
 }
 
 impl TeardownCollection for ErasedSubscription {
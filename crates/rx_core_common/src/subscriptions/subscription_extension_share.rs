@@ -6,6 +6,7 @@ where
 {
 	/// Wrap this subscription into a [SharedSubscription], erasing it and
 	/// allowing you to freely clone it, to unsubscribe it from multiple places.
+	#[track_caller]
 	fn into_shared(self) -> SharedSubscription {
 		SharedSubscription::new(self)
 	}
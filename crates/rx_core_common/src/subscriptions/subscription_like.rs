@@ -1,3 +1,5 @@
+use crate::SubscriptionDebugId;
+
 /// A [SubscriptionLike] is something that can be "unsubscribed" from, which
 /// will close it, rendering it no longer operational. If it also owns
 /// resources, it will also release those resources, usually by executing
@@ -26,4 +28,14 @@ pub trait SubscriptionLike {
 	///
 	/// Once closed, a subscription stays closed.
 	fn unsubscribe(&mut self);
+
+	/// Identifies the subscription in the `SubscriptionRegistry`, only
+	/// available when the `debug_subscriptions` feature is enabled.
+	///
+	/// Subscriptions owning a [SubscriptionData][crate::SubscriptionData]
+	/// forward its id, subscriptions that do not, return [None].
+	#[inline]
+	fn debug_id(&self) -> Option<SubscriptionDebugId> {
+		None
+	}
 }
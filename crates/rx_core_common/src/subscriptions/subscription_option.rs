@@ -1,4 +1,4 @@
-use crate::{SubscriptionLike, SubscriptionWithTeardown, TeardownCollection};
+use crate::{SubscriptionDebugId, SubscriptionLike, SubscriptionWithTeardown, TeardownCollection};
 
 pub struct OptionSubscription<S>
 where
@@ -45,6 +45,12 @@ where
 			destination.unsubscribe();
 		}
 	}
+
+	fn debug_id(&self) -> Option<SubscriptionDebugId> {
+		self.subscription
+			.as_ref()
+			.and_then(|subscription| subscription.debug_id())
+	}
 }
 
 impl<S> Drop for OptionSubscription<S>
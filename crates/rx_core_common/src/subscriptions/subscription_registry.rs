@@ -0,0 +1,302 @@
+use std::{
+	backtrace::Backtrace,
+	collections::{BTreeMap, HashSet},
+	fmt::Write,
+	panic::Location,
+	sync::{Arc, LazyLock, Mutex, MutexGuard, PoisonError},
+};
+
+use crate::SubscriptionDebugId;
+
+static SUBSCRIPTION_REGISTRY: LazyLock<Mutex<SubscriptionRegistryState>> =
+	LazyLock::new(Mutex::default);
+
+fn lock_registry() -> MutexGuard<'static, SubscriptionRegistryState> {
+	SUBSCRIPTION_REGISTRY
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+}
+
+#[derive(Default)]
+struct SubscriptionRegistryState {
+	next_id: usize,
+	open_subscriptions: BTreeMap<SubscriptionDebugId, OpenSubscription>,
+}
+
+/// A subscription that was created but not yet closed.
+#[derive(Debug, Clone)]
+pub struct OpenSubscription {
+	pub id: SubscriptionDebugId,
+	pub type_name: String,
+	/// Where the subscription was created.
+	pub location: &'static Location<'static>,
+	/// Only captured when the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`
+	/// environment variables enable it.
+	pub backtrace: Arc<Backtrace>,
+	/// The subscription this subscription was added to as a teardown.
+	pub parent: Option<SubscriptionDebugId>,
+	/// Additional information attached to the subscription, like the entity
+	/// owning it.
+	pub annotations: Vec<String>,
+}
+
+/// Keeps track of every open subscription in the process, enabled by the
+/// `debug_subscriptions` feature.
+///
+/// Subscriptions that never close are listed here forever, making it
+/// possible to find where they were created.
+pub struct SubscriptionRegistry;
+
+impl SubscriptionRegistry {
+	pub(crate) fn register(
+		type_name: String,
+		location: &'static Location<'static>,
+	) -> SubscriptionDebugId {
+		let mut registry = lock_registry();
+		let id = SubscriptionDebugId(registry.next_id);
+		registry.next_id += 1;
+		registry.open_subscriptions.insert(
+			id,
+			OpenSubscription {
+				id,
+				type_name,
+				location,
+				backtrace: Arc::new(Backtrace::capture()),
+				parent: None,
+				annotations: Vec::new(),
+			},
+		);
+		id
+	}
+
+	/// Used by subscriptions wrapping a [`SubscriptionData`][crate::SubscriptionData]
+	/// to register themselves in its place.
+	pub(crate) fn relabel(
+		id: SubscriptionDebugId,
+		type_name: String,
+		location: &'static Location<'static>,
+	) {
+		if let Some(open_subscription) = lock_registry().open_subscriptions.get_mut(&id) {
+			open_subscription.type_name = type_name;
+			open_subscription.location = location;
+		}
+	}
+
+	pub(crate) fn unregister(id: SubscriptionDebugId) {
+		lock_registry().open_subscriptions.remove(&id);
+	}
+
+	/// Records `child` as a teardown of `parent`.
+	///
+	/// When the two already reference each other, like a shared subscription
+	/// and its source, the latest relationship wins to keep the graph a tree.
+	pub(crate) fn adopt(parent: SubscriptionDebugId, child: SubscriptionDebugId) {
+		let mut registry = lock_registry();
+
+		if parent == child {
+			return;
+		}
+
+		let mut ancestor = parent;
+		while let Some(ancestor_parent) = registry
+			.open_subscriptions
+			.get(&ancestor)
+			.and_then(|open_subscription| open_subscription.parent)
+		{
+			if ancestor_parent == child {
+				if let Some(open_subscription) = registry.open_subscriptions.get_mut(&ancestor) {
+					open_subscription.parent = None;
+				}
+				break;
+			}
+			ancestor = ancestor_parent;
+		}
+
+		if let Some(open_subscription) = registry.open_subscriptions.get_mut(&child) {
+			open_subscription.parent = Some(parent);
+		}
+	}
+
+	/// Attaches additional information to an open subscription, shown in
+	/// [`SubscriptionRegistry::dump`].
+	pub fn annotate(id: SubscriptionDebugId, annotation: impl Into<String>) {
+		if let Some(open_subscription) = lock_registry().open_subscriptions.get_mut(&id) {
+			open_subscription.annotations.push(annotation.into());
+		}
+	}
+
+	pub fn is_open(id: SubscriptionDebugId) -> bool {
+		lock_registry().open_subscriptions.contains_key(&id)
+	}
+
+	pub fn get(id: SubscriptionDebugId) -> Option<OpenSubscription> {
+		lock_registry().open_subscriptions.get(&id).cloned()
+	}
+
+	pub fn open_subscription_count() -> usize {
+		lock_registry().open_subscriptions.len()
+	}
+
+	/// Returns every open subscription in the order they were created.
+	pub fn open_subscriptions() -> Vec<OpenSubscription> {
+		lock_registry()
+			.open_subscriptions
+			.values()
+			.cloned()
+			.collect()
+	}
+
+	/// Renders every open subscription as a tree, children indented under
+	/// the subscription they were added to.
+	pub fn dump() -> String {
+		let open_subscriptions = Self::open_subscriptions();
+
+		let mut children = BTreeMap::<SubscriptionDebugId, Vec<&OpenSubscription>>::new();
+		let mut roots = Vec::<&OpenSubscription>::new();
+		let open_ids = open_subscriptions
+			.iter()
+			.map(|open_subscription| open_subscription.id)
+			.collect::<HashSet<_>>();
+
+		for open_subscription in open_subscriptions.iter() {
+			match open_subscription.parent {
+				Some(parent) if open_ids.contains(&parent) => {
+					children.entry(parent).or_default().push(open_subscription);
+				}
+				_ => roots.push(open_subscription),
+			}
+		}
+
+		let mut output = String::new();
+		for root in roots {
+			Self::dump_node(&mut output, root, &children, 0);
+		}
+		output
+	}
+
+	fn dump_node(
+		output: &mut String,
+		open_subscription: &OpenSubscription,
+		children: &BTreeMap<SubscriptionDebugId, Vec<&OpenSubscription>>,
+		depth: usize,
+	) {
+		let _ = write!(
+			output,
+			"{}{} {} ({})",
+			"  ".repeat(depth),
+			open_subscription.type_name,
+			open_subscription.id,
+			open_subscription.location
+		);
+		if !open_subscription.annotations.is_empty() {
+			let _ = write!(output, " [{}]", open_subscription.annotations.join(", "));
+		}
+		output.push('\n');
+
+		for child in children.get(&open_subscription.id).into_iter().flatten() {
+			Self::dump_node(output, child, children, depth + 1);
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{
+		SharedSubscription, SubscriptionData, SubscriptionLike, SubscriptionRegistry,
+		TeardownCollectionExtension,
+	};
+
+	// The registry is global, and tests run in parallel, so only the
+	// subscriptions created by the test itself are checked.
+
+	#[test]
+	fn should_register_subscriptions_until_unsubscribed() {
+		let mut subscription = SubscriptionData::default();
+		let debug_id = subscription.debug_id().unwrap();
+
+		let open_subscription = SubscriptionRegistry::get(debug_id).unwrap();
+		assert_eq!(open_subscription.type_name, "SubscriptionData");
+		assert_eq!(open_subscription.location.file(), file!());
+		assert_eq!(open_subscription.parent, None);
+
+		subscription.unsubscribe();
+		assert!(!SubscriptionRegistry::is_open(debug_id));
+	}
+
+	#[test]
+	fn should_unregister_subscriptions_on_drop() {
+		let subscription = SubscriptionData::default();
+		let debug_id = subscription.debug_id().unwrap();
+		assert!(SubscriptionRegistry::is_open(debug_id));
+
+		drop(subscription);
+		assert!(!SubscriptionRegistry::is_open(debug_id));
+	}
+
+	#[test]
+	fn should_track_subscriptions_added_as_teardowns() {
+		let mut parent = SubscriptionData::default();
+		let parent_debug_id = parent.debug_id().unwrap();
+		let child = SubscriptionData::default();
+		let child_debug_id = child.debug_id().unwrap();
+
+		parent.add(child);
+
+		assert_eq!(
+			SubscriptionRegistry::get(child_debug_id).unwrap().parent,
+			Some(parent_debug_id)
+		);
+
+		parent.unsubscribe();
+		assert!(!SubscriptionRegistry::is_open(parent_debug_id));
+		assert!(!SubscriptionRegistry::is_open(child_debug_id));
+	}
+
+	#[test]
+	fn should_register_shared_subscriptions_as_the_parent_of_their_source() {
+		let source = SubscriptionData::default();
+		let source_debug_id = source.debug_id().unwrap();
+
+		let mut shared_subscription = SharedSubscription::new(source);
+		let shared_debug_id = shared_subscription.debug_id().unwrap();
+
+		let open_shared_subscription = SubscriptionRegistry::get(shared_debug_id).unwrap();
+		assert_eq!(open_shared_subscription.type_name, "SharedSubscription");
+		assert_eq!(open_shared_subscription.parent, None);
+		assert_eq!(
+			SubscriptionRegistry::get(source_debug_id).unwrap().parent,
+			Some(shared_debug_id)
+		);
+
+		shared_subscription.unsubscribe();
+		assert!(!SubscriptionRegistry::is_open(shared_debug_id));
+		assert!(!SubscriptionRegistry::is_open(source_debug_id));
+	}
+
+	#[test]
+	fn should_dump_open_subscriptions_as_a_tree() {
+		let mut parent = SubscriptionData::default();
+		let parent_debug_id = parent.debug_id().unwrap();
+		let child = SubscriptionData::default();
+		let child_debug_id = child.debug_id().unwrap();
+		parent.add(child);
+		SubscriptionRegistry::annotate(parent_debug_id, "owned by test");
+
+		let dump = SubscriptionRegistry::dump();
+		let mut lines = dump.lines();
+		let parent_line = lines
+			.find(|line| line.contains(&format!("{parent_debug_id} (")))
+			.unwrap();
+		assert!(parent_line.starts_with("SubscriptionData"));
+		assert!(parent_line.ends_with("[owned by test]"));
+
+		// Subscriptions from parallel tests could only be listed here if they
+		// were also children of the parent.
+		let child_line = lines.next().unwrap();
+		assert!(child_line.starts_with("  SubscriptionData"));
+		assert!(child_line.contains(&format!("{child_debug_id} (")));
+
+		parent.unsubscribe();
+		assert!(!SubscriptionRegistry::dump().contains(&format!("{parent_debug_id} (")));
+	}
+}
@@ -3,9 +3,9 @@ use std::sync::{Arc, Mutex, MutexGuard};
 use rx_core_macro_subscription_derive::RxSubscription;
 
 use crate::{
-	LockWithPoisonBehavior, SubscriptionClosedFlag, SubscriptionData, SubscriptionLike,
-	SubscriptionLikePushNotificationExtention, SubscriptionNotification, SubscriptionWithTeardown,
-	TeardownCollectionExtension,
+	LockWithPoisonBehavior, SubscriptionClosedFlag, SubscriptionData, SubscriptionDebugId,
+	SubscriptionLike, SubscriptionLikePushNotificationExtention, SubscriptionNotification,
+	SubscriptionWithTeardown, TeardownCollectionExtension,
 };
 
 pub(crate) const SUBSCRIPTION_MAX_RECURSION_DEPTH: usize = 10;
@@ -97,11 +97,17 @@ pub struct SharedSubscription {
 }
 
 impl SharedSubscription {
+	#[track_caller]
 	pub fn new<S>(mut subscription: S) -> Self
 	where
 		S: 'static + SubscriptionWithTeardown + Send + Sync,
 	{
 		let mut shared_subscription = Self::default();
+		#[cfg(feature = "debug_subscriptions")]
+		shared_subscription
+			.subscription
+			.lock_ignore_poison()
+			.register_as::<Self>();
 		subscription.add(shared_subscription.clone());
 		shared_subscription.add(subscription);
 		shared_subscription
@@ -207,6 +213,20 @@ impl SubscriptionLike for SharedSubscription {
 
 		self.try_apply_deferred();
 	}
+
+	/// Waits for the lock, so the id is never missed just because another
+	/// thread was using this subscription at the same time.
+	fn debug_id(&self) -> Option<SubscriptionDebugId> {
+		#[cfg(feature = "debug_subscriptions")]
+		{
+			self.subscription.lock_ignore_poison().debug_id()
+		}
+
+		#[cfg(not(feature = "debug_subscriptions"))]
+		{
+			None
+		}
+	}
 }
 
 impl Drop for SharedSubscription {
@@ -215,3 +235,35 @@ impl Drop for SharedSubscription {
 		self.try_apply_deferred();
 	}
 }
+
+#[cfg(all(test, feature = "debug_subscriptions"))]
+mod test {
+	use std::{
+		sync::{Arc, Barrier},
+		thread,
+		time::Duration,
+	};
+
+	use crate::{SharedSubscription, SubscriptionData, SubscriptionLike};
+
+	#[test]
+	fn should_return_the_debug_id_even_when_locked_by_another_thread() {
+		let shared_subscription = SharedSubscription::new(SubscriptionData::default());
+		let expected_debug_id = shared_subscription.subscription.lock().unwrap().debug_id();
+		assert!(expected_debug_id.is_some());
+
+		let barrier = Arc::new(Barrier::new(2));
+		let barrier_clone = barrier.clone();
+		let shared_subscription_clone = shared_subscription.clone();
+		let locking_thread = thread::spawn(move || {
+			let _guard = shared_subscription_clone.subscription.lock().unwrap();
+			barrier_clone.wait();
+			thread::sleep(Duration::from_millis(20));
+		});
+
+		barrier.wait();
+		assert_eq!(shared_subscription.debug_id(), expected_debug_id);
+
+		locking_thread.join().unwrap();
+	}
+}
@@ -2,7 +2,10 @@ use std::fmt::Debug;
 
 use disqualified::ShortName;
 
-use crate::{Scheduler, SchedulerHandle, SubscriptionLike, WorkCancellationId, WorkInvokeId};
+use crate::{
+	Scheduler, SchedulerHandle, SubscriptionDebugId, SubscriptionLike, WorkCancellationId,
+	WorkInvokeId,
+};
 
 /// A teardown is a closure which owns resources, by the nature of them being
 /// moved into said closure. The closure itself is responsible for releasing
@@ -23,6 +26,9 @@ use crate::{Scheduler, SchedulerHandle, SubscriptionLike, WorkCancellationId, Wo
 #[derive(Default)]
 pub struct Teardown {
 	teardown_fn: Option<Box<dyn FnOnce() + Send + Sync>>,
+	/// The subscription this teardown was created from.
+	#[cfg(feature = "debug_subscriptions")]
+	debug_id: Option<SubscriptionDebugId>,
 }
 
 impl PartialEq for Teardown {
@@ -48,12 +54,16 @@ impl Teardown {
 	{
 		Self {
 			teardown_fn: Some(Box::new(f)),
+			#[cfg(feature = "debug_subscriptions")]
+			debug_id: None,
 		}
 	}
 
 	pub fn new_from_box(f: Box<dyn FnOnce() + Send + Sync>) -> Self {
 		Self {
 			teardown_fn: Some(f),
+			#[cfg(feature = "debug_subscriptions")]
+			debug_id: None,
 		}
 	}
 
@@ -120,6 +130,21 @@ impl Teardown {
 	pub fn is_closed(&self) -> bool {
 		self.teardown_fn.is_none()
 	}
+
+	/// The [SubscriptionLike::debug_id] of the subscription this teardown was
+	/// created from.
+	#[inline]
+	pub fn debug_id(&self) -> Option<SubscriptionDebugId> {
+		#[cfg(feature = "debug_subscriptions")]
+		{
+			self.debug_id
+		}
+
+		#[cfg(not(feature = "debug_subscriptions"))]
+		{
+			None
+		}
+	}
 }
 
 /// Exposes and respects the original subscriptions closed-ness by storing it
@@ -133,6 +158,8 @@ where
 {
 	fn from(mut subscription: S) -> Self {
 		Self {
+			#[cfg(feature = "debug_subscriptions")]
+			debug_id: subscription.debug_id(),
 			teardown_fn: if subscription.is_closed() {
 				None
 			} else {
@@ -68,6 +68,11 @@ fn impl_delegate_subscription_like_to_destination_inner(derive_input: &DeriveInp
 			fn unsubscribe(&mut self) {
 				#unsubscribe_impl
 			}
+
+			#[inline]
+			fn debug_id(&self) -> Option<#_rx_core_common_crate::SubscriptionDebugId> {
+				self.#teardown_field.debug_id()
+			}
 		}
 	}
 }
@@ -204,6 +209,32 @@ mod test {
 		assert!(!s.contains(&quote! { self.teardown.unsubscribe(); }.to_string()));
 	}
 
+	#[test]
+	fn should_forward_the_debug_id_of_the_teardown_field() {
+		let input: DeriveInput = parse_quote! {
+			#[rx_delegate_subscription_like_to_destination]
+			struct Foo {
+				#[teardown]
+				teardown: Dummy,
+				#[destination]
+				destination: Dummy,
+			}
+		};
+		let tokens = impl_delegate_subscription_like_to_destination(&input).unwrap();
+		let s = tokens.to_string();
+		assert!(
+			s.contains(
+				&quote! {
+					#[inline]
+					fn debug_id(&self) -> Option<rx_core_common::SubscriptionDebugId> {
+						self.teardown.debug_id()
+					}
+				}
+				.to_string()
+			)
+		);
+	}
+
 	#[test]
 	fn should_return_none_when_not_requested() {
 		let input: DeriveInput = parse_quote! { struct Foo { #[destination] destination: Dummy } };
@@ -0,0 +1,151 @@
+use rx_core_common::{ObservableOutput, PhantomInvariant, SubscriberNotification};
+
+/// Generates an `EitherObservableNotification` enum of the given arity, its
+/// selector trait and a selector for each of its variants.
+///
+/// The generic parameter names double as the names of the variants.
+macro_rules! impl_either_observable_notification {
+	(
+		$notification:ident,
+		$selector_trait:ident,
+		$generics:tt,
+		$($selector:ident => $variant:ident),+
+	) => {
+		impl_either_observable_notification!(@enum $notification, $generics);
+		impl_either_observable_notification!(@trait $notification, $selector_trait, $generics);
+		$(
+			impl_either_observable_notification!(
+				@selector $notification, $selector_trait, $selector, $variant, $generics
+			);
+		)+
+	};
+	(@enum $notification:ident, ($($o:ident),+)) => {
+		#[derive(Debug)]
+		pub enum $notification<$($o),+>
+		where
+			$($o: ObservableOutput,)+
+		{
+			$($o(SubscriberNotification<$o::Out, $o::OutError>),)+
+		}
+	};
+	(@trait $notification:ident, $selector_trait:ident, ($($o:ident),+)) => {
+		pub trait $selector_trait<$($o),+>
+		where
+			$($o: ObservableOutput,)+
+		{
+			type Variant: ObservableOutput;
+
+			fn select(
+				notification: SubscriberNotification<
+					<Self::Variant as ObservableOutput>::Out,
+					<Self::Variant as ObservableOutput>::OutError,
+				>,
+			) -> $notification<$($o),+>;
+		}
+	};
+	(
+		@selector
+		$notification:ident,
+		$selector_trait:ident,
+		$selector:ident,
+		$variant:ident,
+		($($o:ident),+)
+	) => {
+		pub struct $selector<$($o),+> {
+			_phantom_data: PhantomInvariant<($($o,)+)>,
+		}
+
+		impl<$($o),+> $selector_trait<$($o),+> for $selector<$($o),+>
+		where
+			$($o: ObservableOutput,)+
+		{
+			type Variant = $variant;
+
+			fn select(
+				notification: SubscriberNotification<
+					<Self::Variant as ObservableOutput>::Out,
+					<Self::Variant as ObservableOutput>::OutError,
+				>,
+			) -> $notification<$($o),+> {
+				$notification::$variant(notification)
+			}
+		}
+	};
+}
+
+impl_either_observable_notification!(
+	EitherObservableNotification2,
+	EitherNotificationSelector2,
+	(O1, O2),
+	EitherNotificationSelector1Of2 => O1,
+	EitherNotificationSelector2Of2 => O2
+);
+
+impl_either_observable_notification!(
+	EitherObservableNotification3,
+	EitherNotificationSelector3,
+	(O1, O2, O3),
+	EitherNotificationSelector1Of3 => O1,
+	EitherNotificationSelector2Of3 => O2,
+	EitherNotificationSelector3Of3 => O3
+);
+
+impl_either_observable_notification!(
+	EitherObservableNotification4,
+	EitherNotificationSelector4,
+	(O1, O2, O3, O4),
+	EitherNotificationSelector1Of4 => O1,
+	EitherNotificationSelector2Of4 => O2,
+	EitherNotificationSelector3Of4 => O3,
+	EitherNotificationSelector4Of4 => O4
+);
+
+impl_either_observable_notification!(
+	EitherObservableNotification5,
+	EitherNotificationSelector5,
+	(O1, O2, O3, O4, O5),
+	EitherNotificationSelector1Of5 => O1,
+	EitherNotificationSelector2Of5 => O2,
+	EitherNotificationSelector3Of5 => O3,
+	EitherNotificationSelector4Of5 => O4,
+	EitherNotificationSelector5Of5 => O5
+);
+
+impl_either_observable_notification!(
+	EitherObservableNotification6,
+	EitherNotificationSelector6,
+	(O1, O2, O3, O4, O5, O6),
+	EitherNotificationSelector1Of6 => O1,
+	EitherNotificationSelector2Of6 => O2,
+	EitherNotificationSelector3Of6 => O3,
+	EitherNotificationSelector4Of6 => O4,
+	EitherNotificationSelector5Of6 => O5,
+	EitherNotificationSelector6Of6 => O6
+);
+
+impl_either_observable_notification!(
+	EitherObservableNotification7,
+	EitherNotificationSelector7,
+	(O1, O2, O3, O4, O5, O6, O7),
+	EitherNotificationSelector1Of7 => O1,
+	EitherNotificationSelector2Of7 => O2,
+	EitherNotificationSelector3Of7 => O3,
+	EitherNotificationSelector4Of7 => O4,
+	EitherNotificationSelector5Of7 => O5,
+	EitherNotificationSelector6Of7 => O6,
+	EitherNotificationSelector7Of7 => O7
+);
+
+impl_either_observable_notification!(
+	EitherObservableNotification8,
+	EitherNotificationSelector8,
+	(O1, O2, O3, O4, O5, O6, O7, O8),
+	EitherNotificationSelector1Of8 => O1,
+	EitherNotificationSelector2Of8 => O2,
+	EitherNotificationSelector3Of8 => O3,
+	EitherNotificationSelector4Of8 => O4,
+	EitherNotificationSelector5Of8 => O5,
+	EitherNotificationSelector6Of8 => O6,
+	EitherNotificationSelector7Of8 => O7,
+	EitherNotificationSelector8Of8 => O8
+);
@@ -0,0 +1,158 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{
+	Observable, ObservableOutput, PhantomInvariant, RxObserver, Subscriber, SubscriberNotification,
+	SubscriptionLike,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::{
+	EitherNotificationSelector2, EitherNotificationSelector3, EitherNotificationSelector4,
+	EitherNotificationSelector5, EitherNotificationSelector6, EitherNotificationSelector7,
+	EitherNotificationSelector8, EitherObservableNotification2, EitherObservableNotification3,
+	EitherObservableNotification4, EitherObservableNotification5, EitherObservableNotification6,
+	EitherObservableNotification7, EitherObservableNotification8,
+};
+
+/// Generates an `EitherSubscriber` of the given arity, materializing every
+/// signal it receives into the variant chosen by its `VariantSelector`.
+macro_rules! impl_either_subscriber {
+	($subscriber:ident, $notification:ident, $selector_trait:ident, ($($o:ident),+)) => {
+		#[derive(RxSubscriber)]
+		#[rx_in(<VariantSelector::Variant as ObservableOutput>::Out)]
+		#[rx_in_error(<VariantSelector::Variant as ObservableOutput>::OutError)]
+		#[rx_delegate_teardown_collection]
+		pub struct $subscriber<VariantSelector, Destination, $($o),+>
+		where
+			VariantSelector: $selector_trait<$($o),+>,
+			Destination: Subscriber<In = $notification<$($o),+>>,
+			$(
+				$o: Observable,
+				$o::OutError: Into<Destination::InError>,
+			)+
+		{
+			#[destination]
+			destination: Destination,
+			_phantom_data: PhantomInvariant<($($o,)+ VariantSelector)>,
+		}
+
+		impl<VariantSelector, Destination, $($o),+> $subscriber<VariantSelector, Destination, $($o),+>
+		where
+			VariantSelector: $selector_trait<$($o),+>,
+			Destination: Subscriber<In = $notification<$($o),+>>,
+			$(
+				$o: Observable,
+				$o::OutError: Into<Destination::InError>,
+			)+
+		{
+			pub fn new(destination: Destination) -> Self {
+				Self {
+					destination,
+					_phantom_data: PhantomData,
+				}
+			}
+		}
+
+		impl<VariantSelector, Destination, $($o),+> RxObserver
+			for $subscriber<VariantSelector, Destination, $($o),+>
+		where
+			VariantSelector: $selector_trait<$($o),+>,
+			Destination: Subscriber<In = $notification<$($o),+>>,
+			$(
+				$o: Observable,
+				$o::OutError: Into<Destination::InError>,
+			)+
+		{
+			#[inline]
+			fn next(&mut self, next: Self::In) {
+				self.destination
+					.next(VariantSelector::select(SubscriberNotification::Next(next)));
+			}
+
+			#[inline]
+			fn error(&mut self, error: Self::InError) {
+				self.destination
+					.next(VariantSelector::select(SubscriberNotification::Error(
+						error,
+					)));
+			}
+
+			#[inline]
+			fn complete(&mut self) {
+				self.destination
+					.next(VariantSelector::select(SubscriberNotification::Complete));
+			}
+		}
+
+		impl<VariantSelector, Destination, $($o),+> SubscriptionLike
+			for $subscriber<VariantSelector, Destination, $($o),+>
+		where
+			VariantSelector: $selector_trait<$($o),+>,
+			Destination: Subscriber<In = $notification<$($o),+>>,
+			$(
+				$o: Observable,
+				$o::OutError: Into<Destination::InError>,
+			)+
+		{
+			#[inline]
+			fn is_closed(&self) -> bool {
+				self.destination.is_closed()
+			}
+
+			#[inline]
+			fn unsubscribe(&mut self) {
+				self.destination
+					.next(VariantSelector::select(SubscriberNotification::Unsubscribe));
+			}
+		}
+	};
+}
+
+impl_either_subscriber!(
+	EitherSubscriber2,
+	EitherObservableNotification2,
+	EitherNotificationSelector2,
+	(O1, O2)
+);
+
+impl_either_subscriber!(
+	EitherSubscriber3,
+	EitherObservableNotification3,
+	EitherNotificationSelector3,
+	(O1, O2, O3)
+);
+
+impl_either_subscriber!(
+	EitherSubscriber4,
+	EitherObservableNotification4,
+	EitherNotificationSelector4,
+	(O1, O2, O3, O4)
+);
+
+impl_either_subscriber!(
+	EitherSubscriber5,
+	EitherObservableNotification5,
+	EitherNotificationSelector5,
+	(O1, O2, O3, O4, O5)
+);
+
+impl_either_subscriber!(
+	EitherSubscriber6,
+	EitherObservableNotification6,
+	EitherNotificationSelector6,
+	(O1, O2, O3, O4, O5, O6)
+);
+
+impl_either_subscriber!(
+	EitherSubscriber7,
+	EitherObservableNotification7,
+	EitherNotificationSelector7,
+	(O1, O2, O3, O4, O5, O6, O7)
+);
+
+impl_either_subscriber!(
+	EitherSubscriber8,
+	EitherObservableNotification8,
+	EitherNotificationSelector8,
+	(O1, O2, O3, O4, O5, O6, O7, O8)
+);
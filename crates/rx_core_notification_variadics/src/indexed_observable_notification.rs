@@ -0,0 +1,13 @@
+use rx_core_common::{Signal, SubscriberNotification};
+
+/// A materialized notification of one of many observables of the same type,
+/// tagged with the index of the observable it came from.
+#[derive(Debug)]
+pub struct IndexedObservableNotification<Out, OutError>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	pub index: usize,
+	pub notification: SubscriberNotification<Out, OutError>,
+}
@@ -0,0 +1,89 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{
+	PhantomInvariant, RxObserver, Signal, Subscriber, SubscriberNotification, SubscriptionLike,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::IndexedObservableNotification;
+
+/// Materializes all signals of an observable into an
+/// [IndexedObservableNotification], so that many observables of the same type
+/// can be told apart by a single downstream subscriber.
+#[derive(RxSubscriber)]
+#[rx_in(Out)]
+#[rx_in_error(OutError)]
+#[rx_delegate_teardown_collection]
+pub struct IndexedSubscriber<Destination, Out, OutError>
+where
+	Destination: Subscriber<In = IndexedObservableNotification<Out, OutError>>,
+	Out: Signal,
+	OutError: Signal,
+{
+	index: usize,
+	#[destination]
+	destination: Destination,
+	_phantom_data: PhantomInvariant<(Out, OutError)>,
+}
+
+impl<Destination, Out, OutError> IndexedSubscriber<Destination, Out, OutError>
+where
+	Destination: Subscriber<In = IndexedObservableNotification<Out, OutError>>,
+	Out: Signal,
+	OutError: Signal,
+{
+	pub fn new(destination: Destination, index: usize) -> Self {
+		Self {
+			index,
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+
+	#[inline]
+	fn forward(&mut self, notification: SubscriberNotification<Out, OutError>) {
+		self.destination.next(IndexedObservableNotification {
+			index: self.index,
+			notification,
+		});
+	}
+}
+
+impl<Destination, Out, OutError> RxObserver for IndexedSubscriber<Destination, Out, OutError>
+where
+	Destination: Subscriber<In = IndexedObservableNotification<Out, OutError>>,
+	Out: Signal,
+	OutError: Signal,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		self.forward(SubscriberNotification::Next(next));
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.forward(SubscriberNotification::Error(error));
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.forward(SubscriberNotification::Complete);
+	}
+}
+
+impl<Destination, Out, OutError> SubscriptionLike for IndexedSubscriber<Destination, Out, OutError>
+where
+	Destination: Subscriber<In = IndexedObservableNotification<Out, OutError>>,
+	Out: Signal,
+	OutError: Signal,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		self.forward(SubscriberNotification::Unsubscribe);
+	}
+}
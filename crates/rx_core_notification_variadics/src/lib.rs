@@ -1,5 +1,9 @@
-mod either_observable_notification_2;
-mod either_subscriber_2;
+mod either_observable_notification;
+mod either_subscriber;
+mod indexed_observable_notification;
+mod indexed_subscriber;
 
-pub use either_observable_notification_2::*;
-pub use either_subscriber_2::*;
+pub use either_observable_notification::*;
+pub use either_subscriber::*;
+pub use indexed_observable_notification::*;
+pub use indexed_subscriber::*;
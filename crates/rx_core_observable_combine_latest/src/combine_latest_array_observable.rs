@@ -0,0 +1,70 @@
+use rx_core_common::{
+	Observable, RxObserver, SharedSubscriber, SharedSubscription, Subscriber, TeardownCollection,
+	TeardownCollectionExtension, UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+use rx_core_notification_variadics::IndexedSubscriber;
+
+use crate::CombineLatestArraySubscriber;
+
+#[derive(RxObservable)]
+#[rx_out(Vec<O::Out>)]
+#[rx_out_error(O::OutError)]
+pub struct CombineLatestArrayObservable<O>
+where
+	O: 'static + Send + Sync + Observable,
+	O::Out: Clone,
+{
+	observables: Vec<O>,
+}
+
+impl<O> CombineLatestArrayObservable<O>
+where
+	O: 'static + Send + Sync + Observable,
+	O::Out: Clone,
+{
+	pub fn new(observables: impl IntoIterator<Item = O>) -> Self {
+		Self {
+			observables: observables.into_iter().collect(),
+		}
+	}
+}
+
+impl<O> Observable for CombineLatestArrayObservable<O>
+where
+	O: 'static + Send + Sync + Observable,
+	O::Out: Clone,
+{
+	type Subscription<Destination>
+		= SharedSubscription
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		observer: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+	{
+		let mut destination = observer.upgrade();
+		if self.observables.is_empty() {
+			// Nothing to combine, there will never be a value to emit
+			destination.complete();
+		}
+
+		let mut subscription = SharedSubscription::default();
+		let mut shared_subscriber = SharedSubscriber::new(
+			CombineLatestArraySubscriber::<_, O>::new(destination, self.observables.len()),
+		);
+
+		for (index, observable) in self.observables.iter_mut().enumerate() {
+			let source_subscription =
+				observable.subscribe(IndexedSubscriber::new(shared_subscriber.clone(), index));
+			subscription.add_teardown(source_subscription.into());
+		}
+
+		shared_subscriber.add(subscription.clone());
+		subscription
+	}
+}
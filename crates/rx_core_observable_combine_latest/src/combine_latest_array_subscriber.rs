@@ -0,0 +1,131 @@
+use rx_core_common::{
+	Observable, RxObserver, Subscriber, SubscriberNotification, SubscriptionLike,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_notification_store::NotificationState;
+use rx_core_notification_variadics::IndexedObservableNotification;
+
+use crate::{should_complete, should_unsubscribe};
+
+const UNREACHABLE_ERROR: &str = "The CombineLatestArraySubscriber expects only materialized notifications through its `next` fn, from an IndexedSubscriber.";
+
+/// # CombineLatestArraySubscriber
+///
+/// From an upstream multiplexer over any number of source observables of the
+/// same type, this subscriber maintains a state for each sources last emission
+/// separately, and emits a `Vec` of them when any of them receive a new value.
+///
+/// The first emission can only happen when all sources have emitted at
+/// least once.
+#[derive(RxSubscriber)]
+#[rx_in(IndexedObservableNotification<O::Out, O::OutError>)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_skip_unsubscribe_on_drop_impl] // This subscribers unsubscribe method should be unreachable!
+pub struct CombineLatestArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	states: Vec<NotificationState<O::Out, O::OutError>>,
+	#[destination]
+	destination: Destination,
+}
+
+impl<Destination, O> CombineLatestArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	pub fn new(destination: Destination, source_count: usize) -> Self {
+		CombineLatestArraySubscriber {
+			states: (0..source_count)
+				.map(|_| NotificationState::default())
+				.collect(),
+			destination,
+		}
+	}
+
+	fn get_next(&self) -> Option<Destination::In> {
+		self.states
+			.iter()
+			.map(|state| state.get_value().cloned())
+			.collect()
+	}
+
+	fn take_any_error(&mut self) -> Option<Destination::InError> {
+		self.states
+			.iter_mut()
+			.find_map(|state| state.take_error())
+			.map(|error| error.into())
+	}
+
+	fn try_complete(&mut self) {
+		if !self.destination.is_closed() && should_complete(&self.states) {
+			self.destination.complete();
+		}
+	}
+
+	fn try_unsubscribe(&mut self) {
+		if !self.destination.is_closed() && should_unsubscribe(&self.states) {
+			self.destination.unsubscribe();
+		}
+	}
+}
+
+impl<Destination, O> RxObserver for CombineLatestArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	fn next(&mut self, next: Self::In) {
+		let was_next = matches!(next.notification, SubscriberNotification::Next(_));
+		self.states[next.index].push(next.notification);
+
+		if let Some(error) = self.take_any_error() {
+			self.destination.error(error);
+			return;
+		}
+
+		self.try_complete();
+		self.try_unsubscribe();
+
+		if was_next
+			&& !self.is_closed()
+			&& let Some(next) = self.get_next()
+		{
+			self.destination.next(next);
+		}
+	}
+
+	fn error(&mut self, _error: Self::InError) {
+		unreachable!("{} - Error", UNREACHABLE_ERROR)
+	}
+
+	fn complete(&mut self) {
+		unreachable!("{} - Complete", UNREACHABLE_ERROR)
+	}
+}
+
+impl<Destination, O> SubscriptionLike for CombineLatestArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		unreachable!("{} - Unsubscribe", UNREACHABLE_ERROR)
+	}
+}
@@ -1,14 +1,90 @@
 use rx_core_common::Observable;
 
-use crate::observable::CombineLatestObservable;
+use crate::observable::{
+	CombineLatestArrayObservable, CombineLatestObservable, CombineLatestObservable3,
+	CombineLatestObservable4, CombineLatestObservable5, CombineLatestObservable6,
+	CombineLatestObservable7, CombineLatestObservable8,
+};
 
-pub fn combine_latest<O1, O2>(o1: O1, o2: O2) -> CombineLatestObservable<O1, O2>
+macro_rules! impl_combine_latest_fn {
+	($fn_name:ident, $observable:ident, $($field:ident: $o:ident),+) => {
+		#[allow(clippy::too_many_arguments)]
+		pub fn $fn_name<$($o),+>($($field: $o),+) -> $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::Out: Clone,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			$observable::new($($field),+)
+		}
+	};
+}
+
+impl_combine_latest_fn!(combine_latest, CombineLatestObservable, o1: O1, o2: O2);
+impl_combine_latest_fn!(combine_latest3, CombineLatestObservable3, o1: O1, o2: O2, o3: O3);
+impl_combine_latest_fn!(
+	combine_latest4,
+	CombineLatestObservable4,
+	o1: O1,
+	o2: O2,
+	o3: O3,
+	o4: O4
+);
+impl_combine_latest_fn!(
+	combine_latest5,
+	CombineLatestObservable5,
+	o1: O1,
+	o2: O2,
+	o3: O3,
+	o4: O4,
+	o5: O5
+);
+impl_combine_latest_fn!(
+	combine_latest6,
+	CombineLatestObservable6,
+	o1: O1,
+	o2: O2,
+	o3: O3,
+	o4: O4,
+	o5: O5,
+	o6: O6
+);
+impl_combine_latest_fn!(
+	combine_latest7,
+	CombineLatestObservable7,
+	o1: O1,
+	o2: O2,
+	o3: O3,
+	o4: O4,
+	o5: O5,
+	o6: O6,
+	o7: O7
+);
+impl_combine_latest_fn!(
+	combine_latest8,
+	CombineLatestObservable8,
+	o1: O1,
+	o2: O2,
+	o3: O3,
+	o4: O4,
+	o5: O5,
+	o6: O6,
+	o7: O7,
+	o8: O8
+);
+
+/// Combines any number of observables of the same type, emitting a `Vec` of
+/// their latest values whenever any of them emits, once all of them did.
+///
+/// Accepts both arrays and `Vec`s. Completes immediately when empty.
+pub fn combine_latest_array<O>(
+	observables: impl IntoIterator<Item = O>,
+) -> CombineLatestArrayObservable<O>
 where
-	O1: 'static + Send + Sync + Observable,
-	O1::Out: Clone,
-	O2: 'static + Send + Sync + Observable,
-	O2::Out: Clone,
-	O2::OutError: Into<O1::OutError>,
+	O: 'static + Send + Sync + Observable,
+	O::Out: Clone,
 {
-	CombineLatestObservable::new(o1, o2)
+	CombineLatestArrayObservable::new(observables)
 }
@@ -0,0 +1,32 @@
+use core::ops::Deref;
+
+use rx_core_common::SubscriberState;
+
+/// A combination can't emit anymore once every source completed, or once one
+/// of them completed without ever emitting while the rest is either primed or
+/// completed too.
+pub(crate) fn should_complete<S>(states: &[S]) -> bool
+where
+	S: Deref<Target = SubscriberState>,
+{
+	states.iter().all(|state| state.is_completed())
+		|| (states
+			.iter()
+			.any(|state| state.is_completed_but_not_primed())
+			&& states
+				.iter()
+				.all(|state| state.is_primed() || state.is_completed()))
+}
+
+/// Unsubscribes once every source is closed, or when one of them closed
+/// without ever emitting while the rest hadn't seen anything yet.
+pub(crate) fn should_unsubscribe<S>(states: &[S]) -> bool
+where
+	S: Deref<Target = SubscriberState>,
+{
+	states.iter().all(|state| state.is_closed())
+		|| (states.iter().any(|state| state.is_closed_but_not_primed())
+			&& states
+				.iter()
+				.all(|state| state.is_waiting() || state.is_closed()))
+}
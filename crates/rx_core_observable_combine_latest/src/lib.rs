@@ -1,9 +1,16 @@
+mod combine_latest_array_observable;
+mod combine_latest_array_subscriber;
 mod combine_latest_observable;
+mod combine_latest_state;
 mod combine_latest_subscriber;
 
+pub(crate) use combine_latest_state::*;
+
+pub use combine_latest_array_subscriber::*;
 pub use combine_latest_subscriber::*;
 
 pub mod observable {
+	pub use super::combine_latest_array_observable::*;
 	pub use super::combine_latest_observable::*;
 }
 
@@ -11,7 +11,7 @@ use crate::{
 	internal::{
 		Connection, ConnectionOptions, ConnectionState, ConnectionSubscriber, ConnectorState,
 	},
-	observable::{Connectable, ConnectableOptions, ConnectionHandle},
+	observable::{Connectable, ConnectableOptions, ConnectableResetDelays, ConnectionHandle},
 };
 
 pub type ConnectionSubscription<Source, Connector> =
@@ -46,8 +46,18 @@ where
 	ConnectionSubscription<Source, ConnectorProvider::Provided>: 'static + TeardownCollection,
 {
 	pub fn new(source: Source, options: ConnectableOptions<ConnectorProvider>) -> Self {
+		Self::new_with_reset_delays(source, options, ConnectableResetDelays::default())
+	}
+
+	/// Like [ConnectableObservable::new], but the resets enabled in the
+	/// options only happen after their delay. See [ConnectableResetDelays].
+	pub fn new_with_reset_delays(
+		source: Source,
+		options: ConnectableOptions<ConnectorProvider>,
+		reset_delays: ConnectableResetDelays,
+	) -> Self {
 		let connection_state = Arc::new(Mutex::new(ConnectionState::new(
-			ConnectionOptions::from_connectable_options(&options),
+			ConnectionOptions::from_connectable_options(&options, reset_delays),
 		)));
 		let connection = Arc::new(Mutex::new(Connection::default()));
 		Self {
@@ -61,6 +71,18 @@ where
 			connection_state,
 		}
 	}
+
+	fn disconnect_connection(
+		connection: Option<
+			ConnectionHandle<ConnectionSubscription<Source, ConnectorProvider::Provided>>,
+		>,
+	) {
+		if let Some(mut connection) = connection
+			&& !connection.is_closed()
+		{
+			connection.unsubscribe();
+		};
+	}
 }
 
 impl<Source, ConnectorProvider> Observable for ConnectableObservable<Source, ConnectorProvider>
@@ -88,13 +110,19 @@ where
 		};
 
 		if !subscription.is_closed() {
-			self.connection_state
+			let cancelled_disconnect = self
+				.connection_state
 				.lock_ignore_poison()
 				.increment_subscriber_count();
 
+			if let Some(cancelled_disconnect) = cancelled_disconnect {
+				cancelled_disconnect.execute();
+			}
+
 			let connection_state_clone = self.connection_state.clone();
 			let connection_clone = self.connection.clone();
 			subscription.add_fn(move || {
+				let mut previous_pending_disconnect = None;
 				let connection_to_disconnect = {
 					let mut connection_state = connection_state_clone.lock_ignore_poison();
 
@@ -102,18 +130,38 @@ where
 					// and the ref count dropped to zero this function returns true
 					let should_disconnect = connection_state.decrement_subscriber_count();
 
-					if should_disconnect {
-						connection_clone.lock_ignore_poison().take_connection()
-					} else {
+					if !should_disconnect {
+						None
+					} else if let Some(delay) = connection_state.ref_count_zero_delay().cloned() {
+						let connection_state_on_delay = connection_state_clone.clone();
+						let connection_on_delay = connection_clone.clone();
+						previous_pending_disconnect = connection_state.replace_pending_disconnect(
+							delay.schedule(move || {
+								let connection_to_disconnect = {
+									let mut connection_state =
+										connection_state_on_delay.lock_ignore_poison();
+									connection_state.forget_pending_disconnect();
+									if connection_state.should_disconnect() {
+										connection_on_delay.lock_ignore_poison().take_connection()
+									} else {
+										None
+									}
+								};
+
+								Self::disconnect_connection(connection_to_disconnect);
+							}),
+						);
 						None
+					} else {
+						connection_clone.lock_ignore_poison().take_connection()
 					}
 				};
 
-				if let Some(mut connection) = connection_to_disconnect
-					&& !connection.is_closed()
-				{
-					connection.unsubscribe();
-				};
+				if let Some(previous_pending_disconnect) = previous_pending_disconnect {
+					previous_pending_disconnect.execute();
+				}
+
+				Self::disconnect_connection(connection_to_disconnect);
 			});
 		}
 
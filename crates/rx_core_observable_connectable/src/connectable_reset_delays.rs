@@ -0,0 +1,97 @@
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+use rx_core_common::{Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension, Teardown};
+
+type ScheduleResetFn = dyn Fn(Box<dyn FnOnce() + Send + Sync>, Duration) -> Teardown + Send + Sync;
+
+/// Delays a reset by `duration`, as measured by the scheduler it was created
+/// with.
+#[derive(Clone)]
+pub struct ResetDelay {
+	duration: Duration,
+	schedule_reset: Arc<ScheduleResetFn>,
+}
+
+impl ResetDelay {
+	pub fn new<S>(duration: Duration, scheduler: SchedulerHandle<S>) -> Self
+	where
+		S: 'static + Scheduler + Send + Sync,
+	{
+		Self {
+			duration,
+			schedule_reset: Arc::new(move |reset, duration| {
+				let mut scheduler_lock = scheduler.lock();
+				let cancellation_id = scheduler_lock.generate_cancellation_id();
+				scheduler_lock.schedule_delayed_work(
+					move |_, _| reset(),
+					duration,
+					cancellation_id,
+				);
+				Teardown::new_work_cancellation(cancellation_id, scheduler.clone())
+			}),
+		}
+	}
+
+	#[inline]
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Schedules the reset, executing the returned teardown cancels it.
+	pub(crate) fn schedule<F>(&self, reset: F) -> Teardown
+	where
+		F: 'static + FnOnce() + Send + Sync,
+	{
+		(self.schedule_reset)(Box::new(reset), self.duration)
+	}
+}
+
+impl Debug for ResetDelay {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("ResetDelay")
+			.field("duration", &self.duration)
+			.finish_non_exhaustive()
+	}
+}
+
+/// Delays the resets enabled by [ConnectableOptions][crate::observable::ConnectableOptions],
+/// following the reset semantics of RxJS 7's `share`:
+///
+/// - `on_error` delays the reset of `reset_connector_on_error`.
+/// - `on_complete` delays the reset of `reset_connector_on_complete`.
+/// - `on_ref_count_zero` delays the disconnect of
+///   `disconnect_when_ref_count_zero` (and with that the reset of
+///   `reset_connector_on_disconnect`). Should a new subscriber arrive before
+///   the delay elapses, the connection is kept alive.
+///
+/// Subscribers arriving while a reset is pending still see the old connector.
+/// A `None` delay resets immediately.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectableResetDelays {
+	pub on_error: Option<ResetDelay>,
+	pub on_complete: Option<ResetDelay>,
+	pub on_ref_count_zero: Option<ResetDelay>,
+}
+
+impl ConnectableResetDelays {
+	pub fn on_error(self, delay: ResetDelay) -> Self {
+		Self {
+			on_error: Some(delay),
+			..self
+		}
+	}
+
+	pub fn on_complete(self, delay: ResetDelay) -> Self {
+		Self {
+			on_complete: Some(delay),
+			..self
+		}
+	}
+
+	pub fn on_ref_count_zero(self, delay: ResetDelay) -> Self {
+		Self {
+			on_ref_count_zero: Some(delay),
+			..self
+		}
+	}
+}
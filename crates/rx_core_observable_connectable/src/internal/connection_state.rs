@@ -1,9 +1,10 @@
-use rx_core_common::{Provider, SubjectLike};
+use rx_core_common::{Provider, SubjectLike, Teardown};
 
-use crate::observable::ConnectableOptions;
+use crate::observable::{ConnectableOptions, ConnectableResetDelays, ResetDelay};
 
 pub(crate) struct ConnectionOptions {
 	disconnect_when_ref_count_zero: bool,
+	reset_delays: ConnectableResetDelays,
 }
 
 pub(crate) struct ConnectionState {
@@ -11,11 +12,16 @@ pub(crate) struct ConnectionState {
 	has_errored: bool,
 	has_completed: bool,
 	connection_options: ConnectionOptions,
+	/// A delayed reset of the connector, after an error or a completion
+	pending_reset: Option<Teardown>,
+	/// A delayed disconnect, after the ref count dropped to zero
+	pending_disconnect: Option<Teardown>,
 }
 
 impl ConnectionOptions {
 	pub(crate) fn from_connectable_options<ConnectorProvider>(
 		value: &ConnectableOptions<ConnectorProvider>,
+		reset_delays: ConnectableResetDelays,
 	) -> Self
 	where
 		ConnectorProvider: 'static + Provider,
@@ -23,6 +29,7 @@ impl ConnectionOptions {
 	{
 		Self {
 			disconnect_when_ref_count_zero: value.disconnect_when_ref_count_zero,
+			reset_delays,
 		}
 	}
 }
@@ -34,6 +41,8 @@ impl ConnectionState {
 			downstream_subscriber_count: 0,
 			has_completed: false,
 			has_errored: false,
+			pending_reset: None,
+			pending_disconnect: None,
 		}
 	}
 
@@ -47,22 +56,74 @@ impl ConnectionState {
 		self.has_completed = true;
 	}
 
-	pub(crate) fn increment_subscriber_count(&mut self) {
+	#[inline]
+	pub(crate) fn reset_delays(&self) -> &ConnectableResetDelays {
+		&self.connection_options.reset_delays
+	}
+
+	#[inline]
+	pub(crate) fn ref_count_zero_delay(&self) -> Option<&ResetDelay> {
+		self.connection_options
+			.reset_delays
+			.on_ref_count_zero
+			.as_ref()
+	}
+
+	/// Returns the pending disconnect that has to be cancelled now that
+	/// there is a subscriber again.
+	#[must_use]
+	pub(crate) fn increment_subscriber_count(&mut self) -> Option<Teardown> {
 		self.downstream_subscriber_count = self.downstream_subscriber_count.saturating_add(1);
+		self.pending_disconnect.take()
 	}
 
 	/// Returns true when disconnect_when_ref_count_zero is enabled and the
 	/// ref count has just dropped to zero.
 	pub(crate) fn decrement_subscriber_count(&mut self) -> bool {
 		self.downstream_subscriber_count = self.downstream_subscriber_count.saturating_sub(1);
+		self.should_disconnect()
+	}
+
+	/// Checked again once a delayed disconnect is due.
+	pub(crate) fn should_disconnect(&self) -> bool {
 		self.connection_options.disconnect_when_ref_count_zero
 			&& self.downstream_subscriber_count == 0
 			&& !self.has_errored
 			&& !self.has_completed
 	}
 
+	/// Returns the previously pending reset, if any.
+	#[must_use]
+	pub(crate) fn replace_pending_reset(&mut self, pending_reset: Teardown) -> Option<Teardown> {
+		self.pending_reset.replace(pending_reset)
+	}
+
+	/// Returns the previously pending disconnect, if any.
+	#[must_use]
+	pub(crate) fn replace_pending_disconnect(
+		&mut self,
+		pending_disconnect: Teardown,
+	) -> Option<Teardown> {
+		self.pending_disconnect.replace(pending_disconnect)
+	}
+
+	/// Forgets the pending disconnect without cancelling it, used once it's
+	/// executing.
+	pub(crate) fn forget_pending_disconnect(&mut self) {
+		self.pending_disconnect.take();
+	}
+
+	/// Returns everything pending, to be cancelled by the caller.
+	#[must_use]
+	pub(crate) fn take_pending(&mut self) -> [Option<Teardown>; 2] {
+		[self.pending_reset.take(), self.pending_disconnect.take()]
+	}
+
 	pub(crate) fn reset(&mut self) {
 		self.has_completed = false;
 		self.has_errored = false;
+		// Forgotten, not cancelled, the reset is either executing right now,
+		// or was taken to be cancelled already.
+		self.pending_reset.take();
 	}
 }
@@ -1,13 +1,14 @@
 use std::sync::{Arc, Mutex};
 
 use rx_core_common::{
-	LockWithPoisonBehavior, Observable, ObservableOutput, Provider, SubjectLike, TeardownCollection,
+	LockWithPoisonBehavior, Observable, ObservableOutput, Provider, SubjectLike, Teardown,
+	TeardownCollection,
 };
 use rx_core_macro_observable_derive::RxObservable;
 
 use crate::{
 	internal::{Connection, ConnectionState, ConnectionSubscriber},
-	observable::{ConnectableOptions, ConnectionHandle, ConnectionSubscription},
+	observable::{ConnectableOptions, ConnectionHandle, ConnectionSubscription, ResetDelay},
 };
 
 #[derive(RxObservable)]
@@ -85,19 +86,50 @@ where
 			Box::new(move || {
 				let mut connection_state = connection_on_complete.lock_ignore_poison();
 
+				let mut previous_pending_reset = None;
 				if reset_on_complete {
-					connector_on_complete.lock_ignore_poison().take();
-					connection_state.reset();
+					if let Some(delay) = connection_state.reset_delays().on_complete.clone() {
+						previous_pending_reset =
+							connection_state.replace_pending_reset(Self::schedule_reset(
+								&delay,
+								connector_on_complete.clone(),
+								connection_on_complete.clone(),
+							));
+					} else {
+						connector_on_complete.lock_ignore_poison().take();
+						connection_state.reset();
+					}
 				}
 				connection_state.completed();
+				drop(connection_state);
+
+				if let Some(previous_pending_reset) = previous_pending_reset {
+					previous_pending_reset.execute();
+				}
 			}),
 			Box::new(move || {
 				let mut connection_state = connection_on_error.lock_ignore_poison();
+
+				let mut previous_pending_reset = None;
 				if reset_on_error {
-					connector_on_error.lock_ignore_poison().take();
-					connection_state.reset();
+					if let Some(delay) = connection_state.reset_delays().on_error.clone() {
+						previous_pending_reset =
+							connection_state.replace_pending_reset(Self::schedule_reset(
+								&delay,
+								connector_on_error.clone(),
+								connection_on_error.clone(),
+							));
+					} else {
+						connector_on_error.lock_ignore_poison().take();
+						connection_state.reset();
+					}
 				}
 				connection_state.errored();
+				drop(connection_state);
+
+				if let Some(previous_pending_reset) = previous_pending_reset {
+					previous_pending_reset.execute();
+				}
 			}),
 			Box::new(move || {
 				if reset_connector_on_disconnect {
@@ -136,7 +168,24 @@ where
 	}
 
 	pub(crate) fn reset(&mut self) {
+		let pending = self.connection_state.lock_ignore_poison().take_pending();
+		for pending in pending.into_iter().flatten() {
+			pending.execute();
+		}
 		self.disconnect();
 		self.connector.lock_ignore_poison().take();
 	}
+
+	/// Drops the connector once the delay is over, so that the next
+	/// connection creates a new one.
+	fn schedule_reset(
+		delay: &ResetDelay,
+		connector: Arc<Mutex<Option<ConnectorProvider::Provided>>>,
+		connection_state: Arc<Mutex<ConnectionState>>,
+	) -> Teardown {
+		delay.schedule(move || {
+			connector.lock_ignore_poison().take();
+			connection_state.lock_ignore_poison().reset();
+		})
+	}
 }
@@ -1,6 +1,7 @@
 mod connectable;
 mod connectable_observable;
 mod connectable_observable_options;
+mod connectable_reset_delays;
 mod connection_handle;
 
 pub mod internal;
@@ -9,5 +10,6 @@ pub mod observable {
 	pub use super::connectable::*;
 	pub use super::connectable_observable::*;
 	pub use super::connectable_observable_options::*;
+	pub use super::connectable_reset_delays::*;
 	pub use super::connection_handle::*;
 }
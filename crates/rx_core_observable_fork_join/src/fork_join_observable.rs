@@ -0,0 +1,73 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{
+	ErasedObservables, Observable, PhantomInvariant, RxObserver, SharedSubscriber,
+	SharedSubscription, Signal, Subscriber, TeardownCollection, TeardownCollectionExtension,
+	UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+use rx_core_notification_variadics::IndexedSubscriber;
+
+use crate::ForkJoinSubscriber;
+
+#[derive(RxObservable, Clone)]
+#[rx_out([Out; SIZE])]
+#[rx_out_error(OutError)]
+pub struct ForkJoinObservable<Out, OutError, const SIZE: usize>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	observables: ErasedObservables<Out, OutError, SIZE>,
+	_phantom_data: PhantomInvariant<(Out, OutError)>,
+}
+
+impl<Out, OutError, const SIZE: usize> ForkJoinObservable<Out, OutError, SIZE>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	pub fn new(observables: impl Into<ErasedObservables<Out, OutError, SIZE>>) -> Self {
+		Self {
+			observables: observables.into(),
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<Out, OutError, const SIZE: usize> Observable for ForkJoinObservable<Out, OutError, SIZE>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	type Subscription<Destination>
+		= SharedSubscription
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		observer: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+	{
+		let mut destination = observer.upgrade();
+		if SIZE == 0 {
+			// Nothing to join, there will never be a value to emit
+			destination.complete();
+		}
+
+		let mut subscription = SharedSubscription::default();
+		let mut shared_subscriber = SharedSubscriber::new(ForkJoinSubscriber::new(destination));
+
+		for (index, observable) in self.observables.iter_mut().enumerate() {
+			let source_subscription =
+				observable.subscribe(IndexedSubscriber::new(shared_subscriber.clone(), index));
+			subscription.add_teardown(source_subscription.into());
+		}
+
+		shared_subscriber.add(subscription.clone());
+		subscription
+	}
+}
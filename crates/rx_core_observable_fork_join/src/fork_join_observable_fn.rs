@@ -0,0 +1,73 @@
+use rx_core_common::{ErasedObservables, Observable, Signal};
+
+use crate::observable::{
+	ForkJoinObservable, ForkJoinObservable2, ForkJoinObservable3, ForkJoinObservable4,
+	ForkJoinObservable5, ForkJoinObservable6, ForkJoinObservable7, ForkJoinObservable8,
+};
+
+/// # ForkJoinObservable
+///
+/// > Category: Combination Observable
+///
+/// Subscribes to multiple input observables of shared output types, and once
+/// all of them have completed, emits an array of the last value of each of
+/// them, then completes.
+///
+/// Unlike [JoinObservable](https://github.com/AlexAegis/rx_bevy/tree/master/crates/rx_core_observable_join),
+/// it does not wait for the rest of the input observables when one of them
+/// completes without ever emitting.
+///
+/// ## Completion Behavior
+///
+/// Completes right after emitting when all input observables completed.
+/// Completes without emitting as soon as any input observable completes
+/// without having emitted a value, or immediately when there are no input
+/// observables.
+///
+/// ## Error Behavior
+///
+/// Errors when any of the input observables errored.
+///
+/// ## Arguments
+///
+/// - `observables`: A tuple or array of observables of shared output types
+///
+/// To join observables of different output types, use [fork_join2] up to
+/// [fork_join8], emitting a tuple instead.
+pub fn fork_join<Out, OutError, const SIZE: usize>(
+	observables: impl Into<ErasedObservables<Out, OutError, SIZE>>,
+) -> ForkJoinObservable<Out, OutError, SIZE>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	ForkJoinObservable::new(observables)
+}
+
+macro_rules! impl_fork_join_fn {
+	($fn_name:ident, $observable:ident, $($field:ident: $o:ident),+) => {
+		/// Like [fork_join], but for observables of different output types,
+		/// emitting a tuple of their last values once all of them completed.
+		///
+		/// The errors of every input observable have to be convertible into
+		/// the error type of the first one.
+		#[allow(clippy::too_many_arguments)]
+		pub fn $fn_name<$($o),+>($($field: $o),+) -> $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			$observable::new($($field),+)
+		}
+	};
+}
+
+impl_fork_join_fn!(fork_join2, ForkJoinObservable2, o1: O1, o2: O2);
+impl_fork_join_fn!(fork_join3, ForkJoinObservable3, o1: O1, o2: O2, o3: O3);
+impl_fork_join_fn!(fork_join4, ForkJoinObservable4, o1: O1, o2: O2, o3: O3, o4: O4);
+impl_fork_join_fn!(fork_join5, ForkJoinObservable5, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5);
+impl_fork_join_fn!(fork_join6, ForkJoinObservable6, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6);
+impl_fork_join_fn!(fork_join7, ForkJoinObservable7, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6, o7: O7);
+impl_fork_join_fn!(fork_join8, ForkJoinObservable8, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6, o7: O7, o8: O8);
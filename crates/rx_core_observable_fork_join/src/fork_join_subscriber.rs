@@ -0,0 +1,105 @@
+use rx_core_common::{RxObserver, Signal, Subscriber, SubscriptionLike};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_notification_store::NotificationState;
+use rx_core_notification_variadics::IndexedObservableNotification;
+
+const UNREACHABLE_ERROR: &str = "The ForkJoinSubscriber expects only materialized notifications through its `next` fn, from an IndexedSubscriber.";
+
+/// # ForkJoinSubscriber
+///
+/// From an upstream multiplexer over any number of source observables of the
+/// same type, this subscriber maintains a state for each sources last emission
+/// separately, and emits an array of them once all of them have completed.
+///
+/// As soon as a source completes without ever emitting, there can never be a
+/// value to emit, so it completes immediately without one.
+#[derive(RxSubscriber)]
+#[rx_in(IndexedObservableNotification<Out, Destination::InError>)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_skip_unsubscribe_on_drop_impl] // This subscribers unsubscribe method should be unreachable!
+pub struct ForkJoinSubscriber<Destination, Out, const SIZE: usize>
+where
+	Destination: Subscriber<In = [Out; SIZE]>,
+	Out: Signal,
+{
+	states: [NotificationState<Out, Destination::InError>; SIZE],
+	#[destination]
+	destination: Destination,
+}
+
+impl<Destination, Out, const SIZE: usize> ForkJoinSubscriber<Destination, Out, SIZE>
+where
+	Destination: Subscriber<In = [Out; SIZE]>,
+	Out: Signal,
+{
+	pub fn new(destination: Destination) -> Self {
+		Self {
+			states: core::array::from_fn(|_| NotificationState::default()),
+			destination,
+		}
+	}
+
+	fn take_next(&mut self) -> [Out; SIZE] {
+		self.states.each_mut().map(|state| {
+			state
+				.take_value()
+				.expect("a completed state that was not primed should have completed early")
+		})
+	}
+}
+
+impl<Destination, Out, const SIZE: usize> RxObserver for ForkJoinSubscriber<Destination, Out, SIZE>
+where
+	Destination: Subscriber<In = [Out; SIZE]>,
+	Out: Signal,
+{
+	fn next(&mut self, next: Self::In) {
+		if self.destination.is_closed() {
+			return;
+		}
+
+		let state = &mut self.states[next.index];
+		if !state.update_with_notification_would_be_invalid(&next.notification) {
+			state.push(next.notification);
+		}
+
+		if let Some(error) = state.take_error() {
+			self.destination.error(error);
+		} else if state.is_completed_but_not_primed() {
+			// This source is empty, there will never be a value to emit
+			self.destination.complete();
+		} else if state.is_closed_but_not_completed() {
+			// This source will never complete, neither will this
+			self.destination.unsubscribe();
+		} else if self.states.iter().all(|state| state.is_completed()) {
+			let next = self.take_next();
+			self.destination.next(next);
+			self.destination.complete();
+		}
+	}
+
+	fn error(&mut self, _error: Self::InError) {
+		unreachable!("{} - Error", UNREACHABLE_ERROR)
+	}
+
+	fn complete(&mut self) {
+		unreachable!("{} - Complete", UNREACHABLE_ERROR)
+	}
+}
+
+impl<Destination, Out, const SIZE: usize> SubscriptionLike
+	for ForkJoinSubscriber<Destination, Out, SIZE>
+where
+	Destination: Subscriber<In = [Out; SIZE]>,
+	Out: Signal,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		unreachable!("{} - Unsubscribe", UNREACHABLE_ERROR)
+	}
+}
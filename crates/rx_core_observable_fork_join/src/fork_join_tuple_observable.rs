@@ -0,0 +1,170 @@
+use rx_core_common::{
+	Observable, SharedSubscriber, SharedSubscription, Subscriber, TeardownCollection,
+	TeardownCollectionExtension, UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+use rx_core_notification_variadics::*;
+
+use crate::{
+	ForkJoinSubscriber2, ForkJoinSubscriber3, ForkJoinSubscriber4, ForkJoinSubscriber5,
+	ForkJoinSubscriber6, ForkJoinSubscriber7, ForkJoinSubscriber8,
+};
+
+macro_rules! impl_fork_join_observable {
+	(@either_subscriber $either_subscriber:ident, $selector:ident, ($($o:ident),+)) => {
+		$either_subscriber<$selector<$($o),+>, _, $($o),+>
+	};
+	(
+		$observable:ident,
+		$subscriber:ident,
+		$either_subscriber:ident,
+		$generics:tt,
+		$($field:ident: $o:ident => $selector:ident),+
+	) => {
+		#[derive(RxObservable)]
+		#[rx_out(($($o::Out,)+))]
+		#[rx_out_error(O1::OutError)]
+		pub struct $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			$($field: $o,)+
+		}
+
+		impl<$($o),+> $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			#[allow(clippy::too_many_arguments)]
+			pub fn new($($field: $o),+) -> Self {
+				Self { $($field),+ }
+			}
+		}
+
+		impl<$($o),+> Observable for $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			type Subscription<Destination>
+				= SharedSubscription
+			where
+				Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+			fn subscribe<Destination>(
+				&mut self,
+				observer: Destination,
+			) -> Self::Subscription<Destination::Upgraded>
+			where
+				Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+			{
+				let destination = observer.upgrade();
+				let mut subscription = SharedSubscription::default();
+				let mut shared_subscriber =
+					SharedSubscriber::new($subscriber::<_, $($o),+>::new(destination));
+
+				$(
+					let $field = self.$field.subscribe(<impl_fork_join_observable!(
+						@either_subscriber $either_subscriber, $selector, $generics
+					)>::new(shared_subscriber.clone()));
+				)+
+
+				shared_subscriber.add(subscription.clone());
+				$(subscription.add_teardown($field.into());)+
+				subscription
+			}
+		}
+	};
+}
+
+impl_fork_join_observable!(
+	ForkJoinObservable2,
+	ForkJoinSubscriber2,
+	EitherSubscriber2,
+	(O1, O2),
+	observable_1: O1 => EitherNotificationSelector1Of2,
+	observable_2: O2 => EitherNotificationSelector2Of2
+);
+
+impl_fork_join_observable!(
+	ForkJoinObservable3,
+	ForkJoinSubscriber3,
+	EitherSubscriber3,
+	(O1, O2, O3),
+	observable_1: O1 => EitherNotificationSelector1Of3,
+	observable_2: O2 => EitherNotificationSelector2Of3,
+	observable_3: O3 => EitherNotificationSelector3Of3
+);
+
+impl_fork_join_observable!(
+	ForkJoinObservable4,
+	ForkJoinSubscriber4,
+	EitherSubscriber4,
+	(O1, O2, O3, O4),
+	observable_1: O1 => EitherNotificationSelector1Of4,
+	observable_2: O2 => EitherNotificationSelector2Of4,
+	observable_3: O3 => EitherNotificationSelector3Of4,
+	observable_4: O4 => EitherNotificationSelector4Of4
+);
+
+impl_fork_join_observable!(
+	ForkJoinObservable5,
+	ForkJoinSubscriber5,
+	EitherSubscriber5,
+	(O1, O2, O3, O4, O5),
+	observable_1: O1 => EitherNotificationSelector1Of5,
+	observable_2: O2 => EitherNotificationSelector2Of5,
+	observable_3: O3 => EitherNotificationSelector3Of5,
+	observable_4: O4 => EitherNotificationSelector4Of5,
+	observable_5: O5 => EitherNotificationSelector5Of5
+);
+
+impl_fork_join_observable!(
+	ForkJoinObservable6,
+	ForkJoinSubscriber6,
+	EitherSubscriber6,
+	(O1, O2, O3, O4, O5, O6),
+	observable_1: O1 => EitherNotificationSelector1Of6,
+	observable_2: O2 => EitherNotificationSelector2Of6,
+	observable_3: O3 => EitherNotificationSelector3Of6,
+	observable_4: O4 => EitherNotificationSelector4Of6,
+	observable_5: O5 => EitherNotificationSelector5Of6,
+	observable_6: O6 => EitherNotificationSelector6Of6
+);
+
+impl_fork_join_observable!(
+	ForkJoinObservable7,
+	ForkJoinSubscriber7,
+	EitherSubscriber7,
+	(O1, O2, O3, O4, O5, O6, O7),
+	observable_1: O1 => EitherNotificationSelector1Of7,
+	observable_2: O2 => EitherNotificationSelector2Of7,
+	observable_3: O3 => EitherNotificationSelector3Of7,
+	observable_4: O4 => EitherNotificationSelector4Of7,
+	observable_5: O5 => EitherNotificationSelector5Of7,
+	observable_6: O6 => EitherNotificationSelector6Of7,
+	observable_7: O7 => EitherNotificationSelector7Of7
+);
+
+impl_fork_join_observable!(
+	ForkJoinObservable8,
+	ForkJoinSubscriber8,
+	EitherSubscriber8,
+	(O1, O2, O3, O4, O5, O6, O7, O8),
+	observable_1: O1 => EitherNotificationSelector1Of8,
+	observable_2: O2 => EitherNotificationSelector2Of8,
+	observable_3: O3 => EitherNotificationSelector3Of8,
+	observable_4: O4 => EitherNotificationSelector4Of8,
+	observable_5: O5 => EitherNotificationSelector5Of8,
+	observable_6: O6 => EitherNotificationSelector6Of8,
+	observable_7: O7 => EitherNotificationSelector7Of8,
+	observable_8: O8 => EitherNotificationSelector8Of8
+);
@@ -0,0 +1,209 @@
+use rx_core_common::{Observable, RxObserver, Subscriber, SubscriptionLike};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_notification_store::NotificationState;
+use rx_core_notification_variadics::{
+	EitherObservableNotification2, EitherObservableNotification3, EitherObservableNotification4,
+	EitherObservableNotification5, EitherObservableNotification6, EitherObservableNotification7,
+	EitherObservableNotification8,
+};
+
+const UNREACHABLE_ERROR: &str = "The ForkJoinSubscriber expects only materialized notifications through its `next` fn, from an EitherSubscriber.";
+
+macro_rules! impl_fork_join_subscriber {
+	($subscriber:ident, $notification:ident, $($o:ident => $state:ident),+) => {
+		/// # ForkJoinSubscriber
+		///
+		/// From an upstream multiplexer over multiple source observables, this
+		/// subscriber maintains a state for each sources last emission
+		/// separately, and emits a tuple of them once all of them have
+		/// completed.
+		///
+		/// As soon as a source completes without ever emitting, there can
+		/// never be a value to emit, so it completes immediately without one.
+		#[derive(RxSubscriber)]
+		#[rx_in($notification<$($o),+>)]
+		#[rx_in_error(Destination::InError)]
+		#[rx_delegate_teardown_collection]
+		#[rx_skip_unsubscribe_on_drop_impl] // This subscribers unsubscribe method should be unreachable!
+		pub struct $subscriber<Destination, $($o),+>
+		where
+			Destination: Subscriber<In = ($($o::Out,)+)>,
+			$(
+				$o: 'static + Observable,
+				$o::OutError: Into<Destination::InError>,
+			)+
+		{
+			$($state: NotificationState<$o::Out, $o::OutError>,)+
+			#[destination]
+			destination: Destination,
+		}
+
+		impl<Destination, $($o),+> $subscriber<Destination, $($o),+>
+		where
+			Destination: Subscriber<In = ($($o::Out,)+)>,
+			$(
+				$o: 'static + Observable,
+				$o::OutError: Into<Destination::InError>,
+			)+
+		{
+			pub fn new(destination: Destination) -> Self {
+				$subscriber {
+					$($state: NotificationState::default(),)+
+					destination,
+				}
+			}
+
+			fn take_next(&mut self) -> Destination::In {
+				($(
+					self.$state
+						.take_value()
+						.expect("a completed state that was not primed should have completed early"),
+				)+)
+			}
+
+			fn take_either_error(&mut self) -> Option<Destination::InError> {
+				$(
+					if let Some(error) = self.$state.take_error() {
+						return Some(error.into());
+					}
+				)+
+				None
+			}
+		}
+
+		impl<Destination, $($o),+> RxObserver for $subscriber<Destination, $($o),+>
+		where
+			Destination: Subscriber<In = ($($o::Out,)+)>,
+			$(
+				$o: 'static + Observable,
+				$o::OutError: Into<Destination::InError>,
+			)+
+		{
+			fn next(&mut self, next: Self::In) {
+				if self.destination.is_closed() {
+					return;
+				}
+
+				match next {
+					$(
+						$notification::$o(notification) => {
+							if !self.$state.update_with_notification_would_be_invalid(&notification) {
+								self.$state.push(notification);
+							}
+						}
+					)+
+				};
+
+				if let Some(error) = self.take_either_error() {
+					self.destination.error(error);
+				} else if $(self.$state.is_completed_but_not_primed())||+ {
+					// A source is empty, there will never be a value to emit
+					self.destination.complete();
+				} else if $(self.$state.is_closed_but_not_completed())||+ {
+					// A source will never complete, neither will this
+					self.destination.unsubscribe();
+				} else if $(self.$state.is_completed())&&+ {
+					let next = self.take_next();
+					self.destination.next(next);
+					self.destination.complete();
+				}
+			}
+
+			fn error(&mut self, _error: Self::InError) {
+				unreachable!("{} - Error", UNREACHABLE_ERROR)
+			}
+
+			fn complete(&mut self) {
+				unreachable!("{} - Complete", UNREACHABLE_ERROR)
+			}
+		}
+
+		impl<Destination, $($o),+> SubscriptionLike for $subscriber<Destination, $($o),+>
+		where
+			Destination: Subscriber<In = ($($o::Out,)+)>,
+			$(
+				$o: 'static + Observable,
+				$o::OutError: Into<Destination::InError>,
+			)+
+		{
+			#[inline]
+			fn is_closed(&self) -> bool {
+				self.destination.is_closed()
+			}
+
+			fn unsubscribe(&mut self) {
+				unreachable!("{} - Unsubscribe", UNREACHABLE_ERROR)
+			}
+		}
+	};
+}
+
+impl_fork_join_subscriber!(
+	ForkJoinSubscriber2,
+	EitherObservableNotification2,
+	O1 => o1_state,
+	O2 => o2_state
+);
+
+impl_fork_join_subscriber!(
+	ForkJoinSubscriber3,
+	EitherObservableNotification3,
+	O1 => o1_state,
+	O2 => o2_state,
+	O3 => o3_state
+);
+
+impl_fork_join_subscriber!(
+	ForkJoinSubscriber4,
+	EitherObservableNotification4,
+	O1 => o1_state,
+	O2 => o2_state,
+	O3 => o3_state,
+	O4 => o4_state
+);
+
+impl_fork_join_subscriber!(
+	ForkJoinSubscriber5,
+	EitherObservableNotification5,
+	O1 => o1_state,
+	O2 => o2_state,
+	O3 => o3_state,
+	O4 => o4_state,
+	O5 => o5_state
+);
+
+impl_fork_join_subscriber!(
+	ForkJoinSubscriber6,
+	EitherObservableNotification6,
+	O1 => o1_state,
+	O2 => o2_state,
+	O3 => o3_state,
+	O4 => o4_state,
+	O5 => o5_state,
+	O6 => o6_state
+);
+
+impl_fork_join_subscriber!(
+	ForkJoinSubscriber7,
+	EitherObservableNotification7,
+	O1 => o1_state,
+	O2 => o2_state,
+	O3 => o3_state,
+	O4 => o4_state,
+	O5 => o5_state,
+	O6 => o6_state,
+	O7 => o7_state
+);
+
+impl_fork_join_subscriber!(
+	ForkJoinSubscriber8,
+	EitherObservableNotification8,
+	O1 => o1_state,
+	O2 => o2_state,
+	O3 => o3_state,
+	O4 => o4_state,
+	O5 => o5_state,
+	O6 => o6_state,
+	O7 => o7_state,
+	O8 => o8_state
+);
@@ -0,0 +1,20 @@
+mod fork_join_observable;
+mod fork_join_subscriber;
+mod fork_join_tuple_observable;
+mod fork_join_tuple_subscriber;
+
+pub use fork_join_subscriber::*;
+pub use fork_join_tuple_subscriber::*;
+
+pub mod observable {
+	pub use super::fork_join_observable::*;
+	pub use super::fork_join_tuple_observable::*;
+}
+
+#[cfg(feature = "observable_fn")]
+mod fork_join_observable_fn;
+
+#[cfg(feature = "observable_fn")]
+pub mod observable_fn {
+	pub use super::fork_join_observable_fn::*;
+}
@@ -0,0 +1,90 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{
+	Never, Observable, PhantomInvariant, Scheduler, SchedulerHandle, Signal, Subscriber,
+	UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::FutureSubscription;
+
+/// # FutureObservable
+///
+/// Emits the output of a [Future] once it resolves, polled by the provided
+/// scheduler, then completes.
+///
+/// A new future is created for every subscription using the `future_creator`.
+///
+/// The future is only polled again when it wakes its waker, which invokes the
+/// polling work on the scheduler.
+///
+/// ## Completion Behavior
+///
+/// Completes immediately after the future resolved.
+///
+/// ## Error Behavior
+///
+/// This observable does not error. Futures resolving to a `Result` can be
+/// turned into errors downstream using `lift_result`.
+///
+/// ## Arguments
+///
+/// - `future_creator`: Creates the future to be polled for each subscription.
+/// - `scheduler`: The scheduler's handle to poll the future with.
+#[derive(RxObservable)]
+#[rx_out(F::Output)]
+#[rx_out_error(Never)]
+pub struct FutureObservable<FutureCreator, F, S>
+where
+	FutureCreator: FnMut() -> F,
+	F: 'static + Future + Send,
+	F::Output: Signal,
+	S: Scheduler,
+{
+	future_creator: FutureCreator,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<F>,
+}
+
+impl<FutureCreator, F, S> FutureObservable<FutureCreator, F, S>
+where
+	FutureCreator: FnMut() -> F,
+	F: 'static + Future + Send,
+	F::Output: Signal,
+	S: Scheduler,
+{
+	pub fn new(future_creator: FutureCreator, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			future_creator,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<FutureCreator, F, S> Observable for FutureObservable<FutureCreator, F, S>
+where
+	FutureCreator: FnMut() -> F,
+	F: 'static + Future + Send,
+	F::Output: Signal,
+	S: 'static + Scheduler,
+{
+	type Subscription<Destination>
+		= FutureSubscription<Destination, S>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		observer: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+	{
+		FutureSubscription::new(
+			(self.future_creator)(),
+			observer.upgrade(),
+			self.scheduler.clone(),
+		)
+	}
+}
@@ -0,0 +1,35 @@
+use rx_core_common::{Scheduler, SchedulerHandle, Signal};
+
+use crate::observable::FutureObservable;
+
+/// # FutureObservable
+///
+/// Emits the output of a [Future] once it resolves, polled by the provided
+/// scheduler, then completes.
+///
+/// A new future is created for every subscription using the `future_creator`.
+///
+/// ## Completion Behavior
+///
+/// Completes immediately after the future resolved.
+///
+/// ## Error Behavior
+///
+/// This observable does not error.
+///
+/// ## Arguments
+///
+/// - `future_creator`: Creates the future to be polled for each subscription.
+/// - `scheduler`: The scheduler's handle to poll the future with.
+pub fn from_future<FutureCreator, F, S>(
+	future_creator: FutureCreator,
+	scheduler: SchedulerHandle<S>,
+) -> FutureObservable<FutureCreator, F, S>
+where
+	FutureCreator: FnMut() -> F,
+	F: 'static + Future + Send,
+	F::Output: Signal,
+	S: Scheduler,
+{
+	FutureObservable::new(future_creator, scheduler)
+}
@@ -0,0 +1,101 @@
+use std::{
+	sync::{Mutex, PoisonError},
+	task::{Context, Poll},
+};
+
+use rx_core_common::{
+	InvokedTaskFactory, Scheduler, SchedulerHandle, SharedSubscriber, Subscriber, SubscriptionLike,
+	Teardown, TeardownCollectionExtension, WorkInvokeId, WorkInvokeWaker, WorkResult,
+};
+use rx_core_macro_subscription_derive::RxSubscription;
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+pub struct FutureSubscription<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: Scheduler,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	scheduler: SchedulerHandle<S>,
+	invoke_id: WorkInvokeId,
+}
+
+impl<Destination, S> FutureSubscription<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: 'static + Scheduler,
+{
+	pub fn new<F>(future: F, destination: Destination, scheduler: SchedulerHandle<S>) -> Self
+	where
+		F: 'static + Future<Output = Destination::In> + Send,
+	{
+		let mut destination = SharedSubscriber::new(destination);
+
+		let invoke_id = {
+			let mut scheduler_lock = scheduler.lock();
+			let invoke_id = scheduler_lock.generate_invoke_id();
+			let waker = WorkInvokeWaker::new(invoke_id, scheduler.clone()).into_waker();
+			let destination_clone = destination.clone();
+			// Only accessed by the work, the lock is there to make it Sync
+			let future = Mutex::new(Box::pin(future));
+
+			scheduler_lock.schedule_invoked_work(
+				S::InvokedWorkFactory::new(move |_, _| {
+					let mut destination_lock = destination_clone.lock();
+
+					if destination_lock.is_closed() {
+						return WorkResult::Done;
+					}
+
+					let mut future = future.lock().unwrap_or_else(PoisonError::into_inner);
+					let mut context = Context::from_waker(&waker);
+
+					match future.as_mut().poll(&mut context) {
+						Poll::Ready(output) => {
+							destination_lock.next(output);
+							destination_lock.complete();
+							WorkResult::Done
+						}
+						Poll::Pending => WorkResult::Pending,
+					}
+				}),
+				invoke_id,
+			);
+
+			// The first poll
+			scheduler_lock.invoke(invoke_id);
+
+			invoke_id
+		};
+
+		destination.add(Teardown::new_invoked_work_cancellation(
+			invoke_id,
+			scheduler.clone(),
+		));
+
+		FutureSubscription {
+			destination,
+			scheduler,
+			invoke_id,
+		}
+	}
+}
+
+impl<Destination, S> SubscriptionLike for FutureSubscription<Destination, S>
+where
+	Destination: Subscriber,
+	S: Scheduler,
+{
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		self.scheduler.lock().cancel_invoked(self.invoke_id);
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
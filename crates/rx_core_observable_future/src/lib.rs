@@ -0,0 +1,16 @@
+mod future_observable;
+mod future_subscription;
+
+pub use future_subscription::*;
+
+pub mod observable {
+	pub use super::future_observable::*;
+}
+
+#[cfg(feature = "observable_fn")]
+mod future_observable_fn;
+
+#[cfg(feature = "observable_fn")]
+pub mod observable_fn {
+	pub use super::future_observable_fn::*;
+}
@@ -0,0 +1,124 @@
+use rx_core_common::{Observable, RxObserver, Subscriber, SubscriptionLike};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_notification_store::NotificationState;
+use rx_core_notification_variadics::IndexedObservableNotification;
+
+const UNREACHABLE_ERROR: &str = "The JoinArraySubscriber expects only materialized notifications through its `next` fn, from an IndexedSubscriber.";
+
+/// # JoinArraySubscriber
+///
+/// From an upstream multiplexer over any number of source observables of the
+/// same type, this subscriber maintains a state for each sources last emission
+/// separately, and emits a `Vec` of them once all of them complete and all of
+/// them have emitted a value before completing.
+#[derive(RxSubscriber)]
+#[rx_in(IndexedObservableNotification<O::Out, O::OutError>)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_skip_unsubscribe_on_drop_impl] // This subscribers unsubscribe method should be unreachable!
+pub struct JoinArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	states: Vec<NotificationState<O::Out, O::OutError>>,
+	#[destination]
+	destination: Destination,
+}
+
+impl<Destination, O> JoinArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	pub fn new(destination: Destination, source_count: usize) -> Self {
+		JoinArraySubscriber {
+			states: (0..source_count)
+				.map(|_| NotificationState::default())
+				.collect(),
+			destination,
+		}
+	}
+
+	fn get_next(&self) -> Option<Destination::In> {
+		self.states
+			.iter()
+			.map(|state| state.get_value().cloned())
+			.collect()
+	}
+
+	fn take_any_error(&mut self) -> Option<Destination::InError> {
+		self.states
+			.iter_mut()
+			.find_map(|state| state.take_error())
+			.map(|error| error.into())
+	}
+
+	fn try_complete(&mut self) {
+		if !self.destination.is_closed() && self.states.iter().all(|state| state.is_completed()) {
+			if let Some(next) = self.get_next() {
+				self.destination.next(next);
+			}
+
+			self.destination.complete();
+		}
+	}
+
+	fn try_unsubscribe(&mut self) {
+		if !self.destination.is_closed() && self.states.iter().all(|state| state.is_closed()) {
+			self.destination.unsubscribe();
+		}
+	}
+}
+
+impl<Destination, O> RxObserver for JoinArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	fn next(&mut self, next: Self::In) {
+		let state = &mut self.states[next.index];
+		if !state.update_with_notification_would_be_invalid(&next.notification) {
+			state.push(next.notification);
+		}
+
+		if let Some(error) = self.take_any_error() {
+			self.destination.error(error);
+			return;
+		}
+
+		self.try_complete();
+		self.try_unsubscribe();
+	}
+
+	fn error(&mut self, _error: Self::InError) {
+		unreachable!("{} - Error", UNREACHABLE_ERROR)
+	}
+
+	fn complete(&mut self) {
+		unreachable!("{} - Complete", UNREACHABLE_ERROR)
+	}
+}
+
+impl<Destination, O> SubscriptionLike for JoinArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		unreachable!("{} - Unsubscribe", UNREACHABLE_ERROR)
+	}
+}
@@ -1,25 +1,53 @@
 use rx_core_common::Observable;
 
-use crate::observable::JoinObservable;
+use crate::observable::{
+	JoinArrayObservable, JoinObservable, JoinObservable3, JoinObservable4, JoinObservable5,
+	JoinObservable6, JoinObservable7, JoinObservable8,
+};
 
-/// # [JoinObservable]
-///
-/// This observable will only emit once both of it's input observables
-/// have completed. After which it will emit a tuple of the last emissions
-/// from each input observable, then complete.
-///
-/// Meaning if even one of the observables haven't emitted before all of them
-/// had completed, only a complete notification will be observed!
+macro_rules! impl_join_fn {
+	($fn_name:ident, $observable:ident, $($field:ident: $o:ident),+) => {
+		/// # [JoinObservable]
+		///
+		/// This observable will only emit once all of it's input observables
+		/// have completed. After which it will emit a tuple of the last emissions
+		/// from each input observable, then complete.
+		///
+		/// Meaning if even one of the observables haven't emitted before all of them
+		/// had completed, only a complete notification will be observed!
+		///
+		/// If not all observables complete, nothing will be emitted even if all
+		/// input observables were primed.
+		#[allow(clippy::too_many_arguments)]
+		pub fn $fn_name<$($o),+>($($field: $o),+) -> $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::Out: Clone,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			$observable::new($($field),+)
+		}
+	};
+}
+
+impl_join_fn!(join, JoinObservable, o1: O1, o2: O2);
+impl_join_fn!(join3, JoinObservable3, o1: O1, o2: O2, o3: O3);
+impl_join_fn!(join4, JoinObservable4, o1: O1, o2: O2, o3: O3, o4: O4);
+impl_join_fn!(join5, JoinObservable5, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5);
+impl_join_fn!(join6, JoinObservable6, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6);
+impl_join_fn!(join7, JoinObservable7, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6, o7: O7);
+impl_join_fn!(join8, JoinObservable8, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6, o7: O7, o8: O8);
+
+/// Joins any number of observables of the same type, emitting a `Vec` of
+/// their last values once all of them completed, then completing.
 ///
-/// If not all observables complete, nothing will be emitted even if all
-/// input observables were primed.
-pub fn join<O1, O2>(o1: O1, o2: O2) -> JoinObservable<O1, O2>
+/// Accepts both arrays and `Vec`s. Completes immediately when empty.
+pub fn join_array<O>(observables: impl IntoIterator<Item = O>) -> JoinArrayObservable<O>
 where
-	O1: 'static + Send + Sync + Observable,
-	O1::Out: Clone,
-	O2: 'static + Send + Sync + Observable,
-	O2::Out: Clone,
-	O2::OutError: Into<O1::OutError>,
+	O: 'static + Send + Sync + Observable,
+	O::Out: Clone,
 {
-	JoinObservable::new(o1, o2)
+	JoinArrayObservable::new(observables)
 }
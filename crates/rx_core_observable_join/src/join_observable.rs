@@ -3,96 +3,182 @@ use rx_core_common::{
 	TeardownCollectionExtension, UpgradeableObserver,
 };
 use rx_core_macro_observable_derive::RxObservable;
-use rx_core_notification_variadics::{
-	EitherNotificationSelector1Of2, EitherNotificationSelector2Of2, EitherSubscriber2,
+use rx_core_notification_variadics::*;
+
+use crate::{
+	JoinSubscriber, JoinSubscriber3, JoinSubscriber4, JoinSubscriber5, JoinSubscriber6,
+	JoinSubscriber7, JoinSubscriber8,
 };
 
-use crate::JoinSubscriber;
+macro_rules! impl_join_observable {
+	(@either_subscriber $either_subscriber:ident, $selector:ident, ($($o:ident),+)) => {
+		$either_subscriber<$selector<$($o),+>, _, $($o),+>
+	};
+	(
+		$observable:ident,
+		$subscriber:ident,
+		$either_subscriber:ident,
+		$generics:tt,
+		$($field:ident: $o:ident => $selector:ident),+
+	) => {
+		/// # [JoinObservable]
+		///
+		/// This observable will only emit once all of it's input observables
+		/// have completed. After which it will emit a tuple of the last emissions
+		/// from each input observable, then complete.
+		///
+		/// Meaning if even one of the observables haven't emitted before all of them
+		/// had completed, only a complete notification will be observed!
+		///
+		/// If not all observables complete, nothing will be emitted even if all
+		/// input observables were primed.
+		#[derive(RxObservable)]
+		#[rx_out(($($o::Out,)+))]
+		#[rx_out_error(O1::OutError)]
+		pub struct $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::Out: Clone,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			$($field: $o,)+
+		}
 
-/// # [JoinObservable]
-///
-/// This observable will only emit once both of it's input observables
-/// have completed. After which it will emit a tuple of the last emissions
-/// from each input observable, then complete.
-///
-/// Meaning if even one of the observables haven't emitted before all of them
-/// had completed, only a complete notification will be observed!
-///
-/// If not all observables complete, nothing will be emitted even if all
-/// input observables were primed.
-#[derive(RxObservable)]
-#[rx_out((O1::Out, O2::Out))]
-#[rx_out_error(O1::OutError)]
-pub struct JoinObservable<O1, O2>
-where
-	O1: 'static + Send + Sync + Observable,
-	O1::Out: Clone,
-	O2: 'static + Send + Sync + Observable,
-	O2::Out: Clone,
-	O2::OutError: Into<O1::OutError>,
-{
-	observable_1: O1,
-	observable_2: O2,
-}
+		impl<$($o),+> $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::Out: Clone,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			#[allow(clippy::too_many_arguments)]
+			pub fn new($($field: $o),+) -> Self {
+				Self { $($field),+ }
+			}
+		}
+
+		impl<$($o),+> Observable for $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::Out: Clone,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			type Subscription<Destination>
+				= SharedSubscription
+			where
+				Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
 
-impl<O1, O2> JoinObservable<O1, O2>
-where
-	O1: 'static + Send + Sync + Observable,
-	O1::Out: Clone,
-	O2: 'static + Send + Sync + Observable,
-	O2::Out: Clone,
-	O2::OutError: Into<O1::OutError>,
-{
-	pub fn new(o1: O1, o2: O2) -> Self {
-		Self {
-			observable_1: o1,
-			observable_2: o2,
+			fn subscribe<Destination>(
+				&mut self,
+				observer: Destination,
+			) -> Self::Subscription<Destination::Upgraded>
+			where
+				Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+			{
+				let destination = observer.upgrade();
+				let mut subscription = SharedSubscription::default();
+				let mut shared_subscriber =
+					SharedSubscriber::new($subscriber::<_, $($o),+>::new(destination));
+
+				$(
+					let $field = self.$field.subscribe(<impl_join_observable!(
+						@either_subscriber $either_subscriber, $selector, $generics
+					)>::new(shared_subscriber.clone()));
+				)+
+
+				shared_subscriber.add(subscription.clone());
+				$(subscription.add_teardown($field.into());)+
+				subscription
+			}
 		}
-	}
+	};
 }
 
-impl<O1, O2> Observable for JoinObservable<O1, O2>
-where
-	O1: 'static + Send + Sync + Observable,
-	O1::Out: Clone,
-	O2: 'static + Send + Sync + Observable,
-	O2::Out: Clone,
-	O2::OutError: Into<O1::OutError>,
-{
-	type Subscription<Destination>
-		= SharedSubscription
-	where
-		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+impl_join_observable!(
+	JoinObservable,
+	JoinSubscriber,
+	EitherSubscriber2,
+	(O1, O2),
+	observable_1: O1 => EitherNotificationSelector1Of2,
+	observable_2: O2 => EitherNotificationSelector2Of2
+);
 
-	fn subscribe<Destination>(
-		&mut self,
-		observer: Destination,
-	) -> Self::Subscription<Destination::Upgraded>
-	where
-		Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
-	{
-		let destination = observer.upgrade();
-		let mut subscription = SharedSubscription::default();
-		let mut shared_subscriber =
-			SharedSubscriber::new(JoinSubscriber::<_, O1, O2>::new(destination));
+impl_join_observable!(
+	JoinObservable3,
+	JoinSubscriber3,
+	EitherSubscriber3,
+	(O1, O2, O3),
+	observable_1: O1 => EitherNotificationSelector1Of3,
+	observable_2: O2 => EitherNotificationSelector2Of3,
+	observable_3: O3 => EitherNotificationSelector3Of3
+);
 
-		let s1 = self.observable_1.subscribe(EitherSubscriber2::<
-			EitherNotificationSelector1Of2<O1, O2>,
-			_,
-			O1,
-			O2,
-		>::new(shared_subscriber.clone()));
+impl_join_observable!(
+	JoinObservable4,
+	JoinSubscriber4,
+	EitherSubscriber4,
+	(O1, O2, O3, O4),
+	observable_1: O1 => EitherNotificationSelector1Of4,
+	observable_2: O2 => EitherNotificationSelector2Of4,
+	observable_3: O3 => EitherNotificationSelector3Of4,
+	observable_4: O4 => EitherNotificationSelector4Of4
+);
 
-		let s2 = self.observable_2.subscribe(EitherSubscriber2::<
-			EitherNotificationSelector2Of2<O1, O2>,
-			_,
-			O1,
-			O2,
-		>::new(shared_subscriber.clone()));
+impl_join_observable!(
+	JoinObservable5,
+	JoinSubscriber5,
+	EitherSubscriber5,
+	(O1, O2, O3, O4, O5),
+	observable_1: O1 => EitherNotificationSelector1Of5,
+	observable_2: O2 => EitherNotificationSelector2Of5,
+	observable_3: O3 => EitherNotificationSelector3Of5,
+	observable_4: O4 => EitherNotificationSelector4Of5,
+	observable_5: O5 => EitherNotificationSelector5Of5
+);
 
-		shared_subscriber.add(subscription.clone());
-		subscription.add_teardown(s1.into());
-		subscription.add_teardown(s2.into());
-		subscription
-	}
-}
+impl_join_observable!(
+	JoinObservable6,
+	JoinSubscriber6,
+	EitherSubscriber6,
+	(O1, O2, O3, O4, O5, O6),
+	observable_1: O1 => EitherNotificationSelector1Of6,
+	observable_2: O2 => EitherNotificationSelector2Of6,
+	observable_3: O3 => EitherNotificationSelector3Of6,
+	observable_4: O4 => EitherNotificationSelector4Of6,
+	observable_5: O5 => EitherNotificationSelector5Of6,
+	observable_6: O6 => EitherNotificationSelector6Of6
+);
+
+impl_join_observable!(
+	JoinObservable7,
+	JoinSubscriber7,
+	EitherSubscriber7,
+	(O1, O2, O3, O4, O5, O6, O7),
+	observable_1: O1 => EitherNotificationSelector1Of7,
+	observable_2: O2 => EitherNotificationSelector2Of7,
+	observable_3: O3 => EitherNotificationSelector3Of7,
+	observable_4: O4 => EitherNotificationSelector4Of7,
+	observable_5: O5 => EitherNotificationSelector5Of7,
+	observable_6: O6 => EitherNotificationSelector6Of7,
+	observable_7: O7 => EitherNotificationSelector7Of7
+);
+
+impl_join_observable!(
+	JoinObservable8,
+	JoinSubscriber8,
+	EitherSubscriber8,
+	(O1, O2, O3, O4, O5, O6, O7, O8),
+	observable_1: O1 => EitherNotificationSelector1Of8,
+	observable_2: O2 => EitherNotificationSelector2Of8,
+	observable_3: O3 => EitherNotificationSelector3Of8,
+	observable_4: O4 => EitherNotificationSelector4Of8,
+	observable_5: O5 => EitherNotificationSelector5Of8,
+	observable_6: O6 => EitherNotificationSelector6Of8,
+	observable_7: O7 => EitherNotificationSelector7Of8,
+	observable_8: O8 => EitherNotificationSelector8Of8
+);
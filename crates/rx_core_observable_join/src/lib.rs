@@ -1,9 +1,13 @@
+mod join_array_observable;
+mod join_array_subscriber;
 mod join_observable;
 mod join_subscriber;
 
+pub use join_array_subscriber::*;
 pub use join_subscriber::*;
 
 pub mod observable {
+	pub use super::join_array_observable::*;
 	pub use super::join_observable::*;
 }
 
@@ -0,0 +1,20 @@
+mod race_observable;
+mod race_source_subscriber;
+mod race_subscriber;
+mod race_tuple_observable;
+
+pub use race_source_subscriber::*;
+pub use race_subscriber::*;
+
+pub mod observable {
+	pub use super::race_observable::*;
+	pub use super::race_tuple_observable::*;
+}
+
+#[cfg(feature = "observable_fn")]
+mod race_observable_fn;
+
+#[cfg(feature = "observable_fn")]
+pub mod observable_fn {
+	pub use super::race_observable_fn::*;
+}
@@ -0,0 +1,92 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{
+	ErasedObservables, Observable, PhantomInvariant, RxObserver, SharedSubscriber,
+	SharedSubscription, Signal, Subscriber, SubscriptionLike, TeardownCollection,
+	TeardownCollectionExtension, UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{RaceSourceSubscriber, RaceSubscriber};
+
+#[derive(RxObservable, Clone)]
+#[rx_out(Out)]
+#[rx_out_error(OutError)]
+pub struct RaceObservable<Out, OutError, const SIZE: usize>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	observables: ErasedObservables<Out, OutError, SIZE>,
+	_phantom_data: PhantomInvariant<(Out, OutError)>,
+}
+
+impl<Out, OutError, const SIZE: usize> RaceObservable<Out, OutError, SIZE>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	pub fn new(observables: impl Into<ErasedObservables<Out, OutError, SIZE>>) -> Self {
+		Self {
+			observables: observables.into(),
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<Out, OutError, const SIZE: usize> Observable for RaceObservable<Out, OutError, SIZE>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	type Subscription<Destination>
+		= SharedSubscription
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		observer: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+	{
+		let mut destination = observer.upgrade();
+		if SIZE == 0 {
+			// Nobody to race, there will never be a winner
+			destination.complete();
+		}
+
+		let source_subscriptions: [SharedSubscription; SIZE] =
+			core::array::from_fn(|_| SharedSubscription::default());
+
+		let mut subscription = SharedSubscription::default();
+		let mut shared_subscriber = SharedSubscriber::new(RaceSubscriber::new(
+			destination,
+			source_subscriptions.clone(),
+		));
+
+		for (index, (observable, mut source_subscription)) in self
+			.observables
+			.iter_mut()
+			.zip(source_subscriptions)
+			.enumerate()
+		{
+			// A source that already emitted while being subscribed to has won
+			// the race, the rest of them are already unsubscribed.
+			if !source_subscription.is_closed() {
+				let inner_subscription = observable.subscribe(RaceSourceSubscriber::new(
+					shared_subscriber.clone(),
+					index,
+					source_subscription.clone(),
+				));
+				source_subscription.add_teardown(inner_subscription.into());
+			}
+
+			subscription.add_teardown(source_subscription.into());
+		}
+
+		shared_subscriber.add(subscription.clone());
+		subscription
+	}
+}
@@ -0,0 +1,70 @@
+use rx_core_common::{ErasedObservables, Observable, Signal};
+
+use crate::observable::{
+	RaceObservable, RaceObservable2, RaceObservable3, RaceObservable4, RaceObservable5,
+	RaceObservable6, RaceObservable7, RaceObservable8,
+};
+
+/// # RaceObservable
+///
+/// > Category: Combination Observable
+///
+/// Subscribes to multiple input observables of shared output types, and
+/// mirrors the first one of them that emits any notification. Also known as
+/// `amb`.
+///
+/// The moment a winner is decided, all other input observables are
+/// unsubscribed. Input observables are subscribed to in order, if one of them
+/// emits during its subscription, the remaining ones are never subscribed to.
+///
+/// ## Completion Behavior
+///
+/// Completes when the winner completes, or immediately when there are no
+/// input observables.
+///
+/// ## Error Behavior
+///
+/// Errors when the winner errors. An error is a notification too, so an input
+/// observable that errors first wins the race.
+///
+/// ## Arguments
+///
+/// - `observables`: A tuple or array of observables of shared output types
+///
+/// To race observables of different output types, use [race2] up to [race8].
+pub fn race<Out, OutError, const SIZE: usize>(
+	observables: impl Into<ErasedObservables<Out, OutError, SIZE>>,
+) -> RaceObservable<Out, OutError, SIZE>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	RaceObservable::new(observables)
+}
+
+macro_rules! impl_race_fn {
+	($fn_name:ident, $observable:ident, $($field:ident: $o:ident),+) => {
+		/// Like [race], but for observables of different output types, as long
+		/// as their values and errors are convertible into the output types of
+		/// the first one.
+		#[allow(clippy::too_many_arguments)]
+		pub fn $fn_name<$($o),+>($($field: $o),+) -> $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::Out: Into<O1::Out>,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			$observable::new($($field),+)
+		}
+	};
+}
+
+impl_race_fn!(race2, RaceObservable2, o1: O1, o2: O2);
+impl_race_fn!(race3, RaceObservable3, o1: O1, o2: O2, o3: O3);
+impl_race_fn!(race4, RaceObservable4, o1: O1, o2: O2, o3: O3, o4: O4);
+impl_race_fn!(race5, RaceObservable5, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5);
+impl_race_fn!(race6, RaceObservable6, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6);
+impl_race_fn!(race7, RaceObservable7, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6, o7: O7);
+impl_race_fn!(race8, RaceObservable8, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6, o7: O7, o8: O8);
@@ -0,0 +1,135 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{
+	PhantomInvariant, RxObserver, SharedSubscription, Signal, Subscriber, SubscriberNotification,
+	SubscriptionLike, Teardown, TeardownCollection,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_notification_variadics::IndexedObservableNotification;
+
+/// Materializes all signals of a racing source into an
+/// [IndexedObservableNotification], just like an `IndexedSubscriber` would.
+///
+/// Signals are converted into the shared output types of the race, so that
+/// input observables of different types can race each other.
+///
+/// Unlike an `IndexedSubscriber`, teardowns are not delegated to the shared
+/// destination but are collected into the sources own subscription, so that
+/// losers of the race are torn down the moment they are unsubscribed.
+#[derive(RxSubscriber)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+pub struct RaceSourceSubscriber<Destination, In, InError, Out, OutError>
+where
+	Destination: Subscriber<In = IndexedObservableNotification<Out, OutError>>,
+	In: Signal + Into<Out>,
+	InError: Signal + Into<OutError>,
+	Out: Signal,
+	OutError: Signal,
+{
+	index: usize,
+	unsubscribed: bool,
+	teardown: SharedSubscription,
+	destination: Destination,
+	_phantom_data: PhantomInvariant<(In, InError, Out, OutError)>,
+}
+
+impl<Destination, In, InError, Out, OutError>
+	RaceSourceSubscriber<Destination, In, InError, Out, OutError>
+where
+	Destination: Subscriber<In = IndexedObservableNotification<Out, OutError>>,
+	In: Signal + Into<Out>,
+	InError: Signal + Into<OutError>,
+	Out: Signal,
+	OutError: Signal,
+{
+	pub fn new(destination: Destination, index: usize, teardown: SharedSubscription) -> Self {
+		Self {
+			index,
+			unsubscribed: false,
+			teardown,
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+
+	#[inline]
+	fn forward(&mut self, notification: SubscriberNotification<Out, OutError>) {
+		self.destination.next(IndexedObservableNotification {
+			index: self.index,
+			notification,
+		});
+	}
+}
+
+impl<Destination, In, InError, Out, OutError> RxObserver
+	for RaceSourceSubscriber<Destination, In, InError, Out, OutError>
+where
+	Destination: Subscriber<In = IndexedObservableNotification<Out, OutError>>,
+	In: Signal + Into<Out>,
+	InError: Signal + Into<OutError>,
+	Out: Signal,
+	OutError: Signal,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		if !self.is_closed() {
+			self.forward(SubscriberNotification::Next(next.into()));
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		if !self.is_closed() {
+			self.forward(SubscriberNotification::Error(error.into()));
+		}
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		if !self.is_closed() {
+			self.forward(SubscriberNotification::Complete);
+		}
+	}
+}
+
+impl<Destination, In, InError, Out, OutError> TeardownCollection
+	for RaceSourceSubscriber<Destination, In, InError, Out, OutError>
+where
+	Destination: Subscriber<In = IndexedObservableNotification<Out, OutError>>,
+	In: Signal + Into<Out>,
+	InError: Signal + Into<OutError>,
+	Out: Signal,
+	OutError: Signal,
+{
+	#[inline]
+	fn add_teardown(&mut self, teardown: Teardown) {
+		self.teardown.add_teardown(teardown);
+	}
+}
+
+impl<Destination, In, InError, Out, OutError> SubscriptionLike
+	for RaceSourceSubscriber<Destination, In, InError, Out, OutError>
+where
+	Destination: Subscriber<In = IndexedObservableNotification<Out, OutError>>,
+	In: Signal + Into<Out>,
+	InError: Signal + Into<OutError>,
+	Out: Signal,
+	OutError: Signal,
+{
+	/// Tracked separately from the teardown, so the unsubscribe notification
+	/// still reaches the destination when it was the sources own subscription
+	/// that got unsubscribed first.
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.unsubscribed
+	}
+
+	fn unsubscribe(&mut self) {
+		if !self.unsubscribed {
+			self.unsubscribed = true;
+			self.forward(SubscriberNotification::Unsubscribe);
+			self.teardown.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,115 @@
+use rx_core_common::{
+	RxObserver, SharedSubscription, Subscriber, SubscriberNotification,
+	SubscriberPushNotificationExtention, SubscriptionLike,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_notification_variadics::IndexedObservableNotification;
+
+const UNREACHABLE_ERROR: &str = "The RaceSubscriber expects only materialized notifications through its `next` fn, from an IndexedSubscriber.";
+
+/// # RaceSubscriber
+///
+/// From an upstream multiplexer over any number of source observables of the
+/// same type, this subscriber picks the first source to emit any notification
+/// as the winner, immediately unsubscribes all other sources, and from then on
+/// only forwards the notifications of the winner.
+#[derive(RxSubscriber)]
+#[rx_in(IndexedObservableNotification<Destination::In, Destination::InError>)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_skip_unsubscribe_on_drop_impl] // This subscribers unsubscribe method should be unreachable!
+pub struct RaceSubscriber<Destination, const SIZE: usize>
+where
+	Destination: Subscriber,
+{
+	winner: Option<usize>,
+	source_subscriptions: [SharedSubscription; SIZE],
+	unsubscribed_sources: [bool; SIZE],
+	#[destination]
+	destination: Destination,
+}
+
+impl<Destination, const SIZE: usize> RaceSubscriber<Destination, SIZE>
+where
+	Destination: Subscriber,
+{
+	/// The `source_subscriptions` are expected to be the same subscriptions,
+	/// in the same order, that the subscriptions of the sources will be added
+	/// to. The losers of the race are unsubscribed through them.
+	pub fn new(destination: Destination, source_subscriptions: [SharedSubscription; SIZE]) -> Self {
+		Self {
+			winner: None,
+			source_subscriptions,
+			unsubscribed_sources: [false; SIZE],
+			destination,
+		}
+	}
+
+	fn unsubscribe_losers(&mut self, winner: usize) {
+		for (index, source_subscription) in self.source_subscriptions.iter_mut().enumerate() {
+			if index != winner && !source_subscription.is_closed() {
+				source_subscription.unsubscribe();
+			}
+		}
+	}
+}
+
+impl<Destination, const SIZE: usize> RxObserver for RaceSubscriber<Destination, SIZE>
+where
+	Destination: Subscriber,
+{
+	fn next(&mut self, next: Self::In) {
+		if self.destination.is_closed() {
+			return;
+		}
+
+		let winner = match self.winner {
+			Some(winner) => winner,
+			None => {
+				if matches!(next.notification, SubscriberNotification::Unsubscribe) {
+					// Unsubscribing is not an emission, it can't win the race.
+					// But if every source is gone, nothing ever will.
+					self.unsubscribed_sources[next.index] = true;
+					if self
+						.unsubscribed_sources
+						.iter()
+						.all(|unsubscribed| *unsubscribed)
+					{
+						self.destination.unsubscribe();
+					}
+					return;
+				}
+
+				self.winner = Some(next.index);
+				self.unsubscribe_losers(next.index);
+				next.index
+			}
+		};
+
+		if next.index == winner {
+			self.destination.push(next.notification);
+		}
+	}
+
+	fn error(&mut self, _error: Self::InError) {
+		unreachable!("{} - Error", UNREACHABLE_ERROR)
+	}
+
+	fn complete(&mut self) {
+		unreachable!("{} - Complete", UNREACHABLE_ERROR)
+	}
+}
+
+impl<Destination, const SIZE: usize> SubscriptionLike for RaceSubscriber<Destination, SIZE>
+where
+	Destination: Subscriber,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		unreachable!("{} - Unsubscribe", UNREACHABLE_ERROR)
+	}
+}
@@ -0,0 +1,160 @@
+use rx_core_common::{
+	Observable, SharedSubscriber, SharedSubscription, Subscriber, SubscriptionLike,
+	TeardownCollection, TeardownCollectionExtension, UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{RaceSourceSubscriber, RaceSubscriber};
+
+macro_rules! impl_race_observable {
+	($observable:ident, $size:literal, $($index:literal => $field:ident: $o:ident),+) => {
+		#[derive(RxObservable)]
+		#[rx_out(O1::Out)]
+		#[rx_out_error(O1::OutError)]
+		pub struct $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::Out: Into<O1::Out>,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			$($field: $o,)+
+		}
+
+		impl<$($o),+> $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::Out: Into<O1::Out>,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			#[allow(clippy::too_many_arguments)]
+			pub fn new($($field: $o),+) -> Self {
+				Self { $($field),+ }
+			}
+		}
+
+		impl<$($o),+> Observable for $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::Out: Into<O1::Out>,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			type Subscription<Destination>
+				= SharedSubscription
+			where
+				Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+			fn subscribe<Destination>(
+				&mut self,
+				observer: Destination,
+			) -> Self::Subscription<Destination::Upgraded>
+			where
+				Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+			{
+				let destination = observer.upgrade();
+				let source_subscriptions: [SharedSubscription; $size] =
+					core::array::from_fn(|_| SharedSubscription::default());
+
+				let mut subscription = SharedSubscription::default();
+				let mut shared_subscriber = SharedSubscriber::new(RaceSubscriber::new(
+					destination,
+					source_subscriptions.clone(),
+				));
+
+				$(
+					let mut source_subscription = source_subscriptions[$index].clone();
+					// A source that already emitted while being subscribed to
+					// has won the race, the rest of them are already
+					// unsubscribed.
+					if !source_subscription.is_closed() {
+						let inner_subscription = self.$field.subscribe(RaceSourceSubscriber::new(
+							shared_subscriber.clone(),
+							$index,
+							source_subscription.clone(),
+						));
+						source_subscription.add_teardown(inner_subscription.into());
+					}
+					subscription.add_teardown(source_subscription.into());
+				)+
+
+				shared_subscriber.add(subscription.clone());
+				subscription
+			}
+		}
+	};
+}
+
+impl_race_observable!(
+	RaceObservable2,
+	2,
+	0 => observable_1: O1,
+	1 => observable_2: O2
+);
+
+impl_race_observable!(
+	RaceObservable3,
+	3,
+	0 => observable_1: O1,
+	1 => observable_2: O2,
+	2 => observable_3: O3
+);
+
+impl_race_observable!(
+	RaceObservable4,
+	4,
+	0 => observable_1: O1,
+	1 => observable_2: O2,
+	2 => observable_3: O3,
+	3 => observable_4: O4
+);
+
+impl_race_observable!(
+	RaceObservable5,
+	5,
+	0 => observable_1: O1,
+	1 => observable_2: O2,
+	2 => observable_3: O3,
+	3 => observable_4: O4,
+	4 => observable_5: O5
+);
+
+impl_race_observable!(
+	RaceObservable6,
+	6,
+	0 => observable_1: O1,
+	1 => observable_2: O2,
+	2 => observable_3: O3,
+	3 => observable_4: O4,
+	4 => observable_5: O5,
+	5 => observable_6: O6
+);
+
+impl_race_observable!(
+	RaceObservable7,
+	7,
+	0 => observable_1: O1,
+	1 => observable_2: O2,
+	2 => observable_3: O3,
+	3 => observable_4: O4,
+	4 => observable_5: O5,
+	5 => observable_6: O6,
+	6 => observable_7: O7
+);
+
+impl_race_observable!(
+	RaceObservable8,
+	8,
+	0 => observable_1: O1,
+	1 => observable_2: O2,
+	2 => observable_3: O3,
+	3 => observable_4: O4,
+	4 => observable_5: O5,
+	5 => observable_6: O6,
+	6 => observable_7: O7,
+	7 => observable_8: O8
+);
@@ -0,0 +1,10 @@
+mod replay_file_observable;
+mod replay_file_observable_error;
+mod replay_file_subscription;
+
+pub use replay_file_subscription::*;
+
+pub mod observable {
+	pub use super::replay_file_observable::*;
+	pub use super::replay_file_observable_error::*;
+}
@@ -0,0 +1,127 @@
+use std::{
+	fs::File,
+	io::{BufRead, BufReader},
+	path::Path,
+	sync::Arc,
+};
+
+use rx_core_common::{
+	Observable, RecordedNotification, Scheduler, SchedulerHandle, Signal, Subscriber,
+	UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+use serde::de::DeserializeOwned;
+
+use crate::{ReplayFileSubscription, observable::ReplayFileObservableError};
+
+/// # ReplayFileObservable
+///
+/// Plays back a recording made by the `record` operator, re-emitting every
+/// notification with the same timing as it was recorded with.
+///
+/// The recording is read and parsed upfront, every subscription replays it
+/// from the start. Playback starts on the first tick after subscribing, and
+/// notifications are emitted on the first tick where their recorded time has
+/// elapsed.
+///
+/// A recording that was cut short by unsubscribing neither completes nor
+/// errors, just like the recorded stream didn't.
+#[derive(RxObservable, Debug)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct ReplayFileObservable<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: Scheduler,
+{
+	recording: Arc<[RecordedNotification<In, InError>]>,
+	scheduler: SchedulerHandle<S>,
+}
+
+impl<In, InError, S> ReplayFileObservable<In, InError, S>
+where
+	In: Signal + Clone + DeserializeOwned,
+	InError: Signal + Clone + DeserializeOwned,
+	S: Scheduler,
+{
+	/// Reads the recording at `path`.
+	pub fn open(
+		path: impl AsRef<Path>,
+		scheduler: SchedulerHandle<S>,
+	) -> Result<Self, ReplayFileObservableError> {
+		let file = File::open(path)?;
+		Self::from_reader(BufReader::new(file), scheduler)
+	}
+
+	/// Reads a recording from any source, one recorded notification per line.
+	/// Empty lines are ignored.
+	pub fn from_reader(
+		reader: impl BufRead,
+		scheduler: SchedulerHandle<S>,
+	) -> Result<Self, ReplayFileObservableError> {
+		let mut recording = Vec::new();
+		for (index, line) in reader.lines().enumerate() {
+			let line = line?;
+			if line.trim().is_empty() {
+				continue;
+			}
+			let recorded_notification =
+				serde_json::from_str(&line).map_err(|error| ReplayFileObservableError::Parse {
+					line: index + 1,
+					error: Arc::new(error),
+				})?;
+			recording.push(recorded_notification);
+		}
+
+		Ok(Self::new(recording, scheduler))
+	}
+}
+
+impl<In, InError, S> ReplayFileObservable<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: Scheduler,
+{
+	/// Plays back an already loaded recording.
+	pub fn new(
+		recording: impl Into<Arc<[RecordedNotification<In, InError>]>>,
+		scheduler: SchedulerHandle<S>,
+	) -> Self {
+		Self {
+			recording: recording.into(),
+			scheduler,
+		}
+	}
+
+	pub fn recording(&self) -> &[RecordedNotification<In, InError>] {
+		&self.recording
+	}
+}
+
+impl<In, InError, S> Observable for ReplayFileObservable<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + Scheduler + Send + Sync,
+{
+	type Subscription<Destination>
+		= ReplayFileSubscription<Destination, S>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+	{
+		ReplayFileSubscription::new(
+			destination.upgrade(),
+			self.recording.clone(),
+			self.scheduler.clone(),
+		)
+	}
+}
@@ -0,0 +1,23 @@
+use std::{io, sync::Arc};
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum ReplayFileObservableError {
+	/// The recording could not be read.
+	#[error("ReplayFileObservableError::Io({0})")]
+	Io(Arc<io::Error>),
+	/// A line of the recording is not a valid recorded notification.
+	#[error("ReplayFileObservableError::Parse(line {line}: {error})")]
+	Parse {
+		/// 1-based line number in the recording
+		line: usize,
+		error: Arc<serde_json::Error>,
+	},
+}
+
+impl From<io::Error> for ReplayFileObservableError {
+	fn from(error: io::Error) -> Self {
+		Self::Io(Arc::new(error))
+	}
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use rx_core_common::{
+	ObserverPushObserverNotificationExtention, RecordedNotification, Scheduler, SchedulerHandle,
+	SchedulerScheduleWorkExtension, SharedSubscriber, Subscriber, SubscriptionLike,
+	WorkCancellationId, WorkResult, WorkTick,
+};
+use rx_core_macro_subscription_derive::RxSubscription;
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+pub struct ReplayFileSubscription<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	Destination::In: Clone,
+	Destination::InError: Clone,
+	S: Scheduler,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	scheduler: SchedulerHandle<S>,
+	cancellation_id: Option<WorkCancellationId>,
+}
+
+impl<Destination, S> ReplayFileSubscription<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	Destination::In: Clone,
+	Destination::InError: Clone,
+	S: Scheduler,
+{
+	pub fn new(
+		destination: Destination,
+		recording: Arc<[RecordedNotification<Destination::In, Destination::InError>]>,
+		scheduler: SchedulerHandle<S>,
+	) -> Self {
+		let destination = SharedSubscriber::new(destination);
+		let cancellation_id = {
+			let mut scheduler = scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			let destination_clone = destination.clone();
+			let mut started_at = None;
+			let mut next_index = 0;
+
+			scheduler.schedule_continuous_work(
+				move |tick, _context| {
+					let now = tick.now();
+					let elapsed = now.saturating_sub(*started_at.get_or_insert(now));
+
+					let mut destination = destination_clone.lock();
+					while let Some(recorded_notification) = recording.get(next_index)
+						&& recorded_notification.elapsed() <= elapsed
+					{
+						next_index += 1;
+						destination.push(recorded_notification.notification().clone());
+						if destination.is_closed() {
+							return WorkResult::Done;
+						}
+					}
+
+					if next_index < recording.len() {
+						WorkResult::Pending
+					} else {
+						WorkResult::Done
+					}
+				},
+				cancellation_id,
+			);
+
+			cancellation_id
+		};
+
+		Self {
+			destination,
+			scheduler,
+			cancellation_id: Some(cancellation_id),
+		}
+	}
+}
+
+impl<Destination, S> SubscriptionLike for ReplayFileSubscription<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	Destination::In: Clone,
+	Destination::InError: Clone,
+	S: Scheduler,
+{
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		if let Some(cancellation_id) = self.cancellation_id.take() {
+			self.scheduler.lock().cancel(cancellation_id);
+		}
+
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,20 @@
+mod observable_into_stream_extension;
+mod observable_stream;
+mod stream_observable;
+mod stream_subscription;
+
+pub use stream_subscription::*;
+
+pub mod observable {
+	pub use super::observable_into_stream_extension::*;
+	pub use super::observable_stream::*;
+	pub use super::stream_observable::*;
+}
+
+#[cfg(feature = "observable_fn")]
+mod stream_observable_fn;
+
+#[cfg(feature = "observable_fn")]
+pub mod observable_fn {
+	pub use super::stream_observable_fn::*;
+}
@@ -0,0 +1,30 @@
+use rx_core_common::{EraseSubscriptionExtension, Observable};
+use rx_core_notification_store::QueueOverflowOptions;
+
+use crate::observable::ObservableStream;
+
+pub trait ObservableIntoStreamExtension: 'static + Observable + Sized + Send + Sync {
+	/// Subscribes to the observable, turning it into a [Stream][futures_core::Stream]
+	/// of `Result<Out, OutError>` items.
+	///
+	/// Uses the default [QueueOverflowOptions] to store values that weren't
+	/// consumed yet.
+	#[inline]
+	fn into_stream(self) -> ObservableStream<Self::Out, Self::OutError> {
+		self.into_stream_with_options(QueueOverflowOptions::default())
+	}
+
+	/// Subscribes to the observable, turning it into a [Stream][futures_core::Stream]
+	/// of `Result<Out, OutError>` items.
+	///
+	/// Values that weren't consumed yet are stored in a queue limited by the
+	/// `options`.
+	fn into_stream_with_options(
+		mut self,
+		options: QueueOverflowOptions,
+	) -> ObservableStream<Self::Out, Self::OutError> {
+		ObservableStream::new(options, |observer| self.subscribe(observer).erase())
+	}
+}
+
+impl<O> ObservableIntoStreamExtension for O where O: 'static + Observable + Send + Sync {}
@@ -0,0 +1,176 @@
+use std::{
+	pin::Pin,
+	sync::{Arc, Mutex, MutexGuard, PoisonError},
+	task::{Context, Poll, Waker},
+};
+
+use futures_core::Stream;
+use rx_core_common::{ErasedSubscription, RxObserver, Signal, SubscriberNotification};
+use rx_core_macro_observer_derive::RxObserver;
+use rx_core_notification_store::{NotificationQueue, QueueOverflowOptions};
+
+struct ObservableStreamState<Out, OutError>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	queue: NotificationQueue<Out, OutError>,
+	/// Kept outside of the queue so it's only yielded after every value
+	/// queued before it.
+	error: Option<OutError>,
+	/// Set once the error was yielded, as nothing comes after it.
+	is_finished: bool,
+	waker: Option<Waker>,
+}
+
+type SharedObservableStreamState<Out, OutError> = Arc<Mutex<ObservableStreamState<Out, OutError>>>;
+
+fn lock_state<Out, OutError>(
+	state: &SharedObservableStreamState<Out, OutError>,
+) -> MutexGuard<'_, ObservableStreamState<Out, OutError>>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Pushes every notification into the state of the [ObservableStream], waking
+/// up whoever is waiting for the next item.
+#[derive(RxObserver)]
+#[rx_in(Out)]
+#[rx_in_error(OutError)]
+pub struct ObservableStreamObserver<Out, OutError>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	state: SharedObservableStreamState<Out, OutError>,
+}
+
+impl<Out, OutError> ObservableStreamObserver<Out, OutError>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	fn update_and_wake(&mut self, update: impl FnOnce(&mut ObservableStreamState<Out, OutError>)) {
+		let waker = {
+			let mut state = lock_state(&self.state);
+			update(&mut state);
+			state.waker.take()
+		};
+
+		if let Some(waker) = waker {
+			waker.wake();
+		}
+	}
+
+	#[inline]
+	fn push(&mut self, notification: SubscriberNotification<Out, OutError>) {
+		self.update_and_wake(|state| state.queue.push(notification));
+	}
+}
+
+impl<Out, OutError> RxObserver for ObservableStreamObserver<Out, OutError>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		self.push(SubscriberNotification::Next(next));
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.update_and_wake(|state| state.error = Some(error));
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.push(SubscriberNotification::Complete);
+	}
+}
+
+/// # [ObservableStream]
+///
+/// A [Stream] of every value an observable emits as `Ok`, and the error it
+/// may emit as `Err`. The stream ends after the observable completes, or
+/// right after its error was yielded.
+///
+/// Values emitted faster than they are consumed are stored in a
+/// [NotificationQueue], which is limited by its [QueueOverflowOptions].
+/// An error is only yielded after every value emitted before it was consumed.
+///
+/// Dropping the stream unsubscribes from the observable.
+pub struct ObservableStream<Out, OutError>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	state: SharedObservableStreamState<Out, OutError>,
+	/// Only kept to unsubscribe on drop
+	_subscription: ErasedSubscription,
+}
+
+impl<Out, OutError> ObservableStream<Out, OutError>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	/// Subscribes using the [ObservableStreamObserver] returned by the
+	/// `subscribe` function.
+	pub fn new<Subscribe>(options: QueueOverflowOptions, subscribe: Subscribe) -> Self
+	where
+		Subscribe: FnOnce(ObservableStreamObserver<Out, OutError>) -> ErasedSubscription,
+	{
+		let state = Arc::new(Mutex::new(ObservableStreamState {
+			queue: NotificationQueue::new(options),
+			error: None,
+			is_finished: false,
+			waker: None,
+		}));
+
+		let subscription = subscribe(ObservableStreamObserver {
+			state: state.clone(),
+		});
+
+		Self {
+			state,
+			_subscription: subscription,
+		}
+	}
+}
+
+impl<Out, OutError> Stream for ObservableStream<Out, OutError>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	type Item = Result<Out, OutError>;
+
+	fn poll_next(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let mut state = lock_state(&self.state);
+
+		if state.is_finished {
+			return Poll::Ready(None);
+		}
+
+		if let Some(next) = state.queue.pop_next_if_in_front() {
+			return Poll::Ready(Some(Ok(next)));
+		}
+
+		if let Some(error) = state.error.take() {
+			state.is_finished = true;
+			return Poll::Ready(Some(Err(error)));
+		}
+
+		if state.queue.is_closed() {
+			state.is_finished = true;
+			return Poll::Ready(None);
+		}
+
+		state.waker = Some(context.waker().clone());
+		Poll::Pending
+	}
+}
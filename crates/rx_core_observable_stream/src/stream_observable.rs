@@ -0,0 +1,90 @@
+use core::marker::PhantomData;
+
+use futures_core::Stream;
+use rx_core_common::{
+	Never, Observable, PhantomInvariant, Scheduler, SchedulerHandle, Signal, Subscriber,
+	UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::StreamSubscription;
+
+/// # StreamObservable
+///
+/// Emits every item of a [Stream], polled by the provided scheduler.
+///
+/// A new stream is created for every subscription using the `stream_creator`.
+///
+/// The stream is only polled again when it wakes its waker, which invokes the
+/// polling work on the scheduler.
+///
+/// ## Completion Behavior
+///
+/// Completes when the stream is exhausted.
+///
+/// ## Error Behavior
+///
+/// This observable does not error. Streams of `Result`s can be turned into
+/// errors downstream using `lift_result`.
+///
+/// ## Arguments
+///
+/// - `stream_creator`: Creates the stream to be polled for each subscription.
+/// - `scheduler`: The scheduler's handle to poll the stream with.
+#[derive(RxObservable)]
+#[rx_out(St::Item)]
+#[rx_out_error(Never)]
+pub struct StreamObservable<StreamCreator, St, S>
+where
+	StreamCreator: FnMut() -> St,
+	St: 'static + Stream + Send,
+	St::Item: Signal,
+	S: Scheduler,
+{
+	stream_creator: StreamCreator,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<St>,
+}
+
+impl<StreamCreator, St, S> StreamObservable<StreamCreator, St, S>
+where
+	StreamCreator: FnMut() -> St,
+	St: 'static + Stream + Send,
+	St::Item: Signal,
+	S: Scheduler,
+{
+	pub fn new(stream_creator: StreamCreator, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			stream_creator,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<StreamCreator, St, S> Observable for StreamObservable<StreamCreator, St, S>
+where
+	StreamCreator: FnMut() -> St,
+	St: 'static + Stream + Send,
+	St::Item: Signal,
+	S: 'static + Scheduler,
+{
+	type Subscription<Destination>
+		= StreamSubscription<Destination, S>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		observer: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+	{
+		StreamSubscription::new(
+			(self.stream_creator)(),
+			observer.upgrade(),
+			self.scheduler.clone(),
+		)
+	}
+}
@@ -0,0 +1,35 @@
+use futures_core::Stream;
+use rx_core_common::{Scheduler, SchedulerHandle, Signal};
+
+use crate::observable::StreamObservable;
+
+/// # StreamObservable
+///
+/// Emits every item of a [Stream], polled by the provided scheduler.
+///
+/// A new stream is created for every subscription using the `stream_creator`.
+///
+/// ## Completion Behavior
+///
+/// Completes when the stream is exhausted.
+///
+/// ## Error Behavior
+///
+/// This observable does not error.
+///
+/// ## Arguments
+///
+/// - `stream_creator`: Creates the stream to be polled for each subscription.
+/// - `scheduler`: The scheduler's handle to poll the stream with.
+pub fn from_stream<StreamCreator, St, S>(
+	stream_creator: StreamCreator,
+	scheduler: SchedulerHandle<S>,
+) -> StreamObservable<StreamCreator, St, S>
+where
+	StreamCreator: FnMut() -> St,
+	St: 'static + Stream + Send,
+	St::Item: Signal,
+	S: Scheduler,
+{
+	StreamObservable::new(stream_creator, scheduler)
+}
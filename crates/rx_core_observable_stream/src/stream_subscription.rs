@@ -0,0 +1,103 @@
+use std::{
+	sync::{Mutex, PoisonError},
+	task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use rx_core_common::{
+	InvokedTaskFactory, Scheduler, SchedulerHandle, SharedSubscriber, Subscriber, SubscriptionLike,
+	Teardown, TeardownCollectionExtension, WorkInvokeId, WorkInvokeWaker, WorkResult,
+};
+use rx_core_macro_subscription_derive::RxSubscription;
+
+#[derive(RxSubscription)]
+#[rx_delegate_teardown_collection]
+pub struct StreamSubscription<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: Scheduler,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	scheduler: SchedulerHandle<S>,
+	invoke_id: WorkInvokeId,
+}
+
+impl<Destination, S> StreamSubscription<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: 'static + Scheduler,
+{
+	pub fn new<St>(stream: St, destination: Destination, scheduler: SchedulerHandle<S>) -> Self
+	where
+		St: 'static + Stream<Item = Destination::In> + Send,
+	{
+		let mut destination = SharedSubscriber::new(destination);
+
+		let invoke_id = {
+			let mut scheduler_lock = scheduler.lock();
+			let invoke_id = scheduler_lock.generate_invoke_id();
+			let waker = WorkInvokeWaker::new(invoke_id, scheduler.clone()).into_waker();
+			let destination_clone = destination.clone();
+			// Only accessed by the work, the lock is there to make it Sync
+			let stream = Mutex::new(Box::pin(stream));
+
+			scheduler_lock.schedule_invoked_work(
+				S::InvokedWorkFactory::new(move |_, _| {
+					let mut destination_lock = destination_clone.lock();
+					let mut stream = stream.lock().unwrap_or_else(PoisonError::into_inner);
+					let mut context = Context::from_waker(&waker);
+
+					loop {
+						if destination_lock.is_closed() {
+							return WorkResult::Done;
+						}
+
+						match stream.as_mut().poll_next(&mut context) {
+							Poll::Ready(Some(next)) => destination_lock.next(next),
+							Poll::Ready(None) => {
+								destination_lock.complete();
+								return WorkResult::Done;
+							}
+							Poll::Pending => return WorkResult::Pending,
+						}
+					}
+				}),
+				invoke_id,
+			);
+
+			// The first poll
+			scheduler_lock.invoke(invoke_id);
+
+			invoke_id
+		};
+
+		destination.add(Teardown::new_invoked_work_cancellation(
+			invoke_id,
+			scheduler.clone(),
+		));
+
+		StreamSubscription {
+			destination,
+			scheduler,
+			invoke_id,
+		}
+	}
+}
+
+impl<Destination, S> SubscriptionLike for StreamSubscription<Destination, S>
+where
+	Destination: Subscriber,
+	S: Scheduler,
+{
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		self.scheduler.lock().cancel_invoked(self.invoke_id);
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
@@ -1,9 +1,16 @@
+mod zip_array_observable;
+mod zip_array_subscriber;
 mod zip_observable;
+mod zip_state;
 mod zip_subscriber;
 
+pub(crate) use zip_state::*;
+
+pub use zip_array_subscriber::*;
 pub use zip_subscriber::*;
 
 pub mod observable {
+	pub use super::zip_array_observable::*;
 	pub use super::zip_observable::*;
 	pub use rx_core_notification_store::{QueueOverflowBehavior, QueueOverflowOptions};
 }
@@ -0,0 +1,145 @@
+use rx_core_common::{
+	Observable, RxObserver, Subscriber, SubscriberNotification, SubscriptionLike,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_notification_store::{NotificationQueue, QueueOverflowOptions};
+use rx_core_notification_variadics::IndexedObservableNotification;
+
+use crate::{ZipQueueStatus, should_complete, should_unsubscribe};
+
+const UNREACHABLE_ERROR: &str = "The ZipArraySubscriber expects only materialized notifications through its `next` fn, from an IndexedSubscriber.";
+
+/// # ZipArraySubscriber
+///
+/// From an upstream multiplexer over any number of source observables of the
+/// same type, this subscriber maintains a queue for each source separately,
+/// and consumes them into a `Vec` when all have values.
+///
+/// Errors and completions behave the same way as they do for the
+/// [ZipSubscriber][crate::ZipSubscriber].
+#[derive(RxSubscriber)]
+#[rx_in(IndexedObservableNotification<O::Out, O::OutError>)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_skip_unsubscribe_on_drop_impl]
+pub struct ZipArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	#[destination]
+	destination: Destination,
+	queues: Vec<NotificationQueue<O::Out, O::OutError>>,
+}
+
+impl<Destination, O> ZipArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	pub fn new(
+		destination: Destination,
+		source_count: usize,
+		options: QueueOverflowOptions,
+	) -> Self {
+		ZipArraySubscriber {
+			queues: (0..source_count)
+				.map(|_| NotificationQueue::new(options.clone()))
+				.collect(),
+			destination,
+		}
+	}
+
+	fn get_next(&mut self) -> Option<Destination::In> {
+		if !self.queues.iter().all(|queue| queue.has_next()) {
+			None
+		} else {
+			self.queues
+				.iter_mut()
+				.map(|queue| queue.pop_next_if_in_front())
+				.collect()
+		}
+	}
+
+	fn take_any_error(&mut self) -> Option<Destination::InError> {
+		self.queues
+			.iter_mut()
+			.find_map(|queue| queue.take_error())
+			.map(|error| error.into())
+	}
+
+	fn statuses(&self) -> Vec<ZipQueueStatus> {
+		self.queues.iter().map(ZipQueueStatus::of).collect()
+	}
+
+	fn try_complete(&mut self) {
+		if !self.destination.is_closed() && should_complete(&self.statuses()) {
+			self.destination.complete();
+		}
+	}
+
+	fn try_unsubscribe(&mut self) {
+		if !self.destination.is_closed() && should_unsubscribe(&self.statuses()) {
+			self.destination.unsubscribe();
+		}
+	}
+}
+
+impl<Destination, O> RxObserver for ZipArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	fn next(&mut self, next: Self::In) {
+		let was_next = matches!(next.notification, SubscriberNotification::Next(_));
+		self.queues[next.index].push(next.notification);
+
+		if let Some(error) = self.take_any_error() {
+			self.destination.error(error);
+			return;
+		}
+
+		if was_next
+			&& !self.is_closed()
+			&& let Some(next) = self.get_next()
+		{
+			self.destination.next(next);
+		}
+
+		// These must happen after `next` because by popping off a set of
+		// values from the queues, a complete/unsubscribe can be exposed.
+		self.try_complete();
+		self.try_unsubscribe();
+	}
+
+	fn error(&mut self, _error: Self::InError) {
+		unreachable!("{}", UNREACHABLE_ERROR)
+	}
+
+	fn complete(&mut self) {
+		unreachable!("{}", UNREACHABLE_ERROR)
+	}
+}
+
+impl<Destination, O> SubscriptionLike for ZipArraySubscriber<Destination, O>
+where
+	Destination: Subscriber<In = Vec<O::Out>>,
+	O: 'static + Observable,
+	O::Out: Clone,
+	O::OutError: Into<Destination::InError>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		unreachable!("{}", UNREACHABLE_ERROR)
+	}
+}
@@ -1,14 +1,42 @@
 use rx_core_common::Observable;
 
-use crate::observable::ZipObservable;
+use crate::observable::{
+	ZipArrayObservable, ZipObservable, ZipObservable3, ZipObservable4, ZipObservable5,
+	ZipObservable6, ZipObservable7, ZipObservable8,
+};
 
-pub fn zip<O1, O2>(o1: O1, o2: O2) -> ZipObservable<O1, O2>
+macro_rules! impl_zip_fn {
+	($fn_name:ident, $observable:ident, $($field:ident: $o:ident),+) => {
+		#[allow(clippy::too_many_arguments)]
+		pub fn $fn_name<$($o),+>($($field: $o),+) -> $observable<$($o),+>
+		where
+			$(
+				$o: 'static + Send + Sync + Observable,
+				$o::Out: Clone,
+				$o::OutError: Into<O1::OutError>,
+			)+
+		{
+			$observable::new($($field),+)
+		}
+	};
+}
+
+impl_zip_fn!(zip, ZipObservable, o1: O1, o2: O2);
+impl_zip_fn!(zip3, ZipObservable3, o1: O1, o2: O2, o3: O3);
+impl_zip_fn!(zip4, ZipObservable4, o1: O1, o2: O2, o3: O3, o4: O4);
+impl_zip_fn!(zip5, ZipObservable5, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5);
+impl_zip_fn!(zip6, ZipObservable6, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6);
+impl_zip_fn!(zip7, ZipObservable7, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6, o7: O7);
+impl_zip_fn!(zip8, ZipObservable8, o1: O1, o2: O2, o3: O3, o4: O4, o5: O5, o6: O6, o7: O7, o8: O8);
+
+/// Zips any number of observables of the same type, emitting a `Vec` of their
+/// values once all of them emitted, matching them in emission order.
+///
+/// Accepts both arrays and `Vec`s. Completes immediately when empty.
+pub fn zip_array<O>(observables: impl IntoIterator<Item = O>) -> ZipArrayObservable<O>
 where
-	O1: 'static + Send + Sync + Observable,
-	O1::Out: Clone,
-	O2: 'static + Send + Sync + Observable,
-	O2::Out: Clone,
-	O2::OutError: Into<O1::OutError>,
+	O: 'static + Send + Sync + Observable,
+	O::Out: Clone,
 {
-	ZipObservable::new(o1, o2)
+	ZipArrayObservable::new(observables)
 }
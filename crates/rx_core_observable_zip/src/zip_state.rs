@@ -0,0 +1,40 @@
+use rx_core_common::Signal;
+use rx_core_notification_store::NotificationQueue;
+
+/// The parts of a sources queue that decide whether a zip is finished.
+#[derive(Clone, Copy)]
+pub(crate) struct ZipQueueStatus {
+	is_empty: bool,
+	is_completed: bool,
+	is_closed: bool,
+}
+
+impl ZipQueueStatus {
+	pub(crate) fn of<In, InError>(queue: &NotificationQueue<In, InError>) -> Self
+	where
+		In: Signal,
+		InError: Signal,
+	{
+		Self {
+			is_empty: queue.is_empty(),
+			is_completed: queue.is_completed(),
+			is_closed: queue.is_closed(),
+		}
+	}
+}
+
+/// Once a source completed with nothing left in its queue, no more tuples can
+/// be made, but only if the rest had nothing queued up either.
+pub(crate) fn should_complete(statuses: &[ZipQueueStatus]) -> bool {
+	statuses.iter().any(|status| status.is_completed)
+		&& statuses
+			.iter()
+			.all(|status| status.is_empty || status.is_completed)
+}
+
+pub(crate) fn should_unsubscribe(statuses: &[ZipQueueStatus]) -> bool {
+	statuses.iter().any(|status| status.is_closed)
+		&& statuses
+			.iter()
+			.all(|status| status.is_empty || status.is_closed)
+}
@@ -0,0 +1,133 @@
+use std::{
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, PhantomInvariant, RxObserver, SharedSubscriber, Signal, Subscriber,
+	SubscriptionData, SubscriptionLike, Teardown, TeardownCollection,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::audit_state::AuditState;
+
+/// Emits the latest upstream value on the first `next` of the duration
+/// observable, ending the audit.
+#[derive(RxSubscriber)]
+#[rx_in(DurationOut)]
+#[rx_in_error(Destination::InError)]
+pub struct AuditDurationSubscriber<DurationOut, Destination>
+where
+	DurationOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	teardown: SubscriptionData,
+	state: Arc<Mutex<AuditState<Destination::In>>>,
+	destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<DurationOut>,
+}
+
+impl<DurationOut, Destination> AuditDurationSubscriber<DurationOut, Destination>
+where
+	DurationOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	pub(crate) fn new(
+		state: Arc<Mutex<AuditState<Destination::In>>>,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		Self {
+			teardown: SubscriptionData::default(),
+			state,
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<DurationOut, Destination> RxObserver for AuditDurationSubscriber<DurationOut, Destination>
+where
+	DurationOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	fn next(&mut self, _next: Self::In) {
+		if self.is_closed() {
+			return;
+		}
+
+		let (latest, upstream_completed) = {
+			let mut state = self.state.lock_ignore_poison();
+			state.auditing = false;
+			(state.latest.take(), state.upstream_completed)
+		};
+		self.unsubscribe();
+
+		if let Some(latest) = latest
+			&& !self.destination.is_closed()
+		{
+			self.destination.next(latest);
+		}
+
+		if upstream_completed && !self.destination.is_closed() {
+			self.destination.complete();
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		if !self.is_closed() {
+			self.destination.error(error);
+			self.unsubscribe();
+		}
+	}
+
+	/// Only a `next` emits the latest value, a duration observable
+	/// completing without one ends the audit silently, leaving the value
+	/// pending for the next one.
+	fn complete(&mut self) {
+		if self.is_closed() {
+			return;
+		}
+
+		let upstream_completed = {
+			let mut state = self.state.lock_ignore_poison();
+			state.auditing = false;
+			state.upstream_completed
+		};
+		self.unsubscribe();
+
+		if upstream_completed && !self.destination.is_closed() {
+			self.destination.complete();
+		}
+	}
+}
+
+impl<DurationOut, Destination> TeardownCollection
+	for AuditDurationSubscriber<DurationOut, Destination>
+where
+	DurationOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	#[inline]
+	fn add_teardown(&mut self, teardown: Teardown) {
+		self.teardown.add_teardown(teardown);
+	}
+}
+
+impl<DurationOut, Destination> SubscriptionLike
+	for AuditDurationSubscriber<DurationOut, Destination>
+where
+	DurationOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.teardown.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.is_closed() {
+			self.teardown.unsubscribe();
+		}
+	}
+}
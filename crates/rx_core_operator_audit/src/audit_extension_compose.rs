@@ -0,0 +1,36 @@
+use rx_core_common::{ComposableOperator, Observable};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::AuditOperator;
+
+pub trait OperatorComposeExtensionAudit: ComposableOperator + Sized {
+	/// # [AuditOperator]
+	///
+	/// The first upstream value is passed to the `duration_selector`, and once the
+	/// returned observable emits, the latest upstream value is emitted. The next
+	/// upstream value starts a new duration observable.
+	///
+	/// - A duration observable completing without emitting ends the audit
+	///   without emitting, the latest value stays pending for the next one.
+	/// - Upstream completion waits for the active duration observable to end.
+	/// - Errors, from upstream or from the duration observables, are forwarded
+	///   downstream.
+	/// - The active duration subscription is torn down together with the
+	///   subscriber.
+	#[inline]
+	fn audit<DurationSelector, DurationObservable>(
+		self,
+		duration_selector: DurationSelector,
+	) -> CompositeOperator<
+		Self,
+		AuditOperator<Self::Out, Self::OutError, DurationSelector, DurationObservable>,
+	>
+	where
+		DurationSelector: 'static + Fn(&Self::Out) -> DurationObservable + Clone + Send + Sync,
+		DurationObservable: 'static + Observable<OutError = Self::OutError>,
+	{
+		self.compose_with(AuditOperator::new(duration_selector))
+	}
+}
+
+impl<Op> OperatorComposeExtensionAudit for Op where Op: ComposableOperator {}
@@ -0,0 +1,32 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::AuditOperator;
+
+pub trait ObservablePipeExtensionAudit<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [AuditOperator]
+	///
+	/// The first upstream value is passed to the `duration_selector`, and once the
+	/// returned observable emits, the latest upstream value is emitted. The next
+	/// upstream value starts a new duration observable.
+	///
+	/// - A duration observable completing without emitting ends the audit
+	///   without emitting, the latest value stays pending for the next one.
+	/// - Upstream completion waits for the active duration observable to end.
+	/// - Errors, from upstream or from the duration observables, are forwarded
+	///   downstream.
+	/// - The active duration subscription is torn down together with the
+	///   subscriber.
+	#[inline]
+	fn audit<DurationSelector, DurationObservable>(
+		self,
+		duration_selector: DurationSelector,
+	) -> <AuditOperator<Self::Out, Self::OutError, DurationSelector, DurationObservable> as Operator<'o>>::OutObservable<Self>
+	where
+		DurationSelector: 'static + Fn(&Self::Out) -> DurationObservable + Clone + Send + Sync,
+		DurationObservable: 'static + Observable<OutError = Self::OutError>,
+	{
+		AuditOperator::new(duration_selector).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionAudit<'o> for O where O: 'o + Observable + Send + Sync {}
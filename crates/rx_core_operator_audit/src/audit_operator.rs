@@ -0,0 +1,76 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{ComposableOperator, Observable, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::AuditSubscriber;
+
+/// # [AuditOperator]
+///
+/// The first upstream value is passed to the `duration_selector`, and once the
+/// returned observable emits, the latest upstream value is emitted. The next
+/// upstream value starts a new duration observable.
+///
+/// - A duration observable completing without emitting ends the audit
+///   without emitting, the latest value stays pending for the next one.
+/// - Upstream completion waits for the active duration observable to end.
+/// - Errors, from upstream or from the duration observables, are forwarded
+///   downstream.
+/// - The active duration subscription is torn down together with the
+///   subscriber.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct AuditOperator<In, InError, DurationSelector, DurationObservable>
+where
+	In: Signal,
+	InError: Signal,
+	DurationSelector: 'static + Fn(&In) -> DurationObservable + Clone + Send + Sync,
+	DurationObservable: 'static + Observable<OutError = InError>,
+{
+	duration_selector: DurationSelector,
+	_phantom_data: PhantomInvariant<(In, InError, DurationObservable)>,
+}
+
+impl<In, InError, DurationSelector, DurationObservable>
+	AuditOperator<In, InError, DurationSelector, DurationObservable>
+where
+	In: Signal,
+	InError: Signal,
+	DurationSelector: 'static + Fn(&In) -> DurationObservable + Clone + Send + Sync,
+	DurationObservable: 'static + Observable<OutError = InError>,
+{
+	pub fn new(duration_selector: DurationSelector) -> Self {
+		Self {
+			duration_selector,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, DurationSelector, DurationObservable> ComposableOperator
+	for AuditOperator<In, InError, DurationSelector, DurationObservable>
+where
+	In: Signal,
+	InError: Signal,
+	DurationSelector: 'static + Fn(&In) -> DurationObservable + Clone + Send + Sync,
+	DurationObservable: 'static + Observable<OutError = InError>,
+{
+	type Subscriber<Destination>
+		= AuditSubscriber<DurationSelector, DurationObservable, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		AuditSubscriber::new(destination, self.duration_selector.clone())
+	}
+}
@@ -0,0 +1,37 @@
+use rx_core_common::{SubscriptionData, SubscriptionLike};
+
+pub(crate) struct AuditState<In> {
+	pub(crate) latest: Option<In>,
+	/// Whether a duration observable is currently active
+	pub(crate) auditing: bool,
+	pub(crate) upstream_completed: bool,
+	duration_subscription: Option<SubscriptionData>,
+}
+
+impl<In> Default for AuditState<In> {
+	fn default() -> Self {
+		Self {
+			latest: None,
+			auditing: false,
+			upstream_completed: false,
+			duration_subscription: None,
+		}
+	}
+}
+
+impl<In> AuditState<In> {
+	/// Replaces the subscription of the previous, already ended, duration
+	/// observable. Returns it to be unsubscribed outside of the lock.
+	pub(crate) fn replace_duration_subscription(
+		&mut self,
+		duration_subscription: Option<SubscriptionData>,
+	) -> Option<SubscriptionData> {
+		std::mem::replace(&mut self.duration_subscription, duration_subscription)
+	}
+
+	pub(crate) fn take_duration_subscription(&mut self) -> Option<SubscriptionData> {
+		self.duration_subscription
+			.take()
+			.filter(|duration_subscription| !duration_subscription.is_closed())
+	}
+}
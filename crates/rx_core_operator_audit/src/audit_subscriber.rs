@@ -0,0 +1,116 @@
+use std::sync::{Arc, Mutex};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, RxObserver, SharedSubscriber, Subscriber, SubscriptionData,
+	SubscriptionLike, TeardownCollectionExtension,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::{AuditDurationSubscriber, audit_state::AuditState};
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct AuditSubscriber<DurationSelector, DurationObservable, Destination>
+where
+	DurationSelector: Fn(&Destination::In) -> DurationObservable,
+	DurationObservable: 'static + Observable<OutError = Destination::InError>,
+	Destination: 'static + Subscriber,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	duration_selector: DurationSelector,
+	state: Arc<Mutex<AuditState<Destination::In>>>,
+}
+
+impl<DurationSelector, DurationObservable, Destination>
+	AuditSubscriber<DurationSelector, DurationObservable, Destination>
+where
+	DurationSelector: Fn(&Destination::In) -> DurationObservable,
+	DurationObservable: 'static + Observable<OutError = Destination::InError>,
+	Destination: 'static + Subscriber,
+{
+	pub fn new(destination: Destination, duration_selector: DurationSelector) -> Self {
+		let mut destination = SharedSubscriber::new(destination);
+		let state = Arc::new(Mutex::new(AuditState::default()));
+
+		let state_clone = state.clone();
+		// Stops the active duration observable once downstream is closed
+		destination.add_fn(move || {
+			let duration_subscription = state_clone
+				.lock_ignore_poison()
+				.take_duration_subscription();
+			if let Some(mut duration_subscription) = duration_subscription {
+				duration_subscription.unsubscribe();
+			}
+		});
+
+		Self {
+			destination,
+			duration_selector,
+			state,
+		}
+	}
+}
+
+impl<DurationSelector, DurationObservable, Destination> RxObserver
+	for AuditSubscriber<DurationSelector, DurationObservable, Destination>
+where
+	DurationSelector: Fn(&Destination::In) -> DurationObservable + Send + Sync,
+	DurationObservable: 'static + Observable<OutError = Destination::InError>,
+	Destination: 'static + Subscriber,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		let should_start_audit = {
+			let mut state = self.state.lock_ignore_poison();
+			!std::mem::replace(&mut state.auditing, true)
+		};
+		let mut duration_observable = should_start_audit.then(|| (self.duration_selector)(&next));
+		self.state.lock_ignore_poison().latest = Some(next);
+
+		if let Some(duration_observable) = duration_observable.as_mut() {
+			let duration_subscription = duration_observable.subscribe(AuditDurationSubscriber::<
+				DurationObservable::Out,
+				Destination,
+			>::new(
+				self.state.clone(),
+				self.destination.clone(),
+			));
+
+			let duration_subscription = (!duration_subscription.is_closed())
+				.then(|| SubscriptionData::new_with_teardown(duration_subscription.into()));
+
+			let previous_duration_subscription = self
+				.state
+				.lock_ignore_poison()
+				.replace_duration_subscription(duration_subscription);
+			if let Some(mut previous_duration_subscription) = previous_duration_subscription {
+				previous_duration_subscription.unsubscribe();
+			}
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		if !self.destination.is_closed() {
+			self.destination.error(error);
+		}
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		let should_complete_immediately = {
+			let mut state = self.state.lock_ignore_poison();
+			state.upstream_completed = true;
+			!state.auditing
+		};
+
+		// Otherwise the active duration observable completes once it ends
+		if should_complete_immediately && !self.destination.is_closed() {
+			self.destination.complete();
+		}
+	}
+}
@@ -0,0 +1,27 @@
+mod audit_duration_subscriber;
+mod audit_operator;
+mod audit_state;
+mod audit_subscriber;
+
+pub use audit_duration_subscriber::*;
+pub use audit_subscriber::*;
+
+pub mod operator {
+	pub use super::audit_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod audit_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::audit_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod audit_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::audit_extension_pipe::*;
+}
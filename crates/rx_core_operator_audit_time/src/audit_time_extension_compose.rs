@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use rx_core_common::{ComposableOperator, Scheduler, SchedulerHandle, Signal};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::AuditTimeOperator;
+
+pub trait OperatorComposeExtensionAuditTime<T, S>: ComposableOperator<Out = T> + Sized
+where
+	T: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+	/// # [AuditTimeOperator]
+	///
+	/// The first upstream value starts a timer of `duration`, once it elapses the
+	/// latest upstream value is emitted. The next upstream value starts a new
+	/// timer.
+	///
+	/// Unlike `debounce_time`, new values do not restart a running timer, and
+	/// unlike `throttle_time`, the value emitted is always the latest one.
+	///
+	/// Upstream completion and cancellation can happen instantly if there is no
+	/// running timer, otherwise they happen once the pending value is emitted.
+	///
+	/// Upstream errors are immediately propagated downstream, cancelling any
+	/// pending value.
+	#[inline]
+	fn audit_time(
+		self,
+		duration: Duration,
+		scheduler: SchedulerHandle<S>,
+	) -> CompositeOperator<Self, AuditTimeOperator<T, Self::OutError, S>> {
+		self.compose_with(AuditTimeOperator::new(duration, scheduler))
+	}
+}
+
+impl<Op, T, S> OperatorComposeExtensionAuditTime<T, S> for Op
+where
+	Op: ComposableOperator<Out = T>,
+	T: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use rx_core_common::{Observable, Operator, Scheduler, SchedulerHandle, Signal};
+
+use crate::operator::AuditTimeOperator;
+
+pub trait ObservablePipeExtensionAuditTime<'o, T, S>:
+	'o + Observable<Out = T> + Sized + Send + Sync
+where
+	T: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+	/// # [AuditTimeOperator]
+	///
+	/// The first upstream value starts a timer of `duration`, once it elapses the
+	/// latest upstream value is emitted. The next upstream value starts a new
+	/// timer.
+	///
+	/// Unlike `debounce_time`, new values do not restart a running timer, and
+	/// unlike `throttle_time`, the value emitted is always the latest one.
+	///
+	/// Upstream completion and cancellation can happen instantly if there is no
+	/// running timer, otherwise they happen once the pending value is emitted.
+	///
+	/// Upstream errors are immediately propagated downstream, cancelling any
+	/// pending value.
+	#[inline]
+	fn audit_time(
+		self,
+		duration: Duration,
+		scheduler: SchedulerHandle<S>,
+	) -> <AuditTimeOperator<T, Self::OutError, S> as Operator<'o>>::OutObservable<Self> {
+		AuditTimeOperator::new(duration, scheduler).operate(self)
+	}
+}
+
+impl<'o, O, T, S> ObservablePipeExtensionAuditTime<'o, T, S> for O
+where
+	O: 'o + Observable<Out = T> + Send + Sync,
+	T: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+}
@@ -0,0 +1,77 @@
+use core::marker::PhantomData;
+use std::time::Duration;
+
+use rx_core_common::{
+	ComposableOperator, PhantomInvariant, Scheduler, SchedulerHandle, Signal, Subscriber,
+};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::AuditTimeSubscriber;
+
+/// # [AuditTimeOperator]
+///
+/// The first upstream value starts a timer of `duration`, once it elapses the
+/// latest upstream value is emitted. The next upstream value starts a new
+/// timer.
+///
+/// Unlike `debounce_time`, new values do not restart a running timer, and
+/// unlike `throttle_time`, the value emitted is always the latest one.
+///
+/// Upstream completion and cancellation can happen instantly if there is no
+/// running timer, otherwise they happen once the pending value is emitted.
+///
+/// Upstream errors are immediately propagated downstream, cancelling any
+/// pending value.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct AuditTimeOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: Scheduler,
+{
+	duration: Duration,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, S> AuditTimeOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: Scheduler,
+{
+	pub fn new(duration: Duration, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			duration,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, S> ComposableOperator for AuditTimeOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+	type Subscriber<Destination>
+		= AuditTimeSubscriber<Destination, S>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		AuditTimeSubscriber::new(destination, self.duration, self.scheduler.clone())
+	}
+}
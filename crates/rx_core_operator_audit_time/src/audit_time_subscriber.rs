@@ -0,0 +1,183 @@
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, RxObserver, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension,
+	SharedSubscriber, Subscriber, SubscriberState, SubscriptionLike, Teardown, WorkCancellationId,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+struct AuditTimeState<In> {
+	latest: Option<In>,
+	auditing: bool,
+	upstream_state: SubscriberState,
+}
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_skip_unsubscribe_on_drop_impl]
+pub struct AuditTimeSubscriber<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: 'static + Scheduler,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	duration: Duration,
+	scheduler: SchedulerHandle<S>,
+	cancellation_id: WorkCancellationId,
+	state: Arc<Mutex<AuditTimeState<Destination::In>>>,
+}
+
+impl<Destination, S> AuditTimeSubscriber<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: Scheduler,
+{
+	pub fn new(
+		mut destination: Destination,
+		duration: Duration,
+		scheduler: SchedulerHandle<S>,
+	) -> Self {
+		let cancellation_id = scheduler.lock().generate_cancellation_id();
+		destination.add_teardown(Teardown::new_work_cancellation(
+			cancellation_id,
+			scheduler.clone(),
+		));
+		Self {
+			destination: SharedSubscriber::new(destination),
+			duration,
+			scheduler,
+			cancellation_id,
+			state: Arc::new(Mutex::new(AuditTimeState {
+				latest: None,
+				auditing: false,
+				upstream_state: SubscriberState::default(),
+			})),
+		}
+	}
+
+	fn schedule_audit_work(&self) {
+		let state = self.state.clone();
+		let mut destination = self.destination.clone();
+
+		self.scheduler.lock().schedule_delayed_work(
+			move |_, _| {
+				let (latest, should_complete, should_unsubscribe) = {
+					let mut state = state.lock_ignore_poison();
+					state.auditing = false;
+					(
+						state.latest.take(),
+						state.upstream_state.is_completed(),
+						state.upstream_state.is_closed_but_not_completed(),
+					)
+				};
+
+				if let Some(latest) = latest
+					&& !destination.is_closed()
+				{
+					destination.next(latest);
+				}
+
+				if should_complete {
+					destination.complete();
+				} else if should_unsubscribe {
+					destination.unsubscribe();
+				}
+			},
+			self.duration,
+			self.cancellation_id,
+		);
+	}
+}
+
+impl<Destination, S> RxObserver for AuditTimeSubscriber<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: 'static + Scheduler + Send + Sync,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		let was_idle = {
+			let mut state = self.state.lock_ignore_poison();
+			state.latest = Some(next);
+			!std::mem::replace(&mut state.auditing, true)
+		};
+
+		if was_idle {
+			self.schedule_audit_work();
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		{
+			let mut state = self.state.lock_ignore_poison();
+			state.upstream_state.error();
+			state.latest = None;
+			state.auditing = false;
+		}
+		self.scheduler.lock().cancel(self.cancellation_id);
+		self.destination.error(error);
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		let should_complete_immediately = {
+			let mut state = self.state.lock_ignore_poison();
+			state.upstream_state.complete();
+			!state.auditing
+		};
+
+		// Otherwise the pending audit completes once it emitted
+		if should_complete_immediately {
+			self.destination.complete();
+		}
+	}
+}
+
+impl<Destination, S> SubscriptionLike for AuditTimeSubscriber<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: 'static + Scheduler + Send + Sync,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		if self.destination.is_closed() {
+			return true;
+		}
+		let state = self.state.lock_ignore_poison();
+		state.upstream_state.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		if self.is_closed() {
+			return;
+		}
+
+		let should_unsubscribe_immediately = {
+			let mut state = self.state.lock_ignore_poison();
+			state.upstream_state.unsubscribe();
+			!state.auditing
+		};
+
+		// Otherwise the pending audit unsubscribes once it emitted
+		if should_unsubscribe_immediately {
+			self.destination.unsubscribe();
+		}
+	}
+}
+
+impl<Destination, S> Drop for AuditTimeSubscriber<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: 'static + Scheduler + Send + Sync,
+{
+	fn drop(&mut self) {
+		self.unsubscribe();
+	}
+}
@@ -0,0 +1,24 @@
+mod audit_time_operator;
+mod audit_time_subscriber;
+
+pub use audit_time_subscriber::*;
+
+pub mod operator {
+	pub use super::audit_time_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod audit_time_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::audit_time_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod audit_time_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::audit_time_extension_pipe::*;
+}
@@ -0,0 +1,19 @@
+use rx_core_common::{ComposableOperator, Observable};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::BufferOperator;
+
+pub trait OperatorComposeExtensionBuffer: ComposableOperator + Sized {
+	#[inline]
+	fn buffer<NotifierObservable>(
+		self,
+		notifier: NotifierObservable,
+	) -> CompositeOperator<Self, BufferOperator<NotifierObservable, Self::Out, Self::OutError>>
+	where
+		NotifierObservable: 'static + Observable<OutError = Self::OutError>,
+	{
+		self.compose_with(BufferOperator::new(notifier))
+	}
+}
+
+impl<Op> OperatorComposeExtensionBuffer for Op where Op: ComposableOperator {}
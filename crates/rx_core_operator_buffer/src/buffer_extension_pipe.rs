@@ -0,0 +1,26 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::BufferOperator;
+
+pub trait ObservablePipeExtensionBuffer<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [BufferOperator]
+	///
+	/// Collects upstream values into a buffer that is emitted every time the
+	/// `notifier` emits, even if it's empty.
+	///
+	/// - An incomplete buffer is emitted upon completion!
+	#[inline]
+	fn buffer<NotifierObservable>(
+		self,
+		notifier: NotifierObservable,
+	) -> <BufferOperator<NotifierObservable, Self::Out, Self::OutError> as Operator<'o>>::OutObservable<
+		Self,
+	>
+	where
+		NotifierObservable: 'static + Observable<OutError = Self::OutError>,
+	{
+		BufferOperator::new(notifier).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionBuffer<'o> for O where O: 'o + Observable + Send + Sync {}
@@ -0,0 +1,64 @@
+use std::{
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, PhantomInvariant, RxObserver, SharedSubscriber, Signal, Subscriber,
+};
+use rx_core_macro_observer_derive::RxObserver;
+
+/// Emits the buffer collected so far every time the notifier emits.
+#[derive(RxObserver)]
+#[rx_in(NotifierOut)]
+#[rx_in_error(Destination::InError)]
+pub struct BufferNotifierDestination<NotifierOut, In, Destination>
+where
+	NotifierOut: Signal,
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	buffer: Arc<Mutex<Vec<In>>>,
+	destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<NotifierOut>,
+}
+
+impl<NotifierOut, In, Destination> BufferNotifierDestination<NotifierOut, In, Destination>
+where
+	NotifierOut: Signal,
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	pub(crate) fn new(
+		buffer: Arc<Mutex<Vec<In>>>,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		Self {
+			buffer,
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<NotifierOut, In, Destination> RxObserver
+	for BufferNotifierDestination<NotifierOut, In, Destination>
+where
+	NotifierOut: Signal,
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	fn next(&mut self, _next: Self::In) {
+		let buffer = std::mem::take(&mut *self.buffer.lock_ignore_poison());
+		self.destination.next(buffer);
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		self.buffer.lock_ignore_poison().clear();
+		self.destination.error(error);
+	}
+
+	/// Upstream values are still collected, and emitted on completion.
+	#[inline]
+	fn complete(&mut self) {}
+}
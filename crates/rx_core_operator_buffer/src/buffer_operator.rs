@@ -0,0 +1,68 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{ComposableOperator, Never, Observable, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::BufferSubscriber;
+
+/// # [BufferOperator]
+///
+/// Collects upstream values into a buffer that is emitted every time the
+/// `notifier` emits, even if it's empty.
+///
+/// - Upstream completion emits the buffer collected so far.
+/// - The `notifier` completing does not complete downstream, values are still
+///   collected until upstream completes.
+/// - Errors, from upstream or from the `notifier`, discard the buffer.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(Vec<In>)]
+#[rx_out_error(InError)]
+pub struct BufferOperator<NotifierObservable, In, InError = Never>
+where
+	NotifierObservable: 'static + Observable<OutError = InError>,
+	In: Signal,
+	InError: Signal,
+{
+	notifier: NotifierObservable,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<NotifierObservable, In, InError> BufferOperator<NotifierObservable, In, InError>
+where
+	NotifierObservable: 'static + Observable<OutError = InError>,
+	In: Signal,
+	InError: Signal,
+{
+	pub fn new(notifier: NotifierObservable) -> Self {
+		Self {
+			notifier,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<NotifierObservable, In, InError> ComposableOperator
+	for BufferOperator<NotifierObservable, In, InError>
+where
+	NotifierObservable: 'static + Observable<OutError = InError>,
+	In: Signal,
+	InError: Signal,
+{
+	type Subscriber<Destination>
+		= BufferSubscriber<In, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		BufferSubscriber::new(destination, &mut self.notifier)
+	}
+}
@@ -0,0 +1,99 @@
+use std::sync::{Arc, Mutex};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, RxObserver, SharedSubscriber, Signal, Subscriber,
+	SubscriptionLike, TeardownCollectionExtension,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::BufferNotifierDestination;
+
+#[derive(RxSubscriber)]
+#[rx_in(In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+pub struct BufferSubscriber<In, Destination>
+where
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	buffer: Arc<Mutex<Vec<In>>>,
+}
+
+impl<In, Destination> BufferSubscriber<In, Destination>
+where
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	pub fn new<NotifierObservable>(
+		destination: Destination,
+		notifier: &mut NotifierObservable,
+	) -> Self
+	where
+		NotifierObservable: 'static + Observable<OutError = Destination::InError>,
+	{
+		let mut destination = SharedSubscriber::new(destination);
+		let buffer = Arc::new(Mutex::new(Vec::new()));
+
+		let notifier_subscription =
+			notifier.subscribe(BufferNotifierDestination::<
+				NotifierObservable::Out,
+				In,
+				Destination,
+			>::new(buffer.clone(), destination.clone()));
+		// Stops notifying once downstream is closed
+		destination.add(notifier_subscription);
+
+		Self {
+			destination,
+			buffer,
+		}
+	}
+
+	#[inline]
+	fn take_buffer(&self) -> Vec<In> {
+		std::mem::take(&mut *self.buffer.lock_ignore_poison())
+	}
+}
+
+impl<In, Destination> RxObserver for BufferSubscriber<In, Destination>
+where
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		self.buffer.lock_ignore_poison().push(next);
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		self.take_buffer();
+		self.destination.error(error);
+	}
+
+	fn complete(&mut self) {
+		let buffer = self.take_buffer();
+		self.destination.next(buffer);
+		self.destination.complete();
+	}
+}
+
+impl<In, Destination> SubscriptionLike for BufferSubscriber<In, Destination>
+where
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		self.take_buffer();
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
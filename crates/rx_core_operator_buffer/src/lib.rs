@@ -0,0 +1,26 @@
+mod buffer_notifier_destination;
+mod buffer_operator;
+mod buffer_subscriber;
+
+pub use buffer_notifier_destination::*;
+pub use buffer_subscriber::*;
+
+pub mod operator {
+	pub use super::buffer_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod buffer_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::buffer_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod buffer_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::buffer_extension_pipe::*;
+}
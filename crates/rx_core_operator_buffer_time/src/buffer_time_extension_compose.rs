@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use rx_core_common::{ComposableOperator, Scheduler, SchedulerHandle};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::BufferTimeOperator;
+
+pub trait OperatorComposeExtensionBufferTime<S>: ComposableOperator + Sized
+where
+	S: 'static + Scheduler + Send + Sync,
+{
+	/// # [BufferTimeOperator]
+	///
+	/// Collects upstream values into a buffer that is emitted every
+	/// `duration`, or once it holds `max_size` values.
+	#[inline]
+	fn buffer_time(
+		self,
+		duration: Duration,
+		max_size: usize,
+		scheduler: SchedulerHandle<S>,
+	) -> CompositeOperator<Self, BufferTimeOperator<Self::Out, Self::OutError, S>> {
+		self.compose_with(BufferTimeOperator::new(duration, max_size, scheduler))
+	}
+}
+
+impl<Op, S> OperatorComposeExtensionBufferTime<S> for Op
+where
+	Op: ComposableOperator,
+	S: 'static + Scheduler + Send + Sync,
+{
+}
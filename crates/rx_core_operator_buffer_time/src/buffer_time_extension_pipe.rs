@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use rx_core_common::{Observable, Operator, Scheduler, SchedulerHandle};
+
+use crate::operator::BufferTimeOperator;
+
+pub trait ObservablePipeExtensionBufferTime<'o, S>: 'o + Observable + Sized + Send + Sync
+where
+	S: 'static + Scheduler + Send + Sync,
+{
+	/// # [BufferTimeOperator]
+	///
+	/// Collects upstream values into a buffer that is emitted every
+	/// `duration`, even if it's empty.
+	///
+	/// - An incomplete buffer is emitted upon completion!
+	///
+	/// ## Arguments
+	///
+	/// - `duration`: How often the buffer is emitted.
+	/// - `max_size`: The buffer is emitted early once it holds this many
+	///   values. Use `usize::MAX` to only emit on the interval. A size of `0`
+	///   is invalid and will use `1` instead.
+	/// - `scheduler`: The scheduler the interval is scheduled on.
+	#[inline]
+	fn buffer_time(
+		self,
+		duration: Duration,
+		max_size: usize,
+		scheduler: SchedulerHandle<S>,
+	) -> <BufferTimeOperator<Self::Out, Self::OutError, S> as Operator<'o>>::OutObservable<Self> {
+		BufferTimeOperator::new(duration, max_size, scheduler).operate(self)
+	}
+}
+
+impl<'o, O, S> ObservablePipeExtensionBufferTime<'o, S> for O
+where
+	O: 'o + Observable + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+}
@@ -0,0 +1,79 @@
+use core::marker::PhantomData;
+use std::{num::NonZero, time::Duration};
+
+use rx_core_common::{
+	ComposableOperator, PhantomInvariant, Scheduler, SchedulerHandle, Signal, Subscriber,
+};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::BufferTimeSubscriber;
+
+/// # [BufferTimeOperator]
+///
+/// Collects upstream values into a buffer that is emitted every `duration`,
+/// even if it's empty.
+///
+/// - When the buffer reaches `max_size` it's emitted right away, without
+///   waiting for the next interval, and a new interval is started.
+/// - Upstream completion emits the buffer collected so far.
+/// - Upstream errors discard the buffer.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(Vec<In>)]
+#[rx_out_error(InError)]
+pub struct BufferTimeOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: Scheduler,
+{
+	duration: Duration,
+	max_size: NonZero<usize>,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, S> BufferTimeOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: Scheduler,
+{
+	pub fn new(duration: Duration, max_size: usize, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			duration,
+			max_size: NonZero::new(max_size).unwrap_or(NonZero::<usize>::MIN),
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, S> ComposableOperator for BufferTimeOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+	type Subscriber<Destination>
+		= BufferTimeSubscriber<In, Destination, S>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		BufferTimeSubscriber::new(
+			destination,
+			self.duration,
+			self.max_size,
+			self.scheduler.clone(),
+		)
+	}
+}
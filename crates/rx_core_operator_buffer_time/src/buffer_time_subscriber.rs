@@ -0,0 +1,150 @@
+use std::{
+	num::NonZero,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, RxObserver, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension,
+	SharedSubscriber, Signal, Subscriber, SubscriptionLike, Teardown, TeardownCollectionExtension,
+	WorkCancellationId, WorkResult,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+#[derive(RxSubscriber)]
+#[rx_in(In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+pub struct BufferTimeSubscriber<In, Destination, S>
+where
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+	S: 'static + Scheduler,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	buffer: Arc<Mutex<Vec<In>>>,
+	duration: Duration,
+	max_size: NonZero<usize>,
+	scheduler: SchedulerHandle<S>,
+	cancellation_id: WorkCancellationId,
+}
+
+impl<In, Destination, S> BufferTimeSubscriber<In, Destination, S>
+where
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+	S: 'static + Scheduler,
+{
+	pub fn new(
+		destination: Destination,
+		duration: Duration,
+		max_size: NonZero<usize>,
+		scheduler: SchedulerHandle<S>,
+	) -> Self {
+		let mut destination = SharedSubscriber::new(destination);
+		let cancellation_id = scheduler.lock().generate_cancellation_id();
+
+		destination.add(Teardown::new_work_cancellation(
+			cancellation_id,
+			scheduler.clone(),
+		));
+
+		let buffer_time_subscriber = Self {
+			destination,
+			buffer: Arc::new(Mutex::new(Vec::new())),
+			duration,
+			max_size,
+			scheduler,
+			cancellation_id,
+		};
+		buffer_time_subscriber.schedule_flush();
+		buffer_time_subscriber
+	}
+
+	/// Starts a new period, emitting the buffer every `duration` from now.
+	fn schedule_flush(&self) {
+		let destination = self.destination.clone();
+		let buffer = self.buffer.clone();
+
+		self.scheduler.lock().schedule_repeated_work(
+			move |_, _| {
+				let mut destination = destination.lock();
+				if destination.is_closed() {
+					return WorkResult::Done;
+				}
+
+				let buffer = std::mem::take(&mut *buffer.lock_ignore_poison());
+				destination.next(buffer);
+
+				WorkResult::Pending
+			},
+			self.duration,
+			false,
+			1,
+			self.cancellation_id,
+		);
+	}
+
+	#[inline]
+	fn take_buffer(&self) -> Vec<In> {
+		std::mem::take(&mut *self.buffer.lock_ignore_poison())
+	}
+}
+
+impl<In, Destination, S> RxObserver for BufferTimeSubscriber<In, Destination, S>
+where
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+	S: 'static + Scheduler,
+{
+	fn next(&mut self, next: Self::In) {
+		let full_buffer = {
+			let mut buffer = self.buffer.lock_ignore_poison();
+			buffer.push(next);
+			(buffer.len() >= self.max_size.get()).then(|| std::mem::take(&mut *buffer))
+		};
+
+		if let Some(full_buffer) = full_buffer {
+			// A full buffer starts a new period, so the next one is not cut short
+			self.scheduler.lock().cancel(self.cancellation_id);
+			self.destination.next(full_buffer);
+			if !self.destination.is_closed() {
+				self.schedule_flush();
+			}
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		self.scheduler.lock().cancel(self.cancellation_id);
+		self.take_buffer();
+		self.destination.error(error);
+	}
+
+	fn complete(&mut self) {
+		self.scheduler.lock().cancel(self.cancellation_id);
+		let buffer = self.take_buffer();
+		self.destination.next(buffer);
+		self.destination.complete();
+	}
+}
+
+impl<In, Destination, S> SubscriptionLike for BufferTimeSubscriber<In, Destination, S>
+where
+	In: Signal,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+	S: 'static + Scheduler,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		self.scheduler.lock().cancel(self.cancellation_id);
+		self.take_buffer();
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
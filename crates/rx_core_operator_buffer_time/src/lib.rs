@@ -0,0 +1,24 @@
+mod buffer_time_operator;
+mod buffer_time_subscriber;
+
+pub use buffer_time_subscriber::*;
+
+pub mod operator {
+	pub use super::buffer_time_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod buffer_time_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::buffer_time_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod buffer_time_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::buffer_time_extension_pipe::*;
+}
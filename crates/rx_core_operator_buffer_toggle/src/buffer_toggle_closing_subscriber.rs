@@ -0,0 +1,118 @@
+use std::{
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, PhantomInvariant, RxObserver, SharedSubscriber, Signal, Subscriber,
+	SubscriptionData, SubscriptionLike, Teardown, TeardownCollection,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::buffer_toggle_state::BufferToggleState;
+
+/// Emits its buffer on the first `next` of the closing observable.
+#[derive(RxSubscriber)]
+#[rx_in(ClosingOut)]
+#[rx_in_error(Destination::InError)]
+pub struct BufferToggleClosingSubscriber<ClosingOut, In, Destination>
+where
+	ClosingOut: Signal,
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	teardown: SubscriptionData,
+	buffer_id: usize,
+	state: Arc<Mutex<BufferToggleState<In>>>,
+	destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<ClosingOut>,
+}
+
+impl<ClosingOut, In, Destination> BufferToggleClosingSubscriber<ClosingOut, In, Destination>
+where
+	ClosingOut: Signal,
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	pub(crate) fn new(
+		buffer_id: usize,
+		state: Arc<Mutex<BufferToggleState<In>>>,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		Self {
+			teardown: SubscriptionData::default(),
+			buffer_id,
+			state,
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<ClosingOut, In, Destination> RxObserver
+	for BufferToggleClosingSubscriber<ClosingOut, In, Destination>
+where
+	ClosingOut: Signal,
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	fn next(&mut self, _next: Self::In) {
+		if !self.is_closed() {
+			let buffer = self.state.lock_ignore_poison().take_buffer(self.buffer_id);
+			self.unsubscribe();
+			if let Some(buffer) = buffer {
+				self.destination.next(buffer.close());
+			}
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		if !self.is_closed() {
+			let buffers = self.state.lock_ignore_poison().take_buffers();
+			for buffer in buffers {
+				buffer.close();
+			}
+			self.destination.error(error);
+			self.unsubscribe();
+		}
+	}
+
+	/// Only a `next` closes the buffer, a closing observable completing
+	/// without one leaves it open until upstream completes.
+	fn complete(&mut self) {
+		self.unsubscribe();
+	}
+}
+
+impl<ClosingOut, In, Destination> TeardownCollection
+	for BufferToggleClosingSubscriber<ClosingOut, In, Destination>
+where
+	ClosingOut: Signal,
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	#[inline]
+	fn add_teardown(&mut self, teardown: Teardown) {
+		self.teardown.add_teardown(teardown);
+	}
+}
+
+impl<ClosingOut, In, Destination> SubscriptionLike
+	for BufferToggleClosingSubscriber<ClosingOut, In, Destination>
+where
+	ClosingOut: Signal,
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.teardown.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.is_closed() {
+			self.teardown.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,36 @@
+use rx_core_common::{ComposableOperator, Observable};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::BufferToggleOperator;
+
+pub trait OperatorComposeExtensionBufferToggle: ComposableOperator + Sized {
+	#[inline]
+	fn buffer_toggle<OpeningObservable, ClosingSelector, ClosingObservable>(
+		self,
+		opening_observable: OpeningObservable,
+		closing_selector: ClosingSelector,
+	) -> CompositeOperator<
+		Self,
+		BufferToggleOperator<
+			OpeningObservable,
+			ClosingSelector,
+			ClosingObservable,
+			Self::Out,
+			Self::OutError,
+		>,
+	>
+	where
+		OpeningObservable: 'static + Observable<OutError = Self::OutError>,
+		ClosingSelector:
+			'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Clone + Send + Sync,
+		ClosingObservable: 'static + Observable<OutError = Self::OutError>,
+		Self::Out: Clone,
+	{
+		self.compose_with(BufferToggleOperator::new(
+			opening_observable,
+			closing_selector,
+		))
+	}
+}
+
+impl<Op> OperatorComposeExtensionBufferToggle for Op where Op: ComposableOperator {}
@@ -0,0 +1,35 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::BufferToggleOperator;
+
+pub trait ObservablePipeExtensionBufferToggle<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [BufferToggleOperator]
+	///
+	/// Opens a new buffer every time `opening_observable` emits, and emits it
+	/// once the observable `closing_selector` created for it emits.
+	///
+	/// - Open buffers are emitted upon completion!
+	#[inline]
+	fn buffer_toggle<OpeningObservable, ClosingSelector, ClosingObservable>(
+		self,
+		opening_observable: OpeningObservable,
+		closing_selector: ClosingSelector,
+	) -> <BufferToggleOperator<
+		OpeningObservable,
+		ClosingSelector,
+		ClosingObservable,
+		Self::Out,
+		Self::OutError,
+	> as Operator<'o>>::OutObservable<Self>
+	where
+		OpeningObservable: 'static + Observable<OutError = Self::OutError>,
+		ClosingSelector:
+			'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Clone + Send + Sync,
+		ClosingObservable: 'static + Observable<OutError = Self::OutError>,
+		Self::Out: Clone,
+	{
+		BufferToggleOperator::new(opening_observable, closing_selector).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionBufferToggle<'o> for O where O: 'o + Observable + Send + Sync {}
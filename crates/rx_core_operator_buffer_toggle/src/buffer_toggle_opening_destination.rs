@@ -0,0 +1,110 @@
+use std::{
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, PhantomInvariant, RxObserver, SharedSubscriber, Signal,
+	Subscriber, SubscriptionData, SubscriptionLike,
+};
+use rx_core_macro_observer_derive::RxObserver;
+
+use crate::{BufferToggleClosingSubscriber, buffer_toggle_state::BufferToggleState};
+
+/// Opens a new buffer for every value of the opening observable.
+#[derive(RxObserver)]
+#[rx_in(OpeningOut)]
+#[rx_in_error(Destination::InError)]
+pub struct BufferToggleOpeningDestination<
+	OpeningOut,
+	ClosingSelector,
+	ClosingObservable,
+	In,
+	Destination,
+> where
+	OpeningOut: Signal,
+	ClosingSelector: 'static + FnMut(OpeningOut) -> ClosingObservable + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = Destination::InError>,
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	closing_selector: ClosingSelector,
+	state: Arc<Mutex<BufferToggleState<In>>>,
+	destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<(OpeningOut, ClosingObservable)>,
+}
+
+impl<OpeningOut, ClosingSelector, ClosingObservable, In, Destination>
+	BufferToggleOpeningDestination<OpeningOut, ClosingSelector, ClosingObservable, In, Destination>
+where
+	OpeningOut: Signal,
+	ClosingSelector: 'static + FnMut(OpeningOut) -> ClosingObservable + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = Destination::InError>,
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	pub(crate) fn new(
+		closing_selector: ClosingSelector,
+		state: Arc<Mutex<BufferToggleState<In>>>,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		Self {
+			closing_selector,
+			state,
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<OpeningOut, ClosingSelector, ClosingObservable, In, Destination> RxObserver
+	for BufferToggleOpeningDestination<OpeningOut, ClosingSelector, ClosingObservable, In, Destination>
+where
+	OpeningOut: Signal,
+	ClosingSelector: 'static + FnMut(OpeningOut) -> ClosingObservable + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = Destination::InError>,
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	fn next(&mut self, next: Self::In) {
+		if self.destination.is_closed() {
+			return;
+		}
+
+		let buffer_id = self.state.lock_ignore_poison().open_buffer();
+
+		let mut closing_observable = (self.closing_selector)(next);
+		let closing_subscription =
+			closing_observable.subscribe(BufferToggleClosingSubscriber::<
+				ClosingObservable::Out,
+				In,
+				Destination,
+			>::new(
+				buffer_id, self.state.clone(), self.destination.clone()
+			));
+
+		if !closing_subscription.is_closed() {
+			let closing_subscription =
+				SubscriptionData::new_with_teardown(closing_subscription.into());
+			let already_closed = self
+				.state
+				.lock_ignore_poison()
+				.attach_closing_subscription(buffer_id, closing_subscription);
+			if let Some(mut already_closed) = already_closed {
+				already_closed.unsubscribe();
+			}
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		let buffers = self.state.lock_ignore_poison().take_buffers();
+		for buffer in buffers {
+			buffer.close();
+		}
+		self.destination.error(error);
+	}
+
+	/// Buffers already opened can still be closed, but no new ones will open.
+	#[inline]
+	fn complete(&mut self) {}
+}
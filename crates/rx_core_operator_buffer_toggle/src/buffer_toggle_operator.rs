@@ -0,0 +1,92 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{ComposableOperator, Never, Observable, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::BufferToggleSubscriber;
+
+/// # [BufferToggleOperator]
+///
+/// Opens a new buffer every time `opening_observable` emits, and emits it
+/// when the observable created for it by `closing_selector` emits.
+///
+/// - Buffers can overlap, every upstream value is collected into all open
+///   buffers.
+/// - The `opening_observable` completing stops opening new buffers, but does
+///   not close the ones already open.
+/// - Upstream completion emits all open buffers.
+/// - Errors, from upstream or from the opening or closing observables,
+///   discard every open buffer.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(Vec<In>)]
+#[rx_out_error(InError)]
+pub struct BufferToggleOperator<
+	OpeningObservable,
+	ClosingSelector,
+	ClosingObservable,
+	In,
+	InError = Never,
+> where
+	OpeningObservable: 'static + Observable<OutError = InError>,
+	ClosingSelector:
+		'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Clone + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = InError>,
+	In: Signal + Clone,
+	InError: Signal,
+{
+	opening_observable: OpeningObservable,
+	closing_selector: ClosingSelector,
+	_phantom_data: PhantomInvariant<(In, InError, ClosingObservable)>,
+}
+
+impl<OpeningObservable, ClosingSelector, ClosingObservable, In, InError>
+	BufferToggleOperator<OpeningObservable, ClosingSelector, ClosingObservable, In, InError>
+where
+	OpeningObservable: 'static + Observable<OutError = InError>,
+	ClosingSelector:
+		'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Clone + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = InError>,
+	In: Signal + Clone,
+	InError: Signal,
+{
+	pub fn new(opening_observable: OpeningObservable, closing_selector: ClosingSelector) -> Self {
+		Self {
+			opening_observable,
+			closing_selector,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<OpeningObservable, ClosingSelector, ClosingObservable, In, InError> ComposableOperator
+	for BufferToggleOperator<OpeningObservable, ClosingSelector, ClosingObservable, In, InError>
+where
+	OpeningObservable: 'static + Observable<OutError = InError>,
+	ClosingSelector:
+		'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Clone + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = InError>,
+	In: Signal + Clone,
+	InError: Signal,
+{
+	type Subscriber<Destination>
+		= BufferToggleSubscriber<In, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		BufferToggleSubscriber::new(
+			destination,
+			&mut self.opening_observable,
+			self.closing_selector.clone(),
+		)
+	}
+}
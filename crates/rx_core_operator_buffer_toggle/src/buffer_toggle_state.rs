@@ -0,0 +1,84 @@
+use derive_where::derive_where;
+use rx_core_common::{Signal, SubscriptionData, SubscriptionLike};
+
+pub(crate) struct BufferToggleBuffer<In>
+where
+	In: Signal + Clone,
+{
+	id: usize,
+	buffer: Vec<In>,
+	closing_subscription: Option<SubscriptionData>,
+}
+
+impl<In> BufferToggleBuffer<In>
+where
+	In: Signal + Clone,
+{
+	/// Stops listening for the closing notification and gives back the values
+	/// collected.
+	pub(crate) fn close(mut self) -> Vec<In> {
+		if let Some(mut closing_subscription) = self.closing_subscription.take() {
+			closing_subscription.unsubscribe();
+		}
+		self.buffer
+	}
+}
+
+/// The buffers currently open, each one waiting for its own closing
+/// observable to emit.
+#[derive_where(Default)]
+pub(crate) struct BufferToggleState<In>
+where
+	In: Signal + Clone,
+{
+	next_buffer_id: usize,
+	buffers: Vec<BufferToggleBuffer<In>>,
+}
+
+impl<In> BufferToggleState<In>
+where
+	In: Signal + Clone,
+{
+	pub(crate) fn open_buffer(&mut self) -> usize {
+		let id = self.next_buffer_id;
+		self.next_buffer_id += 1;
+
+		self.buffers.push(BufferToggleBuffer {
+			id,
+			buffer: Vec::new(),
+			closing_subscription: None,
+		});
+
+		id
+	}
+
+	/// Gives the subscription back if the buffer had already closed.
+	pub(crate) fn attach_closing_subscription(
+		&mut self,
+		id: usize,
+		closing_subscription: SubscriptionData,
+	) -> Option<SubscriptionData> {
+		match self.buffers.iter_mut().find(|buffer| buffer.id == id) {
+			Some(buffer) => {
+				buffer.closing_subscription = Some(closing_subscription);
+				None
+			}
+			None => Some(closing_subscription),
+		}
+	}
+
+	pub(crate) fn push(&mut self, next: In) {
+		for buffer in self.buffers.iter_mut() {
+			buffer.buffer.push(next.clone());
+		}
+	}
+
+	pub(crate) fn take_buffer(&mut self, id: usize) -> Option<BufferToggleBuffer<In>> {
+		let index = self.buffers.iter().position(|buffer| buffer.id == id)?;
+		Some(self.buffers.remove(index))
+	}
+
+	pub(crate) fn take_buffers(&mut self) -> Vec<BufferToggleBuffer<In>> {
+		std::mem::take(&mut self.buffers)
+	}
+}
@@ -0,0 +1,102 @@
+use std::sync::{Arc, Mutex};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, RxObserver, SharedSubscriber, Signal, Subscriber,
+	SubscriptionLike, TeardownCollectionExtension,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::{BufferToggleOpeningDestination, buffer_toggle_state::BufferToggleState};
+
+#[derive(RxSubscriber)]
+#[rx_in(In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+pub struct BufferToggleSubscriber<In, Destination>
+where
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	state: Arc<Mutex<BufferToggleState<In>>>,
+}
+
+impl<In, Destination> BufferToggleSubscriber<In, Destination>
+where
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	pub fn new<OpeningObservable, ClosingSelector, ClosingObservable>(
+		destination: Destination,
+		opening_observable: &mut OpeningObservable,
+		closing_selector: ClosingSelector,
+	) -> Self
+	where
+		OpeningObservable: 'static + Observable<OutError = Destination::InError>,
+		ClosingSelector: 'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Send + Sync,
+		ClosingObservable: 'static + Observable<OutError = Destination::InError>,
+	{
+		let mut destination = SharedSubscriber::new(destination);
+		let state = Arc::new(Mutex::new(BufferToggleState::default()));
+
+		let opening_subscription =
+			opening_observable.subscribe(BufferToggleOpeningDestination::new(
+				closing_selector,
+				state.clone(),
+				destination.clone(),
+			));
+		// Stops opening buffers once downstream is closed
+		destination.add(opening_subscription);
+
+		Self { destination, state }
+	}
+}
+
+impl<In, Destination> RxObserver for BufferToggleSubscriber<In, Destination>
+where
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		self.state.lock_ignore_poison().push(next);
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		let buffers = self.state.lock_ignore_poison().take_buffers();
+		for buffer in buffers {
+			buffer.close();
+		}
+		self.destination.error(error);
+	}
+
+	fn complete(&mut self) {
+		let buffers = self.state.lock_ignore_poison().take_buffers();
+		for buffer in buffers {
+			self.destination.next(buffer.close());
+		}
+		self.destination.complete();
+	}
+}
+
+impl<In, Destination> SubscriptionLike for BufferToggleSubscriber<In, Destination>
+where
+	In: Signal + Clone,
+	Destination: 'static + Subscriber<In = Vec<In>>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		let buffers = self.state.lock_ignore_poison().take_buffers();
+		for buffer in buffers {
+			buffer.close();
+		}
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,29 @@
+mod buffer_toggle_closing_subscriber;
+mod buffer_toggle_opening_destination;
+mod buffer_toggle_operator;
+mod buffer_toggle_state;
+mod buffer_toggle_subscriber;
+
+pub use buffer_toggle_closing_subscriber::*;
+pub use buffer_toggle_opening_destination::*;
+pub use buffer_toggle_subscriber::*;
+
+pub mod operator {
+	pub use super::buffer_toggle_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod buffer_toggle_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::buffer_toggle_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod buffer_toggle_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::buffer_toggle_extension_pipe::*;
+}
@@ -0,0 +1,57 @@
+use std::hash::Hash;
+
+use rx_core_common::{ComposableOperator, Observable};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::DistinctOperator;
+
+pub trait OperatorComposeExtensionDistinct: ComposableOperator + Sized {
+	/// # [DistinctOperator]
+	///
+	/// Only emits upstream values whose key, as returned by `key_fn`, was never
+	/// seen before.
+	///
+	/// Every key is stored in a set for the lifetime of the subscription, use
+	/// `distinct_with_flush` to be able to clear it.
+	#[inline]
+	fn distinct<KeyFn, Key>(
+		self,
+		key_fn: KeyFn,
+	) -> CompositeOperator<Self, DistinctOperator<Self::Out, Self::OutError, KeyFn, Key>>
+	where
+		KeyFn: 'static + Fn(&Self::Out) -> Key + Clone + Send + Sync,
+		Key: 'static + Eq + Hash + Send + Sync,
+	{
+		self.compose_with(DistinctOperator::new(key_fn))
+	}
+
+	/// # [DistinctOperator]
+	///
+	/// Only emits upstream values whose key, as returned by `key_fn`, was never
+	/// seen before.
+	///
+	/// Every key is stored in a set that is cleared every time the `flush`
+	/// notifier emits, letting previously seen keys through again.
+	///
+	/// - Errors of the `flush` notifier are not forwarded downstream, the set is
+	///   just no longer flushed.
+	/// - The `flush` subscription is torn down together with the subscriber.
+	#[inline]
+	fn distinct_with_flush<KeyFn, Key, FlushObservable>(
+		self,
+		key_fn: KeyFn,
+		flush: FlushObservable,
+	) -> CompositeOperator<
+		Self,
+		DistinctOperator<Self::Out, Self::OutError, KeyFn, Key, FlushObservable>,
+	>
+	where
+		KeyFn: 'static + Fn(&Self::Out) -> Key + Clone + Send + Sync,
+		Key: 'static + Eq + Hash + Send + Sync,
+		FlushObservable: 'static + Observable,
+	{
+		self.compose_with(DistinctOperator::new_with_flush(key_fn, flush))
+	}
+}
+
+impl<Op> OperatorComposeExtensionDistinct for Op where Op: ComposableOperator {}
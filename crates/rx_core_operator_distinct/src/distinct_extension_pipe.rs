@@ -0,0 +1,57 @@
+use std::hash::Hash;
+
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::DistinctOperator;
+
+pub trait ObservablePipeExtensionDistinct<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [DistinctOperator]
+	///
+	/// Only emits upstream values whose key, as returned by `key_fn`, was never
+	/// seen before.
+	///
+	/// Every key is stored in a set for the lifetime of the subscription, use
+	/// `distinct_with_flush` to be able to clear it.
+	#[inline]
+	fn distinct<KeyFn, Key>(
+		self,
+		key_fn: KeyFn,
+	) -> <DistinctOperator<Self::Out, Self::OutError, KeyFn, Key> as Operator<'o>>::OutObservable<
+		Self,
+	>
+	where
+		KeyFn: 'static + Fn(&Self::Out) -> Key + Clone + Send + Sync,
+		Key: 'static + Eq + Hash + Send + Sync,
+	{
+		DistinctOperator::new(key_fn).operate(self)
+	}
+
+	/// # [DistinctOperator]
+	///
+	/// Only emits upstream values whose key, as returned by `key_fn`, was never
+	/// seen before.
+	///
+	/// Every key is stored in a set that is cleared every time the `flush`
+	/// notifier emits, letting previously seen keys through again.
+	///
+	/// - Errors of the `flush` notifier are not forwarded downstream, the set is
+	///   just no longer flushed.
+	/// - The `flush` subscription is torn down together with the subscriber.
+	#[inline]
+	fn distinct_with_flush<KeyFn, Key, FlushObservable>(
+		self,
+		key_fn: KeyFn,
+		flush: FlushObservable,
+	) -> <DistinctOperator<Self::Out, Self::OutError, KeyFn, Key, FlushObservable> as Operator<'o>>::OutObservable<
+		Self,
+	>
+	where
+		KeyFn: 'static + Fn(&Self::Out) -> Key + Clone + Send + Sync,
+		Key: 'static + Eq + Hash + Send + Sync,
+		FlushObservable: 'static + Observable,
+	{
+		DistinctOperator::new_with_flush(key_fn, flush).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionDistinct<'o> for O where O: 'o + Observable + Send + Sync {}
@@ -0,0 +1,56 @@
+use std::{
+	collections::HashSet,
+	hash::Hash,
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{LockWithPoisonBehavior, PhantomInvariant, RxObserver, Signal};
+use rx_core_macro_observer_derive::RxObserver;
+
+/// Forgets every key seen so far every time the flush notifier emits.
+#[derive(RxObserver)]
+#[rx_in(FlushOut)]
+#[rx_in_error(FlushError)]
+pub struct DistinctFlushDestination<FlushOut, FlushError, Key>
+where
+	FlushOut: Signal,
+	FlushError: Signal,
+	Key: 'static + Eq + Hash + Send + Sync,
+{
+	keys: Arc<Mutex<HashSet<Key>>>,
+	_phantom_data: PhantomInvariant<(FlushOut, FlushError)>,
+}
+
+impl<FlushOut, FlushError, Key> DistinctFlushDestination<FlushOut, FlushError, Key>
+where
+	FlushOut: Signal,
+	FlushError: Signal,
+	Key: 'static + Eq + Hash + Send + Sync,
+{
+	pub(crate) fn new(keys: Arc<Mutex<HashSet<Key>>>) -> Self {
+		Self {
+			keys,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<FlushOut, FlushError, Key> RxObserver for DistinctFlushDestination<FlushOut, FlushError, Key>
+where
+	FlushOut: Signal,
+	FlushError: Signal,
+	Key: 'static + Eq + Hash + Send + Sync,
+{
+	#[inline]
+	fn next(&mut self, _next: Self::In) {
+		self.keys.lock_ignore_poison().clear();
+	}
+
+	/// The flush notifier erroring only stops the flushing.
+	#[inline]
+	fn error(&mut self, _error: Self::InError) {}
+
+	#[inline]
+	fn complete(&mut self) {}
+}
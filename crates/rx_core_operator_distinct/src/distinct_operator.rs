@@ -0,0 +1,98 @@
+use core::marker::PhantomData;
+use std::hash::Hash;
+
+use rx_core_common::{ComposableOperator, Observable, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+use rx_core_observable_never::observable::NeverObservable;
+
+use crate::DistinctSubscriber;
+
+/// # [DistinctOperator]
+///
+/// Only emits upstream values whose key, as returned by `key_fn`, was never
+/// seen before.
+///
+/// Every key is stored in a set for the lifetime of the subscription. An
+/// optional `flush` notifier clears this set every time it emits, letting
+/// previously seen keys through again.
+///
+/// - Errors of the `flush` notifier are not forwarded downstream, the set is
+///   just no longer flushed.
+/// - The `flush` subscription is torn down together with the subscriber.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct DistinctOperator<In, InError, KeyFn, Key, FlushObservable = NeverObservable>
+where
+	In: Signal,
+	InError: Signal,
+	KeyFn: 'static + Fn(&In) -> Key + Clone + Send + Sync,
+	Key: 'static + Eq + Hash + Send + Sync,
+	FlushObservable: 'static + Observable,
+{
+	key_fn: KeyFn,
+	flush: Option<FlushObservable>,
+	_phantom_data: PhantomInvariant<(In, InError, Key)>,
+}
+
+impl<In, InError, KeyFn, Key> DistinctOperator<In, InError, KeyFn, Key>
+where
+	In: Signal,
+	InError: Signal,
+	KeyFn: 'static + Fn(&In) -> Key + Clone + Send + Sync,
+	Key: 'static + Eq + Hash + Send + Sync,
+{
+	pub fn new(key_fn: KeyFn) -> Self {
+		Self {
+			key_fn,
+			flush: None,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, KeyFn, Key, FlushObservable>
+	DistinctOperator<In, InError, KeyFn, Key, FlushObservable>
+where
+	In: Signal,
+	InError: Signal,
+	KeyFn: 'static + Fn(&In) -> Key + Clone + Send + Sync,
+	Key: 'static + Eq + Hash + Send + Sync,
+	FlushObservable: 'static + Observable,
+{
+	pub fn new_with_flush(key_fn: KeyFn, flush: FlushObservable) -> Self {
+		Self {
+			key_fn,
+			flush: Some(flush),
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, KeyFn, Key, FlushObservable> ComposableOperator
+	for DistinctOperator<In, InError, KeyFn, Key, FlushObservable>
+where
+	In: Signal,
+	InError: Signal,
+	KeyFn: 'static + Fn(&In) -> Key + Clone + Send + Sync,
+	Key: 'static + Eq + Hash + Send + Sync,
+	FlushObservable: 'static + Observable,
+{
+	type Subscriber<Destination>
+		= DistinctSubscriber<KeyFn, Key, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		DistinctSubscriber::new(destination, self.key_fn.clone(), self.flush.as_mut())
+	}
+}
@@ -0,0 +1,90 @@
+use std::{
+	collections::HashSet,
+	hash::Hash,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, RxObserver, Subscriber, TeardownCollectionExtension,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::DistinctFlushDestination;
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct DistinctSubscriber<KeyFn, Key, Destination>
+where
+	KeyFn: Fn(&Destination::In) -> Key,
+	Key: 'static + Eq + Hash + Send + Sync,
+	Destination: Subscriber,
+{
+	#[destination]
+	destination: Destination,
+	key_fn: KeyFn,
+	/// Shared with the flush notifier
+	keys: Arc<Mutex<HashSet<Key>>>,
+}
+
+impl<KeyFn, Key, Destination> DistinctSubscriber<KeyFn, Key, Destination>
+where
+	KeyFn: Fn(&Destination::In) -> Key,
+	Key: 'static + Eq + Hash + Send + Sync,
+	Destination: Subscriber,
+{
+	pub fn new<FlushObservable>(
+		mut destination: Destination,
+		key_fn: KeyFn,
+		flush: Option<&mut FlushObservable>,
+	) -> Self
+	where
+		FlushObservable: 'static + Observable,
+	{
+		let keys = Arc::new(Mutex::new(HashSet::new()));
+
+		if let Some(flush) = flush {
+			let flush_subscription = flush.subscribe(DistinctFlushDestination::<
+				FlushObservable::Out,
+				FlushObservable::OutError,
+				Key,
+			>::new(keys.clone()));
+			// Stops flushing once downstream is closed
+			destination.add(flush_subscription);
+		}
+
+		Self {
+			destination,
+			key_fn,
+			keys,
+		}
+	}
+}
+
+impl<KeyFn, Key, Destination> RxObserver for DistinctSubscriber<KeyFn, Key, Destination>
+where
+	KeyFn: Fn(&Destination::In) -> Key + Send + Sync,
+	Key: 'static + Eq + Hash + Send + Sync,
+	Destination: Subscriber,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		let key = (self.key_fn)(&next);
+
+		if self.keys.lock_ignore_poison().insert(key) {
+			self.destination.next(next);
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.destination.error(error);
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.destination.complete();
+	}
+}
@@ -0,0 +1,26 @@
+mod distinct_flush_destination;
+mod distinct_operator;
+mod distinct_subscriber;
+
+pub use distinct_flush_destination::*;
+pub use distinct_subscriber::*;
+
+pub mod operator {
+	pub use super::distinct_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod distinct_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::distinct_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod distinct_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::distinct_extension_pipe::*;
+}
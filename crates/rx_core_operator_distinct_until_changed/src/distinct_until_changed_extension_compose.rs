@@ -0,0 +1,40 @@
+use rx_core_common::ComposableOperator;
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::DistinctUntilChangedOperator;
+
+pub trait OperatorComposeExtensionDistinctUntilChanged: ComposableOperator + Sized {
+	/// # [DistinctUntilChangedOperator]
+	///
+	/// Only emits upstream values that are different from the previously
+	/// emitted value, compared using [PartialEq]. The first value is always
+	/// emitted.
+	#[inline]
+	fn distinct_until_changed(
+		self,
+	) -> CompositeOperator<Self, DistinctUntilChangedOperator<Self::Out, Self::OutError>>
+	where
+		Self::Out: Clone + PartialEq,
+	{
+		self.compose_with(DistinctUntilChangedOperator::default())
+	}
+
+	/// # [DistinctUntilChangedOperator]
+	///
+	/// Only emits upstream values that are different from the previously
+	/// emitted value, compared using the `comparator` which returns true when
+	/// two values are considered equal. The first value is always emitted.
+	#[inline]
+	fn distinct_until_changed_by<Comparator>(
+		self,
+		comparator: Comparator,
+	) -> CompositeOperator<Self, DistinctUntilChangedOperator<Self::Out, Self::OutError, Comparator>>
+	where
+		Self::Out: Clone,
+		Comparator: 'static + Fn(&Self::Out, &Self::Out) -> bool + Clone + Send + Sync,
+	{
+		self.compose_with(DistinctUntilChangedOperator::new(comparator))
+	}
+}
+
+impl<Op> OperatorComposeExtensionDistinctUntilChanged for Op where Op: ComposableOperator {}
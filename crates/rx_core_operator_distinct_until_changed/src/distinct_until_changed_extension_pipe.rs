@@ -0,0 +1,48 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::DistinctUntilChangedOperator;
+
+pub trait ObservablePipeExtensionDistinctUntilChanged<'o>:
+	'o + Observable + Sized + Send + Sync
+{
+	/// # [DistinctUntilChangedOperator]
+	///
+	/// Only emits upstream values that are different from the previously
+	/// emitted value, compared using [PartialEq]. The first value is always
+	/// emitted.
+	#[inline]
+	fn distinct_until_changed(
+		self,
+	) -> <DistinctUntilChangedOperator<Self::Out, Self::OutError> as Operator<'o>>::OutObservable<
+		Self,
+	>
+	where
+		Self::Out: Clone + PartialEq,
+	{
+		DistinctUntilChangedOperator::default().operate(self)
+	}
+
+	/// # [DistinctUntilChangedOperator]
+	///
+	/// Only emits upstream values that are different from the previously
+	/// emitted value, compared using the `comparator` which returns true when
+	/// two values are considered equal. The first value is always emitted.
+	#[inline]
+	fn distinct_until_changed_by<Comparator>(
+		self,
+		comparator: Comparator,
+	) -> <DistinctUntilChangedOperator<Self::Out, Self::OutError, Comparator> as Operator<'o>>::OutObservable<
+		Self,
+	>
+	where
+		Self::Out: Clone,
+		Comparator: 'static + Fn(&Self::Out, &Self::Out) -> bool + Clone + Send + Sync,
+	{
+		DistinctUntilChangedOperator::new(comparator).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionDistinctUntilChanged<'o> for O where
+	O: 'o + Observable + Send + Sync
+{
+}
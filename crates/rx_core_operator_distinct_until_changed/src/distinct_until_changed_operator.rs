@@ -0,0 +1,81 @@
+use core::marker::PhantomData;
+
+use derive_where::derive_where;
+use rx_core_common::{ComposableOperator, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::DistinctUntilChangedSubscriber;
+
+/// Compares values using their [PartialEq] implementation.
+pub type DefaultComparator<In> = fn(&In, &In) -> bool;
+
+/// # [DistinctUntilChangedOperator]
+///
+/// Only emits upstream values that are different from the previously emitted
+/// value. The first value is always emitted.
+///
+/// By default values are compared using [PartialEq], a custom `comparator`
+/// can be provided that returns true when two values are considered equal.
+#[derive_where(Clone)]
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct DistinctUntilChangedOperator<In, InError, Comparator = DefaultComparator<In>>
+where
+	In: Signal + Clone,
+	InError: Signal,
+	Comparator: 'static + Fn(&In, &In) -> bool + Clone + Send + Sync,
+{
+	comparator: Comparator,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, Comparator> DistinctUntilChangedOperator<In, InError, Comparator>
+where
+	In: Signal + Clone,
+	InError: Signal,
+	Comparator: 'static + Fn(&In, &In) -> bool + Clone + Send + Sync,
+{
+	pub fn new(comparator: Comparator) -> Self {
+		Self {
+			comparator,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError> Default for DistinctUntilChangedOperator<In, InError, DefaultComparator<In>>
+where
+	In: Signal + Clone + PartialEq,
+	InError: Signal,
+{
+	fn default() -> Self {
+		Self::new(PartialEq::eq)
+	}
+}
+
+impl<In, InError, Comparator> ComposableOperator
+	for DistinctUntilChangedOperator<In, InError, Comparator>
+where
+	In: Signal + Clone,
+	InError: Signal,
+	Comparator: 'static + Fn(&In, &In) -> bool + Clone + Send + Sync,
+{
+	type Subscriber<Destination>
+		= DistinctUntilChangedSubscriber<Comparator, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		DistinctUntilChangedSubscriber::new(destination, self.comparator.clone())
+	}
+}
@@ -0,0 +1,63 @@
+use rx_core_common::{RxObserver, Subscriber};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct DistinctUntilChangedSubscriber<Comparator, Destination>
+where
+	Comparator: Fn(&Destination::In, &Destination::In) -> bool,
+	Destination: Subscriber,
+	Destination::In: Clone,
+{
+	#[destination]
+	destination: Destination,
+	comparator: Comparator,
+	last: Option<Destination::In>,
+}
+
+impl<Comparator, Destination> DistinctUntilChangedSubscriber<Comparator, Destination>
+where
+	Comparator: Fn(&Destination::In, &Destination::In) -> bool,
+	Destination: Subscriber,
+	Destination::In: Clone,
+{
+	pub fn new(destination: Destination, comparator: Comparator) -> Self {
+		Self {
+			destination,
+			comparator,
+			last: None,
+		}
+	}
+}
+
+impl<Comparator, Destination> RxObserver for DistinctUntilChangedSubscriber<Comparator, Destination>
+where
+	Comparator: Fn(&Destination::In, &Destination::In) -> bool + Send + Sync,
+	Destination: Subscriber,
+	Destination::In: Clone,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		if let Some(last) = self.last.as_ref()
+			&& (self.comparator)(last, &next)
+		{
+			return;
+		}
+
+		self.last = Some(next.clone());
+		self.destination.next(next);
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.destination.error(error);
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.destination.complete();
+	}
+}
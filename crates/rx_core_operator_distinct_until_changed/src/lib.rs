@@ -0,0 +1,24 @@
+mod distinct_until_changed_operator;
+mod distinct_until_changed_subscriber;
+
+pub use distinct_until_changed_subscriber::*;
+
+pub mod operator {
+	pub use super::distinct_until_changed_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod distinct_until_changed_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::distinct_until_changed_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod distinct_until_changed_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::distinct_until_changed_extension_pipe::*;
+}
@@ -0,0 +1,31 @@
+use rx_core_common::ComposableOperator;
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::DistinctUntilKeyChangedOperator;
+
+pub trait OperatorComposeExtensionDistinctUntilKeyChanged: ComposableOperator + Sized {
+	/// # [DistinctUntilKeyChangedOperator]
+	///
+	/// Only emits upstream values whose key, as returned by `key_fn`, is
+	/// different from the key of the previously emitted value. The first value
+	/// is always emitted.
+	///
+	/// Only the key of the last value is stored, the values themselves are not
+	/// cloned.
+	#[inline]
+	fn distinct_until_key_changed<KeyFn, Key>(
+		self,
+		key_fn: KeyFn,
+	) -> CompositeOperator<
+		Self,
+		DistinctUntilKeyChangedOperator<Self::Out, Self::OutError, KeyFn, Key>,
+	>
+	where
+		KeyFn: 'static + Fn(&Self::Out) -> Key + Clone + Send + Sync,
+		Key: 'static + PartialEq + Send + Sync,
+	{
+		self.compose_with(DistinctUntilKeyChangedOperator::new(key_fn))
+	}
+}
+
+impl<Op> OperatorComposeExtensionDistinctUntilKeyChanged for Op where Op: ComposableOperator {}
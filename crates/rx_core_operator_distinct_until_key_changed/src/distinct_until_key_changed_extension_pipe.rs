@@ -0,0 +1,34 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::DistinctUntilKeyChangedOperator;
+
+pub trait ObservablePipeExtensionDistinctUntilKeyChanged<'o>:
+	'o + Observable + Sized + Send + Sync
+{
+	/// # [DistinctUntilKeyChangedOperator]
+	///
+	/// Only emits upstream values whose key, as returned by `key_fn`, is
+	/// different from the key of the previously emitted value. The first value
+	/// is always emitted.
+	///
+	/// Only the key of the last value is stored, the values themselves are not
+	/// cloned.
+	#[inline]
+	fn distinct_until_key_changed<KeyFn, Key>(
+		self,
+		key_fn: KeyFn,
+	) -> <DistinctUntilKeyChangedOperator<Self::Out, Self::OutError, KeyFn, Key> as Operator<'o>>::OutObservable<
+		Self,
+	>
+	where
+		KeyFn: 'static + Fn(&Self::Out) -> Key + Clone + Send + Sync,
+		Key: 'static + PartialEq + Send + Sync,
+	{
+		DistinctUntilKeyChangedOperator::new(key_fn).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionDistinctUntilKeyChanged<'o> for O where
+	O: 'o + Observable + Send + Sync
+{
+}
@@ -0,0 +1,72 @@
+use core::marker::PhantomData;
+
+use derive_where::derive_where;
+use rx_core_common::{ComposableOperator, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::DistinctUntilKeyChangedSubscriber;
+
+/// # [DistinctUntilKeyChangedOperator]
+///
+/// Only emits upstream values whose key, as returned by `key_fn`, is
+/// different from the key of the previously emitted value. The first value
+/// is always emitted.
+///
+/// Only the key of the last value is stored, the values themselves are not
+/// cloned.
+#[derive_where(Clone)]
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct DistinctUntilKeyChangedOperator<In, InError, KeyFn, Key>
+where
+	In: Signal,
+	InError: Signal,
+	KeyFn: 'static + Fn(&In) -> Key + Clone + Send + Sync,
+	Key: 'static + PartialEq + Send + Sync,
+{
+	key_fn: KeyFn,
+	_phantom_data: PhantomInvariant<(In, InError, Key)>,
+}
+
+impl<In, InError, KeyFn, Key> DistinctUntilKeyChangedOperator<In, InError, KeyFn, Key>
+where
+	In: Signal,
+	InError: Signal,
+	KeyFn: 'static + Fn(&In) -> Key + Clone + Send + Sync,
+	Key: 'static + PartialEq + Send + Sync,
+{
+	pub fn new(key_fn: KeyFn) -> Self {
+		Self {
+			key_fn,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, KeyFn, Key> ComposableOperator
+	for DistinctUntilKeyChangedOperator<In, InError, KeyFn, Key>
+where
+	In: Signal,
+	InError: Signal,
+	KeyFn: 'static + Fn(&In) -> Key + Clone + Send + Sync,
+	Key: 'static + PartialEq + Send + Sync,
+{
+	type Subscriber<Destination>
+		= DistinctUntilKeyChangedSubscriber<KeyFn, Key, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		DistinctUntilKeyChangedSubscriber::new(destination, self.key_fn.clone())
+	}
+}
@@ -0,0 +1,64 @@
+use rx_core_common::{RxObserver, Subscriber};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct DistinctUntilKeyChangedSubscriber<KeyFn, Key, Destination>
+where
+	KeyFn: Fn(&Destination::In) -> Key,
+	Key: PartialEq,
+	Destination: Subscriber,
+{
+	#[destination]
+	destination: Destination,
+	key_fn: KeyFn,
+	last_key: Option<Key>,
+}
+
+impl<KeyFn, Key, Destination> DistinctUntilKeyChangedSubscriber<KeyFn, Key, Destination>
+where
+	KeyFn: Fn(&Destination::In) -> Key,
+	Key: PartialEq,
+	Destination: Subscriber,
+{
+	pub fn new(destination: Destination, key_fn: KeyFn) -> Self {
+		Self {
+			destination,
+			key_fn,
+			last_key: None,
+		}
+	}
+}
+
+impl<KeyFn, Key, Destination> RxObserver
+	for DistinctUntilKeyChangedSubscriber<KeyFn, Key, Destination>
+where
+	KeyFn: Fn(&Destination::In) -> Key + Send + Sync,
+	Key: PartialEq + Send + Sync,
+	Destination: Subscriber,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		let key = (self.key_fn)(&next);
+
+		if self.last_key.as_ref() == Some(&key) {
+			return;
+		}
+
+		self.last_key = Some(key);
+		self.destination.next(next);
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.destination.error(error);
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.destination.complete();
+	}
+}
@@ -0,0 +1,141 @@
+use std::{
+	hash::Hash,
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, PhantomInvariant, RxObserver, SharedSubscriber, Signal, Subscriber,
+	SubscriptionData, SubscriptionLike, Teardown, TeardownCollection,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::{group_by_state::GroupByState, operator::GroupedObservable};
+
+/// Expires its group on the first `next` of the duration observable.
+#[derive(RxSubscriber)]
+#[rx_in(DurationOut)]
+#[rx_in_error(InError)]
+pub struct GroupByDurationSubscriber<DurationOut, Key, Element, InError, Destination>
+where
+	DurationOut: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+	Destination:
+		'static + Subscriber<In = GroupedObservable<Key, Element, InError>, InError = InError>,
+{
+	teardown: SubscriptionData,
+	key: Key,
+	group_id: usize,
+	state: Arc<Mutex<GroupByState<Key, Element, InError>>>,
+	destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<DurationOut>,
+}
+
+impl<DurationOut, Key, Element, InError, Destination>
+	GroupByDurationSubscriber<DurationOut, Key, Element, InError, Destination>
+where
+	DurationOut: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+	Destination:
+		'static + Subscriber<In = GroupedObservable<Key, Element, InError>, InError = InError>,
+{
+	pub(crate) fn new(
+		key: Key,
+		group_id: usize,
+		state: Arc<Mutex<GroupByState<Key, Element, InError>>>,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		Self {
+			teardown: SubscriptionData::default(),
+			key,
+			group_id,
+			state,
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<DurationOut, Key, Element, InError, Destination> RxObserver
+	for GroupByDurationSubscriber<DurationOut, Key, Element, InError, Destination>
+where
+	DurationOut: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+	Destination:
+		'static + Subscriber<In = GroupedObservable<Key, Element, InError>, InError = InError>,
+{
+	fn next(&mut self, _next: Self::In) {
+		if !self.is_closed() {
+			let group = self
+				.state
+				.lock_ignore_poison()
+				.take_group(&self.key, self.group_id);
+			self.unsubscribe();
+			if let Some(group) = group {
+				group.complete();
+			}
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		if !self.is_closed() {
+			let groups = self.state.lock_ignore_poison().take_groups();
+			for group in groups {
+				group.error(error.clone());
+			}
+			self.destination.error(error);
+			self.unsubscribe();
+		}
+	}
+
+	/// Only a `next` expires the group, a duration observable completing
+	/// without one leaves it open until upstream finishes.
+	fn complete(&mut self) {
+		self.unsubscribe();
+	}
+}
+
+impl<DurationOut, Key, Element, InError, Destination> TeardownCollection
+	for GroupByDurationSubscriber<DurationOut, Key, Element, InError, Destination>
+where
+	DurationOut: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+	Destination:
+		'static + Subscriber<In = GroupedObservable<Key, Element, InError>, InError = InError>,
+{
+	#[inline]
+	fn add_teardown(&mut self, teardown: Teardown) {
+		self.teardown.add_teardown(teardown);
+	}
+}
+
+impl<DurationOut, Key, Element, InError, Destination> SubscriptionLike
+	for GroupByDurationSubscriber<DurationOut, Key, Element, InError, Destination>
+where
+	DurationOut: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+	Destination:
+		'static + Subscriber<In = GroupedObservable<Key, Element, InError>, InError = InError>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.teardown.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.is_closed() {
+			self.teardown.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,72 @@
+use std::hash::Hash;
+
+use rx_core_common::{ComposableOperator, Signal};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::{GroupByOperator, GroupDuration, NoGroupDuration};
+
+pub trait OperatorComposeExtensionGroupBy: ComposableOperator + Sized {
+	#[inline]
+	fn group_by<KeySelector, Key>(
+		self,
+		key_selector: KeySelector,
+	) -> CompositeOperator<
+		Self,
+		GroupByOperator<
+			KeySelector,
+			fn(Self::Out) -> Self::Out,
+			NoGroupDuration,
+			Self::Out,
+			Key,
+			Self::Out,
+			Self::OutError,
+		>,
+	>
+	where
+		KeySelector: 'static + Fn(&Self::Out) -> Key + Clone + Send + Sync,
+		Key: Signal + Clone + Eq + Hash,
+		Self::Out: Clone,
+		Self::OutError: Clone,
+	{
+		self.compose_with(GroupByOperator::new(
+			key_selector,
+			core::convert::identity as fn(Self::Out) -> Self::Out,
+			NoGroupDuration,
+		))
+	}
+
+	#[inline]
+	fn group_by_with<KeySelector, Key, ElementSelector, Element, Duration>(
+		self,
+		key_selector: KeySelector,
+		element_selector: ElementSelector,
+		duration: Duration,
+	) -> CompositeOperator<
+		Self,
+		GroupByOperator<
+			KeySelector,
+			ElementSelector,
+			Duration,
+			Self::Out,
+			Key,
+			Element,
+			Self::OutError,
+		>,
+	>
+	where
+		KeySelector: 'static + Fn(&Self::Out) -> Key + Clone + Send + Sync,
+		ElementSelector: 'static + FnMut(Self::Out) -> Element + Clone + Send + Sync,
+		Duration: GroupDuration<Key, Element, Self::OutError> + Clone,
+		Key: Signal + Clone + Eq + Hash,
+		Element: Signal + Clone,
+		Self::OutError: Clone,
+	{
+		self.compose_with(GroupByOperator::new(
+			key_selector,
+			element_selector,
+			duration,
+		))
+	}
+}
+
+impl<Op> OperatorComposeExtensionGroupBy for Op where Op: ComposableOperator {}
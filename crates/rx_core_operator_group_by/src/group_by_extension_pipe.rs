@@ -0,0 +1,82 @@
+use std::hash::Hash;
+
+use rx_core_common::{Observable, Operator, Signal};
+
+use crate::operator::{GroupByOperator, GroupDuration, NoGroupDuration};
+
+pub trait ObservablePipeExtensionGroupBy<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [GroupByOperator]
+	///
+	/// Splits upstream into groups by the key `key_selector` returns for each
+	/// value. Each group is a `GroupedObservable` that knows its key, use a
+	/// higher-order operator like `merge_map` to process them.
+	///
+	/// Groups stay open until upstream finishes, use
+	/// [group_by_with][ObservablePipeExtensionGroupBy::group_by_with] to
+	/// expire them.
+	#[inline]
+	fn group_by<KeySelector, Key>(
+		self,
+		key_selector: KeySelector,
+	) -> <GroupByOperator<
+		KeySelector,
+		fn(Self::Out) -> Self::Out,
+		NoGroupDuration,
+		Self::Out,
+		Key,
+		Self::Out,
+		Self::OutError,
+	> as Operator<'o>>::OutObservable<Self>
+	where
+		KeySelector: 'static + Fn(&Self::Out) -> Key + Clone + Send + Sync,
+		Key: Signal + Clone + Eq + Hash,
+		Self::Out: Clone,
+		Self::OutError: Clone,
+	{
+		GroupByOperator::new(
+			key_selector,
+			core::convert::identity as fn(Self::Out) -> Self::Out,
+			NoGroupDuration,
+		)
+		.operate(self)
+	}
+
+	/// # [GroupByOperator]
+	///
+	/// Splits upstream into groups by the key `key_selector` returns for each
+	/// value, and sends the values into their groups mapped by
+	/// `element_selector`.
+	///
+	/// ## Arguments
+	///
+	/// - `duration`: Either `NoGroupDuration`, or a closure that returns an
+	///   observable for each new group, which expires the group on its first
+	///   `next`. The next value with the same key then opens a new group.
+	#[inline]
+	fn group_by_with<KeySelector, Key, ElementSelector, Element, Duration>(
+		self,
+		key_selector: KeySelector,
+		element_selector: ElementSelector,
+		duration: Duration,
+	) -> <GroupByOperator<
+		KeySelector,
+		ElementSelector,
+		Duration,
+		Self::Out,
+		Key,
+		Element,
+		Self::OutError,
+	> as Operator<'o>>::OutObservable<Self>
+	where
+		KeySelector: 'static + Fn(&Self::Out) -> Key + Clone + Send + Sync,
+		ElementSelector: 'static + FnMut(Self::Out) -> Element + Clone + Send + Sync,
+		Duration: GroupDuration<Key, Element, Self::OutError> + Clone,
+		Key: Signal + Clone + Eq + Hash,
+		Element: Signal + Clone,
+		Self::OutError: Clone,
+	{
+		GroupByOperator::new(key_selector, element_selector, duration).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionGroupBy<'o> for O where O: 'o + Observable + Send + Sync {}
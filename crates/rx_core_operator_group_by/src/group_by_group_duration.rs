@@ -0,0 +1,69 @@
+use rx_core_common::{Observable, Signal};
+
+use crate::operator::GroupedObservable;
+
+/// Decides how long a group of the `group_by` operator stays open.
+///
+/// Implemented for [NoGroupDuration] and for closures that return an
+/// observable for each new group. The group expires on the first `next` of
+/// this observable, and a new group is opened for the next value with the
+/// same key.
+pub trait GroupDuration<Key, Element, InError>: 'static + Send + Sync
+where
+	Key: Signal + Clone,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	type DurationObservable: 'static + Observable<OutError = InError> + Send + Sync;
+
+	/// Returns `None` when the group should never expire.
+	fn select_duration(
+		&mut self,
+		group: &GroupedObservable<Key, Element, InError>,
+	) -> Option<Self::DurationObservable>;
+}
+
+/// Groups stay open until upstream finishes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoGroupDuration;
+
+impl<Key, Element, InError> GroupDuration<Key, Element, InError> for NoGroupDuration
+where
+	Key: Signal + Clone,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	/// Never actually selected.
+	type DurationObservable = GroupedObservable<Key, Element, InError>;
+
+	#[inline]
+	fn select_duration(
+		&mut self,
+		_group: &GroupedObservable<Key, Element, InError>,
+	) -> Option<Self::DurationObservable> {
+		None
+	}
+}
+
+impl<DurationSelector, DurationObservable, Key, Element, InError>
+	GroupDuration<Key, Element, InError> for DurationSelector
+where
+	DurationSelector: 'static
+		+ FnMut(GroupedObservable<Key, Element, InError>) -> DurationObservable
+		+ Send
+		+ Sync,
+	DurationObservable: 'static + Observable<OutError = InError> + Send + Sync,
+	Key: Signal + Clone,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	type DurationObservable = DurationObservable;
+
+	#[inline]
+	fn select_duration(
+		&mut self,
+		group: &GroupedObservable<Key, Element, InError>,
+	) -> Option<Self::DurationObservable> {
+		Some((self)(group.clone()))
+	}
+}
@@ -0,0 +1,119 @@
+use core::marker::PhantomData;
+use std::hash::Hash;
+
+use rx_core_common::{ComposableOperator, Never, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::{
+	GroupBySubscriber,
+	operator::{GroupDuration, GroupedObservable},
+};
+
+/// # [GroupByOperator]
+///
+/// Splits upstream into groups by the key `key_selector` returns for each
+/// value. Each group is a [GroupedObservable] emitted downstream the moment
+/// the first value of its key arrives, right before that value.
+///
+/// - `element_selector` maps the values before they are sent into their group.
+/// - `duration` decides when a group expires, see [GroupDuration]. After a
+///   group expires, the next value with the same key opens a new group.
+/// - Upstream completion completes every open group.
+/// - Errors, from upstream or from a duration observable, are forwarded to
+///   every open group and downstream.
+/// - Unsubscribing unsubscribes every open group along with everyone
+///   subscribed to them.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(GroupedObservable<Key, Element, InError>)]
+#[rx_out_error(InError)]
+pub struct GroupByOperator<
+	KeySelector,
+	ElementSelector,
+	Duration,
+	In,
+	Key,
+	Element,
+	InError = Never,
+> where
+	KeySelector: 'static + Fn(&In) -> Key + Clone + Send + Sync,
+	ElementSelector: 'static + FnMut(In) -> Element + Clone + Send + Sync,
+	Duration: GroupDuration<Key, Element, InError> + Clone,
+	In: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	key_selector: KeySelector,
+	element_selector: ElementSelector,
+	duration: Duration,
+	_phantom_data: PhantomInvariant<(In, Key, Element, InError)>,
+}
+
+impl<KeySelector, ElementSelector, Duration, In, Key, Element, InError>
+	GroupByOperator<KeySelector, ElementSelector, Duration, In, Key, Element, InError>
+where
+	KeySelector: 'static + Fn(&In) -> Key + Clone + Send + Sync,
+	ElementSelector: 'static + FnMut(In) -> Element + Clone + Send + Sync,
+	Duration: GroupDuration<Key, Element, InError> + Clone,
+	In: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	pub fn new(
+		key_selector: KeySelector,
+		element_selector: ElementSelector,
+		duration: Duration,
+	) -> Self {
+		Self {
+			key_selector,
+			element_selector,
+			duration,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<KeySelector, ElementSelector, Duration, In, Key, Element, InError> ComposableOperator
+	for GroupByOperator<KeySelector, ElementSelector, Duration, In, Key, Element, InError>
+where
+	KeySelector: 'static + Fn(&In) -> Key + Clone + Send + Sync,
+	ElementSelector: 'static + FnMut(In) -> Element + Clone + Send + Sync,
+	Duration: GroupDuration<Key, Element, InError> + Clone,
+	In: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	type Subscriber<Destination>
+		= GroupBySubscriber<
+		KeySelector,
+		ElementSelector,
+		Duration,
+		In,
+		Key,
+		Element,
+		InError,
+		Destination,
+	>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		GroupBySubscriber::new(
+			destination,
+			self.key_selector.clone(),
+			self.element_selector.clone(),
+			self.duration.clone(),
+		)
+	}
+}
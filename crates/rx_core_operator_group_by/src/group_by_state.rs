@@ -0,0 +1,133 @@
+use std::{collections::HashMap, hash::Hash};
+
+use derive_where::derive_where;
+use rx_core_common::{Signal, SubscriptionData, SubscriptionLike};
+
+use crate::operator::GroupedObservable;
+
+pub(crate) struct GroupByGroup<Key, Element, InError>
+where
+	Key: Signal + Clone,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	id: usize,
+	group: GroupedObservable<Key, Element, InError>,
+	duration_subscription: Option<SubscriptionData>,
+}
+
+impl<Key, Element, InError> GroupByGroup<Key, Element, InError>
+where
+	Key: Signal + Clone,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	fn take_group(mut self) -> GroupedObservable<Key, Element, InError> {
+		if let Some(mut duration_subscription) = self.duration_subscription.take() {
+			duration_subscription.unsubscribe();
+		}
+		self.group
+	}
+
+	pub(crate) fn complete(self) {
+		self.take_group().complete();
+	}
+
+	pub(crate) fn error(self, error: InError) {
+		self.take_group().error(error);
+	}
+
+	pub(crate) fn unsubscribe(self) {
+		self.take_group().unsubscribe();
+	}
+}
+
+/// The groups currently open, by key.
+#[derive_where(Default)]
+pub(crate) struct GroupByState<Key, Element, InError>
+where
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	next_group_id: usize,
+	groups: HashMap<Key, GroupByGroup<Key, Element, InError>>,
+}
+
+impl<Key, Element, InError> GroupByState<Key, Element, InError>
+where
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	/// Returns the open group of the key, or `None` if there isn't one.
+	pub(crate) fn get_group(&self, key: &Key) -> Option<GroupedObservable<Key, Element, InError>> {
+		self.groups
+			.get(key)
+			.map(|group| group.group.clone())
+			.filter(|group| !group.is_closed())
+	}
+
+	/// Replaces any closed group that was left behind under the same key.
+	pub(crate) fn open_group(
+		&mut self,
+		key: Key,
+	) -> (usize, GroupedObservable<Key, Element, InError>) {
+		let id = self.next_group_id;
+		self.next_group_id += 1;
+
+		let group = GroupedObservable::new(key.clone());
+		let replaced = self.groups.insert(
+			key,
+			GroupByGroup {
+				id,
+				group: group.clone(),
+				duration_subscription: None,
+			},
+		);
+		if let Some(replaced) = replaced {
+			replaced.unsubscribe();
+		}
+
+		(id, group)
+	}
+
+	/// Gives the subscription back if the group had already expired.
+	pub(crate) fn attach_duration_subscription(
+		&mut self,
+		key: &Key,
+		id: usize,
+		duration_subscription: SubscriptionData,
+	) -> Option<SubscriptionData> {
+		match self.groups.get_mut(key).filter(|group| group.id == id) {
+			Some(group) => {
+				group.duration_subscription = Some(duration_subscription);
+				None
+			}
+			None => Some(duration_subscription),
+		}
+	}
+
+	pub(crate) fn take_group(
+		&mut self,
+		key: &Key,
+		id: usize,
+	) -> Option<GroupByGroup<Key, Element, InError>> {
+		if self.groups.get(key).is_some_and(|group| group.id == id) {
+			self.groups.remove(key)
+		} else {
+			None
+		}
+	}
+
+	/// In the order they were opened.
+	pub(crate) fn take_groups(&mut self) -> Vec<GroupByGroup<Key, Element, InError>> {
+		let mut groups = self
+			.groups
+			.drain()
+			.map(|(_, group)| group)
+			.collect::<Vec<_>>();
+		groups.sort_by_key(|group| group.id);
+		groups
+	}
+}
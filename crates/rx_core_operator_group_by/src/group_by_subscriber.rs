@@ -0,0 +1,206 @@
+use std::{
+	hash::Hash,
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, ObservableOutput, PhantomInvariant, RxObserver,
+	SharedSubscriber, Signal, Subscriber, SubscriptionData, SubscriptionLike,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::{
+	GroupByDurationSubscriber,
+	group_by_state::GroupByState,
+	operator::{GroupDuration, GroupedObservable},
+};
+
+#[derive(RxSubscriber)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_delegate_teardown_collection]
+pub struct GroupBySubscriber<
+	KeySelector,
+	ElementSelector,
+	Duration,
+	In,
+	Key,
+	Element,
+	InError,
+	Destination,
+> where
+	KeySelector: 'static + Fn(&In) -> Key + Send + Sync,
+	ElementSelector: 'static + FnMut(In) -> Element + Send + Sync,
+	Duration: GroupDuration<Key, Element, InError>,
+	In: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+	Destination:
+		'static + Subscriber<In = GroupedObservable<Key, Element, InError>, InError = InError>,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	key_selector: KeySelector,
+	element_selector: ElementSelector,
+	duration: Duration,
+	state: Arc<Mutex<GroupByState<Key, Element, InError>>>,
+	_phantom_data: PhantomInvariant<In>,
+}
+
+impl<KeySelector, ElementSelector, Duration, In, Key, Element, InError, Destination>
+	GroupBySubscriber<KeySelector, ElementSelector, Duration, In, Key, Element, InError, Destination>
+where
+	KeySelector: 'static + Fn(&In) -> Key + Send + Sync,
+	ElementSelector: 'static + FnMut(In) -> Element + Send + Sync,
+	Duration: GroupDuration<Key, Element, InError>,
+	In: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+	Destination:
+		'static + Subscriber<In = GroupedObservable<Key, Element, InError>, InError = InError>,
+{
+	pub fn new(
+		destination: Destination,
+		key_selector: KeySelector,
+		element_selector: ElementSelector,
+		duration: Duration,
+	) -> Self {
+		Self {
+			destination: SharedSubscriber::new(destination),
+			key_selector,
+			element_selector,
+			duration,
+			state: Arc::new(Mutex::new(GroupByState::default())),
+			_phantom_data: PhantomData,
+		}
+	}
+
+	/// Opens a new group, emits it, then subscribes to its duration.
+	fn open_group(&mut self, key: Key) -> GroupedObservable<Key, Element, InError> {
+		let (group_id, group) = self.state.lock_ignore_poison().open_group(key.clone());
+		self.destination.next(group.clone());
+
+		if let Some(mut duration_observable) = self.duration.select_duration(&group) {
+			let duration_subscription = duration_observable.subscribe(GroupByDurationSubscriber::<
+				<Duration::DurationObservable as ObservableOutput>::Out,
+				Key,
+				Element,
+				InError,
+				Destination,
+			>::new(
+				key.clone(),
+				group_id,
+				self.state.clone(),
+				self.destination.clone(),
+			));
+
+			if !duration_subscription.is_closed() {
+				let duration_subscription =
+					SubscriptionData::new_with_teardown(duration_subscription.into());
+				let already_expired = self
+					.state
+					.lock_ignore_poison()
+					.attach_duration_subscription(&key, group_id, duration_subscription);
+				if let Some(mut already_expired) = already_expired {
+					already_expired.unsubscribe();
+				}
+			}
+		}
+
+		group
+	}
+}
+
+impl<KeySelector, ElementSelector, Duration, In, Key, Element, InError, Destination> RxObserver
+	for GroupBySubscriber<
+		KeySelector,
+		ElementSelector,
+		Duration,
+		In,
+		Key,
+		Element,
+		InError,
+		Destination,
+	>
+where
+	KeySelector: 'static + Fn(&In) -> Key + Send + Sync,
+	ElementSelector: 'static + FnMut(In) -> Element + Send + Sync,
+	Duration: GroupDuration<Key, Element, InError>,
+	In: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+	Destination:
+		'static + Subscriber<In = GroupedObservable<Key, Element, InError>, InError = InError>,
+{
+	fn next(&mut self, next: Self::In) {
+		let key = (self.key_selector)(&next);
+		let element = (self.element_selector)(next);
+
+		let existing_group = self.state.lock_ignore_poison().get_group(&key);
+		let mut group = match existing_group {
+			Some(group) => group,
+			None => self.open_group(key),
+		};
+
+		group.next(element);
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		let groups = self.state.lock_ignore_poison().take_groups();
+		for group in groups {
+			group.error(error.clone());
+		}
+		self.destination.error(error);
+	}
+
+	fn complete(&mut self) {
+		let groups = self.state.lock_ignore_poison().take_groups();
+		for group in groups {
+			group.complete();
+		}
+		self.destination.complete();
+	}
+}
+
+impl<KeySelector, ElementSelector, Duration, In, Key, Element, InError, Destination>
+	SubscriptionLike
+	for GroupBySubscriber<
+		KeySelector,
+		ElementSelector,
+		Duration,
+		In,
+		Key,
+		Element,
+		InError,
+		Destination,
+	>
+where
+	KeySelector: 'static + Fn(&In) -> Key + Send + Sync,
+	ElementSelector: 'static + FnMut(In) -> Element + Send + Sync,
+	Duration: GroupDuration<Key, Element, InError>,
+	In: Signal,
+	Key: Signal + Clone + Eq + Hash,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+	Destination:
+		'static + Subscriber<In = GroupedObservable<Key, Element, InError>, InError = InError>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		let groups = self.state.lock_ignore_poison().take_groups();
+		for group in groups {
+			group.unsubscribe();
+		}
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,92 @@
+use derive_where::derive_where;
+use rx_core_common::{
+	Never, Observable, RxObserver, Signal, Subscriber, SubscriptionLike, UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+use rx_core_subject_publish::{internal::MulticastSubscription, subject::PublishSubject};
+
+/// # [GroupedObservable]
+///
+/// A group of upstream values that share the same key, emitted by the
+/// `group_by` operator.
+///
+/// Every subscriber of a group shares the same source, they only observe the
+/// values that arrive after they subscribed.
+#[derive_where(Clone, Debug; Key)]
+#[derive(RxObservable)]
+#[rx_out(Element)]
+#[rx_out_error(InError)]
+pub struct GroupedObservable<Key, Element, InError = Never>
+where
+	Key: Signal + Clone,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	key: Key,
+	#[derive_where(skip(Debug))]
+	subject: PublishSubject<Element, InError>,
+}
+
+impl<Key, Element, InError> GroupedObservable<Key, Element, InError>
+where
+	Key: Signal + Clone,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	pub(crate) fn new(key: Key) -> Self {
+		Self {
+			key,
+			subject: PublishSubject::default(),
+		}
+	}
+
+	pub fn get_key(&self) -> &Key {
+		&self.key
+	}
+
+	/// A group is closed once it had expired, or when upstream had finished.
+	#[inline]
+	pub fn is_closed(&self) -> bool {
+		self.subject.is_closed()
+	}
+
+	pub(crate) fn next(&mut self, next: Element) {
+		self.subject.next(next);
+	}
+
+	pub(crate) fn error(&mut self, error: InError) {
+		self.subject.error(error);
+	}
+
+	pub(crate) fn complete(&mut self) {
+		self.subject.complete();
+	}
+
+	pub(crate) fn unsubscribe(&mut self) {
+		self.subject.unsubscribe();
+	}
+}
+
+impl<Key, Element, InError> Observable for GroupedObservable<Key, Element, InError>
+where
+	Key: Signal + Clone,
+	Element: Signal + Clone,
+	InError: Signal + Clone,
+{
+	type Subscription<Destination>
+		= MulticastSubscription<Element, InError>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	#[inline]
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		self.subject.subscribe(destination)
+	}
+}
@@ -0,0 +1,31 @@
+mod group_by_duration_subscriber;
+mod group_by_group_duration;
+mod group_by_operator;
+mod group_by_state;
+mod group_by_subscriber;
+mod grouped_observable;
+
+pub use group_by_duration_subscriber::*;
+pub use group_by_subscriber::*;
+
+pub mod operator {
+	pub use super::group_by_group_duration::*;
+	pub use super::group_by_operator::*;
+	pub use super::grouped_observable::*;
+}
+
+#[cfg(feature = "compose")]
+mod group_by_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::group_by_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod group_by_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::group_by_extension_pipe::*;
+}
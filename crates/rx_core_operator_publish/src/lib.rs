@@ -0,0 +1,13 @@
+mod publish_operator;
+
+pub mod operator {
+	pub use super::publish_operator::*;
+}
+
+#[cfg(feature = "pipe")]
+mod publish_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::publish_extension_pipe::*;
+}
@@ -0,0 +1,77 @@
+use rx_core_common::{Observable, Operator, ProvideWithDefault};
+use rx_core_subject_async::subject::AsyncSubject;
+use rx_core_subject_behavior::subject::BehaviorSubjectProvider;
+use rx_core_subject_publish::subject::PublishSubject;
+use rx_core_subject_replay::subject::ReplayBufferSubjectProvider;
+
+use crate::operator::PublishOperator;
+
+pub trait ObservablePipeExtensionPublish<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [PublishOperator]
+	///
+	/// Turns the source into a `ConnectableObservable` with a `PublishSubject`
+	/// connector: subscribers only observe values emitted after they
+	/// subscribed, once `connect` is called.
+	#[inline]
+	fn publish(
+		self,
+	) -> <PublishOperator<ProvideWithDefault<PublishSubject<Self::Out, Self::OutError>>> as Operator<'o>>::OutObservable<Self>
+	where
+		Self::Out: Clone,
+		Self::OutError: Clone,
+	{
+		PublishOperator::new(ProvideWithDefault::default()).operate(self)
+	}
+
+	/// # [PublishOperator]
+	///
+	/// Turns the source into a `ConnectableObservable` with a `BehaviorSubject`
+	/// connector: subscribers immediately observe the last value, or
+	/// `initial_value` when the source hasn't emitted yet.
+	#[inline]
+	fn publish_behavior(
+		self,
+		initial_value: Self::Out,
+	) -> <PublishOperator<BehaviorSubjectProvider<Self::Out, Self::OutError>> as Operator<'o>>::OutObservable<Self>
+	where
+		Self::Out: Clone,
+		Self::OutError: Clone,
+	{
+		PublishOperator::new(BehaviorSubjectProvider::new(initial_value)).operate(self)
+	}
+
+	/// # [PublishOperator]
+	///
+	/// Turns the source into a `ConnectableObservable` with a
+	/// `ReplayBufferSubject` connector: subscribers immediately observe the
+	/// last `capacity` values, all of them when `None`.
+	#[inline]
+	fn publish_replay(
+		self,
+		capacity: Option<usize>,
+	) -> <PublishOperator<ReplayBufferSubjectProvider<Self::Out, Self::OutError>> as Operator<'o>>::OutObservable<Self>
+	where
+		Self::Out: Clone,
+		Self::OutError: Clone,
+	{
+		PublishOperator::new(ReplayBufferSubjectProvider::new(capacity)).operate(self)
+	}
+
+	/// # [PublishOperator]
+	///
+	/// Turns the source into a `ConnectableObservable` with an `AsyncSubject`
+	/// connector: subscribers only observe the last value, once the source
+	/// completes.
+	#[inline]
+	fn publish_last(
+		self,
+	) -> <PublishOperator<ProvideWithDefault<AsyncSubject<Self::Out, Self::OutError>>> as Operator<'o>>::OutObservable<Self>
+	where
+		Self::Out: Clone,
+		Self::OutError: Clone,
+	{
+		PublishOperator::new(ProvideWithDefault::default()).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionPublish<'o> for O where O: 'o + Observable + Send + Sync {}
@@ -0,0 +1,67 @@
+use rx_core_common::{
+	Observable, ObservableOutput, ObserverInput, Operator, Provider, SubjectLike,
+};
+use rx_core_macro_operator_derive::RxOperator;
+use rx_core_observable_connectable::observable::{ConnectableObservable, ConnectableOptions};
+
+/// # [PublishOperator]
+///
+/// Turns the source into a [ConnectableObservable], multicasting it through
+/// the connector subject provided by `ConnectorProvider`.
+///
+/// Unlike `share`, nothing happens until `connect` is called on the resulting
+/// observable, and the connection is kept alive until it is disconnected,
+/// regardless of how many subscribers there are. The connector subject is
+/// never reset, late subscribers of a finished connection observe what the
+/// connector replays to them.
+///
+/// The connector decides what subscribers observe:
+///
+/// - `publish`: a `PublishSubject`, only values emitted after subscribing.
+/// - `publish_behavior`: a `BehaviorSubject`, the last value (or the initial
+///   value) first.
+/// - `publish_replay`: a `ReplayBufferSubject`, the buffered values first.
+/// - `publish_last`: an `AsyncSubject`, only the last value, on completion.
+#[derive(RxOperator, Clone, Default)]
+#[rx_in(<ConnectorProvider::Provided as ObserverInput>::In)]
+#[rx_in_error(<ConnectorProvider::Provided as ObserverInput>::InError)]
+#[rx_out(<ConnectorProvider::Provided as ObservableOutput>::Out)]
+#[rx_out_error(<ConnectorProvider::Provided as ObservableOutput>::OutError)]
+pub struct PublishOperator<ConnectorProvider>
+where
+	ConnectorProvider: 'static + Provider,
+	ConnectorProvider::Provided: SubjectLike + Clone,
+{
+	connector_provider: ConnectorProvider,
+}
+
+impl<ConnectorProvider> PublishOperator<ConnectorProvider>
+where
+	ConnectorProvider: 'static + Provider,
+	ConnectorProvider::Provided: SubjectLike + Clone,
+{
+	pub fn new(connector_provider: ConnectorProvider) -> Self {
+		Self { connector_provider }
+	}
+}
+
+impl<'o, ConnectorProvider> Operator<'o> for PublishOperator<ConnectorProvider>
+where
+	ConnectorProvider: 'static + Provider,
+	ConnectorProvider::Provided: SubjectLike + Clone,
+	Self::In: Clone,
+	Self::InError: Clone,
+{
+	type OutObservable<InObservable>
+		= ConnectableObservable<InObservable, ConnectorProvider>
+	where
+		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync;
+
+	#[inline]
+	fn operate<InObservable>(self, source: InObservable) -> Self::OutObservable<InObservable>
+	where
+		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync,
+	{
+		ConnectableObservable::new(source, ConnectableOptions::new(self.connector_provider))
+	}
+}
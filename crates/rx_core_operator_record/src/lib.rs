@@ -0,0 +1,26 @@
+mod record_operator;
+mod record_operator_error;
+mod record_subscriber;
+
+pub use record_subscriber::*;
+
+pub mod operator {
+	pub use super::record_operator::*;
+	pub use super::record_operator_error::*;
+}
+
+#[cfg(feature = "compose")]
+mod record_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::record_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod record_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::record_extension_pipe::*;
+}
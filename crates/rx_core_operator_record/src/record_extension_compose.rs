@@ -0,0 +1,54 @@
+use std::path::PathBuf;
+
+use rx_core_common::{ComposableOperator, Scheduler, SchedulerHandle, Signal};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+use serde::Serialize;
+
+use crate::operator::RecordOperator;
+
+pub trait OperatorComposeExtensionRecord<T, S>: ComposableOperator<Out = T> + Sized
+where
+	T: Signal + Serialize,
+	S: 'static + Scheduler + Send + Sync,
+{
+	/// # [RecordOperator]
+	///
+	/// The `record` operator writes every upstream notification into a file
+	/// along with the time elapsed since the recording started, while passing
+	/// them through unchanged. Recordings can be played back with the original
+	/// timing using the `ReplayFileObservable`.
+	///
+	/// Every subscription creates (or truncates) the file at `path`. Each line
+	/// of the recording is a JSON array of `[elapsed_nanos, notification]`.
+	///
+	/// Time is measured by the scheduler, the recording starts on the first
+	/// tick after subscribing, and notifications are stamped with the time of
+	/// the latest tick.
+	///
+	/// Both the values and the errors have to be serializable. Streams that
+	/// can't error have a `Never` error type, which can't be serialized, use
+	/// `map_never` to retype it into `()` before recording.
+	///
+	/// When the file can't be created or written to, a
+	/// `RecordOperatorError::Io` error is sent downstream. Upstream errors are
+	/// recorded, then forwarded as `RecordOperatorError::Upstream`.
+	#[inline]
+	fn record(
+		self,
+		path: impl Into<PathBuf>,
+		scheduler: SchedulerHandle<S>,
+	) -> CompositeOperator<Self, RecordOperator<T, Self::OutError, S>>
+	where
+		Self::OutError: Serialize,
+	{
+		self.compose_with(RecordOperator::new(path, scheduler))
+	}
+}
+
+impl<Op, T, S> OperatorComposeExtensionRecord<T, S> for Op
+where
+	Op: ComposableOperator<Out = T>,
+	T: Signal + Serialize,
+	S: 'static + Scheduler + Send + Sync,
+{
+}
@@ -0,0 +1,85 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use rx_core_common::{
+	ComposableOperator, PhantomInvariant, Scheduler, SchedulerHandle, Signal, Subscriber,
+};
+use rx_core_macro_operator_derive::RxOperator;
+use serde::Serialize;
+
+use crate::{RecordSubscriber, operator::RecordOperatorError};
+
+/// # [RecordOperator]
+///
+/// The `record` operator writes every upstream notification into a file
+/// along with the time elapsed since the recording started, while passing
+/// them through unchanged. Recordings can be played back with the original
+/// timing using the `ReplayFileObservable`.
+///
+/// Every subscription creates (or truncates) the file at `path`. Each line
+/// of the recording is a JSON array of `[elapsed_nanos, notification]`.
+///
+/// Time is measured by the scheduler, the recording starts on the first
+/// tick after subscribing, and notifications are stamped with the time of
+/// the latest tick.
+///
+/// Both the values and the errors have to be serializable. Streams that
+/// can't error have a `Never` error type, which can't be serialized, use
+/// `map_never` to retype it into `()` before recording.
+///
+/// When the file can't be created or written to, a
+/// [RecordOperatorError::Io] error is sent downstream. Upstream errors are
+/// recorded, then forwarded as [RecordOperatorError::Upstream].
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(RecordOperatorError<InError>)]
+pub struct RecordOperator<In, InError, S>
+where
+	In: Signal + Serialize,
+	InError: Signal + Serialize,
+	S: Scheduler,
+{
+	path: PathBuf,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, S> RecordOperator<In, InError, S>
+where
+	In: Signal + Serialize,
+	InError: Signal + Serialize,
+	S: Scheduler,
+{
+	pub fn new(path: impl Into<PathBuf>, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			path: path.into(),
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, S> ComposableOperator for RecordOperator<In, InError, S>
+where
+	In: Signal + Serialize,
+	InError: Signal + Serialize,
+	S: 'static + Scheduler + Send + Sync,
+{
+	type Subscriber<Destination>
+		= RecordSubscriber<InError, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		RecordSubscriber::new(destination, &self.path, self.scheduler.clone())
+	}
+}
@@ -0,0 +1,41 @@
+use std::{io, sync::Arc};
+
+use rx_core_common::Signal;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone)]
+pub enum RecordOperatorError<InError>
+where
+	InError: Signal,
+{
+	/// The recording file could not be created or written to.
+	#[error("RecordOperatorError::Io({0})")]
+	Io(Arc<io::Error>),
+	/// A notification could not be serialized.
+	#[error("RecordOperatorError::Serialize({0})")]
+	Serialize(Arc<serde_json::Error>),
+	#[error(transparent)]
+	Upstream(InError),
+}
+
+impl<InError> From<io::Error> for RecordOperatorError<InError>
+where
+	InError: Signal,
+{
+	fn from(error: io::Error) -> Self {
+		Self::Io(Arc::new(error))
+	}
+}
+
+impl<InError> From<serde_json::Error> for RecordOperatorError<InError>
+where
+	InError: Signal,
+{
+	fn from(error: serde_json::Error) -> Self {
+		if error.is_io() {
+			Self::Io(Arc::new(error.into()))
+		} else {
+			Self::Serialize(Arc::new(error))
+		}
+	}
+}
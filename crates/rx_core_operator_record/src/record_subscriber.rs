@@ -0,0 +1,180 @@
+use std::{
+	fs::File,
+	io::{LineWriter, Write},
+	path::Path,
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, RxObserver, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension,
+	Signal, Subscriber, Teardown, WorkResult, WorkTick,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use serde::Serialize;
+
+use crate::operator::RecordOperatorError;
+
+/// Borrowing mirror of `ObserverNotification`, serialized the same way, so
+/// values don't have to be cloned to be recorded.
+#[derive(Serialize)]
+#[serde(rename = "ObserverNotification")]
+enum NotificationRef<'a, In, InError> {
+	Next(&'a In),
+	Error(&'a InError),
+	Complete,
+}
+
+#[derive(Default)]
+struct RecordClock {
+	started_at: Option<Duration>,
+	now: Duration,
+}
+
+impl RecordClock {
+	fn observe(&mut self, now: Duration) {
+		self.started_at.get_or_insert(now);
+		self.now = now;
+	}
+
+	fn elapsed(&self) -> Duration {
+		self.started_at
+			.map(|started_at| self.now.saturating_sub(started_at))
+			.unwrap_or_default()
+	}
+}
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(InError)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct RecordSubscriber<InError, Destination>
+where
+	InError: Signal + Serialize,
+	Destination: 'static + Subscriber<InError = RecordOperatorError<InError>>,
+	Destination::In: Serialize,
+{
+	#[destination]
+	destination: Destination,
+	/// `None` once the recording has ended, or if it couldn't even start.
+	writer: Option<LineWriter<File>>,
+	clock: Arc<Mutex<RecordClock>>,
+}
+
+impl<InError, Destination> RecordSubscriber<InError, Destination>
+where
+	InError: Signal + Serialize,
+	Destination: 'static + Subscriber<InError = RecordOperatorError<InError>>,
+	Destination::In: Serialize,
+{
+	pub fn new<S>(mut destination: Destination, path: &Path, scheduler: SchedulerHandle<S>) -> Self
+	where
+		S: 'static + Scheduler,
+	{
+		let clock = Arc::new(Mutex::new(RecordClock::default()));
+
+		let writer = match File::create(path) {
+			Ok(file) => {
+				let mut scheduler_lock = scheduler.lock();
+				let cancellation_id = scheduler_lock.generate_cancellation_id();
+				let clock_clone = clock.clone();
+				scheduler_lock.schedule_continuous_work(
+					move |tick, _context| {
+						clock_clone.lock_ignore_poison().observe(tick.now());
+						WorkResult::Pending
+					},
+					cancellation_id,
+				);
+				drop(scheduler_lock);
+
+				destination
+					.add_teardown(Teardown::new_work_cancellation(cancellation_id, scheduler));
+				Some(LineWriter::new(file))
+			}
+			Err(error) => {
+				destination.error(error.into());
+				None
+			}
+		};
+
+		Self {
+			destination,
+			writer,
+			clock,
+		}
+	}
+
+	fn write(
+		&mut self,
+		notification: NotificationRef<'_, Destination::In, InError>,
+	) -> Result<(), RecordOperatorError<InError>> {
+		let Some(writer) = self.writer.as_mut() else {
+			return Ok(());
+		};
+
+		let elapsed_nanos = self.clock.lock_ignore_poison().elapsed().as_nanos() as u64;
+		serde_json::to_writer(&mut *writer, &(elapsed_nanos, notification))?;
+		writer.write_all(b"\n")?;
+		Ok(())
+	}
+
+	/// Stops the recording and sends the error downstream when the
+	/// notification couldn't be written.
+	fn write_or_error(
+		&mut self,
+		notification: NotificationRef<'_, Destination::In, InError>,
+	) -> bool {
+		if let Err(error) = self.write(notification) {
+			self.writer = None;
+			self.destination.error(error);
+			false
+		} else {
+			true
+		}
+	}
+
+	fn end_recording(&mut self) -> bool {
+		if let Some(mut writer) = self.writer.take()
+			&& let Err(error) = writer.flush()
+		{
+			self.destination.error(error.into());
+			return false;
+		}
+		true
+	}
+}
+
+impl<InError, Destination> RxObserver for RecordSubscriber<InError, Destination>
+where
+	InError: Signal + Serialize,
+	Destination: 'static + Subscriber<InError = RecordOperatorError<InError>>,
+	Destination::In: Serialize,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		if self.writer.is_some() && self.write_or_error(NotificationRef::Next(&next)) {
+			self.destination.next(next);
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		if self.writer.is_some()
+			&& self.write_or_error(NotificationRef::Error(&error))
+			&& self.end_recording()
+		{
+			self.destination.error(RecordOperatorError::Upstream(error));
+		}
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		if self.writer.is_some()
+			&& self.write_or_error(NotificationRef::Complete)
+			&& self.end_recording()
+		{
+			self.destination.complete();
+		}
+	}
+}
@@ -0,0 +1,23 @@
+mod repeat_observable;
+mod repeat_operator;
+mod repeat_subscriber;
+
+pub(crate) mod internal {
+	pub(crate) use super::repeat_subscriber::*;
+}
+
+pub mod observable {
+	pub use super::repeat_observable::*;
+}
+
+pub mod operator {
+	pub use super::repeat_operator::*;
+}
+
+#[cfg(feature = "pipe")]
+mod repeat_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::repeat_extension_pipe::*;
+}
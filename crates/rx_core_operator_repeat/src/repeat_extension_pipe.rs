@@ -0,0 +1,32 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::RepeatOperator;
+
+pub trait ObservablePipeExtensionRepeat<'o>: 'o + Observable + Sized + Send + Sync
+where
+	'o: 'static,
+{
+	/// # [RepeatOperator]
+	///
+	/// The `repeat` operator resubscribes to its source when it completes, until
+	/// it was subscribed to `count` times. Downstream only completes after the
+	/// last one.
+	///
+	/// - Errors are forwarded downstream immediately, without repeating.
+	/// - A `count` of `0` completes immediately without subscribing to the
+	///   source.
+	#[inline]
+	fn repeat(
+		self,
+		count: usize,
+	) -> <RepeatOperator<Self::Out, Self::OutError> as Operator<'o>>::OutObservable<Self> {
+		RepeatOperator::<Self::Out, Self::OutError>::new(count).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionRepeat<'o> for O
+where
+	O: 'o + Observable + Send + Sync,
+	'o: 'static,
+{
+}
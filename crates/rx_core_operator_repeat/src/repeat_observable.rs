@@ -0,0 +1,76 @@
+use std::sync::{Arc, Mutex};
+
+use derive_where::derive_where;
+use rx_core_common::*;
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::internal::{RepeatContext, RepeatState};
+
+#[derive_where(Clone)]
+#[derive(RxObservable)]
+#[rx_out(Source::Out)]
+#[rx_out_error(Source::OutError)]
+pub struct RepeatObservable<Source>
+where
+	Source: Observable,
+{
+	source: Arc<Mutex<Source>>,
+	count: usize,
+}
+
+impl<Source> RepeatObservable<Source>
+where
+	Source: Observable,
+{
+	pub fn new(source: Source, count: usize) -> Self {
+		Self {
+			source: Arc::new(Mutex::new(source)),
+			count,
+		}
+	}
+}
+
+impl<Source> Observable for RepeatObservable<Source>
+where
+	Source: 'static + Observable + Send + Sync,
+{
+	type Subscription<Destination>
+		= SharedSubscription
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		let mut shared_destination = SharedSubscriber::new(destination.upgrade());
+		let state = Arc::new(Mutex::new(RepeatState::default()));
+
+		let state_clone = state.clone();
+		shared_destination.add(Teardown::new(move || {
+			RepeatState::unsubscribe_current(&state_clone);
+		}));
+
+		RepeatContext::new(
+			self.source.clone(),
+			self.count,
+			state,
+			shared_destination.clone(),
+		)
+		.subscribe_to_source();
+
+		let mut subscription = SharedSubscription::default();
+		shared_destination.add(subscription.clone());
+		subscription.add_teardown(Teardown::new(move || {
+			// Only when unsubscribed from outside, not after a terminal signal
+			if !shared_destination.is_closed() {
+				shared_destination.unsubscribe();
+			}
+		}));
+		subscription
+	}
+}
@@ -0,0 +1,62 @@
+use std::marker::PhantomData;
+
+use rx_core_common::{Observable, Operator, PhantomInvariant, Signal};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::observable::RepeatObservable;
+
+/// # [RepeatOperator]
+///
+/// The `repeat` operator resubscribes to its source when it completes, until
+/// it was subscribed to `count` times. Downstream only completes after the
+/// last one.
+///
+/// - Errors are forwarded downstream immediately, without repeating.
+/// - A `count` of `0` completes immediately without subscribing to the
+///   source.
+#[derive(RxOperator, Clone)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct RepeatOperator<In, InError>
+where
+	In: Signal,
+	InError: Signal,
+{
+	count: usize,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError> RepeatOperator<In, InError>
+where
+	In: Signal,
+	InError: Signal,
+{
+	pub fn new(count: usize) -> Self {
+		Self {
+			count,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<'o, In, InError> Operator<'o> for RepeatOperator<In, InError>
+where
+	In: Signal,
+	InError: Signal,
+	'o: 'static,
+{
+	type OutObservable<InObservable>
+		= RepeatObservable<InObservable>
+	where
+		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync;
+
+	#[inline]
+	fn operate<InObservable>(self, source: InObservable) -> Self::OutObservable<InObservable>
+	where
+		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync,
+	{
+		RepeatObservable::new(source, self.count)
+	}
+}
@@ -0,0 +1,192 @@
+use std::sync::{Arc, Mutex};
+
+use derive_where::derive_where;
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, RxObserver, SharedSubscriber, SharedSubscription,
+	Subscriber, SubscriptionData, SubscriptionLike, Teardown, TeardownCollection,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+#[derive(Default)]
+pub(crate) struct RepeatState {
+	/// Set while the source is being subscribed to, resubscriptions requested
+	/// in the meantime are performed once it returns instead of recursing.
+	subscribing: bool,
+	resubscribe_pending: bool,
+	completions: usize,
+	current_subscription: Option<SharedSubscription>,
+}
+
+impl RepeatState {
+	pub(crate) fn unsubscribe_current(state: &Arc<Mutex<Self>>) {
+		let current_subscription = state.lock_ignore_poison().current_subscription.take();
+		if let Some(mut current_subscription) = current_subscription {
+			current_subscription.unsubscribe();
+		}
+	}
+}
+
+/// Everything needed to (re)subscribe to the source of a single `repeat`
+/// subscription.
+#[derive_where(Clone)]
+pub(crate) struct RepeatContext<Source, Destination>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	source: Arc<Mutex<Source>>,
+	count: usize,
+	state: Arc<Mutex<RepeatState>>,
+	destination: SharedSubscriber<Destination>,
+}
+
+impl<Source, Destination> RepeatContext<Source, Destination>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	pub(crate) fn new(
+		source: Arc<Mutex<Source>>,
+		count: usize,
+		state: Arc<Mutex<RepeatState>>,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		Self {
+			source,
+			count,
+			state,
+			destination,
+		}
+	}
+
+	/// Subscribes to the source, or completes when it was already subscribed
+	/// to `count` times.
+	pub(crate) fn subscribe_to_source(&mut self) {
+		{
+			let mut state = self.state.lock_ignore_poison();
+			if state.subscribing {
+				state.resubscribe_pending = true;
+				return;
+			}
+			state.subscribing = true;
+		}
+
+		loop {
+			RepeatState::unsubscribe_current(&self.state);
+
+			let is_done = self.state.lock_ignore_poison().completions >= self.count;
+			if is_done {
+				self.state.lock_ignore_poison().subscribing = false;
+				self.destination.complete();
+				break;
+			}
+
+			if self.destination.is_closed() {
+				self.state.lock_ignore_poison().subscribing = false;
+				break;
+			}
+
+			let subscription = self
+				.source
+				.lock_ignore_poison()
+				.subscribe(RepeatSubscriber::new(self.clone()));
+
+			let current_subscription =
+				(!subscription.is_closed()).then(|| SharedSubscription::new(subscription));
+
+			let mut state = self.state.lock_ignore_poison();
+			state.current_subscription = current_subscription;
+			if state.resubscribe_pending {
+				state.resubscribe_pending = false;
+			} else {
+				state.subscribing = false;
+				break;
+			}
+		}
+	}
+}
+
+#[derive(RxSubscriber)]
+#[rx_in(Source::Out)]
+#[rx_in_error(Source::OutError)]
+pub(crate) struct RepeatSubscriber<Source, Destination>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	teardown: SubscriptionData,
+	context: RepeatContext<Source, Destination>,
+}
+
+impl<Source, Destination> RepeatSubscriber<Source, Destination>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	fn new(context: RepeatContext<Source, Destination>) -> Self {
+		Self {
+			teardown: SubscriptionData::default(),
+			context,
+		}
+	}
+}
+
+impl<Source, Destination> RxObserver for RepeatSubscriber<Source, Destination>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	fn next(&mut self, next: Self::In) {
+		if !self.is_closed() {
+			self.context.destination.next(next);
+
+			if self.context.destination.is_closed() {
+				self.unsubscribe();
+			}
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		if !self.is_closed() {
+			self.unsubscribe();
+			self.context.destination.error(error);
+		}
+	}
+
+	fn complete(&mut self) {
+		if !self.is_closed() {
+			self.unsubscribe();
+			self.context.state.lock_ignore_poison().completions += 1;
+			self.context.subscribe_to_source();
+		}
+	}
+}
+
+impl<Source, Destination> TeardownCollection for RepeatSubscriber<Source, Destination>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	#[inline]
+	fn add_teardown(&mut self, teardown: Teardown) {
+		self.teardown.add_teardown(teardown);
+	}
+}
+
+impl<Source, Destination> SubscriptionLike for RepeatSubscriber<Source, Destination>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.teardown.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.is_closed() {
+			self.teardown.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,25 @@
+mod repeat_when_notifier_destination;
+mod repeat_when_observable;
+mod repeat_when_operator;
+mod repeat_when_subscriber;
+
+pub(crate) mod internal {
+	pub(crate) use super::repeat_when_notifier_destination::*;
+	pub(crate) use super::repeat_when_subscriber::*;
+}
+
+pub mod observable {
+	pub use super::repeat_when_observable::*;
+}
+
+pub mod operator {
+	pub use super::repeat_when_operator::*;
+}
+
+#[cfg(feature = "pipe")]
+mod repeat_when_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::repeat_when_extension_pipe::*;
+}
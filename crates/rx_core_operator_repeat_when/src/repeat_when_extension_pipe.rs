@@ -0,0 +1,48 @@
+use rx_core_common::{Observable, Operator};
+use rx_core_subject_publish::subject::PublishSubject;
+
+use crate::operator::RepeatWhenOperator;
+
+pub trait ObservablePipeExtensionRepeatWhen<'o>:
+	'o + Observable<OutError: Clone> + Sized + Send + Sync
+where
+	'o: 'static,
+{
+	/// # [RepeatWhenOperator]
+	///
+	/// The `repeat_when` operator lets a `notifier` observable decide when to
+	/// resubscribe to the source after it completes.
+	///
+	/// On the first completion, the `notifier_fn` is called with an observable of
+	/// the completions of the source, and the observable it returns is subscribed
+	/// to. Every completion is then sent to this completions observable as a `()`,
+	/// and every time the `notifier` emits, the source is resubscribed to.
+	///
+	/// - When the `notifier` completes, downstream completes too.
+	/// - When the `notifier` errors, the error is forwarded downstream.
+	/// - Errors of the source are forwarded downstream immediately.
+	/// - Completions of the source are never forwarded downstream directly, only
+	///   the `notifier` completing completes downstream.
+	#[inline]
+	fn repeat_when<NotifierFn, Notifier>(
+		self,
+		notifier_fn: NotifierFn,
+	) -> <RepeatWhenOperator<NotifierFn, Notifier, Self::Out, Self::OutError> as Operator<'o>>::OutObservable<Self>
+	where
+		NotifierFn: 'static
+			+ FnMut(PublishSubject<(), Self::OutError>) -> Notifier
+			+ Clone
+			+ Send
+			+ Sync,
+		Notifier: 'static + Observable<OutError = Self::OutError> + Send + Sync,
+	{
+		RepeatWhenOperator::new(notifier_fn).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionRepeatWhen<'o> for O
+where
+	O: 'o + Observable<OutError: Clone> + Send + Sync,
+	'o: 'static,
+{
+}
@@ -0,0 +1,58 @@
+use rx_core_common::{Observable, RxObserver, Subscriber};
+use rx_core_macro_observer_derive::RxObserver;
+use rx_core_subject_publish::subject::PublishSubject;
+
+use crate::internal::RepeatWhenContext;
+
+/// Resubscribes to the source every time the notifier emits.
+#[derive(RxObserver)]
+#[rx_in(Notifier::Out)]
+#[rx_in_error(Source::OutError)]
+pub(crate) struct RepeatWhenNotifierDestination<Source, NotifierFn, Notifier, Destination>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static + FnMut(PublishSubject<(), Source::OutError>) -> Notifier + Send + Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	context: RepeatWhenContext<Source, NotifierFn, Notifier, Destination>,
+}
+
+impl<Source, NotifierFn, Notifier, Destination>
+	RepeatWhenNotifierDestination<Source, NotifierFn, Notifier, Destination>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static + FnMut(PublishSubject<(), Source::OutError>) -> Notifier + Send + Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	pub(crate) fn new(
+		context: RepeatWhenContext<Source, NotifierFn, Notifier, Destination>,
+	) -> Self {
+		Self { context }
+	}
+}
+
+impl<Source, NotifierFn, Notifier, Destination> RxObserver
+	for RepeatWhenNotifierDestination<Source, NotifierFn, Notifier, Destination>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static + FnMut(PublishSubject<(), Source::OutError>) -> Notifier + Send + Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	#[inline]
+	fn next(&mut self, _next: Self::In) {
+		self.context.subscribe_to_source();
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.context.get_destination().error(error);
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.context.get_destination().complete();
+	}
+}
@@ -0,0 +1,246 @@
+use std::sync::{Arc, Mutex};
+
+use derive_where::derive_where;
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, RxObserver, SharedSubscriber, SharedSubscription, Signal,
+	Subscriber, SubscriptionData, SubscriptionLike, Teardown, TeardownCollection,
+	TeardownCollectionExtension,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_subject_publish::subject::PublishSubject;
+
+use crate::internal::RepeatWhenNotifierDestination;
+
+pub(crate) struct RepeatWhenState<NotifierFn, InError>
+where
+	InError: Signal + Clone,
+{
+	/// Set while the source is being subscribed to, resubscriptions requested
+	/// in the meantime are performed once it returns instead of recursing.
+	subscribing: bool,
+	resubscribe_pending: bool,
+	current_subscription: Option<SharedSubscription>,
+	/// Only taken when the first completion is observed.
+	notifier_fn: Option<NotifierFn>,
+	completions: Option<PublishSubject<(), InError>>,
+}
+
+impl<NotifierFn, InError> RepeatWhenState<NotifierFn, InError>
+where
+	InError: Signal + Clone,
+{
+	pub(crate) fn new(notifier_fn: NotifierFn) -> Self {
+		Self {
+			subscribing: false,
+			resubscribe_pending: false,
+			current_subscription: None,
+			notifier_fn: Some(notifier_fn),
+			completions: None,
+		}
+	}
+
+	pub(crate) fn unsubscribe_current(state: &Arc<Mutex<Self>>) {
+		let current_subscription = state.lock_ignore_poison().current_subscription.take();
+		if let Some(mut current_subscription) = current_subscription {
+			current_subscription.unsubscribe();
+		}
+	}
+}
+
+/// Everything needed to (re)subscribe to the source of a single `repeat_when`
+/// subscription.
+#[derive_where(Clone)]
+pub(crate) struct RepeatWhenContext<Source, NotifierFn, Notifier, Destination>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static + FnMut(PublishSubject<(), Source::OutError>) -> Notifier + Send + Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	source: Arc<Mutex<Source>>,
+	state: Arc<Mutex<RepeatWhenState<NotifierFn, Source::OutError>>>,
+	destination: SharedSubscriber<Destination>,
+}
+
+impl<Source, NotifierFn, Notifier, Destination>
+	RepeatWhenContext<Source, NotifierFn, Notifier, Destination>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static + FnMut(PublishSubject<(), Source::OutError>) -> Notifier + Send + Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	pub(crate) fn new(
+		source: Arc<Mutex<Source>>,
+		state: Arc<Mutex<RepeatWhenState<NotifierFn, Source::OutError>>>,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		Self {
+			source,
+			state,
+			destination,
+		}
+	}
+
+	#[inline]
+	pub(crate) fn get_destination(&mut self) -> &mut SharedSubscriber<Destination> {
+		&mut self.destination
+	}
+
+	/// Unsubscribes from the current subscription of the source, if any, and
+	/// subscribes to it again.
+	pub(crate) fn subscribe_to_source(&self) {
+		{
+			let mut state = self.state.lock_ignore_poison();
+			if state.subscribing {
+				state.resubscribe_pending = true;
+				return;
+			}
+			state.subscribing = true;
+		}
+
+		loop {
+			RepeatWhenState::unsubscribe_current(&self.state);
+
+			if self.destination.is_closed() {
+				self.state.lock_ignore_poison().subscribing = false;
+				break;
+			}
+
+			let subscription = self
+				.source
+				.lock_ignore_poison()
+				.subscribe(RepeatWhenSubscriber::new(self.clone()));
+
+			let current_subscription =
+				(!subscription.is_closed()).then(|| SharedSubscription::new(subscription));
+
+			let mut state = self.state.lock_ignore_poison();
+			state.current_subscription = current_subscription;
+			if state.resubscribe_pending {
+				state.resubscribe_pending = false;
+			} else {
+				state.subscribing = false;
+				break;
+			}
+		}
+	}
+
+	/// Notifies the notifier about the completion, subscribing to it on the
+	/// first completion.
+	fn notify_completion(&mut self) {
+		let notifier_fn = self.state.lock_ignore_poison().notifier_fn.take();
+		if let Some(mut notifier_fn) = notifier_fn {
+			let completions = PublishSubject::default();
+			let mut notifier = notifier_fn(completions.clone());
+			self.state.lock_ignore_poison().completions = Some(completions);
+
+			let notifier_subscription =
+				notifier.subscribe(RepeatWhenNotifierDestination::new(self.clone()));
+			self.destination.add(notifier_subscription);
+		}
+
+		let completions = self.state.lock_ignore_poison().completions.clone();
+		if let Some(mut completions) = completions {
+			completions.next(());
+		}
+	}
+}
+
+#[derive(RxSubscriber)]
+#[rx_in(Source::Out)]
+#[rx_in_error(Source::OutError)]
+pub(crate) struct RepeatWhenSubscriber<Source, NotifierFn, Notifier, Destination>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static + FnMut(PublishSubject<(), Source::OutError>) -> Notifier + Send + Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	teardown: SubscriptionData,
+	context: RepeatWhenContext<Source, NotifierFn, Notifier, Destination>,
+}
+
+impl<Source, NotifierFn, Notifier, Destination>
+	RepeatWhenSubscriber<Source, NotifierFn, Notifier, Destination>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static + FnMut(PublishSubject<(), Source::OutError>) -> Notifier + Send + Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	fn new(context: RepeatWhenContext<Source, NotifierFn, Notifier, Destination>) -> Self {
+		Self {
+			teardown: SubscriptionData::default(),
+			context,
+		}
+	}
+}
+
+impl<Source, NotifierFn, Notifier, Destination> RxObserver
+	for RepeatWhenSubscriber<Source, NotifierFn, Notifier, Destination>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static + FnMut(PublishSubject<(), Source::OutError>) -> Notifier + Send + Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	fn next(&mut self, next: Self::In) {
+		if !self.is_closed() {
+			self.context.destination.next(next);
+
+			if self.context.destination.is_closed() {
+				self.unsubscribe();
+			}
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		if !self.is_closed() {
+			self.unsubscribe();
+			self.context.destination.error(error);
+		}
+	}
+
+	fn complete(&mut self) {
+		if !self.is_closed() {
+			self.unsubscribe();
+			self.context.notify_completion();
+		}
+	}
+}
+
+impl<Source, NotifierFn, Notifier, Destination> TeardownCollection
+	for RepeatWhenSubscriber<Source, NotifierFn, Notifier, Destination>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static + FnMut(PublishSubject<(), Source::OutError>) -> Notifier + Send + Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	#[inline]
+	fn add_teardown(&mut self, teardown: Teardown) {
+		self.teardown.add_teardown(teardown);
+	}
+}
+
+impl<Source, NotifierFn, Notifier, Destination> SubscriptionLike
+	for RepeatWhenSubscriber<Source, NotifierFn, Notifier, Destination>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static + FnMut(PublishSubject<(), Source::OutError>) -> Notifier + Send + Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.teardown.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.is_closed() {
+			self.teardown.unsubscribe();
+		}
+	}
+}
@@ -1,24 +1,37 @@
+mod retry_config;
 mod retry_observable;
 mod retry_operator;
 mod retry_subscriber;
+mod retry_with_observable;
+mod retry_with_operator;
+mod retry_with_subscriber;
+
+pub use retry_config::*;
 
 pub(crate) mod internal {
 	pub(crate) use super::retry_subscriber::*;
+	pub(crate) use super::retry_with_subscriber::*;
 }
 
 pub mod observable {
 	pub use super::retry_observable::*;
+	pub use super::retry_with_observable::*;
 }
 
 pub mod operator {
+	pub use super::retry_config::*;
 	pub use super::retry_operator::*;
+	pub use super::retry_with_operator::*;
 	pub use rx_core_observable_connectable::observable::ConnectableOptions;
 }
 
 #[cfg(feature = "pipe")]
 mod retry_extension_pipe;
+#[cfg(feature = "pipe")]
+mod retry_with_extension_pipe;
 
 #[cfg(feature = "pipe")]
 pub mod extension_pipe {
 	pub use super::retry_extension_pipe::*;
+	pub use super::retry_with_extension_pipe::*;
 }
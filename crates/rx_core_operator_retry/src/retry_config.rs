@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+/// How long to wait before resubscribing to the source after an error.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RetryDelay {
+	/// Wait the same amount of time before every retry.
+	Fixed(Duration),
+	/// Double the delay on every consecutive retry, starting from `initial`,
+	/// but never wait longer than `max`.
+	Exponential { initial: Duration, max: Duration },
+	/// Wait a random amount of time between zero and the exponential delay
+	/// ("full jitter"), so that many retrying subscriptions don't hammer the
+	/// source at the same time.
+	Jittered { initial: Duration, max: Duration },
+}
+
+impl RetryDelay {
+	/// The delay before the `retry`th consecutive retry, starting from `1`.
+	pub fn delay_for(&self, retry: usize) -> Duration {
+		match *self {
+			Self::Fixed(delay) => delay,
+			Self::Exponential { initial, max } => Self::exponential_delay(initial, max, retry),
+			Self::Jittered { initial, max } => {
+				Self::exponential_delay(initial, max, retry).mul_f64(rand::random_range(0.0..=1.0))
+			}
+		}
+	}
+
+	fn exponential_delay(initial: Duration, max: Duration, retry: usize) -> Duration {
+		let exponent = retry.saturating_sub(1).min(u32::BITS as usize - 1) as u32;
+		initial.saturating_mul(2u32.pow(exponent)).min(max)
+	}
+}
+
+impl Default for RetryDelay {
+	/// Defaults to `Fixed(Duration::ZERO)`, resubscribing on the next tick of
+	/// the scheduler.
+	fn default() -> Self {
+		Self::Fixed(Duration::ZERO)
+	}
+}
+
+/// Options for configuring the `retry_with` operator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetryConfig {
+	/// How many times the source can be resubscribed to before the error is
+	/// let through.
+	/// Default: `usize::MAX`.
+	pub max_retries: usize,
+	/// How long to wait before each retry.
+	/// Default: `RetryDelay::Fixed(Duration::ZERO)`.
+	pub delay: RetryDelay,
+	/// When enabled, every `next` signal resets the retry counter, so only
+	/// consecutive failures count towards `max_retries` and the delay.
+	/// Default: `false`.
+	pub reset_on_success: bool,
+}
+
+impl RetryConfig {
+	/// Creates a config with the given retry count and default options.
+	///
+	/// Defaults:
+	/// - `delay`: `RetryDelay::Fixed(Duration::ZERO)`
+	/// - `reset_on_success`: `false`
+	pub fn new(max_retries: usize) -> Self {
+		Self {
+			max_retries,
+			..Default::default()
+		}
+	}
+
+	#[must_use]
+	pub fn with_delay(mut self, delay: RetryDelay) -> Self {
+		self.delay = delay;
+		self
+	}
+
+	#[must_use]
+	pub fn with_reset_on_success(mut self, reset_on_success: bool) -> Self {
+		self.reset_on_success = reset_on_success;
+		self
+	}
+}
+
+impl Default for RetryConfig {
+	/// Defaults:
+	/// - `max_retries`: `usize::MAX`
+	/// - `delay`: `RetryDelay::Fixed(Duration::ZERO)`
+	/// - `reset_on_success`: `false`
+	fn default() -> Self {
+		Self {
+			max_retries: usize::MAX,
+			delay: RetryDelay::default(),
+			reset_on_success: false,
+		}
+	}
+}
@@ -0,0 +1,44 @@
+use rx_core_common::{Observable, Operator, Scheduler, SchedulerHandle};
+
+use crate::{RetryConfig, operator::RetryWithOperator};
+
+pub trait ObservablePipeExtensionRetryWith<'o, S>: 'o + Observable + Sized + Send + Sync
+where
+	S: 'static + Scheduler + Send + Sync,
+	'o: 'static,
+{
+	/// # [RetryWithOperator]
+	///
+	/// The `retry_with` operator resubscribes to its source when it errors, but
+	/// unlike `retry`, it waits before doing so using the provided scheduler.
+	///
+	/// Once the retries run out, the error is let through.
+	///
+	/// ## Options
+	///
+	/// Use [RetryConfig] to configure the retries.
+	///
+	/// - `max_retries`: How many times to resubscribe before giving up.
+	///   Default: `usize::MAX`.
+	/// - `delay`: The delay before each retry. Possible values:
+	///   `RetryDelay::Fixed`, `RetryDelay::Exponential`, `RetryDelay::Jittered`.
+	///   Default: `RetryDelay::Fixed(Duration::ZERO)`.
+	/// - `reset_on_success`: Reset the retry counter on every `next` signal.
+	///   Default: `false`.
+	#[inline]
+	fn retry_with(
+		self,
+		config: RetryConfig,
+		scheduler: SchedulerHandle<S>,
+	) -> <RetryWithOperator<Self::Out, Self::OutError, S> as Operator<'o>>::OutObservable<Self> {
+		RetryWithOperator::new(config, scheduler).operate(self)
+	}
+}
+
+impl<'o, O, S> ObservablePipeExtensionRetryWith<'o, S> for O
+where
+	O: 'o + Observable + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+	'o: 'static,
+{
+}
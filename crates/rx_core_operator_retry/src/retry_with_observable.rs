@@ -0,0 +1,89 @@
+use std::sync::{Arc, Mutex};
+
+use derive_where::derive_where;
+use rx_core_common::*;
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{
+	RetryConfig,
+	internal::{RetryWithContext, RetryWithState},
+};
+
+#[derive_where(Clone)]
+#[derive(RxObservable)]
+#[rx_out(Source::Out)]
+#[rx_out_error(Source::OutError)]
+pub struct RetryWithObservable<Source, S>
+where
+	Source: Observable,
+	S: Scheduler,
+{
+	source: Arc<Mutex<Source>>,
+	config: RetryConfig,
+	scheduler: SchedulerHandle<S>,
+}
+
+impl<Source, S> RetryWithObservable<Source, S>
+where
+	Source: Observable,
+	S: Scheduler,
+{
+	pub fn new(source: Source, config: RetryConfig, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			source: Arc::new(Mutex::new(source)),
+			config,
+			scheduler,
+		}
+	}
+}
+
+impl<Source, S> Observable for RetryWithObservable<Source, S>
+where
+	Source: 'static + Observable + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	type Subscription<Destination>
+		= SharedSubscription
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		let mut shared_destination = SharedSubscriber::new(destination.upgrade());
+		let state = Arc::new(Mutex::new(RetryWithState::default()));
+
+		let context = RetryWithContext::new(
+			self.source.clone(),
+			self.config,
+			self.scheduler.clone(),
+			state.clone(),
+			shared_destination.clone(),
+		);
+
+		shared_destination.add(Teardown::new_work_cancellation(
+			context.get_cancellation_id(),
+			self.scheduler.clone(),
+		));
+		shared_destination.add(Teardown::new(move || {
+			RetryWithState::unsubscribe_current(&state);
+		}));
+
+		context.subscribe_to_source();
+
+		let mut subscription = SharedSubscription::default();
+		shared_destination.add(subscription.clone());
+		subscription.add_teardown(Teardown::new(move || {
+			// Only when unsubscribed from outside, not after a terminal signal
+			if !shared_destination.is_closed() {
+				shared_destination.unsubscribe();
+			}
+		}));
+		subscription
+	}
+}
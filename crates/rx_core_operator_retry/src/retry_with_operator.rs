@@ -0,0 +1,76 @@
+use std::marker::PhantomData;
+
+use rx_core_common::{Observable, Operator, PhantomInvariant, Scheduler, SchedulerHandle, Signal};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::{RetryConfig, observable::RetryWithObservable};
+
+/// # [RetryWithOperator]
+///
+/// The `retry_with` operator resubscribes to its source when it errors, but
+/// unlike `retry`, it waits before doing so using the provided scheduler.
+///
+/// Once the retries run out, the error is let through.
+///
+/// ## Options
+///
+/// Use [RetryConfig] to configure the retries.
+///
+/// - `max_retries`: How many times to resubscribe before giving up.
+///   Default: `usize::MAX`.
+/// - `delay`: The delay before each retry. Possible values:
+///   `RetryDelay::Fixed`, `RetryDelay::Exponential`, `RetryDelay::Jittered`.
+///   Default: `RetryDelay::Fixed(Duration::ZERO)`.
+/// - `reset_on_success`: Reset the retry counter on every `next` signal.
+///   Default: `false`.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct RetryWithOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: Scheduler,
+{
+	config: RetryConfig,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, S> RetryWithOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: Scheduler,
+{
+	pub fn new(config: RetryConfig, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			config,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<'o, In, InError, S> Operator<'o> for RetryWithOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: 'static + Scheduler + Send + Sync,
+	'o: 'static,
+{
+	type OutObservable<InObservable>
+		= RetryWithObservable<InObservable, S>
+	where
+		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync;
+
+	#[inline]
+	fn operate<InObservable>(self, source: InObservable) -> Self::OutObservable<InObservable>
+	where
+		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync,
+	{
+		RetryWithObservable::new(source, self.config, self.scheduler)
+	}
+}
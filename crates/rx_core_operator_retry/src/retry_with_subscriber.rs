@@ -0,0 +1,210 @@
+use std::sync::{Arc, Mutex};
+
+use derive_where::derive_where;
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, RxObserver, Scheduler, SchedulerHandle,
+	SchedulerScheduleWorkExtension, SharedSubscriber, SharedSubscription, Subscriber,
+	SubscriptionData, SubscriptionLike, Teardown, TeardownCollection, WorkCancellationId,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::RetryConfig;
+
+#[derive(Default)]
+pub(crate) struct RetryWithState {
+	/// Consecutive retries so far
+	pub(crate) retries: usize,
+	pub(crate) current_subscription: Option<SharedSubscription>,
+}
+
+impl RetryWithState {
+	pub(crate) fn unsubscribe_current(state: &Arc<Mutex<Self>>) {
+		let current_subscription = state.lock_ignore_poison().current_subscription.take();
+		if let Some(mut current_subscription) = current_subscription {
+			current_subscription.unsubscribe();
+		}
+	}
+}
+
+/// Everything needed to (re)subscribe to the source of a single `retry_with`
+/// subscription.
+#[derive_where(Clone)]
+pub(crate) struct RetryWithContext<Source, Destination, S>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	source: Arc<Mutex<Source>>,
+	config: RetryConfig,
+	scheduler: SchedulerHandle<S>,
+	cancellation_id: WorkCancellationId,
+	state: Arc<Mutex<RetryWithState>>,
+	destination: SharedSubscriber<Destination>,
+}
+
+impl<Source, Destination, S> RetryWithContext<Source, Destination, S>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	pub(crate) fn new(
+		source: Arc<Mutex<Source>>,
+		config: RetryConfig,
+		scheduler: SchedulerHandle<S>,
+		state: Arc<Mutex<RetryWithState>>,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		let cancellation_id = scheduler.lock().generate_cancellation_id();
+		Self {
+			source,
+			config,
+			scheduler,
+			cancellation_id,
+			state,
+			destination,
+		}
+	}
+
+	pub(crate) fn get_cancellation_id(&self) -> WorkCancellationId {
+		self.cancellation_id
+	}
+
+	pub(crate) fn subscribe_to_source(&self) {
+		if self.destination.is_closed() {
+			return;
+		}
+
+		let subscription = self
+			.source
+			.lock_ignore_poison()
+			.subscribe(RetryWithSubscriber::new(self.clone()));
+
+		if !subscription.is_closed() {
+			self.state
+				.lock_ignore_poison()
+				.current_subscription
+				.replace(SharedSubscription::new(subscription));
+		}
+	}
+
+	fn schedule_retry(&self, retry: usize) {
+		let context = self.clone();
+		self.scheduler.lock().schedule_delayed_work(
+			move |_, _| context.subscribe_to_source(),
+			self.config.delay.delay_for(retry),
+			self.cancellation_id,
+		);
+	}
+}
+
+#[derive(RxSubscriber)]
+#[rx_in(Source::Out)]
+#[rx_in_error(Source::OutError)]
+pub(crate) struct RetryWithSubscriber<Source, Destination, S>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	teardown: SubscriptionData,
+	context: RetryWithContext<Source, Destination, S>,
+}
+
+impl<Source, Destination, S> RetryWithSubscriber<Source, Destination, S>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	fn new(context: RetryWithContext<Source, Destination, S>) -> Self {
+		Self {
+			teardown: SubscriptionData::default(),
+			context,
+		}
+	}
+}
+
+impl<Source, Destination, S> RxObserver for RetryWithSubscriber<Source, Destination, S>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	fn next(&mut self, next: Self::In) {
+		if self.is_closed() {
+			return;
+		}
+
+		if self.context.config.reset_on_success {
+			self.context.state.lock_ignore_poison().retries = 0;
+		}
+
+		self.context.destination.next(next);
+
+		if self.context.destination.is_closed() {
+			self.unsubscribe();
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		if self.is_closed() {
+			return;
+		}
+
+		self.unsubscribe();
+
+		let retry = {
+			let mut state = self.context.state.lock_ignore_poison();
+			(state.retries < self.context.config.max_retries).then(|| {
+				state.retries += 1;
+				state.retries
+			})
+		};
+
+		if let Some(retry) = retry {
+			self.context.schedule_retry(retry);
+		} else {
+			self.context.destination.error(error);
+		}
+	}
+
+	fn complete(&mut self) {
+		if !self.is_closed() {
+			self.unsubscribe();
+			self.context.destination.complete();
+		}
+	}
+}
+
+impl<Source, Destination, S> TeardownCollection for RetryWithSubscriber<Source, Destination, S>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	#[inline]
+	fn add_teardown(&mut self, teardown: Teardown) {
+		self.teardown.add_teardown(teardown);
+	}
+}
+
+impl<Source, Destination, S> SubscriptionLike for RetryWithSubscriber<Source, Destination, S>
+where
+	Source: 'static + Observable + Send + Sync,
+	Destination: 'static + Subscriber<In = Source::Out, InError = Source::OutError> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.teardown.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.is_closed() {
+			self.teardown.unsubscribe();
+		}
+	}
+}
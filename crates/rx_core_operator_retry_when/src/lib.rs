@@ -0,0 +1,25 @@
+mod retry_when_notifier_destination;
+mod retry_when_observable;
+mod retry_when_operator;
+mod retry_when_subscriber;
+
+pub(crate) mod internal {
+	pub(crate) use super::retry_when_notifier_destination::*;
+	pub(crate) use super::retry_when_subscriber::*;
+}
+
+pub mod observable {
+	pub use super::retry_when_observable::*;
+}
+
+pub mod operator {
+	pub use super::retry_when_operator::*;
+}
+
+#[cfg(feature = "pipe")]
+mod retry_when_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::retry_when_extension_pipe::*;
+}
@@ -0,0 +1,47 @@
+use rx_core_common::{Observable, Operator};
+use rx_core_subject_publish::subject::PublishSubject;
+
+use crate::operator::RetryWhenOperator;
+
+pub trait ObservablePipeExtensionRetryWhen<'o>:
+	'o + Observable<OutError: Clone> + Sized + Send + Sync
+where
+	'o: 'static,
+{
+	/// # [RetryWhenOperator]
+	///
+	/// The `retry_when` operator lets a `notifier` observable decide when to
+	/// resubscribe to the source after it errors.
+	///
+	/// On the first error, the `notifier_fn` is called with an observable of the
+	/// errors of the source, and the observable it returns is subscribed to.
+	/// Every error is then sent to this errors observable, and every time the
+	/// `notifier` emits, the source is resubscribed to.
+	///
+	/// - When the `notifier` errors, the error is forwarded downstream.
+	/// - When the `notifier` completes, downstream completes too.
+	/// - Errors of the source are never forwarded downstream directly, to give
+	///   up with an error, the `notifier` has to error.
+	#[inline]
+	fn retry_when<NotifierFn, Notifier>(
+		self,
+		notifier_fn: NotifierFn,
+	) -> <RetryWhenOperator<NotifierFn, Notifier, Self::Out, Self::OutError> as Operator<'o>>::OutObservable<Self>
+	where
+		NotifierFn: 'static
+			+ FnMut(PublishSubject<Self::OutError, Self::OutError>) -> Notifier
+			+ Clone
+			+ Send
+			+ Sync,
+		Notifier: 'static + Observable<OutError = Self::OutError> + Send + Sync,
+	{
+		RetryWhenOperator::new(notifier_fn).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionRetryWhen<'o> for O
+where
+	O: 'o + Observable<OutError: Clone> + Send + Sync,
+	'o: 'static,
+{
+}
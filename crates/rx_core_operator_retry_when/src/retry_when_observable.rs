@@ -0,0 +1,90 @@
+use std::sync::{Arc, Mutex};
+
+use derive_where::derive_where;
+use rx_core_common::*;
+use rx_core_macro_observable_derive::RxObservable;
+use rx_core_subject_publish::subject::PublishSubject;
+
+use crate::internal::{RetryWhenContext, RetryWhenState};
+
+#[derive_where(Clone; NotifierFn)]
+#[derive(RxObservable)]
+#[rx_out(Source::Out)]
+#[rx_out_error(Source::OutError)]
+pub struct RetryWhenObservable<Source, NotifierFn, Notifier>
+where
+	Source: Observable<OutError: Clone>,
+	NotifierFn: 'static
+		+ FnMut(PublishSubject<Source::OutError, Source::OutError>) -> Notifier
+		+ Clone
+		+ Send
+		+ Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+{
+	source: Arc<Mutex<Source>>,
+	notifier_fn: NotifierFn,
+}
+
+impl<Source, NotifierFn, Notifier> RetryWhenObservable<Source, NotifierFn, Notifier>
+where
+	Source: Observable<OutError: Clone>,
+	NotifierFn: 'static
+		+ FnMut(PublishSubject<Source::OutError, Source::OutError>) -> Notifier
+		+ Clone
+		+ Send
+		+ Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+{
+	pub fn new(source: Source, notifier_fn: NotifierFn) -> Self {
+		Self {
+			source: Arc::new(Mutex::new(source)),
+			notifier_fn,
+		}
+	}
+}
+
+impl<Source, NotifierFn, Notifier> Observable for RetryWhenObservable<Source, NotifierFn, Notifier>
+where
+	Source: 'static + Observable<OutError: Clone> + Send + Sync,
+	NotifierFn: 'static
+		+ FnMut(PublishSubject<Source::OutError, Source::OutError>) -> Notifier
+		+ Clone
+		+ Send
+		+ Sync,
+	Notifier: 'static + Observable<OutError = Source::OutError> + Send + Sync,
+{
+	type Subscription<Destination>
+		= SharedSubscription
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		let mut shared_destination = SharedSubscriber::new(destination.upgrade());
+		let state = Arc::new(Mutex::new(RetryWhenState::new(self.notifier_fn.clone())));
+
+		let state_clone = state.clone();
+		shared_destination.add(Teardown::new(move || {
+			RetryWhenState::unsubscribe_current(&state_clone);
+		}));
+
+		RetryWhenContext::new(self.source.clone(), state, shared_destination.clone())
+			.subscribe_to_source();
+
+		let mut subscription = SharedSubscription::default();
+		shared_destination.add(subscription.clone());
+		subscription.add_teardown(Teardown::new(move || {
+			// Only when unsubscribed from outside, not after a terminal signal
+			if !shared_destination.is_closed() {
+				shared_destination.unsubscribe();
+			}
+		}));
+		subscription
+	}
+}
@@ -0,0 +1,75 @@
+use std::marker::PhantomData;
+
+use rx_core_common::{Observable, Operator, PhantomInvariant, Signal};
+use rx_core_macro_operator_derive::RxOperator;
+use rx_core_subject_publish::subject::PublishSubject;
+
+use crate::observable::RetryWhenObservable;
+
+/// # [RetryWhenOperator]
+///
+/// The `retry_when` operator lets a `notifier` observable decide when to
+/// resubscribe to the source after it errors.
+///
+/// On the first error, the `notifier_fn` is called with an observable of the
+/// errors of the source, and the observable it returns is subscribed to.
+/// Every error is then sent to this errors observable, and every time the
+/// `notifier` emits, the source is resubscribed to.
+///
+/// - When the `notifier` errors, the error is forwarded downstream.
+/// - When the `notifier` completes, downstream completes too.
+/// - Errors of the source are never forwarded downstream directly, to give
+///   up with an error, the `notifier` has to error.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct RetryWhenOperator<NotifierFn, Notifier, In, InError>
+where
+	NotifierFn: 'static + FnMut(PublishSubject<InError, InError>) -> Notifier + Clone + Send + Sync,
+	Notifier: 'static + Observable<OutError = InError> + Send + Sync,
+	In: Signal,
+	InError: Signal + Clone,
+{
+	notifier_fn: NotifierFn,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<NotifierFn, Notifier, In, InError> RetryWhenOperator<NotifierFn, Notifier, In, InError>
+where
+	NotifierFn: 'static + FnMut(PublishSubject<InError, InError>) -> Notifier + Clone + Send + Sync,
+	Notifier: 'static + Observable<OutError = InError> + Send + Sync,
+	In: Signal,
+	InError: Signal + Clone,
+{
+	pub fn new(notifier_fn: NotifierFn) -> Self {
+		Self {
+			notifier_fn,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<'o, NotifierFn, Notifier, In, InError> Operator<'o>
+	for RetryWhenOperator<NotifierFn, Notifier, In, InError>
+where
+	NotifierFn: 'static + FnMut(PublishSubject<InError, InError>) -> Notifier + Clone + Send + Sync,
+	Notifier: 'static + Observable<OutError = InError> + Send + Sync,
+	In: Signal,
+	InError: Signal + Clone,
+	'o: 'static,
+{
+	type OutObservable<InObservable>
+		= RetryWhenObservable<InObservable, NotifierFn, Notifier>
+	where
+		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync;
+
+	#[inline]
+	fn operate<InObservable>(self, source: InObservable) -> Self::OutObservable<InObservable>
+	where
+		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync,
+	{
+		RetryWhenObservable::new(source, self.notifier_fn)
+	}
+}
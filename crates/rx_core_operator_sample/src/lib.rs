@@ -0,0 +1,26 @@
+mod sample_notifier_destination;
+mod sample_operator;
+mod sample_subscriber;
+
+pub use sample_notifier_destination::*;
+pub use sample_subscriber::*;
+
+pub mod operator {
+	pub use super::sample_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod sample_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::sample_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod sample_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::sample_extension_pipe::*;
+}
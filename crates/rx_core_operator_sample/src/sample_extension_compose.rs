@@ -0,0 +1,30 @@
+use rx_core_common::{ComposableOperator, Observable};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::SampleOperator;
+
+pub trait OperatorComposeExtensionSample: ComposableOperator + Sized {
+	/// # [SampleOperator]
+	///
+	/// Emits the latest upstream value every time the `notifier` emits, as long
+	/// as upstream emitted something since the previous sample.
+	///
+	/// - The `notifier` completing does not complete downstream, it just stops
+	///   sampling.
+	/// - Upstream completing completes downstream without emitting the pending
+	///   value.
+	/// - Errors, from upstream or from the `notifier`, are forwarded downstream.
+	/// - The `notifier` subscription is torn down together with the subscriber.
+	#[inline]
+	fn sample<NotifierObservable>(
+		self,
+		notifier: NotifierObservable,
+	) -> CompositeOperator<Self, SampleOperator<NotifierObservable, Self::Out, Self::OutError>>
+	where
+		NotifierObservable: 'static + Observable<OutError = Self::OutError>,
+	{
+		self.compose_with(SampleOperator::new(notifier))
+	}
+}
+
+impl<Op> OperatorComposeExtensionSample for Op where Op: ComposableOperator {}
@@ -0,0 +1,31 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::SampleOperator;
+
+pub trait ObservablePipeExtensionSample<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [SampleOperator]
+	///
+	/// Emits the latest upstream value every time the `notifier` emits, as long
+	/// as upstream emitted something since the previous sample.
+	///
+	/// - The `notifier` completing does not complete downstream, it just stops
+	///   sampling.
+	/// - Upstream completing completes downstream without emitting the pending
+	///   value.
+	/// - Errors, from upstream or from the `notifier`, are forwarded downstream.
+	/// - The `notifier` subscription is torn down together with the subscriber.
+	#[inline]
+	fn sample<NotifierObservable>(
+		self,
+		notifier: NotifierObservable,
+	) -> <SampleOperator<NotifierObservable, Self::Out, Self::OutError> as Operator<'o>>::OutObservable<
+		Self,
+	>
+	where
+		NotifierObservable: 'static + Observable<OutError = Self::OutError>,
+	{
+		SampleOperator::new(notifier).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionSample<'o> for O where O: 'o + Observable + Send + Sync {}
@@ -0,0 +1,66 @@
+use std::{
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, PhantomInvariant, RxObserver, SharedSubscriber, Signal, Subscriber,
+	SubscriptionLike,
+};
+use rx_core_macro_observer_derive::RxObserver;
+
+/// Emits the latest upstream value downstream every time the notifier emits.
+#[derive(RxObserver)]
+#[rx_in(NotifierOut)]
+#[rx_in_error(Destination::InError)]
+pub struct SampleNotifierDestination<NotifierOut, Destination>
+where
+	NotifierOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	destination: SharedSubscriber<Destination>,
+	latest: Arc<Mutex<Option<Destination::In>>>,
+	_phantom_data: PhantomInvariant<NotifierOut>,
+}
+
+impl<NotifierOut, Destination> SampleNotifierDestination<NotifierOut, Destination>
+where
+	NotifierOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	pub(crate) fn new(
+		destination: SharedSubscriber<Destination>,
+		latest: Arc<Mutex<Option<Destination::In>>>,
+	) -> Self {
+		Self {
+			destination,
+			latest,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<NotifierOut, Destination> RxObserver for SampleNotifierDestination<NotifierOut, Destination>
+where
+	NotifierOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	fn next(&mut self, _next: Self::In) {
+		let latest = self.latest.lock_ignore_poison().take();
+		if let Some(latest) = latest
+			&& !self.destination.is_closed()
+		{
+			self.destination.next(latest);
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		if !self.destination.is_closed() {
+			self.destination.error(error);
+		}
+	}
+
+	/// A notifier completing only stops sampling.
+	#[inline]
+	fn complete(&mut self) {}
+}
@@ -0,0 +1,70 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{ComposableOperator, Never, Observable, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::SampleSubscriber;
+
+/// # [SampleOperator]
+///
+/// Emits the latest upstream value every time the `notifier` emits, as long
+/// as upstream emitted something since the previous sample.
+///
+/// - The `notifier` completing does not complete downstream, it just stops
+///   sampling.
+/// - Upstream completing completes downstream without emitting the pending
+///   value.
+/// - Errors, from upstream or from the `notifier`, are forwarded downstream.
+/// - The `notifier` subscription is torn down together with the subscriber.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct SampleOperator<NotifierObservable, In, InError = Never>
+where
+	NotifierObservable: 'static + Observable<OutError = InError>,
+	In: Signal,
+	InError: Signal,
+{
+	notifier: NotifierObservable,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<NotifierObservable, In, InError> SampleOperator<NotifierObservable, In, InError>
+where
+	NotifierObservable: 'static + Observable<OutError = InError>,
+	In: Signal,
+	InError: Signal,
+{
+	pub fn new(notifier: NotifierObservable) -> Self {
+		Self {
+			notifier,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<NotifierObservable, In, InError> ComposableOperator
+	for SampleOperator<NotifierObservable, In, InError>
+where
+	NotifierObservable: 'static + Observable<OutError = InError>,
+	In: Signal,
+	InError: Signal,
+{
+	type Subscriber<Destination>
+		= SampleSubscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		SampleSubscriber::new(destination, &mut self.notifier)
+	}
+}
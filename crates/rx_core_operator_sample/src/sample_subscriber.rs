@@ -0,0 +1,93 @@
+use std::sync::{Arc, Mutex};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, RxObserver, SharedSubscriber, Subscriber, SubscriptionLike,
+	TeardownCollectionExtension,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::SampleNotifierDestination;
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+pub struct SampleSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	/// Shared with the notifier, taken on every sample
+	latest: Arc<Mutex<Option<Destination::In>>>,
+}
+
+impl<Destination> SampleSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	pub fn new<NotifierObservable>(
+		destination: Destination,
+		notifier: &mut NotifierObservable,
+	) -> Self
+	where
+		NotifierObservable: 'static + Observable<OutError = Destination::InError>,
+	{
+		let mut destination = SharedSubscriber::new(destination);
+		let latest = Arc::new(Mutex::new(None));
+
+		let notifier_subscription =
+			notifier.subscribe(SampleNotifierDestination::<
+				NotifierObservable::Out,
+				Destination,
+			>::new(destination.clone(), latest.clone()));
+		// Stops sampling once downstream is closed
+		destination.add(notifier_subscription);
+
+		Self {
+			destination,
+			latest,
+		}
+	}
+}
+
+impl<Destination> RxObserver for SampleSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		self.latest.lock_ignore_poison().replace(next);
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		if !self.destination.is_closed() {
+			self.destination.error(error);
+		}
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		if !self.destination.is_closed() {
+			self.destination.complete();
+		}
+	}
+}
+
+impl<Destination> SubscriptionLike for SampleSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,24 @@
+mod sample_time_operator;
+mod sample_time_subscriber;
+
+pub use sample_time_subscriber::*;
+
+pub mod operator {
+	pub use super::sample_time_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod sample_time_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::sample_time_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod sample_time_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::sample_time_extension_pipe::*;
+}
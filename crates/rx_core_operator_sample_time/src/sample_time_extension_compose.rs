@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use rx_core_common::{ComposableOperator, Scheduler, SchedulerHandle, Signal};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::SampleTimeOperator;
+
+pub trait OperatorComposeExtensionSampleTime<T, S>: ComposableOperator<Out = T> + Sized
+where
+	T: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+	/// # [SampleTimeOperator]
+	///
+	/// Emits the latest upstream value once every `period`, as long as upstream
+	/// emitted something since the previous sample.
+	///
+	/// The sampling runs as repeated work on the scheduler, starting at
+	/// subscription. Upstream completing completes downstream without emitting
+	/// the pending value, and upstream errors are forwarded immediately.
+	#[inline]
+	fn sample_time(
+		self,
+		period: Duration,
+		scheduler: SchedulerHandle<S>,
+	) -> CompositeOperator<Self, SampleTimeOperator<T, Self::OutError, S>> {
+		self.compose_with(SampleTimeOperator::new(period, scheduler))
+	}
+}
+
+impl<Op, T, S> OperatorComposeExtensionSampleTime<T, S> for Op
+where
+	Op: ComposableOperator<Out = T>,
+	T: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+}
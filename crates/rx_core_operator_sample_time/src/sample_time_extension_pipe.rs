@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use rx_core_common::{Observable, Operator, Scheduler, SchedulerHandle, Signal};
+
+use crate::operator::SampleTimeOperator;
+
+pub trait ObservablePipeExtensionSampleTime<'o, T, S>:
+	'o + Observable<Out = T> + Sized + Send + Sync
+where
+	T: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+	/// # [SampleTimeOperator]
+	///
+	/// Emits the latest upstream value once every `period`, as long as upstream
+	/// emitted something since the previous sample.
+	///
+	/// The sampling runs as repeated work on the scheduler, starting at
+	/// subscription. Upstream completing completes downstream without emitting
+	/// the pending value, and upstream errors are forwarded immediately.
+	#[inline]
+	fn sample_time(
+		self,
+		period: Duration,
+		scheduler: SchedulerHandle<S>,
+	) -> <SampleTimeOperator<T, Self::OutError, S> as Operator<'o>>::OutObservable<Self> {
+		SampleTimeOperator::new(period, scheduler).operate(self)
+	}
+}
+
+impl<'o, O, T, S> ObservablePipeExtensionSampleTime<'o, T, S> for O
+where
+	O: 'o + Observable<Out = T> + Send + Sync,
+	T: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+}
@@ -0,0 +1,71 @@
+use core::marker::PhantomData;
+use std::time::Duration;
+
+use rx_core_common::{
+	ComposableOperator, PhantomInvariant, Scheduler, SchedulerHandle, Signal, Subscriber,
+};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::SampleTimeSubscriber;
+
+/// # [SampleTimeOperator]
+///
+/// Emits the latest upstream value once every `period`, as long as upstream
+/// emitted something since the previous sample.
+///
+/// The sampling runs as repeated work on the scheduler, starting at
+/// subscription. Upstream completing completes downstream without emitting
+/// the pending value, and upstream errors are forwarded immediately.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct SampleTimeOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: Scheduler,
+{
+	period: Duration,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, S> SampleTimeOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: Scheduler,
+{
+	pub fn new(period: Duration, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			period,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, S> ComposableOperator for SampleTimeOperator<In, InError, S>
+where
+	In: Signal,
+	InError: Signal,
+	S: 'static + Scheduler + Send + Sync,
+{
+	type Subscriber<Destination>
+		= SampleTimeSubscriber<Destination, S>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		SampleTimeSubscriber::new(destination, self.period, self.scheduler.clone())
+	}
+}
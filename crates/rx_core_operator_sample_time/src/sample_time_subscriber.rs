@@ -0,0 +1,100 @@
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, RxObserver, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension,
+	SharedSubscriber, Subscriber, SubscriptionLike, Teardown, WorkCancellationId, WorkResult,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct SampleTimeSubscriber<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: 'static + Scheduler,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	/// Shared with the sampling work, taken on every sample
+	latest: Arc<Mutex<Option<Destination::In>>>,
+	scheduler: SchedulerHandle<S>,
+	cancellation_id: WorkCancellationId,
+}
+
+impl<Destination, S> SampleTimeSubscriber<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: Scheduler,
+{
+	pub fn new(
+		mut destination: Destination,
+		period: Duration,
+		scheduler: SchedulerHandle<S>,
+	) -> Self {
+		let cancellation_id = scheduler.lock().generate_cancellation_id();
+		destination.add_teardown(Teardown::new_work_cancellation(
+			cancellation_id,
+			scheduler.clone(),
+		));
+
+		let destination = SharedSubscriber::new(destination);
+		let latest = Arc::new(Mutex::new(None));
+
+		let mut destination_clone = destination.clone();
+		let latest_clone = latest.clone();
+		scheduler.lock().schedule_repeated_work(
+			move |_, _| {
+				if destination_clone.is_closed() {
+					return WorkResult::Done;
+				}
+
+				let latest = latest_clone.lock_ignore_poison().take();
+				if let Some(latest) = latest {
+					destination_clone.next(latest);
+				}
+
+				WorkResult::Pending
+			},
+			period,
+			false,
+			1,
+			cancellation_id,
+		);
+
+		Self {
+			destination,
+			latest,
+			scheduler,
+			cancellation_id,
+		}
+	}
+}
+
+impl<Destination, S> RxObserver for SampleTimeSubscriber<Destination, S>
+where
+	Destination: 'static + Subscriber,
+	S: 'static + Scheduler + Send + Sync,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		self.latest.lock_ignore_poison().replace(next);
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.scheduler.lock().cancel(self.cancellation_id);
+		self.destination.error(error);
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.scheduler.lock().cancel(self.cancellation_id);
+		self.destination.complete();
+	}
+}
@@ -7,7 +7,9 @@ pub mod observable {
 
 pub mod operator {
 	pub use super::share_operator::*;
-	pub use rx_core_observable_connectable::observable::ConnectableOptions;
+	pub use rx_core_observable_connectable::observable::{
+		ConnectableOptions, ConnectableResetDelays, ResetDelay,
+	};
 }
 
 #[cfg(feature = "pipe")]
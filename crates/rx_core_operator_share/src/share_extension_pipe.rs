@@ -1,6 +1,6 @@
 use rx_core_common::{Observable, Operator, Provider, SubjectLike};
 
-use crate::operator::{ConnectableOptions, ShareOperator};
+use crate::operator::{ConnectableOptions, ConnectableResetDelays, ShareOperator};
 
 pub trait ObservablePipeExtensionShare<'o>: 'o + Observable + Sized + Send + Sync {
 	#[inline]
@@ -16,6 +16,23 @@ pub trait ObservablePipeExtensionShare<'o>: 'o + Observable + Sized + Send + Syn
 	{
 		ShareOperator::new(options).operate(self)
 	}
+
+	/// Like `share`, but the resets enabled in the options only happen after
+	/// their delay, following RxJS 7's `share` reset semantics.
+	#[inline]
+	fn share_with_reset_delays<ConnectorProvider>(
+		self,
+		options: ConnectableOptions<ConnectorProvider>,
+		reset_delays: ConnectableResetDelays,
+	) -> <ShareOperator<ConnectorProvider> as Operator<'o>>::OutObservable<Self>
+	where
+		Self::Out: Clone,
+		Self::OutError: Clone,
+		ConnectorProvider: 'static + Provider,
+		ConnectorProvider::Provided: SubjectLike<In = Self::Out, InError = Self::OutError> + Clone,
+	{
+		ShareOperator::new_with_reset_delays(options, reset_delays).operate(self)
+	}
 }
 
 impl<'o, O> ObservablePipeExtensionShare<'o> for O where O: 'o + Observable + Send + Sync {}
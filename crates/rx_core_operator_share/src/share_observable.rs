@@ -2,7 +2,8 @@ use derive_where::derive_where;
 use rx_core_common::*;
 use rx_core_macro_observable_derive::RxObservable;
 use rx_core_observable_connectable::observable::{
-	Connectable, ConnectableObservable, ConnectableOptions, ConnectionHandle,
+	Connectable, ConnectableObservable, ConnectableOptions, ConnectableResetDelays,
+	ConnectionHandle,
 };
 
 #[derive_where(Clone)]
@@ -40,8 +41,20 @@ where
 	ConnectorProvider::Provided: SubjectLike<In = Source::Out, InError = Source::OutError> + Clone,
 {
 	pub fn new(source: Source, options: ConnectableOptions<ConnectorProvider>) -> Self {
+		Self::new_with_reset_delays(source, options, ConnectableResetDelays::default())
+	}
+
+	pub fn new_with_reset_delays(
+		source: Source,
+		options: ConnectableOptions<ConnectorProvider>,
+		reset_delays: ConnectableResetDelays,
+	) -> Self {
 		Self {
-			connectable: ConnectableObservable::new(source, options),
+			connectable: ConnectableObservable::new_with_reset_delays(
+				source,
+				options,
+				reset_delays,
+			),
 			connection: None,
 		}
 	}
@@ -104,7 +117,11 @@ where
 	{
 		let subscription = self.connectable.subscribe(destination);
 
-		self.connect();
+		// A connector that has already finished (and wasn't reset) closes new
+		// subscriptions immediately, there is no reason to connect then.
+		if !subscription.is_closed() {
+			self.connect();
+		}
 
 		subscription
 	}
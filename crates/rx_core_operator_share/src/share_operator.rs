@@ -2,7 +2,7 @@ use rx_core_common::{
 	Observable, ObservableOutput, ObserverInput, Operator, Provider, SubjectLike,
 };
 use rx_core_macro_operator_derive::RxOperator;
-use rx_core_observable_connectable::observable::ConnectableOptions;
+use rx_core_observable_connectable::observable::{ConnectableOptions, ConnectableResetDelays};
 
 use crate::observable::ShareObservable;
 
@@ -17,6 +17,7 @@ where
 	ConnectorProvider::Provided: SubjectLike + Clone,
 {
 	options: ConnectableOptions<ConnectorProvider>,
+	reset_delays: ConnectableResetDelays,
 }
 
 impl<ConnectorProvider> ShareOperator<ConnectorProvider>
@@ -25,7 +26,17 @@ where
 	ConnectorProvider::Provided: SubjectLike + Clone,
 {
 	pub fn new(options: ConnectableOptions<ConnectorProvider>) -> Self {
-		Self { options }
+		Self::new_with_reset_delays(options, ConnectableResetDelays::default())
+	}
+
+	pub fn new_with_reset_delays(
+		options: ConnectableOptions<ConnectorProvider>,
+		reset_delays: ConnectableResetDelays,
+	) -> Self {
+		Self {
+			options,
+			reset_delays,
+		}
 	}
 }
 
@@ -46,6 +57,6 @@ where
 	where
 		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync,
 	{
-		ShareObservable::new(source, self.options)
+		ShareObservable::new_with_reset_delays(source, self.options, self.reset_delays)
 	}
 }
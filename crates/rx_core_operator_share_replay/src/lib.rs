@@ -0,0 +1,17 @@
+mod share_replay_operator;
+mod share_replay_options;
+
+pub use share_replay_options::*;
+
+pub mod operator {
+	pub use super::share_replay_operator::*;
+	pub use super::share_replay_options::*;
+}
+
+#[cfg(feature = "pipe")]
+mod share_replay_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::share_replay_extension_pipe::*;
+}
@@ -0,0 +1,48 @@
+use rx_core_common::{Observable, Operator, SchedulerClock, SchedulerHandle};
+
+use crate::{ShareReplayOptions, operator::ShareReplayOperator};
+
+pub trait ObservablePipeExtensionShareReplay<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [ShareReplayOperator]
+	///
+	/// The `share_replay` operator shares a single upstream subscription across
+	/// every downstream subscriber, just like `share`, while also replaying the
+	/// last `buffer_size` values observed within the last `window_time` to late
+	/// subscribers.
+	///
+	/// It follows the reset semantics of RxJS 7's `shareReplay`:
+	///
+	/// - Upstream errors reset the replay buffer, the next subscriber
+	///   resubscribes to the source.
+	/// - Upstream completion does not, late subscribers receive the replayed
+	///   values followed by the completion, without resubscribing to the source.
+	/// - Reaching zero subscribers only unsubscribes the source and forgets the
+	///   replayed values when `ref_count` is enabled.
+	///
+	/// ## Options
+	///
+	/// Use [ShareReplayOptions] to configure the replay buffer.
+	///
+	/// - `buffer_size`: How many values are replayed, `None` means unbounded.
+	///   Default: `None`.
+	/// - `window_time`: How long values are replayed, `None` means forever.
+	///   Default: `None`.
+	/// - `ref_count`: Whether to unsubscribe the source when there are no
+	///   subscribers left.
+	///   Default: `false`.
+	#[inline]
+	fn share_replay<S>(
+		self,
+		options: ShareReplayOptions,
+		scheduler: SchedulerHandle<S>,
+	) -> <ShareReplayOperator<Self::Out, Self::OutError, S> as Operator<'o>>::OutObservable<Self>
+	where
+		Self::Out: Clone,
+		Self::OutError: Clone,
+		S: 'static + SchedulerClock,
+	{
+		ShareReplayOperator::new(options, scheduler).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionShareReplay<'o> for O where O: 'o + Observable + Send + Sync {}
@@ -0,0 +1,106 @@
+use core::marker::PhantomData;
+use std::time::Duration;
+
+use rx_core_common::{
+	Observable, Operator, PhantomInvariant, SchedulerClock, SchedulerHandle, Signal,
+};
+use rx_core_macro_operator_derive::RxOperator;
+use rx_core_observable_connectable::observable::ConnectableOptions;
+use rx_core_operator_share::observable::ShareObservable;
+use rx_core_subject_replay::subject::{ReplayWindowOptions, ReplayWindowSubjectProvider};
+
+use crate::ShareReplayOptions;
+
+/// # [ShareReplayOperator]
+///
+/// The `share_replay` operator shares a single upstream subscription across
+/// every downstream subscriber, just like `share`, while also replaying the
+/// last `buffer_size` values observed within the last `window_time` to late
+/// subscribers.
+///
+/// It follows the reset semantics of RxJS 7's `shareReplay`:
+///
+/// - Upstream errors reset the replay buffer, the next subscriber
+///   resubscribes to the source.
+/// - Upstream completion does not, late subscribers receive the replayed
+///   values followed by the completion, without resubscribing to the source.
+/// - Reaching zero subscribers only unsubscribes the source and forgets the
+///   replayed values when `ref_count` is enabled.
+///
+/// ## Options
+///
+/// Use [ShareReplayOptions] to configure the replay buffer.
+///
+/// - `buffer_size`: How many values are replayed, `None` means unbounded.
+///   Default: `None`.
+/// - `window_time`: How long values are replayed, `None` means forever.
+///   Default: `None`.
+/// - `ref_count`: Whether to unsubscribe the source when there are no
+///   subscribers left.
+///   Default: `false`.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct ShareReplayOperator<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + SchedulerClock,
+{
+	options: ShareReplayOptions,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, S> ShareReplayOperator<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + SchedulerClock,
+{
+	pub fn new(options: ShareReplayOptions, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			options,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<'o, In, InError, S> Operator<'o> for ShareReplayOperator<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + SchedulerClock,
+{
+	type OutObservable<InObservable>
+		= ShareObservable<InObservable, ReplayWindowSubjectProvider<In, InError, S>>
+	where
+		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync;
+
+	fn operate<InObservable>(self, source: InObservable) -> Self::OutObservable<InObservable>
+	where
+		InObservable: 'o + Observable<Out = Self::In, OutError = Self::InError> + Send + Sync,
+	{
+		let replay_window_options = ReplayWindowOptions {
+			window_time: self.options.window_time.unwrap_or(Duration::MAX),
+			capacity: self.options.buffer_size,
+		};
+
+		ShareObservable::new(
+			source,
+			ConnectableOptions {
+				connector_provider: ReplayWindowSubjectProvider::new(
+					replay_window_options,
+					self.scheduler,
+				),
+				disconnect_when_ref_count_zero: self.options.ref_count,
+				reset_connector_on_disconnect: self.options.ref_count,
+				reset_connector_on_error: true,
+				reset_connector_on_complete: false,
+			},
+		)
+	}
+}
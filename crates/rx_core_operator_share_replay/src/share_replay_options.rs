@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+/// Options for configuring the `share_replay` operator.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ShareReplayOptions {
+	/// The maximum number of values replayed to late subscribers, `None`
+	/// means unbounded.
+	/// Default: `None`.
+	pub buffer_size: Option<usize>,
+	/// How long an observed value is kept around for replaying, `None` means
+	/// forever.
+	/// Default: `None`.
+	pub window_time: Option<Duration>,
+	/// When true, the source is unsubscribed once every subscriber has
+	/// unsubscribed, and the replayed values are forgotten. When false, the
+	/// source is kept alive even without subscribers.
+	/// Default: `false`.
+	pub ref_count: bool,
+}
+
+impl ShareReplayOptions {
+	/// Creates options replaying the last `buffer_size` values.
+	pub fn new(buffer_size: usize) -> Self {
+		Self {
+			buffer_size: Some(buffer_size),
+			..Default::default()
+		}
+	}
+
+	#[must_use]
+	pub fn with_window_time(mut self, window_time: Duration) -> Self {
+		self.window_time = Some(window_time);
+		self
+	}
+
+	#[must_use]
+	pub fn with_ref_count(mut self) -> Self {
+		self.ref_count = true;
+		self
+	}
+}
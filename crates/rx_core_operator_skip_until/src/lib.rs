@@ -0,0 +1,26 @@
+mod skip_until_notifier_destination;
+mod skip_until_operator;
+mod skip_until_subscriber;
+
+pub use skip_until_notifier_destination::*;
+pub use skip_until_subscriber::*;
+
+pub mod operator {
+	pub use super::skip_until_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod skip_until_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::skip_until_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod skip_until_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::skip_until_extension_pipe::*;
+}
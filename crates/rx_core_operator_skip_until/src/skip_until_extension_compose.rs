@@ -0,0 +1,28 @@
+use rx_core_common::{ComposableOperator, Observable};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::SkipUntilOperator;
+
+pub trait OperatorComposeExtensionSkipUntil: ComposableOperator + Sized {
+	/// # [SkipUntilOperator]
+	///
+	/// Skips upstream values until the `notifier` emits, then mirrors upstream.
+	///
+	/// - The `notifier` is subscribed to before upstream, so a `notifier` that
+	///   emits immediately lets every upstream value through.
+	/// - The `notifier` completing without emitting skips every upstream value.
+	/// - Errors, from upstream or from the `notifier`, are forwarded downstream.
+	/// - The `notifier` subscription is torn down together with the subscriber.
+	#[inline]
+	fn skip_until<NotifierObservable>(
+		self,
+		notifier: NotifierObservable,
+	) -> CompositeOperator<Self, SkipUntilOperator<NotifierObservable, Self::Out, Self::OutError>>
+	where
+		NotifierObservable: 'static + Observable<OutError = Self::OutError>,
+	{
+		self.compose_with(SkipUntilOperator::new(notifier))
+	}
+}
+
+impl<Op> OperatorComposeExtensionSkipUntil for Op where Op: ComposableOperator {}
@@ -0,0 +1,29 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::SkipUntilOperator;
+
+pub trait ObservablePipeExtensionSkipUntil<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [SkipUntilOperator]
+	///
+	/// Skips upstream values until the `notifier` emits, then mirrors upstream.
+	///
+	/// - The `notifier` is subscribed to before upstream, so a `notifier` that
+	///   emits immediately lets every upstream value through.
+	/// - The `notifier` completing without emitting skips every upstream value.
+	/// - Errors, from upstream or from the `notifier`, are forwarded downstream.
+	/// - The `notifier` subscription is torn down together with the subscriber.
+	#[inline]
+	fn skip_until<NotifierObservable>(
+		self,
+		notifier: NotifierObservable,
+	) -> <SkipUntilOperator<NotifierObservable, Self::Out, Self::OutError> as Operator<'o>>::OutObservable<
+		Self,
+	>
+	where
+		NotifierObservable: 'static + Observable<OutError = Self::OutError>,
+	{
+		SkipUntilOperator::new(notifier).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionSkipUntil<'o> for O where O: 'o + Observable + Send + Sync {}
@@ -0,0 +1,73 @@
+use std::{
+	marker::PhantomData,
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+};
+
+use rx_core_common::{
+	PhantomInvariant, RxObserver, SharedSubscriber, SharedSubscription, Signal, Subscriber,
+	SubscriptionLike,
+};
+use rx_core_macro_observer_derive::RxObserver;
+
+/// Lets upstream values through once the notifier emits, then unsubscribes
+/// from the notifier as it has nothing left to do.
+#[derive(RxObserver)]
+#[rx_in(NotifierOut)]
+#[rx_in_error(Destination::InError)]
+pub struct SkipUntilNotifierDestination<NotifierOut, Destination>
+where
+	NotifierOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	skipping: Arc<AtomicBool>,
+	notifier_subscription: SharedSubscription,
+	destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<NotifierOut>,
+}
+
+impl<NotifierOut, Destination> SkipUntilNotifierDestination<NotifierOut, Destination>
+where
+	NotifierOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	pub(crate) fn new(
+		skipping: Arc<AtomicBool>,
+		notifier_subscription: SharedSubscription,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		Self {
+			skipping,
+			notifier_subscription,
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<NotifierOut, Destination> RxObserver for SkipUntilNotifierDestination<NotifierOut, Destination>
+where
+	NotifierOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	fn next(&mut self, _next: Self::In) {
+		if !self.notifier_subscription.is_closed() {
+			self.skipping.store(false, Ordering::Relaxed);
+			self.notifier_subscription.unsubscribe();
+		}
+	}
+
+	/// Forwarded even after the notifier had emitted, if it still manages to
+	/// error before it could be unsubscribed.
+	fn error(&mut self, error: Self::InError) {
+		if !self.destination.is_closed() {
+			self.destination.error(error);
+		}
+	}
+
+	/// A notifier completing without emitting keeps skipping upstream values.
+	#[inline]
+	fn complete(&mut self) {}
+}
@@ -0,0 +1,103 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicBool, Ordering},
+};
+
+use rx_core_common::{
+	Observable, RxObserver, SharedSubscriber, SharedSubscription, Subscriber, SubscriptionLike,
+	TeardownCollectionExtension,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::SkipUntilNotifierDestination;
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+pub struct SkipUntilSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	skipping: Arc<AtomicBool>,
+}
+
+impl<Destination> SkipUntilSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	pub fn new<NotifierObservable>(
+		destination: Destination,
+		notifier: &mut NotifierObservable,
+	) -> Self
+	where
+		NotifierObservable: 'static + Observable<OutError = Destination::InError>,
+	{
+		let mut destination = SharedSubscriber::new(destination);
+		let skipping = Arc::new(AtomicBool::new(true));
+
+		// Stops notifying once downstream is closed, or once it had emitted
+		let mut notifier_subscription = SharedSubscription::default();
+		destination.add(notifier_subscription.clone());
+
+		let subscription = notifier.subscribe(SkipUntilNotifierDestination::<
+			NotifierObservable::Out,
+			Destination,
+		>::new(
+			skipping.clone(),
+			notifier_subscription.clone(),
+			destination.clone(),
+		));
+		notifier_subscription.add(subscription);
+
+		Self {
+			destination,
+			skipping,
+		}
+	}
+}
+
+impl<Destination> RxObserver for SkipUntilSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		if !self.skipping.load(Ordering::Relaxed) && !self.destination.is_closed() {
+			self.destination.next(next);
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		if !self.destination.is_closed() {
+			self.destination.error(error);
+		}
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		if !self.destination.is_closed() {
+			self.destination.complete();
+		}
+	}
+}
+
+impl<Destination> SubscriptionLike for SkipUntilSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,24 @@
+mod skip_while_operator;
+mod skip_while_subscriber;
+
+pub use skip_while_subscriber::*;
+
+pub mod operator {
+	pub use super::skip_while_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod skip_while_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::skip_while_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod skip_while_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::skip_while_extension_pipe::*;
+}
@@ -0,0 +1,23 @@
+use rx_core_common::ComposableOperator;
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::SkipWhileOperator;
+
+pub trait OperatorComposeExtensionSkipWhile: ComposableOperator + Sized {
+	/// # [SkipWhileOperator]
+	///
+	/// Skips upstream values as long as they pass the `predicate`, then mirrors
+	/// every value starting with the first one that does not.
+	///
+	/// The `predicate` also receives the index of the value, and is no longer
+	/// called once a value failed it.
+	#[inline]
+	fn skip_while<Predicate: 'static + Fn(&Self::Out, usize) -> bool + Clone + Send + Sync>(
+		self,
+		predicate: Predicate,
+	) -> CompositeOperator<Self, SkipWhileOperator<Self::Out, Self::OutError, Predicate>> {
+		self.compose_with(SkipWhileOperator::new(predicate))
+	}
+}
+
+impl<Op> OperatorComposeExtensionSkipWhile for Op where Op: ComposableOperator {}
@@ -0,0 +1,24 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::SkipWhileOperator;
+
+pub trait ObservablePipeExtensionSkipWhile<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [SkipWhileOperator]
+	///
+	/// Skips upstream values as long as they pass the `predicate`, then mirrors
+	/// every value starting with the first one that does not.
+	///
+	/// The `predicate` also receives the index of the value, and is no longer
+	/// called once a value failed it.
+	#[inline]
+	fn skip_while<Predicate: 'static + Fn(&Self::Out, usize) -> bool + Clone + Send + Sync>(
+		self,
+		predicate: Predicate,
+	) -> <SkipWhileOperator<Self::Out, Self::OutError, Predicate> as Operator<'o>>::OutObservable<
+		Self,
+	> {
+		SkipWhileOperator::new(predicate).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionSkipWhile<'o> for O where O: 'o + Observable + Send + Sync {}
@@ -0,0 +1,67 @@
+use core::marker::PhantomData;
+
+use derive_where::derive_where;
+use rx_core_common::{ComposableOperator, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::SkipWhileSubscriber;
+
+/// # [SkipWhileOperator]
+///
+/// Skips upstream values as long as they pass the `predicate`, then mirrors
+/// every value starting with the first one that does not.
+///
+/// The `predicate` also receives the index of the value, and is no longer
+/// called once a value failed it.
+#[derive_where(Clone)]
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct SkipWhileOperator<In, InError, Predicate>
+where
+	In: Signal,
+	InError: Signal,
+	Predicate: 'static + for<'a> Fn(&'a In, usize) -> bool + Clone + Send + Sync,
+{
+	predicate: Predicate,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, Predicate> SkipWhileOperator<In, InError, Predicate>
+where
+	In: Signal,
+	InError: Signal,
+	Predicate: 'static + for<'a> Fn(&'a In, usize) -> bool + Clone + Send + Sync,
+{
+	pub fn new(predicate: Predicate) -> Self {
+		Self {
+			predicate,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, Predicate> ComposableOperator for SkipWhileOperator<In, InError, Predicate>
+where
+	In: Signal,
+	InError: Signal,
+	Predicate: 'static + for<'a> Fn(&'a In, usize) -> bool + Clone + Send + Sync,
+{
+	type Subscriber<Destination>
+		= SkipWhileSubscriber<Predicate, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		SkipWhileSubscriber::new(destination, self.predicate.clone())
+	}
+}
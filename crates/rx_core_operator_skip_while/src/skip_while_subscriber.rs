@@ -0,0 +1,62 @@
+use rx_core_common::{RxObserver, Subscriber};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_delegate_subscription_like_to_destination]
+pub struct SkipWhileSubscriber<Predicate, Destination>
+where
+	Predicate: for<'a> Fn(&'a Destination::In, usize) -> bool,
+	Destination: Subscriber,
+{
+	#[destination]
+	destination: Destination,
+	predicate: Predicate,
+	index: usize,
+	skipping: bool,
+}
+
+impl<Predicate, Destination> SkipWhileSubscriber<Predicate, Destination>
+where
+	Predicate: for<'a> Fn(&'a Destination::In, usize) -> bool,
+	Destination: Subscriber,
+{
+	pub fn new(destination: Destination, predicate: Predicate) -> Self {
+		Self {
+			destination,
+			predicate,
+			index: 0,
+			skipping: true,
+		}
+	}
+}
+
+impl<Predicate, Destination> RxObserver for SkipWhileSubscriber<Predicate, Destination>
+where
+	Predicate: for<'a> Fn(&'a Destination::In, usize) -> bool + Send + Sync,
+	Destination: Subscriber,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		if self.skipping {
+			self.skipping = (self.predicate)(&next, self.index);
+			self.index = self.index.saturating_add(1);
+		}
+
+		if !self.skipping {
+			self.destination.next(next);
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.destination.error(error);
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.destination.complete();
+	}
+}
@@ -0,0 +1,26 @@
+mod take_until_notifier_destination;
+mod take_until_operator;
+mod take_until_subscriber;
+
+pub use take_until_notifier_destination::*;
+pub use take_until_subscriber::*;
+
+pub mod operator {
+	pub use super::take_until_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod take_until_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::take_until_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod take_until_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::take_until_extension_pipe::*;
+}
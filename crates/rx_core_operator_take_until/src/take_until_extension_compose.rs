@@ -0,0 +1,28 @@
+use rx_core_common::{ComposableOperator, Observable};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::TakeUntilOperator;
+
+pub trait OperatorComposeExtensionTakeUntil: ComposableOperator + Sized {
+	/// # [TakeUntilOperator]
+	///
+	/// Mirrors upstream until the `notifier` emits, then completes.
+	///
+	/// - The `notifier` is subscribed to before upstream, so a `notifier` that
+	///   emits immediately completes downstream before any upstream value.
+	/// - The `notifier` completing without emitting does not complete downstream.
+	/// - Errors, from upstream or from the `notifier`, are forwarded downstream.
+	/// - The `notifier` subscription is torn down together with the subscriber.
+	#[inline]
+	fn take_until<NotifierObservable>(
+		self,
+		notifier: NotifierObservable,
+	) -> CompositeOperator<Self, TakeUntilOperator<NotifierObservable, Self::Out, Self::OutError>>
+	where
+		NotifierObservable: 'static + Observable<OutError = Self::OutError>,
+	{
+		self.compose_with(TakeUntilOperator::new(notifier))
+	}
+}
+
+impl<Op> OperatorComposeExtensionTakeUntil for Op where Op: ComposableOperator {}
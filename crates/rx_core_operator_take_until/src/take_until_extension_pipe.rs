@@ -0,0 +1,29 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::TakeUntilOperator;
+
+pub trait ObservablePipeExtensionTakeUntil<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [TakeUntilOperator]
+	///
+	/// Mirrors upstream until the `notifier` emits, then completes.
+	///
+	/// - The `notifier` is subscribed to before upstream, so a `notifier` that
+	///   emits immediately completes downstream before any upstream value.
+	/// - The `notifier` completing without emitting does not complete downstream.
+	/// - Errors, from upstream or from the `notifier`, are forwarded downstream.
+	/// - The `notifier` subscription is torn down together with the subscriber.
+	#[inline]
+	fn take_until<NotifierObservable>(
+		self,
+		notifier: NotifierObservable,
+	) -> <TakeUntilOperator<NotifierObservable, Self::Out, Self::OutError> as Operator<'o>>::OutObservable<
+		Self,
+	>
+	where
+		NotifierObservable: 'static + Observable<OutError = Self::OutError>,
+	{
+		TakeUntilOperator::new(notifier).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionTakeUntil<'o> for O where O: 'o + Observable + Send + Sync {}
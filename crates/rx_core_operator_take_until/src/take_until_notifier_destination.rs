@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+
+use rx_core_common::{
+	PhantomInvariant, RxObserver, SharedSubscriber, Signal, Subscriber, SubscriptionLike,
+};
+use rx_core_macro_observer_derive::RxObserver;
+
+/// Completes downstream the first time the notifier emits.
+#[derive(RxObserver)]
+#[rx_in(NotifierOut)]
+#[rx_in_error(Destination::InError)]
+pub struct TakeUntilNotifierDestination<NotifierOut, Destination>
+where
+	NotifierOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<NotifierOut>,
+}
+
+impl<NotifierOut, Destination> TakeUntilNotifierDestination<NotifierOut, Destination>
+where
+	NotifierOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	pub(crate) fn new(destination: SharedSubscriber<Destination>) -> Self {
+		Self {
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<NotifierOut, Destination> RxObserver for TakeUntilNotifierDestination<NotifierOut, Destination>
+where
+	NotifierOut: Signal,
+	Destination: 'static + Subscriber,
+{
+	fn next(&mut self, _next: Self::In) {
+		if !self.destination.is_closed() {
+			self.destination.complete();
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		if !self.destination.is_closed() {
+			self.destination.error(error);
+		}
+	}
+
+	/// A notifier completing without emitting never completes downstream.
+	#[inline]
+	fn complete(&mut self) {}
+}
@@ -0,0 +1,68 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{ComposableOperator, Never, Observable, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::TakeUntilSubscriber;
+
+/// # [TakeUntilOperator]
+///
+/// Mirrors upstream until the `notifier` emits, then completes.
+///
+/// - The `notifier` is subscribed to before upstream, so a `notifier` that
+///   emits immediately completes downstream before any upstream value.
+/// - The `notifier` completing without emitting does not complete downstream.
+/// - Errors, from upstream or from the `notifier`, are forwarded downstream.
+/// - The `notifier` subscription is torn down together with the subscriber.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct TakeUntilOperator<NotifierObservable, In, InError = Never>
+where
+	NotifierObservable: 'static + Observable<OutError = InError>,
+	In: Signal,
+	InError: Signal,
+{
+	notifier: NotifierObservable,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<NotifierObservable, In, InError> TakeUntilOperator<NotifierObservable, In, InError>
+where
+	NotifierObservable: 'static + Observable<OutError = InError>,
+	In: Signal,
+	InError: Signal,
+{
+	pub fn new(notifier: NotifierObservable) -> Self {
+		Self {
+			notifier,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<NotifierObservable, In, InError> ComposableOperator
+	for TakeUntilOperator<NotifierObservable, In, InError>
+where
+	NotifierObservable: 'static + Observable<OutError = InError>,
+	In: Signal,
+	InError: Signal,
+{
+	type Subscriber<Destination>
+		= TakeUntilSubscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		TakeUntilSubscriber::new(destination, &mut self.notifier)
+	}
+}
@@ -0,0 +1,86 @@
+use rx_core_common::{
+	Observable, RxObserver, SharedSubscriber, Subscriber, SubscriptionLike,
+	TeardownCollectionExtension,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::TakeUntilNotifierDestination;
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+pub struct TakeUntilSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+}
+
+impl<Destination> TakeUntilSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	pub fn new<NotifierObservable>(
+		destination: Destination,
+		notifier: &mut NotifierObservable,
+	) -> Self
+	where
+		NotifierObservable: 'static + Observable<OutError = Destination::InError>,
+	{
+		let mut destination = SharedSubscriber::new(destination);
+
+		let notifier_subscription = notifier.subscribe(TakeUntilNotifierDestination::<
+			NotifierObservable::Out,
+			Destination,
+		>::new(destination.clone()));
+		// Stops notifying once downstream is closed
+		destination.add(notifier_subscription);
+
+		Self { destination }
+	}
+}
+
+impl<Destination> RxObserver for TakeUntilSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		if !self.destination.is_closed() {
+			self.destination.next(next);
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		if !self.destination.is_closed() {
+			self.destination.error(error);
+		}
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		if !self.destination.is_closed() {
+			self.destination.complete();
+		}
+	}
+}
+
+impl<Destination> SubscriptionLike for TakeUntilSubscriber<Destination>
+where
+	Destination: 'static + Subscriber,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
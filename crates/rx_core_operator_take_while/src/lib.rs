@@ -0,0 +1,24 @@
+mod take_while_operator;
+mod take_while_subscriber;
+
+pub use take_while_subscriber::*;
+
+pub mod operator {
+	pub use super::take_while_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod take_while_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::take_while_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod take_while_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::take_while_extension_pipe::*;
+}
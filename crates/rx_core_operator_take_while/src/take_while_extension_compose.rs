@@ -0,0 +1,26 @@
+use rx_core_common::ComposableOperator;
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::TakeWhileOperator;
+
+pub trait OperatorComposeExtensionTakeWhile: ComposableOperator + Sized {
+	/// # [TakeWhileOperator]
+	///
+	/// Mirrors upstream values as long as they pass the `predicate`, then
+	/// completes on the first value that does not.
+	///
+	/// The `predicate` also receives the index of the value.
+	///
+	/// When `inclusive` is true, the first value failing the `predicate` is also
+	/// emitted before completing.
+	#[inline]
+	fn take_while<Predicate: 'static + Fn(&Self::Out, usize) -> bool + Clone + Send + Sync>(
+		self,
+		predicate: Predicate,
+		inclusive: bool,
+	) -> CompositeOperator<Self, TakeWhileOperator<Self::Out, Self::OutError, Predicate>> {
+		self.compose_with(TakeWhileOperator::new(predicate, inclusive))
+	}
+}
+
+impl<Op> OperatorComposeExtensionTakeWhile for Op where Op: ComposableOperator {}
@@ -0,0 +1,27 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::TakeWhileOperator;
+
+pub trait ObservablePipeExtensionTakeWhile<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [TakeWhileOperator]
+	///
+	/// Mirrors upstream values as long as they pass the `predicate`, then
+	/// completes on the first value that does not.
+	///
+	/// The `predicate` also receives the index of the value.
+	///
+	/// When `inclusive` is true, the first value failing the `predicate` is also
+	/// emitted before completing.
+	#[inline]
+	fn take_while<Predicate: 'static + Fn(&Self::Out, usize) -> bool + Clone + Send + Sync>(
+		self,
+		predicate: Predicate,
+		inclusive: bool,
+	) -> <TakeWhileOperator<Self::Out, Self::OutError, Predicate> as Operator<'o>>::OutObservable<
+		Self,
+	> {
+		TakeWhileOperator::new(predicate, inclusive).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionTakeWhile<'o> for O where O: 'o + Observable + Send + Sync {}
@@ -0,0 +1,71 @@
+use core::marker::PhantomData;
+
+use derive_where::derive_where;
+use rx_core_common::{ComposableOperator, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::TakeWhileSubscriber;
+
+/// # [TakeWhileOperator]
+///
+/// Mirrors upstream values as long as they pass the `predicate`, then
+/// completes on the first value that does not.
+///
+/// The `predicate` also receives the index of the value.
+///
+/// When `inclusive` is true, the first value failing the `predicate` is also
+/// emitted before completing.
+#[derive_where(Clone)]
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+pub struct TakeWhileOperator<In, InError, Predicate>
+where
+	In: Signal,
+	InError: Signal,
+	Predicate: 'static + for<'a> Fn(&'a In, usize) -> bool + Clone + Send + Sync,
+{
+	predicate: Predicate,
+	inclusive: bool,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, Predicate> TakeWhileOperator<In, InError, Predicate>
+where
+	In: Signal,
+	InError: Signal,
+	Predicate: 'static + for<'a> Fn(&'a In, usize) -> bool + Clone + Send + Sync,
+{
+	pub fn new(predicate: Predicate, inclusive: bool) -> Self {
+		Self {
+			predicate,
+			inclusive,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, Predicate> ComposableOperator for TakeWhileOperator<In, InError, Predicate>
+where
+	In: Signal,
+	InError: Signal,
+	Predicate: 'static + for<'a> Fn(&'a In, usize) -> bool + Clone + Send + Sync,
+{
+	type Subscriber<Destination>
+		= TakeWhileSubscriber<Predicate, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		TakeWhileSubscriber::new(destination, self.predicate.clone(), self.inclusive)
+	}
+}
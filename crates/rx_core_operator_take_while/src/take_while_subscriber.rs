@@ -0,0 +1,108 @@
+use rx_core_common::{RxObserver, Subscriber, SubscriptionClosedFlag, SubscriptionLike};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+#[derive(RxSubscriber)]
+#[rx_in(Destination::In)]
+#[rx_in_error(Destination::InError)]
+#[rx_delegate_teardown_collection]
+#[rx_skip_unsubscribe_on_drop_impl]
+pub struct TakeWhileSubscriber<Predicate, Destination>
+where
+	Predicate: for<'a> Fn(&'a Destination::In, usize) -> bool,
+	Destination: Subscriber,
+{
+	#[destination]
+	destination: Destination,
+	predicate: Predicate,
+	inclusive: bool,
+	index: usize,
+	/// Closedness is tracked in case downstream doesn't immediately reflect it.
+	closed: SubscriptionClosedFlag,
+}
+
+impl<Predicate, Destination> TakeWhileSubscriber<Predicate, Destination>
+where
+	Predicate: for<'a> Fn(&'a Destination::In, usize) -> bool,
+	Destination: Subscriber,
+{
+	pub fn new(destination: Destination, predicate: Predicate, inclusive: bool) -> Self {
+		let closed: SubscriptionClosedFlag = destination.is_closed().into();
+
+		Self {
+			destination,
+			predicate,
+			inclusive,
+			index: 0,
+			closed,
+		}
+	}
+}
+
+impl<Predicate, Destination> RxObserver for TakeWhileSubscriber<Predicate, Destination>
+where
+	Predicate: for<'a> Fn(&'a Destination::In, usize) -> bool + Send + Sync,
+	Destination: Subscriber,
+{
+	fn next(&mut self, next: Self::In) {
+		if self.is_closed() {
+			return;
+		}
+
+		let passed = (self.predicate)(&next, self.index);
+		self.index = self.index.saturating_add(1);
+
+		if passed {
+			self.destination.next(next);
+		} else {
+			if self.inclusive {
+				self.destination.next(next);
+			}
+
+			if !self.is_closed() {
+				self.complete();
+			}
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.destination.error(error);
+		self.closed.close();
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.destination.complete();
+		self.closed.close();
+	}
+}
+
+impl<Predicate, Destination> SubscriptionLike for TakeWhileSubscriber<Predicate, Destination>
+where
+	Predicate: for<'a> Fn(&'a Destination::In, usize) -> bool,
+	Destination: Subscriber,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		*self.closed || self.destination.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.is_closed() {
+			self.destination.unsubscribe();
+		}
+		self.closed.close();
+	}
+}
+
+impl<Predicate, Destination> Drop for TakeWhileSubscriber<Predicate, Destination>
+where
+	Predicate: for<'a> Fn(&'a Destination::In, usize) -> bool,
+	Destination: Subscriber,
+{
+	#[inline]
+	fn drop(&mut self) {
+		self.unsubscribe();
+	}
+}
@@ -0,0 +1,32 @@
+mod timeout_config;
+mod timeout_error;
+mod timeout_fallback_destination;
+mod timeout_operator;
+mod timeout_subscriber;
+mod timeout_with;
+
+pub use timeout_fallback_destination::*;
+pub use timeout_subscriber::*;
+
+pub mod operator {
+	pub use super::timeout_config::*;
+	pub use super::timeout_error::*;
+	pub use super::timeout_operator::*;
+	pub use super::timeout_with::*;
+}
+
+#[cfg(feature = "compose")]
+mod timeout_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::timeout_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod timeout_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::timeout_extension_pipe::*;
+}
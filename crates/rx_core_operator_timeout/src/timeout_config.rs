@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use crate::operator::TimeoutWithError;
+
+/// Decides when the [TimeoutOperator][crate::operator::TimeoutOperator]
+/// times out, and what happens then.
+///
+/// When neither `first` nor `each` is set, the operator never times out.
+#[derive(Clone, Debug)]
+pub struct TimeoutConfig<With = TimeoutWithError> {
+	/// How long to wait for the first value after subscribing. Defaults to
+	/// `each` when not set.
+	pub first: Option<Duration>,
+	/// How long to wait for every value after the previous one. When only
+	/// `first` is set, there is no time limit after the first value.
+	pub each: Option<Duration>,
+	/// What to do once timed out. Either [TimeoutWithError] to error with
+	/// [TimeoutError::Timeout][crate::operator::TimeoutError::Timeout], or a
+	/// fallback observable to switch to.
+	pub with: With,
+}
+
+impl Default for TimeoutConfig {
+	fn default() -> Self {
+		Self {
+			first: None,
+			each: None,
+			with: TimeoutWithError,
+		}
+	}
+}
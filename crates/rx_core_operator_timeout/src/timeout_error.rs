@@ -0,0 +1,15 @@
+use rx_core_common::Signal;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub enum TimeoutError<InError>
+where
+	InError: Signal,
+{
+	/// No value was observed within the configured duration.
+	#[error("TimeoutError::Timeout")]
+	Timeout,
+	/// An error of upstream, or of the fallback observable.
+	#[error(transparent)]
+	Upstream(InError),
+}
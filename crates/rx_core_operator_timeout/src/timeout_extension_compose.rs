@@ -0,0 +1,32 @@
+use rx_core_common::{ComposableOperator, Scheduler, SchedulerHandle};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::{TimeoutConfig, TimeoutOperator, TimeoutWith};
+
+pub trait OperatorComposeExtensionTimeout: ComposableOperator + Sized {
+	/// # [TimeoutOperator]
+	///
+	/// Mirrors upstream as long as values keep arriving in time. Once they
+	/// don't, upstream is unsubscribed and the operator either errors with
+	/// [TimeoutError::Timeout] or switches to a fallback observable, depending on
+	/// `with` in the [TimeoutConfig].
+	///
+	/// - `first` limits how long to wait for the first value, `each` limits the
+	///   time between values (and the first one too, if `first` is not set).
+	/// - Upstream errors are forwarded as [TimeoutError::Upstream], and so are
+	///   the errors of the fallback observable.
+	#[inline]
+	fn timeout<With, S>(
+		self,
+		config: TimeoutConfig<With>,
+		scheduler: SchedulerHandle<S>,
+	) -> CompositeOperator<Self, TimeoutOperator<Self::Out, Self::OutError, With, S>>
+	where
+		With: TimeoutWith<Self::Out, Self::OutError>,
+		S: 'static + Scheduler + Send + Sync,
+	{
+		self.compose_with(TimeoutOperator::new(config, scheduler))
+	}
+}
+
+impl<Op> OperatorComposeExtensionTimeout for Op where Op: ComposableOperator {}
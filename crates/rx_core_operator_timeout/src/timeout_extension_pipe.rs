@@ -0,0 +1,31 @@
+use rx_core_common::{Observable, Operator, Scheduler, SchedulerHandle};
+
+use crate::operator::{TimeoutConfig, TimeoutOperator, TimeoutWith};
+
+pub trait ObservablePipeExtensionTimeout<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [TimeoutOperator]
+	///
+	/// Mirrors upstream as long as values keep arriving in time. Once they
+	/// don't, upstream is unsubscribed and the operator either errors with
+	/// [TimeoutError::Timeout] or switches to a fallback observable, depending on
+	/// `with` in the [TimeoutConfig].
+	///
+	/// - `first` limits how long to wait for the first value, `each` limits the
+	///   time between values (and the first one too, if `first` is not set).
+	/// - Upstream errors are forwarded as [TimeoutError::Upstream], and so are
+	///   the errors of the fallback observable.
+	#[inline]
+	fn timeout<With, S>(
+		self,
+		config: TimeoutConfig<With>,
+		scheduler: SchedulerHandle<S>,
+	) -> <TimeoutOperator<Self::Out, Self::OutError, With, S> as Operator<'o>>::OutObservable<Self>
+	where
+		With: TimeoutWith<Self::Out, Self::OutError>,
+		S: 'static + Scheduler + Send + Sync,
+	{
+		TimeoutOperator::new(config, scheduler).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionTimeout<'o> for O where O: 'o + Observable + Send + Sync {}
@@ -0,0 +1,61 @@
+use std::marker::PhantomData;
+
+use rx_core_common::{
+	PhantomInvariant, RxObserver, SharedSubscriber, Signal, Subscriber, SubscriptionLike,
+};
+use rx_core_macro_observer_derive::RxObserver;
+
+use crate::operator::TimeoutError;
+
+/// Forwards the fallback observable downstream after a timeout.
+#[derive(RxObserver)]
+#[rx_in(Destination::In)]
+#[rx_in_error(InError)]
+pub struct TimeoutFallbackDestination<InError, Destination>
+where
+	InError: Signal,
+	Destination: 'static + Subscriber<InError = TimeoutError<InError>>,
+{
+	destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<InError>,
+}
+
+impl<InError, Destination> TimeoutFallbackDestination<InError, Destination>
+where
+	InError: Signal,
+	Destination: 'static + Subscriber<InError = TimeoutError<InError>>,
+{
+	pub(crate) fn new(destination: SharedSubscriber<Destination>) -> Self {
+		Self {
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<InError, Destination> RxObserver for TimeoutFallbackDestination<InError, Destination>
+where
+	InError: Signal,
+	Destination: 'static + Subscriber<InError = TimeoutError<InError>>,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		if !self.destination.is_closed() {
+			self.destination.next(next);
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		if !self.destination.is_closed() {
+			self.destination.error(TimeoutError::Upstream(error));
+		}
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		if !self.destination.is_closed() {
+			self.destination.complete();
+		}
+	}
+}
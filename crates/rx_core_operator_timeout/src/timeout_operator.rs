@@ -0,0 +1,79 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{
+	ComposableOperator, PhantomInvariant, Scheduler, SchedulerHandle, Signal, Subscriber,
+};
+use rx_core_macro_operator_derive::RxOperator;
+
+use crate::{
+	TimeoutSubscriber,
+	operator::{TimeoutConfig, TimeoutError, TimeoutWith},
+};
+
+/// # [TimeoutOperator]
+///
+/// Mirrors upstream as long as values keep arriving in time. Once they
+/// don't, upstream is unsubscribed and the operator either errors with
+/// [TimeoutError::Timeout] or switches to a fallback observable, depending on
+/// `with` in the [TimeoutConfig].
+///
+/// - `first` limits how long to wait for the first value, `each` limits the
+///   time between values (and the first one too, if `first` is not set).
+/// - Upstream errors are forwarded as [TimeoutError::Upstream], and so are
+///   the errors of the fallback observable.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(TimeoutError<InError>)]
+pub struct TimeoutOperator<In, InError, With, S>
+where
+	In: Signal,
+	InError: Signal,
+	With: TimeoutWith<In, InError>,
+	S: Scheduler,
+{
+	config: TimeoutConfig<With>,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, With, S> TimeoutOperator<In, InError, With, S>
+where
+	In: Signal,
+	InError: Signal,
+	With: TimeoutWith<In, InError>,
+	S: Scheduler,
+{
+	pub fn new(config: TimeoutConfig<With>, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			config,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, With, S> ComposableOperator for TimeoutOperator<In, InError, With, S>
+where
+	In: Signal,
+	InError: Signal,
+	With: TimeoutWith<In, InError>,
+	S: 'static + Scheduler + Send + Sync,
+{
+	type Subscriber<Destination>
+		= TimeoutSubscriber<In, InError, With, Destination, S>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		TimeoutSubscriber::new(destination, self.config.clone(), self.scheduler.clone())
+	}
+}
@@ -0,0 +1,187 @@
+use core::marker::PhantomData;
+use std::{
+	sync::{
+		Arc, Mutex,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::Duration,
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, PhantomInvariant, RxObserver, Scheduler, SchedulerHandle,
+	SchedulerScheduleWorkExtension, SharedSubscriber, SharedSubscription, Signal, Subscriber,
+	SubscriptionLike, Teardown, TeardownCollection, TeardownCollectionExtension,
+	WorkCancellationId,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+
+use crate::operator::{TimeoutConfig, TimeoutError, TimeoutWith};
+
+#[derive(RxSubscriber)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+pub struct TimeoutSubscriber<In, InError, With, Destination, S>
+where
+	In: Signal,
+	InError: Signal,
+	With: TimeoutWith<In, InError>,
+	Destination: 'static + Subscriber<In = In, InError = TimeoutError<InError>>,
+	S: 'static + Scheduler,
+{
+	#[destination]
+	shared_destination: SharedSubscriber<Destination>,
+	/// Collects the teardowns of upstream, so it can be unsubscribed on its
+	/// own when switching to the fallback observable, while downstream stays
+	/// open.
+	upstream_teardown: SharedSubscription,
+	timed_out: Arc<AtomicBool>,
+	each: Option<Duration>,
+	with: Arc<Mutex<With>>,
+	scheduler: SchedulerHandle<S>,
+	cancellation_id: WorkCancellationId,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, With, Destination, S> TimeoutSubscriber<In, InError, With, Destination, S>
+where
+	In: Signal,
+	InError: Signal,
+	With: TimeoutWith<In, InError>,
+	Destination: 'static + Subscriber<In = In, InError = TimeoutError<InError>> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	pub fn new(
+		destination: Destination,
+		config: TimeoutConfig<With>,
+		scheduler: SchedulerHandle<S>,
+	) -> Self {
+		let cancellation_id = scheduler.lock().generate_cancellation_id();
+		let mut shared_destination = SharedSubscriber::new(destination);
+		shared_destination.add_teardown(Teardown::new_work_cancellation(
+			cancellation_id,
+			scheduler.clone(),
+		));
+
+		let upstream_teardown = SharedSubscription::default();
+		shared_destination.add(upstream_teardown.clone());
+
+		let subscriber = Self {
+			shared_destination,
+			upstream_teardown,
+			timed_out: Arc::new(AtomicBool::new(false)),
+			each: config.each,
+			with: Arc::new(Mutex::new(config.with)),
+			scheduler,
+			cancellation_id,
+			_phantom_data: PhantomData,
+		};
+
+		if let Some(first) = config.first.or(config.each) {
+			subscriber.schedule_timeout(first);
+		}
+
+		subscriber
+	}
+
+	fn schedule_timeout(&self, duration: Duration) {
+		let shared_destination = self.shared_destination.clone();
+		let mut upstream_teardown = self.upstream_teardown.clone();
+		let timed_out = self.timed_out.clone();
+		let with = self.with.clone();
+
+		self.scheduler.lock().schedule_delayed_work(
+			move |_, _| {
+				if shared_destination.is_closed() || timed_out.swap(true, Ordering::Relaxed) {
+					return;
+				}
+
+				upstream_teardown.unsubscribe();
+				with.lock_ignore_poison().on_timeout(shared_destination);
+			},
+			duration,
+			self.cancellation_id,
+		);
+	}
+}
+
+impl<In, InError, With, Destination, S> RxObserver
+	for TimeoutSubscriber<In, InError, With, Destination, S>
+where
+	In: Signal,
+	InError: Signal,
+	With: TimeoutWith<In, InError>,
+	Destination: 'static + Subscriber<In = In, InError = TimeoutError<InError>> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		if self.is_closed() || self.timed_out.load(Ordering::Relaxed) {
+			return;
+		}
+
+		self.scheduler.lock().cancel(self.cancellation_id);
+		self.shared_destination.next(next);
+
+		if let Some(each) = self.each
+			&& !self.is_closed()
+		{
+			self.schedule_timeout(each);
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		if !self.is_closed() && !self.timed_out.load(Ordering::Relaxed) {
+			self.scheduler.lock().cancel(self.cancellation_id);
+			self.shared_destination.error(TimeoutError::Upstream(error));
+		}
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		if !self.is_closed() && !self.timed_out.load(Ordering::Relaxed) {
+			self.scheduler.lock().cancel(self.cancellation_id);
+			self.shared_destination.complete();
+		}
+	}
+}
+
+impl<In, InError, With, Destination, S> TeardownCollection
+	for TimeoutSubscriber<In, InError, With, Destination, S>
+where
+	In: Signal,
+	InError: Signal,
+	With: TimeoutWith<In, InError>,
+	Destination: 'static + Subscriber<In = In, InError = TimeoutError<InError>> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	fn add_teardown(&mut self, teardown: Teardown) {
+		if !self.upstream_teardown.is_closed() {
+			self.upstream_teardown.add_teardown(teardown);
+		} else {
+			teardown.execute();
+		}
+	}
+}
+
+impl<In, InError, With, Destination, S> SubscriptionLike
+	for TimeoutSubscriber<In, InError, With, Destination, S>
+where
+	In: Signal,
+	InError: Signal,
+	With: TimeoutWith<In, InError>,
+	Destination: 'static + Subscriber<In = In, InError = TimeoutError<InError>> + Send + Sync,
+	S: 'static + Scheduler + Send + Sync,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.shared_destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		if !self.is_closed() {
+			self.upstream_teardown.unsubscribe();
+			self.shared_destination.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,56 @@
+use rx_core_common::{
+	Observable, RxObserver, SharedSubscriber, Signal, Subscriber, SubscriptionLike,
+	TeardownCollectionExtension,
+};
+
+use crate::{TimeoutFallbackDestination, operator::TimeoutError};
+
+/// What the [TimeoutOperator][crate::operator::TimeoutOperator] does once it
+/// timed out. Upstream is already unsubscribed by then.
+///
+/// Implemented by [TimeoutWithError] to error downstream, and by observables
+/// to switch to them as a fallback.
+pub trait TimeoutWith<In, InError>: 'static + Clone + Send + Sync
+where
+	In: Signal,
+	InError: Signal,
+{
+	fn on_timeout<Destination>(&mut self, destination: SharedSubscriber<Destination>)
+	where
+		Destination: 'static + Subscriber<In = In, InError = TimeoutError<InError>> + Send + Sync;
+}
+
+/// Errors downstream with [TimeoutError::Timeout] once timed out.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TimeoutWithError;
+
+impl<In, InError> TimeoutWith<In, InError> for TimeoutWithError
+where
+	In: Signal,
+	InError: Signal,
+{
+	fn on_timeout<Destination>(&mut self, mut destination: SharedSubscriber<Destination>)
+	where
+		Destination: 'static + Subscriber<In = In, InError = TimeoutError<InError>> + Send + Sync,
+	{
+		if !destination.is_closed() {
+			destination.error(TimeoutError::Timeout);
+		}
+	}
+}
+
+impl<In, InError, Fallback> TimeoutWith<In, InError> for Fallback
+where
+	In: Signal,
+	InError: Signal,
+	Fallback: 'static + Observable<Out = In, OutError = InError> + Clone + Send + Sync,
+{
+	fn on_timeout<Destination>(&mut self, mut destination: SharedSubscriber<Destination>)
+	where
+		Destination: 'static + Subscriber<In = In, InError = TimeoutError<InError>> + Send + Sync,
+	{
+		let fallback_subscription =
+			self.subscribe(TimeoutFallbackDestination::new(destination.clone()));
+		destination.add(fallback_subscription);
+	}
+}
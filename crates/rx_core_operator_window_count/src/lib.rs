@@ -0,0 +1,24 @@
+mod window_count_operator;
+mod window_count_subscriber;
+
+pub use window_count_subscriber::*;
+
+pub mod operator {
+	pub use super::window_count_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod window_count_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::window_count_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod window_count_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::window_count_extension_pipe::*;
+}
@@ -0,0 +1,20 @@
+use rx_core_common::ComposableOperator;
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::WindowCountOperator;
+
+pub trait OperatorComposeExtensionWindowCount: ComposableOperator + Sized {
+	#[inline]
+	fn window_count(
+		self,
+		window_size: usize,
+	) -> CompositeOperator<Self, WindowCountOperator<Self::Out, Self::OutError>>
+	where
+		Self::Out: Clone,
+		Self::OutError: Clone,
+	{
+		self.compose_with(WindowCountOperator::new(window_size))
+	}
+}
+
+impl<Op> OperatorComposeExtensionWindowCount for Op where Op: ComposableOperator {}
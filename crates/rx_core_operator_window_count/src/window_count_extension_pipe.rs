@@ -0,0 +1,29 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::WindowCountOperator;
+
+pub trait ObservablePipeExtensionWindowCount<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [WindowCountOperator]
+	///
+	/// Splits upstream into windows of `window_size` values, where each window
+	/// is an observable itself. Use a higher-order operator like `merge_all`
+	/// or `concat_all` to process them.
+	///
+	/// ## Arguments
+	///
+	/// - `window_size`: The size of a window. A size of `0` is invalid and
+	///   will use `1` instead.
+	#[inline]
+	fn window_count(
+		self,
+		window_size: usize,
+	) -> <WindowCountOperator<Self::Out, Self::OutError> as Operator<'o>>::OutObservable<Self>
+	where
+		Self::Out: Clone,
+		Self::OutError: Clone,
+	{
+		WindowCountOperator::new(window_size).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionWindowCount<'o> for O where O: 'o + Observable + Send + Sync {}
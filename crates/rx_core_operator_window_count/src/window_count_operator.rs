@@ -0,0 +1,70 @@
+use core::marker::PhantomData;
+use std::num::NonZero;
+
+use derive_where::derive_where;
+use rx_core_common::{ComposableOperator, Never, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+use rx_core_subject_publish::subject::PublishObservable;
+
+use crate::WindowCountSubscriber;
+
+/// # [WindowCountOperator]
+///
+/// Splits upstream into windows of `window_size` values. Each window is a
+/// [PublishObservable] emitted downstream the moment it opens, and completed
+/// once it's full.
+///
+/// - The first window opens on subscribe, and the next one opens right after
+///   the previous was completed, so an empty window may be open on upstream
+///   completion, which then completes it.
+/// - Upstream errors are forwarded to the open window too.
+#[derive_where(Debug, Clone)]
+#[derive_where(skip_inner(Debug))]
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(PublishObservable<In, InError>)]
+#[rx_out_error(InError)]
+pub struct WindowCountOperator<In, InError = Never>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	window_size: NonZero<usize>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError> WindowCountOperator<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	pub fn new(window_size: usize) -> Self {
+		Self {
+			window_size: NonZero::new(window_size).unwrap_or(NonZero::<usize>::MIN),
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError> ComposableOperator for WindowCountOperator<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	type Subscriber<Destination>
+		= WindowCountSubscriber<In, InError, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		WindowCountSubscriber::new(destination, self.window_size)
+	}
+}
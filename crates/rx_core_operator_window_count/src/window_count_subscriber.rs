@@ -0,0 +1,103 @@
+use std::num::NonZero;
+
+use derive_where::derive_where;
+
+use rx_core_common::{RxObserver, Signal, Subscriber, SubscriptionLike};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_subject_publish::subject::{PublishObservable, PublishSubject};
+
+#[derive_where(Debug)]
+#[derive(RxSubscriber)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_delegate_teardown_collection]
+pub struct WindowCountSubscriber<In, InError, Destination>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	#[destination]
+	#[derive_where(skip)]
+	destination: Destination,
+	#[derive_where(skip(Debug))]
+	window: PublishSubject<In, InError>,
+	window_length: usize,
+	window_size: NonZero<usize>,
+}
+
+impl<In, InError, Destination> WindowCountSubscriber<In, InError, Destination>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	pub fn new(mut destination: Destination, window_size: NonZero<usize>) -> Self {
+		let window = PublishSubject::default();
+		destination.next(window.as_observable());
+
+		Self {
+			destination,
+			window,
+			window_length: 0,
+			window_size,
+		}
+	}
+
+	fn open_next_window(&mut self) {
+		self.window.complete();
+		self.window = PublishSubject::default();
+		self.window_length = 0;
+
+		if !self.destination.is_closed() {
+			self.destination.next(self.window.as_observable());
+		}
+	}
+}
+
+impl<In, InError, Destination> RxObserver for WindowCountSubscriber<In, InError, Destination>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		self.window.next(next);
+		self.window_length += 1;
+
+		if self.window_length == self.window_size.get() {
+			self.open_next_window();
+		}
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.window.error(error.clone());
+		self.destination.error(error);
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.window.complete();
+		self.destination.complete();
+	}
+}
+
+impl<In, InError, Destination> SubscriptionLike for WindowCountSubscriber<In, InError, Destination>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		// Windows without a subscriber of their own can't outlive the source
+		self.window.unsubscribe();
+		self.destination.unsubscribe();
+	}
+}
@@ -0,0 +1,24 @@
+mod window_time_operator;
+mod window_time_subscriber;
+
+pub use window_time_subscriber::*;
+
+pub mod operator {
+	pub use super::window_time_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod window_time_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::window_time_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod window_time_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::window_time_extension_pipe::*;
+}
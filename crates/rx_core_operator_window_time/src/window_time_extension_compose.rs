@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use rx_core_common::{ComposableOperator, Scheduler, SchedulerHandle, Signal};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::WindowTimeOperator;
+
+pub trait OperatorComposeExtensionWindowTime<T, S>: ComposableOperator<Out = T> + Sized
+where
+	T: Signal + Clone,
+	S: 'static + Scheduler + Send + Sync,
+{
+	/// # [WindowTimeOperator]
+	///
+	/// Splits upstream into windows that each stay open for `duration`, where
+	/// each window is an observable itself.
+	#[inline]
+	fn window_time(
+		self,
+		duration: Duration,
+		scheduler: SchedulerHandle<S>,
+	) -> CompositeOperator<Self, WindowTimeOperator<T, Self::OutError, S>>
+	where
+		Self::OutError: Clone,
+	{
+		self.compose_with(WindowTimeOperator::new(duration, scheduler))
+	}
+}
+
+impl<Op, T, S> OperatorComposeExtensionWindowTime<T, S> for Op
+where
+	Op: ComposableOperator<Out = T>,
+	T: Signal + Clone,
+	S: 'static + Scheduler + Send + Sync,
+{
+}
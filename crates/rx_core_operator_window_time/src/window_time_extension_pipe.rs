@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use rx_core_common::{Observable, Operator, Scheduler, SchedulerHandle, Signal};
+
+use crate::operator::WindowTimeOperator;
+
+pub trait ObservablePipeExtensionWindowTime<'o, T, S>:
+	'o + Observable<Out = T> + Sized + Send + Sync
+where
+	T: Signal + Clone,
+	S: 'static + Scheduler + Send + Sync,
+{
+	/// # [WindowTimeOperator]
+	///
+	/// Splits upstream into windows that each stay open for `duration`, where
+	/// each window is an observable itself. Use a higher-order operator like
+	/// `merge_all` or `concat_all` to process them.
+	///
+	/// The first window opens on subscribe, and the next one opens as soon as
+	/// the previous one closes.
+	#[inline]
+	fn window_time(
+		self,
+		duration: Duration,
+		scheduler: SchedulerHandle<S>,
+	) -> <WindowTimeOperator<T, Self::OutError, S> as Operator<'o>>::OutObservable<Self>
+	where
+		Self::OutError: Clone,
+	{
+		WindowTimeOperator::new(duration, scheduler).operate(self)
+	}
+}
+
+impl<'o, O, T, S> ObservablePipeExtensionWindowTime<'o, T, S> for O
+where
+	O: 'o + Observable<Out = T> + Send + Sync,
+	T: Signal + Clone,
+	S: 'static + Scheduler + Send + Sync,
+{
+}
@@ -0,0 +1,73 @@
+use core::marker::PhantomData;
+use std::time::Duration;
+
+use rx_core_common::{
+	ComposableOperator, PhantomInvariant, Scheduler, SchedulerHandle, Signal, Subscriber,
+};
+use rx_core_macro_operator_derive::RxOperator;
+use rx_core_subject_publish::subject::PublishObservable;
+
+use crate::WindowTimeSubscriber;
+
+/// # [WindowTimeOperator]
+///
+/// Splits upstream into windows that each stay open for `duration`. Each
+/// window is a [PublishObservable] emitted downstream the moment it opens.
+///
+/// The first window opens on subscribe, and every time a window closes, the
+/// next one opens immediately, even if it will stay empty.
+///
+/// Upstream completion and errors are forwarded to the open window too.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(PublishObservable<In, InError>)]
+#[rx_out_error(InError)]
+pub struct WindowTimeOperator<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: Scheduler,
+{
+	duration: Duration,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, S> WindowTimeOperator<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: Scheduler,
+{
+	pub fn new(duration: Duration, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			duration,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, S> ComposableOperator for WindowTimeOperator<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + Scheduler + Send + Sync,
+{
+	type Subscriber<Destination>
+		= WindowTimeSubscriber<In, InError, Destination, S>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		WindowTimeSubscriber::new(destination, self.duration, self.scheduler.clone())
+	}
+}
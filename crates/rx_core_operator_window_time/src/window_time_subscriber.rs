@@ -0,0 +1,150 @@
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, RxObserver, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension,
+	SharedSubscriber, Signal, Subscriber, SubscriptionLike, Teardown, TeardownCollectionExtension,
+	WorkCancellationId, WorkResult,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_subject_publish::subject::{PublishObservable, PublishSubject};
+
+#[derive(RxSubscriber)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_delegate_teardown_collection]
+pub struct WindowTimeSubscriber<In, InError, Destination, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+	S: 'static + Scheduler,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	window: Arc<Mutex<PublishSubject<In, InError>>>,
+	scheduler: SchedulerHandle<S>,
+	cancellation_id: WorkCancellationId,
+}
+
+impl<In, InError, Destination, S> WindowTimeSubscriber<In, InError, Destination, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+	S: 'static + Scheduler,
+{
+	pub fn new(
+		destination: Destination,
+		duration: Duration,
+		scheduler: SchedulerHandle<S>,
+	) -> Self {
+		let mut destination = SharedSubscriber::new(destination);
+		let window = Arc::new(Mutex::new(PublishSubject::default()));
+		destination.next(window.lock_ignore_poison().as_observable());
+
+		let cancellation_id = {
+			let mut scheduler = scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			let destination_clone = destination.clone();
+			let window_clone = window.clone();
+
+			scheduler.schedule_repeated_work(
+				move |_, _| {
+					let mut destination = destination_clone.lock();
+					if destination.is_closed() {
+						return WorkResult::Done;
+					}
+
+					let (mut closed_window, next_window) = {
+						let mut window = window_clone.lock_ignore_poison();
+						let next_window = PublishSubject::default();
+						(
+							std::mem::replace(&mut *window, next_window.clone()),
+							next_window,
+						)
+					};
+
+					closed_window.complete();
+					destination.next(next_window.as_observable());
+
+					WorkResult::Pending
+				},
+				duration,
+				false,
+				1,
+				cancellation_id,
+			);
+
+			cancellation_id
+		};
+
+		destination.add(Teardown::new_work_cancellation(
+			cancellation_id,
+			scheduler.clone(),
+		));
+
+		Self {
+			destination,
+			window,
+			scheduler,
+			cancellation_id,
+		}
+	}
+
+	/// Cloned out of the lock, so the window can be interacted with from its
+	/// own subscribers.
+	#[inline]
+	fn get_window(&self) -> PublishSubject<In, InError> {
+		self.window.lock_ignore_poison().clone()
+	}
+}
+
+impl<In, InError, Destination, S> RxObserver for WindowTimeSubscriber<In, InError, Destination, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+	S: 'static + Scheduler,
+{
+	#[inline]
+	fn next(&mut self, next: Self::In) {
+		self.get_window().next(next);
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		self.scheduler.lock().cancel(self.cancellation_id);
+		self.get_window().error(error.clone());
+		self.destination.error(error);
+	}
+
+	fn complete(&mut self) {
+		self.scheduler.lock().cancel(self.cancellation_id);
+		self.get_window().complete();
+		self.destination.complete();
+	}
+}
+
+impl<In, InError, Destination, S> SubscriptionLike
+	for WindowTimeSubscriber<In, InError, Destination, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+	S: 'static + Scheduler,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		self.scheduler.lock().cancel(self.cancellation_id);
+		self.get_window().unsubscribe();
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
@@ -0,0 +1,29 @@
+mod window_toggle_closing_subscriber;
+mod window_toggle_opening_destination;
+mod window_toggle_operator;
+mod window_toggle_state;
+mod window_toggle_subscriber;
+
+pub use window_toggle_closing_subscriber::*;
+pub use window_toggle_opening_destination::*;
+pub use window_toggle_subscriber::*;
+
+pub mod operator {
+	pub use super::window_toggle_operator::*;
+}
+
+#[cfg(feature = "compose")]
+mod window_toggle_extension_compose;
+
+#[cfg(feature = "compose")]
+pub mod extension_compose {
+	pub use super::window_toggle_extension_compose::*;
+}
+
+#[cfg(feature = "pipe")]
+mod window_toggle_extension_pipe;
+
+#[cfg(feature = "pipe")]
+pub mod extension_pipe {
+	pub use super::window_toggle_extension_pipe::*;
+}
@@ -0,0 +1,125 @@
+use std::{
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, PhantomInvariant, RxObserver, SharedSubscriber, Signal, Subscriber,
+	SubscriptionData, SubscriptionLike, Teardown, TeardownCollection,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_subject_publish::subject::PublishObservable;
+
+use crate::window_toggle_state::WindowToggleState;
+
+/// Closes its window on the first `next` of the closing observable.
+#[derive(RxSubscriber)]
+#[rx_in(ClosingOut)]
+#[rx_in_error(InError)]
+pub struct WindowToggleClosingSubscriber<ClosingOut, In, InError, Destination>
+where
+	ClosingOut: Signal,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	teardown: SubscriptionData,
+	window_id: usize,
+	state: Arc<Mutex<WindowToggleState<In, InError>>>,
+	destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<ClosingOut>,
+}
+
+impl<ClosingOut, In, InError, Destination>
+	WindowToggleClosingSubscriber<ClosingOut, In, InError, Destination>
+where
+	ClosingOut: Signal,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	pub(crate) fn new(
+		window_id: usize,
+		state: Arc<Mutex<WindowToggleState<In, InError>>>,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		Self {
+			teardown: SubscriptionData::default(),
+			window_id,
+			state,
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<ClosingOut, In, InError, Destination> RxObserver
+	for WindowToggleClosingSubscriber<ClosingOut, In, InError, Destination>
+where
+	ClosingOut: Signal,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	fn next(&mut self, _next: Self::In) {
+		if !self.is_closed() {
+			let window = self.state.lock_ignore_poison().take_window(self.window_id);
+			self.unsubscribe();
+			if let Some(window) = window {
+				window.complete();
+			}
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		if !self.is_closed() {
+			let windows = self.state.lock_ignore_poison().take_windows();
+			for window in windows {
+				window.error(error.clone());
+			}
+			self.destination.error(error);
+			self.unsubscribe();
+		}
+	}
+
+	/// Only a `next` closes the window, a closing observable completing
+	/// without one leaves it open until upstream completes.
+	fn complete(&mut self) {
+		self.unsubscribe();
+	}
+}
+
+impl<ClosingOut, In, InError, Destination> TeardownCollection
+	for WindowToggleClosingSubscriber<ClosingOut, In, InError, Destination>
+where
+	ClosingOut: Signal,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	#[inline]
+	fn add_teardown(&mut self, teardown: Teardown) {
+		self.teardown.add_teardown(teardown);
+	}
+}
+
+impl<ClosingOut, In, InError, Destination> SubscriptionLike
+	for WindowToggleClosingSubscriber<ClosingOut, In, InError, Destination>
+where
+	ClosingOut: Signal,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.teardown.is_closed()
+	}
+
+	#[inline]
+	fn unsubscribe(&mut self) {
+		if !self.is_closed() {
+			self.teardown.unsubscribe();
+		}
+	}
+}
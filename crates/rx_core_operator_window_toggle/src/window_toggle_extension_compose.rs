@@ -0,0 +1,37 @@
+use rx_core_common::{ComposableOperator, Observable};
+use rx_core_operator_composite::{OperatorComposeExtension, operator::CompositeOperator};
+
+use crate::operator::WindowToggleOperator;
+
+pub trait OperatorComposeExtensionWindowToggle: ComposableOperator + Sized {
+	#[inline]
+	fn window_toggle<OpeningObservable, ClosingSelector, ClosingObservable>(
+		self,
+		opening_observable: OpeningObservable,
+		closing_selector: ClosingSelector,
+	) -> CompositeOperator<
+		Self,
+		WindowToggleOperator<
+			OpeningObservable,
+			ClosingSelector,
+			ClosingObservable,
+			Self::Out,
+			Self::OutError,
+		>,
+	>
+	where
+		OpeningObservable: 'static + Observable<OutError = Self::OutError>,
+		ClosingSelector:
+			'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Clone + Send + Sync,
+		ClosingObservable: 'static + Observable<OutError = Self::OutError>,
+		Self::Out: Clone,
+		Self::OutError: Clone,
+	{
+		self.compose_with(WindowToggleOperator::new(
+			opening_observable,
+			closing_selector,
+		))
+	}
+}
+
+impl<Op> OperatorComposeExtensionWindowToggle for Op where Op: ComposableOperator {}
@@ -0,0 +1,36 @@
+use rx_core_common::{Observable, Operator};
+
+use crate::operator::WindowToggleOperator;
+
+pub trait ObservablePipeExtensionWindowToggle<'o>: 'o + Observable + Sized + Send + Sync {
+	/// # [WindowToggleOperator]
+	///
+	/// Opens a new window every time `opening_observable` emits, and closes it
+	/// once the observable `closing_selector` created for it emits. Each
+	/// window is an observable itself, use a higher-order operator like
+	/// `merge_all` to process them.
+	#[inline]
+	fn window_toggle<OpeningObservable, ClosingSelector, ClosingObservable>(
+		self,
+		opening_observable: OpeningObservable,
+		closing_selector: ClosingSelector,
+	) -> <WindowToggleOperator<
+		OpeningObservable,
+		ClosingSelector,
+		ClosingObservable,
+		Self::Out,
+		Self::OutError,
+	> as Operator<'o>>::OutObservable<Self>
+	where
+		OpeningObservable: 'static + Observable<OutError = Self::OutError>,
+		ClosingSelector:
+			'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Clone + Send + Sync,
+		ClosingObservable: 'static + Observable<OutError = Self::OutError>,
+		Self::Out: Clone,
+		Self::OutError: Clone,
+	{
+		WindowToggleOperator::new(opening_observable, closing_selector).operate(self)
+	}
+}
+
+impl<'o, O> ObservablePipeExtensionWindowToggle<'o> for O where O: 'o + Observable + Send + Sync {}
@@ -0,0 +1,131 @@
+use std::{
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, PhantomInvariant, RxObserver, SharedSubscriber, Signal,
+	Subscriber, SubscriptionData, SubscriptionLike,
+};
+use rx_core_macro_observer_derive::RxObserver;
+use rx_core_subject_publish::subject::PublishObservable;
+
+use crate::{WindowToggleClosingSubscriber, window_toggle_state::WindowToggleState};
+
+/// Opens a new window for every value of the opening observable.
+#[derive(RxObserver)]
+#[rx_in(OpeningOut)]
+#[rx_in_error(InError)]
+pub struct WindowToggleOpeningDestination<
+	OpeningOut,
+	ClosingSelector,
+	ClosingObservable,
+	In,
+	InError,
+	Destination,
+> where
+	OpeningOut: Signal,
+	ClosingSelector: 'static + FnMut(OpeningOut) -> ClosingObservable + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = InError>,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	closing_selector: ClosingSelector,
+	state: Arc<Mutex<WindowToggleState<In, InError>>>,
+	destination: SharedSubscriber<Destination>,
+	_phantom_data: PhantomInvariant<(OpeningOut, ClosingObservable)>,
+}
+
+impl<OpeningOut, ClosingSelector, ClosingObservable, In, InError, Destination>
+	WindowToggleOpeningDestination<
+		OpeningOut,
+		ClosingSelector,
+		ClosingObservable,
+		In,
+		InError,
+		Destination,
+	>
+where
+	OpeningOut: Signal,
+	ClosingSelector: 'static + FnMut(OpeningOut) -> ClosingObservable + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = InError>,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	pub(crate) fn new(
+		closing_selector: ClosingSelector,
+		state: Arc<Mutex<WindowToggleState<In, InError>>>,
+		destination: SharedSubscriber<Destination>,
+	) -> Self {
+		Self {
+			closing_selector,
+			state,
+			destination,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<OpeningOut, ClosingSelector, ClosingObservable, In, InError, Destination> RxObserver
+	for WindowToggleOpeningDestination<
+		OpeningOut,
+		ClosingSelector,
+		ClosingObservable,
+		In,
+		InError,
+		Destination,
+	>
+where
+	OpeningOut: Signal,
+	ClosingSelector: 'static + FnMut(OpeningOut) -> ClosingObservable + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = InError>,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	fn next(&mut self, next: Self::In) {
+		if self.destination.is_closed() {
+			return;
+		}
+
+		let (window_id, window) = self.state.lock_ignore_poison().open_window();
+		self.destination.next(window.as_observable());
+
+		let mut closing_observable = (self.closing_selector)(next);
+		let closing_subscription =
+			closing_observable.subscribe(WindowToggleClosingSubscriber::<
+				ClosingObservable::Out,
+				In,
+				InError,
+				Destination,
+			>::new(
+				window_id, self.state.clone(), self.destination.clone()
+			));
+
+		if !closing_subscription.is_closed() {
+			let closing_subscription =
+				SubscriptionData::new_with_teardown(closing_subscription.into());
+			let already_closed = self
+				.state
+				.lock_ignore_poison()
+				.attach_closing_subscription(window_id, closing_subscription);
+			if let Some(mut already_closed) = already_closed {
+				already_closed.unsubscribe();
+			}
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		let windows = self.state.lock_ignore_poison().take_windows();
+		for window in windows {
+			window.error(error.clone());
+		}
+		self.destination.error(error);
+	}
+
+	/// Windows already opened can still be closed, but no new ones will open.
+	#[inline]
+	fn complete(&mut self) {}
+}
@@ -0,0 +1,93 @@
+use core::marker::PhantomData;
+
+use rx_core_common::{ComposableOperator, Never, Observable, PhantomInvariant, Signal, Subscriber};
+use rx_core_macro_operator_derive::RxOperator;
+use rx_core_subject_publish::subject::PublishObservable;
+
+use crate::WindowToggleSubscriber;
+
+/// # [WindowToggleOperator]
+///
+/// Opens a new window every time `opening_observable` emits, and closes it
+/// when the observable created for it by `closing_selector` emits. Each
+/// window is a [PublishObservable] emitted downstream the moment it opens.
+///
+/// - Windows can overlap, every upstream value is sent to all open windows.
+/// - The `opening_observable` completing stops opening new windows, but does
+///   not close the ones already open.
+/// - Upstream completion completes all open windows.
+/// - Errors, from upstream or from the opening or closing observables, are
+///   forwarded to every open window and downstream.
+#[derive(RxOperator)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(PublishObservable<In, InError>)]
+#[rx_out_error(InError)]
+pub struct WindowToggleOperator<
+	OpeningObservable,
+	ClosingSelector,
+	ClosingObservable,
+	In,
+	InError = Never,
+> where
+	OpeningObservable: 'static + Observable<OutError = InError>,
+	ClosingSelector:
+		'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Clone + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = InError>,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	opening_observable: OpeningObservable,
+	closing_selector: ClosingSelector,
+	_phantom_data: PhantomInvariant<(In, InError, ClosingObservable)>,
+}
+
+impl<OpeningObservable, ClosingSelector, ClosingObservable, In, InError>
+	WindowToggleOperator<OpeningObservable, ClosingSelector, ClosingObservable, In, InError>
+where
+	OpeningObservable: 'static + Observable<OutError = InError>,
+	ClosingSelector:
+		'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Clone + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = InError>,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	pub fn new(opening_observable: OpeningObservable, closing_selector: ClosingSelector) -> Self {
+		Self {
+			opening_observable,
+			closing_selector,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<OpeningObservable, ClosingSelector, ClosingObservable, In, InError> ComposableOperator
+	for WindowToggleOperator<OpeningObservable, ClosingSelector, ClosingObservable, In, InError>
+where
+	OpeningObservable: 'static + Observable<OutError = InError>,
+	ClosingSelector:
+		'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Clone + Send + Sync,
+	ClosingObservable: 'static + Observable<OutError = InError>,
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	type Subscriber<Destination>
+		= WindowToggleSubscriber<In, InError, Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync;
+
+	#[inline]
+	fn operator_subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscriber<Destination>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		WindowToggleSubscriber::new(
+			destination,
+			&mut self.opening_observable,
+			self.closing_selector.clone(),
+		)
+	}
+}
@@ -0,0 +1,103 @@
+use derive_where::derive_where;
+use rx_core_common::{RxObserver, Signal, SubscriptionData, SubscriptionLike};
+use rx_core_subject_publish::subject::PublishSubject;
+
+pub(crate) struct WindowToggleWindow<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	id: usize,
+	window: PublishSubject<In, InError>,
+	closing_subscription: Option<SubscriptionData>,
+}
+
+impl<In, InError> WindowToggleWindow<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	pub(crate) fn complete(mut self) {
+		self.window.complete();
+		if let Some(mut closing_subscription) = self.closing_subscription.take() {
+			closing_subscription.unsubscribe();
+		}
+	}
+
+	pub(crate) fn error(mut self, error: InError) {
+		self.window.error(error);
+		if let Some(mut closing_subscription) = self.closing_subscription.take() {
+			closing_subscription.unsubscribe();
+		}
+	}
+
+	pub(crate) fn unsubscribe(mut self) {
+		self.window.unsubscribe();
+		if let Some(mut closing_subscription) = self.closing_subscription.take() {
+			closing_subscription.unsubscribe();
+		}
+	}
+}
+
+/// The windows currently open, each one waiting for its own closing
+/// observable to emit.
+#[derive_where(Default)]
+pub(crate) struct WindowToggleState<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	next_window_id: usize,
+	windows: Vec<WindowToggleWindow<In, InError>>,
+}
+
+impl<In, InError> WindowToggleState<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	pub(crate) fn open_window(&mut self) -> (usize, PublishSubject<In, InError>) {
+		let id = self.next_window_id;
+		self.next_window_id += 1;
+
+		let window = PublishSubject::default();
+		self.windows.push(WindowToggleWindow {
+			id,
+			window: window.clone(),
+			closing_subscription: None,
+		});
+
+		(id, window)
+	}
+
+	/// Gives the subscription back if the window had already closed.
+	pub(crate) fn attach_closing_subscription(
+		&mut self,
+		id: usize,
+		closing_subscription: SubscriptionData,
+	) -> Option<SubscriptionData> {
+		match self.windows.iter_mut().find(|window| window.id == id) {
+			Some(window) => {
+				window.closing_subscription = Some(closing_subscription);
+				None
+			}
+			None => Some(closing_subscription),
+		}
+	}
+
+	pub(crate) fn take_window(&mut self, id: usize) -> Option<WindowToggleWindow<In, InError>> {
+		let index = self.windows.iter().position(|window| window.id == id)?;
+		Some(self.windows.remove(index))
+	}
+
+	pub(crate) fn take_windows(&mut self) -> Vec<WindowToggleWindow<In, InError>> {
+		std::mem::take(&mut self.windows)
+	}
+
+	pub(crate) fn get_open_windows(&self) -> Vec<PublishSubject<In, InError>> {
+		self.windows
+			.iter()
+			.map(|window| window.window.clone())
+			.collect()
+	}
+}
@@ -0,0 +1,117 @@
+use std::{
+	marker::PhantomData,
+	sync::{Arc, Mutex},
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, PhantomInvariant, RxObserver, SharedSubscriber, Signal,
+	Subscriber, SubscriptionLike, TeardownCollectionExtension,
+};
+use rx_core_macro_subscriber_derive::RxSubscriber;
+use rx_core_subject_publish::subject::PublishObservable;
+
+use crate::{WindowToggleOpeningDestination, window_toggle_state::WindowToggleState};
+
+#[derive(RxSubscriber)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_delegate_teardown_collection]
+pub struct WindowToggleSubscriber<In, InError, Destination>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	#[destination]
+	destination: SharedSubscriber<Destination>,
+	state: Arc<Mutex<WindowToggleState<In, InError>>>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, Destination> WindowToggleSubscriber<In, InError, Destination>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	pub fn new<OpeningObservable, ClosingSelector, ClosingObservable>(
+		destination: Destination,
+		opening_observable: &mut OpeningObservable,
+		closing_selector: ClosingSelector,
+	) -> Self
+	where
+		OpeningObservable: 'static + Observable<OutError = InError>,
+		ClosingSelector: 'static + FnMut(OpeningObservable::Out) -> ClosingObservable + Send + Sync,
+		ClosingObservable: 'static + Observable<OutError = InError>,
+	{
+		let mut destination = SharedSubscriber::new(destination);
+		let state = Arc::new(Mutex::new(WindowToggleState::default()));
+
+		let opening_subscription =
+			opening_observable.subscribe(WindowToggleOpeningDestination::new(
+				closing_selector,
+				state.clone(),
+				destination.clone(),
+			));
+		// Stops opening windows once downstream is closed
+		destination.add(opening_subscription);
+
+		Self {
+			destination,
+			state,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, Destination> RxObserver for WindowToggleSubscriber<In, InError, Destination>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	fn next(&mut self, next: Self::In) {
+		let windows = self.state.lock_ignore_poison().get_open_windows();
+		for mut window in windows {
+			window.next(next.clone());
+		}
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		let windows = self.state.lock_ignore_poison().take_windows();
+		for window in windows {
+			window.error(error.clone());
+		}
+		self.destination.error(error);
+	}
+
+	fn complete(&mut self) {
+		let windows = self.state.lock_ignore_poison().take_windows();
+		for window in windows {
+			window.complete();
+		}
+		self.destination.complete();
+	}
+}
+
+impl<In, InError, Destination> SubscriptionLike for WindowToggleSubscriber<In, InError, Destination>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	Destination: 'static + Subscriber<In = PublishObservable<In, InError>, InError = InError>,
+{
+	#[inline]
+	fn is_closed(&self) -> bool {
+		self.destination.is_closed()
+	}
+
+	fn unsubscribe(&mut self) {
+		let windows = self.state.lock_ignore_poison().take_windows();
+		for window in windows {
+			window.unsubscribe();
+		}
+		if !self.destination.is_closed() {
+			self.destination.unsubscribe();
+		}
+	}
+}
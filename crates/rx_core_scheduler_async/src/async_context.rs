@@ -0,0 +1,77 @@
+use rx_core_common::{WorkContext, WorkContextProvider};
+use rx_core_scheduler_ticking::Tick;
+
+pub struct AsyncContextProvider;
+
+impl WorkContextProvider for AsyncContextProvider {
+	type Item<'c> = AsyncContext;
+}
+
+/// # [AsyncContext]
+///
+/// The context every work receives when ticked by the
+/// [AsyncExecutor][crate::AsyncExecutor].
+///
+/// Work that knows when it will have something to do next can tell the
+/// executor using [AsyncContext::wake_at], so the timer wheel can put the
+/// worker thread to sleep until then. Work that does not request a wake-up
+/// is treated as continuous work, and will be ticked again on the next step
+/// of the timer wheel.
+#[derive(Debug)]
+pub struct AsyncContext {
+	now: Tick,
+	next_wake: Option<Tick>,
+}
+
+impl AsyncContext {
+	pub(crate) fn new(now: Tick) -> Self {
+		Self {
+			now,
+			next_wake: None,
+		}
+	}
+
+	/// The tick the work is currently executed on.
+	#[inline]
+	pub fn now(&self) -> Tick {
+		self.now
+	}
+
+	/// Requests the currently ticked work to be ticked again once `tick` is
+	/// reached. When requested multiple times, the earliest one is kept.
+	pub fn wake_at(&mut self, tick: Tick) {
+		match self.next_wake {
+			Some(next_wake) if next_wake <= tick => {}
+			_ => {
+				self.next_wake = Some(tick);
+			}
+		}
+	}
+
+	#[inline]
+	pub(crate) fn take_next_wake(&mut self) -> Option<Tick> {
+		self.next_wake.take()
+	}
+}
+
+impl WorkContext<'_> for AsyncContext {}
+
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+
+	use super::*;
+
+	#[test]
+	fn should_keep_the_earliest_wake_request() {
+		let mut context = AsyncContext::new(Tick::default());
+		context.wake_at(Tick::new(Duration::from_millis(200)));
+		context.wake_at(Tick::new(Duration::from_millis(100)));
+		context.wake_at(Tick::new(Duration::from_millis(300)));
+		assert_eq!(
+			context.take_next_wake(),
+			Some(Tick::new(Duration::from_millis(100)))
+		);
+		assert_eq!(context.take_next_wake(), None);
+	}
+}
@@ -0,0 +1,118 @@
+use std::{
+	sync::Arc,
+	thread::{self, JoinHandle},
+	time::{Duration, Instant},
+};
+
+use rx_core_common::{SchedulerHandle, WorkTick};
+use rx_core_macro_executor_derive::RxExecutor;
+use rx_core_scheduler_ticking::Tick;
+
+use crate::{
+	AsyncContextProvider, AsyncExecutorOptions, AsyncExecutorWorker, AsyncScheduler,
+	ExecutorSignal, IdleFuture, block_on,
+};
+
+/// # [AsyncExecutor]
+///
+/// An executor that doesn't need to be ticked manually. Work is executed on a
+/// dedicated background thread, which sleeps until the earliest deadline in
+/// its timer wheel, or until new work is scheduled.
+///
+/// Time is measured from the wall-clock, starting from when the executor was
+/// created.
+///
+/// Dropping the executor stops its thread, and drops all remaining work.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rx_core_common::WorkExecutor;
+/// use rx_core_scheduler_async::AsyncExecutor;
+///
+/// let executor = AsyncExecutor::default();
+/// let _scheduler = executor.get_scheduler_handle();
+/// // Pass the scheduler to operators and observables that need one
+/// executor.block_until_idle();
+/// ```
+#[derive(RxExecutor)]
+#[rx_context(AsyncContextProvider)]
+#[rx_tick(Tick)]
+#[rx_scheduler(AsyncScheduler)]
+pub struct AsyncExecutor {
+	#[scheduler_handle]
+	scheduler: SchedulerHandle<AsyncScheduler>,
+	signal: Arc<ExecutorSignal>,
+	started_at: Instant,
+	worker_thread: Option<JoinHandle<()>>,
+}
+
+impl AsyncExecutor {
+	pub fn new(options: AsyncExecutorOptions) -> Self {
+		let started_at = Instant::now();
+		let signal = Arc::new(ExecutorSignal::default());
+		let scheduler = SchedulerHandle::new(AsyncScheduler::new(started_at, signal.clone()));
+
+		let worker =
+			AsyncExecutorWorker::new(scheduler.clone(), signal.clone(), started_at, options);
+
+		let worker_thread = thread::Builder::new()
+			.name("rx_core_scheduler_async".to_string())
+			.spawn(move || worker.run())
+			.expect("Failed to spawn the worker thread of the AsyncExecutor!");
+
+		Self {
+			scheduler,
+			signal,
+			started_at,
+			worker_thread: Some(worker_thread),
+		}
+	}
+
+	/// Returns the time elapsed since the executor was created.
+	#[inline]
+	pub fn now(&self) -> Duration {
+		self.get_current_tick().now()
+	}
+
+	pub fn get_current_tick(&self) -> Tick {
+		Tick::new(self.started_at.elapsed())
+	}
+
+	/// Returns `true` when there is no work left to execute.
+	///
+	/// Note that invokable work counts as work, even if it was not invoked.
+	pub fn is_idle(&self) -> bool {
+		self.signal.is_idle()
+	}
+
+	/// Returns a future that resolves once there is no work left to execute,
+	/// or when the executor had stopped.
+	pub fn until_idle(&self) -> IdleFuture {
+		IdleFuture::new(self.signal.clone())
+	}
+
+	/// Blocks the current thread until there is no work left to execute.
+	pub fn block_until_idle(&self) {
+		block_on(self.until_idle());
+	}
+}
+
+impl Default for AsyncExecutor {
+	fn default() -> Self {
+		Self::new(AsyncExecutorOptions::default())
+	}
+}
+
+impl Drop for AsyncExecutor {
+	fn drop(&mut self) {
+		self.signal.shut_down();
+
+		if let Some(worker_thread) = self.worker_thread.take()
+			&& worker_thread.thread().id() != thread::current().id()
+		{
+			// A panic on the worker thread was already reported by its hook
+			let _ = worker_thread.join();
+		}
+	}
+}
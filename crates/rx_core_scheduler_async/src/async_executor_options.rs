@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct AsyncExecutorOptions {
+	/// The length of a single step of the timer wheel. Deadlines are rounded
+	/// up to the next step, and continuous work is executed once per step.
+	///
+	/// Default: 1ms
+	pub resolution: Duration,
+	/// How many slots the timer wheel has. Work scheduled further than
+	/// `resolution * timer_wheel_slots` away shares slots with nearer work.
+	///
+	/// Default: 512
+	pub timer_wheel_slots: usize,
+	/// How many times work can schedule more work that is immediately
+	/// executable, within a single step.
+	///
+	/// Default: 100
+	pub max_single_tick_recursion_depth: usize,
+}
+
+impl Default for AsyncExecutorOptions {
+	fn default() -> Self {
+		Self {
+			resolution: Duration::from_millis(1),
+			timer_wheel_slots: 512,
+			max_single_tick_recursion_depth: 100,
+		}
+	}
+}
@@ -0,0 +1,284 @@
+use std::{
+	collections::HashMap,
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+use rx_core_common::{
+	ScheduledWork, ScheduledWorkAction, SchedulerHandle, WorkCancellationId, WorkInvokeId,
+	WorkResult,
+};
+use rx_core_scheduler_ticking::Tick;
+
+use crate::{
+	AsyncContext, AsyncContextProvider, AsyncExecutorOptions, AsyncScheduler, ExecutorSignal,
+	TimerWheel, WorkId, WorkIdGenerator,
+};
+
+const MAX_RECURSION_DEPTH: &str = "Recursive work exceeding `max_single_tick_recursion_depth`!";
+
+type BoxedWork =
+	Box<dyn ScheduledWork<Tick = Tick, WorkContextProvider = AsyncContextProvider> + Send + Sync>;
+
+/// Owns all the work of an [AsyncExecutor][crate::AsyncExecutor] and
+/// executes it on the executors background thread.
+pub(crate) struct AsyncExecutorWorker {
+	scheduler: SchedulerHandle<AsyncScheduler>,
+	signal: Arc<ExecutorSignal>,
+	started_at: Instant,
+	resolution: Duration,
+	timer_wheel: TimerWheel<WorkId>,
+	work_id_generator: WorkIdGenerator,
+	active_work: HashMap<WorkId, BoxedWork>,
+	/// Freshly activated work, ticked as soon as possible.
+	ready: Vec<WorkId>,
+	cancellation_map: HashMap<WorkCancellationId, Vec<WorkId>>,
+	/// The reverse of the `cancellation_map`, so that finished work can be
+	/// removed from it.
+	work_cancellation_ids: HashMap<WorkId, WorkCancellationId>,
+	invokable_work: HashMap<WorkInvokeId, BoxedWork>,
+	invoked: Vec<WorkInvokeId>,
+	max_single_tick_recursion_depth: usize,
+}
+
+/// Lets everyone waiting for the executor know that it won't do anything
+/// anymore, even if the worker thread is stopped by a panicking work.
+struct ShutDownOnDrop(Arc<ExecutorSignal>);
+
+impl Drop for ShutDownOnDrop {
+	fn drop(&mut self) {
+		self.0.shut_down();
+	}
+}
+
+impl AsyncExecutorWorker {
+	pub(crate) fn new(
+		scheduler: SchedulerHandle<AsyncScheduler>,
+		signal: Arc<ExecutorSignal>,
+		started_at: Instant,
+		options: AsyncExecutorOptions,
+	) -> Self {
+		Self {
+			scheduler,
+			signal,
+			started_at,
+			resolution: options.resolution,
+			timer_wheel: TimerWheel::new(options.resolution, options.timer_wheel_slots),
+			work_id_generator: WorkIdGenerator::default(),
+			active_work: HashMap::new(),
+			ready: Vec::new(),
+			cancellation_map: HashMap::new(),
+			work_cancellation_ids: HashMap::new(),
+			invokable_work: HashMap::new(),
+			invoked: Vec::new(),
+			max_single_tick_recursion_depth: options.max_single_tick_recursion_depth,
+		}
+	}
+
+	pub(crate) fn run(mut self) {
+		let _shut_down_on_drop = ShutDownOnDrop(self.signal.clone());
+
+		while !self.signal.is_shut_down() {
+			let tick = Tick::new(self.started_at.elapsed());
+			self.tick_to(tick);
+
+			self.signal.set_idle(self.is_empty());
+
+			let next_deadline = self
+				.timer_wheel
+				.next_deadline()
+				.map(|deadline| self.started_at + deadline);
+			self.signal.wait(next_deadline);
+		}
+	}
+
+	fn is_empty(&self) -> bool {
+		self.active_work.is_empty() && self.invokable_work.is_empty()
+	}
+
+	fn tick_to(&mut self, tick: Tick) {
+		for i in 0..=self.max_single_tick_recursion_depth {
+			let had_actions = self.drain_scheduler_queue();
+
+			let no_work_ticked = self.tick_due(tick);
+
+			self.execute_invoked(tick);
+
+			if no_work_ticked && !had_actions {
+				break;
+			}
+
+			if i == self.max_single_tick_recursion_depth {
+				panic!("{}", MAX_RECURSION_DEPTH);
+			}
+		}
+	}
+
+	fn drain_scheduler_queue(&mut self) -> bool {
+		if !self.signal.take_actions() {
+			return false;
+		}
+
+		// The scheduler is released before anything is done with the actions
+		let actions = self.scheduler.lock().drain_actions();
+		let had_actions = !actions.is_empty();
+
+		for action in actions {
+			match action {
+				ScheduledWorkAction::Activate((cancellation_id, work)) => {
+					let work_id = self.work_id_generator.get_next();
+					self.active_work.insert(work_id, work);
+					self.ready.push(work_id);
+					self.cancellation_map
+						.entry(cancellation_id)
+						.or_default()
+						.push(work_id);
+					self.work_cancellation_ids.insert(work_id, cancellation_id);
+				}
+				ScheduledWorkAction::AddInvoked((invoke_id, work)) => {
+					self.invokable_work.insert(invoke_id, work);
+				}
+				ScheduledWorkAction::Invoke(invoke_id) => {
+					self.invoked.push(invoke_id);
+				}
+				ScheduledWorkAction::CancelInvoked(cancelled_invocation_id) => {
+					self.invokable_work.remove(&cancelled_invocation_id);
+					self.invoked
+						.retain(|invoked_id| invoked_id != &cancelled_invocation_id);
+				}
+				ScheduledWorkAction::Cancel(cancelled_id) => {
+					// Their entries in the timer wheel will be ignored
+					if let Some(work_ids) = self.cancellation_map.remove(&cancelled_id) {
+						for work_id in work_ids {
+							self.active_work.remove(&work_id);
+							self.work_cancellation_ids.remove(&work_id);
+						}
+					}
+				}
+			}
+		}
+
+		had_actions
+	}
+
+	/// Ticks every freshly activated work and every work whose deadline had
+	/// passed. Returns `true` if no work was ticked.
+	fn tick_due(&mut self, tick: Tick) -> bool {
+		let mut due_work = std::mem::take(&mut self.ready);
+		due_work.extend(self.timer_wheel.advance_to(*tick));
+
+		let mut no_work_ticked = true;
+
+		for work_id in due_work {
+			let Some(work) = self.active_work.get_mut(&work_id) else {
+				continue;
+			};
+
+			no_work_ticked = false;
+			let mut context = AsyncContext::new(tick);
+
+			match work.tick(tick, &mut context) {
+				WorkResult::Done => {
+					self.remove_finished_work(work_id);
+				}
+				WorkResult::Pending => {
+					// Work that doesn't know when it should be woken up is
+					// ticked again on the next step
+					let next_wake = context
+						.take_next_wake()
+						.unwrap_or_else(|| tick + self.resolution);
+					self.timer_wheel.insert(*next_wake, work_id);
+				}
+			}
+		}
+
+		no_work_ticked
+	}
+
+	/// Without also removing it from the `cancellation_map`, every finished
+	/// work would leave its id behind for as long as the executor lives.
+	fn remove_finished_work(&mut self, work_id: WorkId) {
+		self.active_work.remove(&work_id);
+
+		if let Some(cancellation_id) = self.work_cancellation_ids.remove(&work_id)
+			&& let Some(work_ids) = self.cancellation_map.get_mut(&cancellation_id)
+		{
+			work_ids.retain(|id| id != &work_id);
+			if work_ids.is_empty() {
+				self.cancellation_map.remove(&cancellation_id);
+			}
+		}
+	}
+
+	fn execute_invoked(&mut self, tick: Tick) {
+		for invoked_id in std::mem::take(&mut self.invoked) {
+			if let Some(invoked_work) = self.invokable_work.get_mut(&invoked_id) {
+				let mut context = AsyncContext::new(tick);
+				let invoke_result = invoked_work.tick(tick, &mut context);
+				if matches!(invoke_result, WorkResult::Done) {
+					self.invokable_work.remove(&invoked_id);
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::{sync::Arc, time::Instant};
+
+	use rx_core_common::{Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension, WorkResult};
+	use rx_core_scheduler_ticking::Tick;
+
+	use crate::{AsyncExecutorOptions, AsyncScheduler, ExecutorSignal};
+
+	use super::AsyncExecutorWorker;
+
+	fn create_worker() -> (AsyncExecutorWorker, SchedulerHandle<AsyncScheduler>) {
+		let started_at = Instant::now();
+		let signal = Arc::new(ExecutorSignal::default());
+		let scheduler = SchedulerHandle::new(AsyncScheduler::new(started_at, signal.clone()));
+		let worker = AsyncExecutorWorker::new(
+			scheduler.clone(),
+			signal,
+			started_at,
+			AsyncExecutorOptions::default(),
+		);
+		(worker, scheduler)
+	}
+
+	#[test]
+	fn should_not_keep_the_cancellation_entries_of_finished_work() {
+		let (mut worker, scheduler) = create_worker();
+
+		for _ in 0..1000 {
+			let mut scheduler = scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_immediate_work(|_, _| {}, cancellation_id);
+		}
+
+		worker.tick_to(Tick::new(Instant::now() - worker.started_at));
+
+		assert!(worker.is_empty());
+		assert!(worker.cancellation_map.is_empty());
+		assert!(worker.work_cancellation_ids.is_empty());
+	}
+
+	#[test]
+	fn should_only_remove_the_finished_work_from_a_shared_cancellation_entry() {
+		let (mut worker, scheduler) = create_worker();
+
+		{
+			let mut scheduler = scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+			scheduler.schedule_immediate_work(|_, _| {}, cancellation_id);
+			scheduler.schedule_continuous_work(|_, _| WorkResult::Pending, cancellation_id);
+		}
+
+		worker.tick_to(Tick::new(Instant::now() - worker.started_at));
+
+		assert_eq!(worker.active_work.len(), 1);
+		assert_eq!(worker.cancellation_map.values().flatten().count(), 1);
+		assert_eq!(worker.work_cancellation_ids.len(), 1);
+	}
+}
@@ -0,0 +1,124 @@
+use std::{sync::Arc, time::Instant};
+
+use rx_core_common::{
+	ScheduledWork, ScheduledWorkAction, Scheduler, SchedulerClock, WorkCancellationId,
+	WorkCancellationIdGenerator, WorkInvokeId, WorkInvokeIdGenerator,
+};
+use rx_core_macro_scheduler_derive::RxScheduler;
+use rx_core_scheduler_ticking::Tick;
+
+use crate::{
+	AsyncContextProvider, AsyncContinuousWorkFactory, AsyncDelayedOnceWorkFactory,
+	AsyncImmediateOnceWorkFactory, AsyncInvokedWorkFactory, AsyncRepeatingWorkFactory,
+	ExecutorSignal,
+};
+
+/// # [AsyncScheduler]
+///
+/// The scheduler of the [AsyncExecutor][crate::AsyncExecutor]. Every action
+/// is queued until the executors worker thread picks it up, which is woken
+/// up immediately.
+///
+/// Unlike the `TickingScheduler`, this scheduler always knows the current
+/// time, as it's measured from the wall-clock since the executor was created.
+#[derive(RxScheduler, Debug)]
+#[rx_tick(Tick)]
+#[rx_context(AsyncContextProvider)]
+pub struct AsyncScheduler {
+	cancellation_id_generator: WorkCancellationIdGenerator,
+	invoke_id_generator: WorkInvokeIdGenerator,
+	started_at: Instant,
+	action_queue: Vec<ScheduledWorkAction<Tick, AsyncContextProvider>>,
+	signal: Arc<ExecutorSignal>,
+}
+
+impl AsyncScheduler {
+	pub(crate) fn new(started_at: Instant, signal: Arc<ExecutorSignal>) -> Self {
+		Self {
+			cancellation_id_generator: WorkCancellationIdGenerator::default(),
+			invoke_id_generator: WorkInvokeIdGenerator::default(),
+			started_at,
+			action_queue: Vec::new(),
+			signal,
+		}
+	}
+
+	/// Returns the time elapsed since the executor was created.
+	#[inline]
+	pub fn now(&self) -> Tick {
+		Tick::new(self.started_at.elapsed())
+	}
+
+	#[inline]
+	pub(crate) fn drain_actions(&mut self) -> Vec<ScheduledWorkAction<Tick, AsyncContextProvider>> {
+		std::mem::take(&mut self.action_queue)
+	}
+
+	fn push_action(&mut self, action: ScheduledWorkAction<Tick, AsyncContextProvider>) {
+		self.action_queue.push(action);
+		self.signal.notify_actions();
+	}
+}
+
+impl Scheduler for AsyncScheduler {
+	type DelayedWorkFactory = AsyncDelayedOnceWorkFactory;
+	type ImmediateWorkFactory = AsyncImmediateOnceWorkFactory;
+	type RepeatedWorkFactory = AsyncRepeatingWorkFactory;
+	type InvokedWorkFactory = AsyncInvokedWorkFactory;
+	type ContinuousWorkFactory = AsyncContinuousWorkFactory;
+
+	fn schedule_work<W>(&mut self, mut work: W, cancellation_id: WorkCancellationId)
+	where
+		W: 'static
+			+ ScheduledWork<Tick = Tick, WorkContextProvider = AsyncContextProvider>
+			+ Send
+			+ Sync,
+	{
+		work.on_scheduled_hook(self.now());
+
+		self.push_action(ScheduledWorkAction::Activate((
+			cancellation_id,
+			Box::new(work),
+		)));
+	}
+
+	fn schedule_invoked_work<W>(&mut self, mut work: W, invoke_id: WorkInvokeId)
+	where
+		W: 'static
+			+ ScheduledWork<Tick = Self::Tick, WorkContextProvider = Self::WorkContextProvider>
+			+ Send
+			+ Sync,
+	{
+		work.on_scheduled_hook(self.now());
+
+		self.push_action(ScheduledWorkAction::AddInvoked((invoke_id, Box::new(work))));
+	}
+
+	fn invoke(&mut self, invoke_id: WorkInvokeId) {
+		self.push_action(ScheduledWorkAction::Invoke(invoke_id));
+	}
+
+	#[inline]
+	fn cancel_invoked(&mut self, invoke_id: WorkInvokeId) {
+		self.push_action(ScheduledWorkAction::CancelInvoked(invoke_id));
+	}
+
+	fn cancel(&mut self, cancellation_id: WorkCancellationId) {
+		self.push_action(ScheduledWorkAction::Cancel(cancellation_id));
+	}
+
+	fn generate_cancellation_id(&mut self) -> WorkCancellationId {
+		self.cancellation_id_generator.get_next()
+	}
+
+	fn generate_invoke_id(&mut self) -> WorkInvokeId {
+		self.invoke_id_generator.get_next()
+	}
+}
+
+impl SchedulerClock for AsyncScheduler {
+	#[inline]
+	fn current_tick(&self) -> Self::Tick {
+		self.now()
+	}
+}
@@ -0,0 +1,131 @@
+use std::{
+	sync::{Condvar, Mutex, MutexGuard},
+	task::Waker,
+	time::Instant,
+};
+
+#[derive(Debug)]
+struct ExecutorSignalState {
+	/// Set by the scheduler whenever a new action was queued, cleared by the
+	/// worker thread right before it drains the scheduler.
+	has_actions: bool,
+	is_idle: bool,
+	is_shut_down: bool,
+	idle_wakers: Vec<Waker>,
+}
+
+/// Shared between the scheduler, the executor and its worker thread to wake
+/// the worker up when there's something to do, and to let others know when
+/// there isn't.
+#[derive(Debug)]
+pub(crate) struct ExecutorSignal {
+	state: Mutex<ExecutorSignalState>,
+	condvar: Condvar,
+}
+
+impl Default for ExecutorSignal {
+	fn default() -> Self {
+		Self {
+			state: Mutex::new(ExecutorSignalState {
+				has_actions: false,
+				is_idle: true,
+				is_shut_down: false,
+				idle_wakers: Vec::new(),
+			}),
+			condvar: Condvar::new(),
+		}
+	}
+}
+
+impl ExecutorSignal {
+	fn lock(&self) -> MutexGuard<'_, ExecutorSignalState> {
+		self.state
+			.lock()
+			.unwrap_or_else(|poison_error| poison_error.into_inner())
+	}
+
+	pub(crate) fn notify_actions(&self) {
+		let mut state = self.lock();
+		state.has_actions = true;
+		state.is_idle = false;
+		self.condvar.notify_all();
+	}
+
+	/// Returns whether there were actions queued since the last call.
+	pub(crate) fn take_actions(&self) -> bool {
+		let mut state = self.lock();
+		std::mem::replace(&mut state.has_actions, false)
+	}
+
+	pub(crate) fn shut_down(&self) {
+		let mut state = self.lock();
+		state.is_shut_down = true;
+		for waker in state.idle_wakers.drain(..) {
+			waker.wake();
+		}
+		self.condvar.notify_all();
+	}
+
+	pub(crate) fn is_shut_down(&self) -> bool {
+		self.lock().is_shut_down
+	}
+
+	pub(crate) fn set_idle(&self, is_idle: bool) {
+		let mut state = self.lock();
+		state.is_idle = is_idle && !state.has_actions;
+		if state.is_idle {
+			for waker in state.idle_wakers.drain(..) {
+				waker.wake();
+			}
+		}
+	}
+
+	pub(crate) fn is_idle(&self) -> bool {
+		let state = self.lock();
+		(state.is_idle && !state.has_actions) || state.is_shut_down
+	}
+
+	/// Returns `true` when idle, otherwise the waker will be woken once it is.
+	pub(crate) fn poll_idle(&self, waker: &Waker) -> bool {
+		let mut state = self.lock();
+		if (state.is_idle && !state.has_actions) || state.is_shut_down {
+			true
+		} else {
+			if !state
+				.idle_wakers
+				.iter()
+				.any(|idle_waker| idle_waker.will_wake(waker))
+			{
+				state.idle_wakers.push(waker.clone());
+			}
+			false
+		}
+	}
+
+	/// Blocks the current thread until either an action is queued, the
+	/// executor is shut down, or the deadline is reached.
+	pub(crate) fn wait(&self, deadline: Option<Instant>) {
+		let mut state = self.lock();
+		while !state.has_actions && !state.is_shut_down {
+			match deadline {
+				Some(deadline) => {
+					let now = Instant::now();
+					if deadline <= now {
+						break;
+					}
+					state = self
+						.condvar
+						.wait_timeout(state, deadline - now)
+						.map(|(state, _)| state)
+						.unwrap_or_else(|poison_error| poison_error.into_inner().0);
+				}
+				None => {
+					state = self
+						.condvar
+						.wait(state)
+						.unwrap_or_else(|poison_error| poison_error.into_inner());
+				}
+			}
+		}
+	}
+}
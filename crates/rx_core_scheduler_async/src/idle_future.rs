@@ -0,0 +1,72 @@
+use std::{
+	pin::{Pin, pin},
+	sync::Arc,
+	task::{Context, Poll, Wake, Waker},
+	thread::{self, Thread},
+};
+
+use crate::ExecutorSignal;
+
+/// Resolves once the [AsyncExecutor][crate::AsyncExecutor] it was created
+/// from has no work left to execute.
+#[derive(Debug)]
+pub struct IdleFuture {
+	signal: Arc<ExecutorSignal>,
+}
+
+impl IdleFuture {
+	pub(crate) fn new(signal: Arc<ExecutorSignal>) -> Self {
+		Self { signal }
+	}
+}
+
+impl Future for IdleFuture {
+	type Output = ();
+
+	fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+		if self.signal.poll_idle(context.waker()) {
+			Poll::Ready(())
+		} else {
+			Poll::Pending
+		}
+	}
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+	fn wake(self: Arc<Self>) {
+		self.0.unpark();
+	}
+}
+
+/// Drives a future to completion on the current thread, parking it while
+/// the future is pending.
+///
+/// Meant for synchronous entry points that want to wait for something async,
+/// like [AsyncExecutor::until_idle][crate::AsyncExecutor::until_idle].
+pub fn block_on<F>(future: F) -> F::Output
+where
+	F: Future,
+{
+	let mut future = pin!(future);
+	let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+	let mut context = Context::from_waker(&waker);
+
+	loop {
+		match future.as_mut().poll(&mut context) {
+			Poll::Ready(output) => return output,
+			Poll::Pending => thread::park(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn should_block_on_a_ready_future() {
+		assert_eq!(block_on(async { 1 + 1 }), 2);
+	}
+}
@@ -0,0 +1,29 @@
+mod async_context;
+mod async_executor;
+mod async_executor_options;
+mod async_executor_worker;
+mod async_scheduler;
+mod executor_signal;
+mod idle_future;
+mod timer_wheel;
+mod work;
+mod work_id;
+
+pub use async_context::*;
+pub use async_executor::*;
+pub use async_executor_options::*;
+pub(crate) use async_executor_worker::*;
+pub use async_scheduler::*;
+pub(crate) use executor_signal::*;
+pub use idle_future::*;
+pub(crate) use timer_wheel::*;
+pub use work::*;
+pub(crate) use work_id::*;
+
+pub mod scheduler {
+	pub use super::async_context::*;
+	pub use super::async_executor::*;
+	pub use super::async_executor_options::*;
+	pub use super::async_scheduler::*;
+	pub use super::idle_future::*;
+}
@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+#[derive(Debug)]
+struct TimerEntry<K> {
+	step: u64,
+	key: K,
+}
+
+/// A hashed timing wheel.
+///
+/// Time is divided into steps of `resolution` length, and each step is
+/// hashed into one of the slots. Entries further away than a full revolution
+/// share slots with nearer ones, and are only expired once their own step is
+/// reached.
+///
+/// Entries can't be removed, the owner of the keys is expected to ignore
+/// expired keys that are no longer relevant.
+#[derive(Debug)]
+pub(crate) struct TimerWheel<K> {
+	resolution_nanos: u128,
+	slots: Vec<Vec<TimerEntry<K>>>,
+	/// The first step that was not yet expired.
+	cursor: u64,
+	len: usize,
+}
+
+impl<K> TimerWheel<K> {
+	pub(crate) fn new(resolution: Duration, slot_count: usize) -> Self {
+		Self {
+			resolution_nanos: resolution.as_nanos().max(1),
+			slots: (0..slot_count.max(1)).map(|_| Vec::new()).collect(),
+			cursor: 0,
+			len: 0,
+		}
+	}
+
+	#[inline]
+	pub(crate) fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	#[inline]
+	fn slot_of(&self, step: u64) -> usize {
+		(step % self.slots.len() as u64) as usize
+	}
+
+	#[inline]
+	fn step_to_duration(&self, step: u64) -> Duration {
+		let nanos = (step as u128).saturating_mul(self.resolution_nanos);
+		Duration::from_nanos(nanos.min(u64::MAX as u128) as u64)
+	}
+
+	/// Inserts a key that should expire once `deadline` is reached. Deadlines
+	/// that are already in the past will expire on the next step.
+	pub(crate) fn insert(&mut self, deadline: Duration, key: K) {
+		let step = deadline
+			.as_nanos()
+			.div_ceil(self.resolution_nanos)
+			.min(u64::MAX as u128) as u64;
+		let step = step.max(self.cursor);
+		let slot = self.slot_of(step);
+		self.slots[slot].push(TimerEntry { step, key });
+		self.len += 1;
+	}
+
+	/// Expires every entry whose deadline is at or before `now`, returning
+	/// their keys ordered by their deadlines.
+	pub(crate) fn advance_to(&mut self, now: Duration) -> Vec<K> {
+		let target = (now.as_nanos() / self.resolution_nanos).min(u64::MAX as u128 - 1) as u64;
+		if target < self.cursor {
+			return Vec::new();
+		}
+
+		let mut expired = Vec::<TimerEntry<K>>::new();
+
+		if !self.is_empty() {
+			// Beyond a full revolution every slot is visited exactly once
+			let steps = (target - self.cursor + 1).min(self.slots.len() as u64);
+			for offset in 0..steps {
+				let slot = self.slot_of(self.cursor + offset);
+				let entries = std::mem::take(&mut self.slots[slot]);
+				let (due, pending): (Vec<_>, Vec<_>) =
+					entries.into_iter().partition(|entry| entry.step <= target);
+				self.slots[slot] = pending;
+				expired.extend(due);
+			}
+		}
+
+		self.cursor = target + 1;
+		self.len -= expired.len();
+		expired.sort_by_key(|entry| entry.step);
+		expired.into_iter().map(|entry| entry.key).collect()
+	}
+
+	/// Returns the time when the earliest entry is going to expire.
+	pub(crate) fn next_deadline(&self) -> Option<Duration> {
+		if self.is_empty() {
+			return None;
+		}
+
+		for offset in 0..self.slots.len() as u64 {
+			let step = self.cursor + offset;
+			if self.slots[self.slot_of(step)]
+				.iter()
+				.any(|entry| entry.step == step)
+			{
+				return Some(self.step_to_duration(step));
+			}
+		}
+
+		// Everything is at least a full revolution away
+		self.slots
+			.iter()
+			.flatten()
+			.map(|entry| entry.step)
+			.min()
+			.map(|step| self.step_to_duration(step))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::time::Duration;
+
+	use super::TimerWheel;
+
+	fn ms(millis: u64) -> Duration {
+		Duration::from_millis(millis)
+	}
+
+	#[test]
+	fn should_be_empty_by_default() {
+		let timer_wheel = TimerWheel::<usize>::new(ms(1), 8);
+		assert!(timer_wheel.is_empty());
+		assert_eq!(timer_wheel.next_deadline(), None);
+	}
+
+	#[test]
+	fn should_expire_entries_once_their_deadline_is_reached() {
+		let mut timer_wheel = TimerWheel::<usize>::new(ms(1), 8);
+		timer_wheel.insert(ms(3), 1);
+		timer_wheel.insert(ms(5), 2);
+
+		assert!(timer_wheel.advance_to(ms(2)).is_empty());
+		assert_eq!(timer_wheel.advance_to(ms(3)), vec![1]);
+		assert_eq!(timer_wheel.advance_to(ms(10)), vec![2]);
+		assert!(timer_wheel.is_empty());
+	}
+
+	#[test]
+	fn should_not_expire_entries_a_full_revolution_away() {
+		let mut timer_wheel = TimerWheel::<usize>::new(ms(1), 4);
+		timer_wheel.insert(ms(2), 1);
+		timer_wheel.insert(ms(6), 2);
+
+		assert_eq!(timer_wheel.advance_to(ms(2)), vec![1]);
+		assert!(timer_wheel.advance_to(ms(5)).is_empty());
+		assert_eq!(timer_wheel.advance_to(ms(6)), vec![2]);
+	}
+
+	#[test]
+	fn should_order_expired_entries_by_their_deadlines_when_skipping_revolutions() {
+		let mut timer_wheel = TimerWheel::<usize>::new(ms(1), 4);
+		timer_wheel.insert(ms(7), 1);
+		timer_wheel.insert(ms(1), 2);
+		timer_wheel.insert(ms(4), 3);
+
+		assert_eq!(timer_wheel.advance_to(ms(100)), vec![2, 3, 1]);
+	}
+
+	#[test]
+	fn should_expire_past_deadlines_on_the_next_step() {
+		let mut timer_wheel = TimerWheel::<usize>::new(ms(1), 8);
+		timer_wheel.advance_to(ms(10));
+		timer_wheel.insert(ms(3), 1);
+
+		assert_eq!(timer_wheel.next_deadline(), Some(ms(11)));
+		assert_eq!(timer_wheel.advance_to(ms(11)), vec![1]);
+	}
+
+	#[test]
+	fn should_round_deadlines_up_to_the_resolution() {
+		let mut timer_wheel = TimerWheel::<usize>::new(ms(10), 8);
+		timer_wheel.insert(ms(15), 1);
+
+		assert_eq!(timer_wheel.next_deadline(), Some(ms(20)));
+		assert!(timer_wheel.advance_to(ms(15)).is_empty());
+		assert_eq!(timer_wheel.advance_to(ms(20)), vec![1]);
+	}
+
+	#[test]
+	fn should_find_the_next_deadline_beyond_a_full_revolution() {
+		let mut timer_wheel = TimerWheel::<usize>::new(ms(1), 4);
+		timer_wheel.insert(ms(9), 1);
+		timer_wheel.insert(ms(13), 2);
+
+		assert_eq!(timer_wheel.next_deadline(), Some(ms(9)));
+	}
+}
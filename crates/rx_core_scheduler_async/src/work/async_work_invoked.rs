@@ -0,0 +1,52 @@
+use derive_where::derive_where;
+use rx_core_common::{
+	InvokedTaskFactory, ScheduledRepeatedWork, ScheduledWork, WorkKind, WorkResult,
+};
+use rx_core_macro_work_derive::RxWork;
+use rx_core_scheduler_ticking::Tick;
+
+use crate::{AsyncContext, AsyncContextProvider};
+
+pub struct AsyncInvokedWorkFactory;
+
+impl InvokedTaskFactory<Tick, AsyncContextProvider> for AsyncInvokedWorkFactory {
+	type Item<Work>
+		= AsyncInvokedWork<Work>
+	where
+		Work: ScheduledRepeatedWork<Tick, AsyncContextProvider>;
+
+	fn new<Work>(work: Work) -> Self::Item<Work>
+	where
+		Work: ScheduledRepeatedWork<Tick, AsyncContextProvider>,
+	{
+		AsyncInvokedWork { work }
+	}
+}
+
+#[derive(RxWork)]
+#[rx_tick(Tick)]
+#[rx_context(AsyncContextProvider)]
+#[derive_where(Debug)]
+pub struct AsyncInvokedWork<Work>
+where
+	Work: ScheduledRepeatedWork<Tick, AsyncContextProvider>,
+{
+	#[derive_where(skip(Debug))]
+	work: Work,
+}
+
+impl<Work> ScheduledWork for AsyncInvokedWork<Work>
+where
+	Work: ScheduledRepeatedWork<Tick, AsyncContextProvider>,
+{
+	#[inline]
+	fn tick(&mut self, tick_input: Self::Tick, context: &mut AsyncContext) -> WorkResult {
+		(self.work)(tick_input, context)
+	}
+
+	fn on_scheduled_hook(&mut self, _tick_input: Self::Tick) {}
+
+	fn kind(&self) -> WorkKind {
+		WorkKind::Invoked
+	}
+}
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use derive_where::derive_where;
+use rx_core_common::{
+	DelayedWork, DelayedWorkFactory, ScheduledOnceWork, ScheduledWork, WorkKind, WorkResult,
+};
+use rx_core_macro_work_derive::RxWork;
+use rx_core_scheduler_ticking::Tick;
+
+use crate::{AsyncContext, AsyncContextProvider};
+
+pub struct AsyncDelayedOnceWorkFactory;
+
+impl DelayedWorkFactory<Tick, AsyncContextProvider> for AsyncDelayedOnceWorkFactory {
+	type Item<Work>
+		= AsyncDelayedOnceWork<Work>
+	where
+		Work: ScheduledOnceWork<Tick, AsyncContextProvider>;
+
+	fn new<Work>(work: Work, delay: Duration) -> Self::Item<Work>
+	where
+		Work: ScheduledOnceWork<Tick, AsyncContextProvider>,
+	{
+		AsyncDelayedOnceWork {
+			work: Some(work),
+			scheduled_on: Tick::default(),
+			delay,
+		}
+	}
+}
+
+#[derive(RxWork)]
+#[rx_tick(Tick)]
+#[rx_context(AsyncContextProvider)]
+#[derive_where(Debug)]
+pub struct AsyncDelayedOnceWork<Work>
+where
+	Work: ScheduledOnceWork<Tick, AsyncContextProvider>,
+{
+	scheduled_on: Tick,
+	delay: Duration,
+
+	#[derive_where(skip(Debug))]
+	work: Option<Work>,
+}
+
+impl<Work> DelayedWork<Work, Tick, AsyncContextProvider> for AsyncDelayedOnceWork<Work> where
+	Work: ScheduledOnceWork<Tick, AsyncContextProvider>
+{
+}
+
+impl<Work> ScheduledWork for AsyncDelayedOnceWork<Work>
+where
+	Work: ScheduledOnceWork<Tick, AsyncContextProvider>,
+{
+	fn tick(&mut self, tick: Tick, context: &mut AsyncContext) -> WorkResult {
+		let due_on = self.scheduled_on + self.delay;
+		if due_on <= tick {
+			if let Some(work) = self.work.take() {
+				(work)(tick, context);
+			}
+			WorkResult::Done
+		} else {
+			context.wake_at(due_on);
+			WorkResult::Pending
+		}
+	}
+
+	fn on_scheduled_hook(&mut self, tick_input: Self::Tick) {
+		self.scheduled_on.update(tick_input);
+	}
+
+	fn kind(&self) -> WorkKind {
+		WorkKind::Delayed
+	}
+
+	fn next_due(&self) -> Option<Self::Tick> {
+		Some(self.scheduled_on + self.delay)
+	}
+}
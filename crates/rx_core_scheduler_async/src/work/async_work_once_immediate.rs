@@ -0,0 +1,54 @@
+use derive_where::derive_where;
+use rx_core_common::{
+	ImmediateTaskFactory, ScheduledOnceWork, ScheduledWork, WorkKind, WorkResult,
+};
+use rx_core_macro_work_derive::RxWork;
+use rx_core_scheduler_ticking::Tick;
+
+use crate::{AsyncContext, AsyncContextProvider};
+
+pub struct AsyncImmediateOnceWorkFactory;
+
+impl ImmediateTaskFactory<Tick, AsyncContextProvider> for AsyncImmediateOnceWorkFactory {
+	type Item<Work>
+		= AsyncImmediateOnceWork<Work>
+	where
+		Work: ScheduledOnceWork<Tick, AsyncContextProvider>;
+
+	fn new<Work>(work: Work) -> Self::Item<Work>
+	where
+		Work: ScheduledOnceWork<Tick, AsyncContextProvider>,
+	{
+		AsyncImmediateOnceWork { work: Some(work) }
+	}
+}
+
+#[derive(RxWork)]
+#[rx_tick(Tick)]
+#[rx_context(AsyncContextProvider)]
+#[derive_where(Debug)]
+pub struct AsyncImmediateOnceWork<Work>
+where
+	Work: ScheduledOnceWork<Tick, AsyncContextProvider>,
+{
+	#[derive_where(skip(Debug))]
+	work: Option<Work>,
+}
+
+impl<Work> ScheduledWork for AsyncImmediateOnceWork<Work>
+where
+	Work: ScheduledOnceWork<Tick, AsyncContextProvider>,
+{
+	fn tick(&mut self, tick: Tick, context: &mut AsyncContext) -> WorkResult {
+		if let Some(work) = self.work.take() {
+			(work)(tick, context);
+		};
+		WorkResult::Done
+	}
+
+	fn on_scheduled_hook(&mut self, _tick_input: Self::Tick) {}
+
+	fn kind(&self) -> WorkKind {
+		WorkKind::Immediate
+	}
+}
@@ -0,0 +1,100 @@
+use core::{num::NonZero, time::Duration};
+
+use derive_where::derive_where;
+use rx_core_common::{
+	RepeatedTaskFactory, ScheduledRepeatedWork, ScheduledWork, WorkKind, WorkResult,
+};
+use rx_core_macro_work_derive::RxWork;
+use rx_core_scheduler_ticking::Tick;
+
+use crate::{AsyncContext, AsyncContextProvider};
+
+pub struct AsyncRepeatingWorkFactory;
+
+impl RepeatedTaskFactory<Tick, AsyncContextProvider> for AsyncRepeatingWorkFactory {
+	type Item<Work>
+		= AsyncRepeatingWork<Work>
+	where
+		Work: ScheduledRepeatedWork<Tick, AsyncContextProvider>;
+
+	fn new<Work>(
+		work: Work,
+		interval: Duration,
+		start_immediately: bool,
+		max_work_per_tick: NonZero<usize>,
+	) -> Self::Item<Work>
+	where
+		Work: ScheduledRepeatedWork<Tick, AsyncContextProvider>,
+	{
+		AsyncRepeatingWork {
+			start_immediately,
+			consumed_until: Tick::default(),
+			interval,
+			max_work_per_tick,
+			work,
+		}
+	}
+}
+
+#[derive(RxWork)]
+#[rx_tick(Tick)]
+#[rx_context(AsyncContextProvider)]
+#[derive_where(Debug)]
+pub struct AsyncRepeatingWork<Work>
+where
+	Work: ScheduledRepeatedWork<Tick, AsyncContextProvider>,
+{
+	/// The work will be executed on the first tick too, regardless if the timer
+	/// had elapsed or not.
+	start_immediately: bool,
+	consumed_until: Tick,
+	interval: Duration,
+	max_work_per_tick: NonZero<usize>,
+	#[derive_where(skip(Debug))]
+	work: Work,
+}
+
+impl<Work> ScheduledWork for AsyncRepeatingWork<Work>
+where
+	Work: ScheduledRepeatedWork<Tick, AsyncContextProvider>,
+{
+	fn tick(&mut self, tick_input: Self::Tick, context: &mut AsyncContext) -> WorkResult {
+		let mut work_result = WorkResult::Pending;
+		let mut executions: usize = 0;
+
+		while (self.consumed_until + self.interval <= tick_input
+			&& !matches!(work_result, WorkResult::Done))
+			|| self.start_immediately
+		{
+			if executions < self.max_work_per_tick.into() {
+				work_result += (self.work)(tick_input, context);
+			}
+			// The consumed until marker has to advance all the way,
+			// regardless of how much work was allowed to execute
+			if !self.start_immediately {
+				self.consumed_until += self.interval;
+			} else {
+				self.start_immediately = false;
+			}
+			executions += 1;
+		}
+
+		if matches!(work_result, WorkResult::Pending) {
+			context.wake_at(self.consumed_until + self.interval);
+		}
+
+		work_result
+	}
+
+	fn on_scheduled_hook(&mut self, tick_input: Self::Tick) {
+		self.consumed_until.update(tick_input);
+	}
+
+	fn kind(&self) -> WorkKind {
+		WorkKind::Repeated
+	}
+
+	fn next_due(&self) -> Option<Self::Tick> {
+		(!self.start_immediately).then(|| self.consumed_until + self.interval)
+	}
+}
@@ -0,0 +1,11 @@
+mod async_work_continuous;
+mod async_work_invoked;
+mod async_work_once_delayed;
+mod async_work_once_immediate;
+mod async_work_repeating;
+
+pub use async_work_continuous::*;
+pub use async_work_invoked::*;
+pub use async_work_once_delayed::*;
+pub use async_work_once_immediate::*;
+pub use async_work_repeating::*;
@@ -1,14 +1,22 @@
+mod scheduled_work_info;
 mod tick;
+mod tick_budget;
 mod tick_index;
 mod ticking_executor;
+mod ticking_executor_metrics;
 mod ticking_scheduler;
+mod tracked_work;
 mod work;
 mod work_id;
 
+pub use scheduled_work_info::*;
 pub use tick::*;
+pub use tick_budget::*;
 pub use tick_index::*;
 pub use ticking_executor::*;
+pub use ticking_executor_metrics::*;
 pub use ticking_scheduler::*;
+pub(crate) use tracked_work::*;
 pub use work::*;
 pub(crate) use work_id::*;
 
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use rx_core_common::{ScheduledWork, WorkCancellationId, WorkInvokeId, WorkKind, WorkPriority};
+
+use crate::Tick;
+
+/// Identifies a scheduled work by the id it was scheduled with.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub enum ScheduledWorkId {
+	Cancellation(WorkCancellationId),
+	Invoke(WorkInvokeId),
+}
+
+/// Execution statistics of a single scheduled work.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WorkStats {
+	/// How many times the work was ticked by the executor.
+	pub run_count: usize,
+	/// The real time spent executing the work. Only measured when execution
+	/// time tracking is enabled on the executor.
+	pub execution_time: Duration,
+}
+
+/// A snapshot describing a scheduled work.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScheduledWorkInfo {
+	pub id: ScheduledWorkId,
+	pub kind: WorkKind,
+	pub priority: WorkPriority,
+	/// The earliest tick the work is expected to execute on. `None` when
+	/// it's executed on the next tick, or only when invoked.
+	pub next_due: Option<Tick>,
+	pub stats: WorkStats,
+}
+
+impl ScheduledWorkInfo {
+	pub(crate) fn new<W>(id: ScheduledWorkId, work: &W, stats: WorkStats) -> Self
+	where
+		W: ScheduledWork<Tick = Tick> + ?Sized,
+	{
+		Self {
+			id,
+			// Whatever the work is, if it's stored by its invoke id, it's
+			// only executed when invoked.
+			kind: match id {
+				ScheduledWorkId::Invoke(_) => WorkKind::Invoked,
+				ScheduledWorkId::Cancellation(_) => work.kind(),
+			},
+			priority: work.priority(),
+			next_due: work.next_due(),
+			stats,
+		}
+	}
+}
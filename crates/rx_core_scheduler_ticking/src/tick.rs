@@ -7,6 +7,8 @@ use rx_core_common::WorkTick;
 
 /// Used for scheduling, subscriptions are ticked with this event
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Tick {
 	now: Duration,
 }
@@ -0,0 +1,58 @@
+use std::time::{Duration, Instant};
+
+/// Limits how much scheduled work a [`TickingSchedulerExecutor`][crate::TickingSchedulerExecutor]
+/// may execute in a single tick.
+///
+/// Work that doesn't fit into the budget spills over into the next tick,
+/// starting with the lowest priority work. Spilled over work is ticked
+/// before the rest of the work of the same priority on the next tick.
+/// [`WorkPriority::Critical`][rx_core_common::WorkPriority::Critical] work is
+/// never deferred.
+///
+/// Invoked work is not subject to the budget.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TickBudget {
+	/// The maximum number of work ticked in a single tick.
+	pub max_work: Option<usize>,
+	/// The maximum amount of real time spent ticking work in a single tick.
+	///
+	/// As it is measured between each work, a long running work can still
+	/// overshoot it.
+	pub max_duration: Option<Duration>,
+}
+
+impl TickBudget {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	#[must_use]
+	pub fn with_max_work(mut self, max_work: usize) -> Self {
+		self.max_work = Some(max_work);
+		self
+	}
+
+	#[must_use]
+	pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+		self.max_duration = Some(max_duration);
+		self
+	}
+
+	/// Returns `true` when neither limit is set.
+	pub fn is_unlimited(&self) -> bool {
+		self.max_work.is_none() && self.max_duration.is_none()
+	}
+
+	pub(crate) fn is_exhausted(
+		&self,
+		ticked_work: usize,
+		tick_started_at: Option<Instant>,
+	) -> bool {
+		self.max_work
+			.is_some_and(|max_work| ticked_work >= max_work)
+			|| self
+				.max_duration
+				.zip(tick_started_at)
+				.is_some_and(|(max_duration, started_at)| started_at.elapsed() >= max_duration)
+	}
+}
@@ -1,16 +1,19 @@
 use std::{
 	collections::{HashMap, HashSet},
-	time::Duration,
+	time::{Duration, Instant},
 };
 
 use indexmap::IndexMap;
 use rx_core_common::{
-	ScheduledWork, ScheduledWorkAction, Scheduler, SchedulerHandle, WorkCancellationId,
-	WorkContextProvider, WorkInvokeId, WorkResult, WorkTick,
+	ScheduledWorkAction, Scheduler, SchedulerHandle, WorkCancellationId, WorkContextProvider,
+	WorkInvokeId, WorkPriority, WorkResult, WorkTick,
 };
 use rx_core_macro_executor_derive::RxExecutor;
 
-use crate::{Tick, WorkId, WorkIdGenerator};
+use crate::{
+	ScheduledWorkId, ScheduledWorkInfo, Tick, TickBudget, TickingExecutorMetrics, TrackedWork,
+	WorkId, WorkIdGenerator, WorkStats,
+};
 
 const MAX_RECURSION_DEPTH: &str = "Recursive work exceeding `max_single_tick_recursion_depth`!";
 
@@ -23,6 +26,30 @@ pub trait SchedulerForTickingExecutor: Scheduler {
 
 	/// Returns true if there are work actions queued.
 	fn has_actions(&self) -> bool;
+
+	/// Work actions queued but not yet received by the executor.
+	fn get_queued_actions(&self) -> &[ScheduledWorkAction<Tick, Self::WorkContextProvider>];
+
+	/// Lists work scheduled but not yet received by the executor.
+	fn get_queued_work_info(&self) -> impl Iterator<Item = ScheduledWorkInfo> + '_ {
+		self.get_queued_actions()
+			.iter()
+			.filter_map(|action| match action {
+				ScheduledWorkAction::Activate((cancellation_id, work)) => {
+					Some(ScheduledWorkInfo::new(
+						ScheduledWorkId::Cancellation(*cancellation_id),
+						work.as_ref(),
+						WorkStats::default(),
+					))
+				}
+				ScheduledWorkAction::AddInvoked((invoke_id, work)) => Some(ScheduledWorkInfo::new(
+					ScheduledWorkId::Invoke(*invoke_id),
+					work.as_ref(),
+					WorkStats::default(),
+				)),
+				_ => None,
+			})
+	}
 }
 
 #[derive(RxExecutor)]
@@ -38,18 +65,17 @@ where
 	scheduler: SchedulerHandle<S>,
 	current_tick: Tick,
 	work_id_generator: WorkIdGenerator,
-	active_work: IndexMap<
-		WorkId,
-		Box<dyn ScheduledWork<Tick = Tick, WorkContextProvider = C> + Send + Sync>,
-	>,
+	active_work: IndexMap<WorkId, TrackedWork<C>>,
 	cancellation_map: HashMap<WorkCancellationId, Vec<WorkId>>,
-	invokable_work: HashMap<
-		WorkInvokeId,
-		Box<dyn ScheduledWork<Tick = Tick, WorkContextProvider = C> + Send + Sync>,
-	>,
+	invokable_work: HashMap<WorkInvokeId, TrackedWork<C>>,
 	invoked: Vec<WorkInvokeId>,
 	already_ticked: HashSet<WorkId>,
+	/// Work deferred to the next tick by the tick budget.
+	spilled_over: HashSet<WorkId>,
 	max_single_tick_recursion_depth: usize,
+	tick_budget: TickBudget,
+	metrics: TickingExecutorMetrics,
+	track_execution_time: bool,
 }
 
 impl<S, C> TickingSchedulerExecutor<S, C>
@@ -67,7 +93,11 @@ where
 			invoked: Vec::new(),
 			scheduler: SchedulerHandle::new(scheduler),
 			already_ticked: HashSet::new(),
+			spilled_over: HashSet::new(),
 			max_single_tick_recursion_depth: 100,
+			tick_budget: TickBudget::default(),
+			metrics: TickingExecutorMetrics::default(),
+			track_execution_time: false,
 		}
 	}
 
@@ -96,6 +126,56 @@ where
 		}
 	}
 
+	/// Limits how much work is executed in a single tick, the rest spills
+	/// over into the next tick, lowest priority first.
+	///
+	/// Default value: unlimited
+	pub fn with_tick_budget(self, tick_budget: TickBudget) -> Self {
+		Self {
+			tick_budget,
+			..self
+		}
+	}
+
+	pub fn set_tick_budget(&mut self, tick_budget: TickBudget) {
+		self.tick_budget = tick_budget;
+	}
+
+	pub fn get_tick_budget(&self) -> TickBudget {
+		self.tick_budget
+	}
+
+	pub fn get_metrics(&self) -> TickingExecutorMetrics {
+		self.metrics
+	}
+
+	/// Measures the real time spent executing each work, reported in
+	/// [`WorkStats::execution_time`][crate::WorkStats::execution_time].
+	///
+	/// Default value: false
+	pub fn with_execution_time_tracking(self, track_execution_time: bool) -> Self {
+		Self {
+			track_execution_time,
+			..self
+		}
+	}
+
+	pub fn set_execution_time_tracking(&mut self, track_execution_time: bool) {
+		self.track_execution_time = track_execution_time;
+	}
+
+	/// Lists all work received by the executor, active work in the order
+	/// they are ticked, then invokable work.
+	///
+	/// Work still in the scheduler's queue can be listed using
+	/// [`SchedulerForTickingExecutor::get_queued_work_info`].
+	pub fn get_work_info(&self) -> impl Iterator<Item = ScheduledWorkInfo> + '_ {
+		self.active_work
+			.values()
+			.chain(self.invokable_work.values())
+			.map(TrackedWork::info)
+	}
+
 	#[inline]
 	pub fn now(&self) -> Duration {
 		self.current_tick.now()
@@ -113,13 +193,17 @@ where
 	pub fn tick_to(&mut self, tick: Tick, context: &mut C::Item<'_>) {
 		self.current_tick.update(tick);
 		self.already_ticked.clear();
+		self.spilled_over.clear();
+		self.metrics.start_tick();
+		// Only reading the clock when it's actually used.
+		let tick_started_at = self.tick_budget.max_duration.map(|_| Instant::now());
 
 		// It should almost always finish in 1-2 loops, but in case something
 		// causes an infinitely scheduling recursive work, it should be loud.
 		for i in 0..=self.max_single_tick_recursion_depth {
 			let had_actions = self.drain_scheduler_queue(tick);
 
-			let no_work_ticked = self.tick_scheduled(tick, context);
+			let no_work_ticked = self.tick_scheduled(tick, context, tick_started_at);
 
 			self.execute_invoked(tick, context);
 
@@ -131,6 +215,21 @@ where
 				panic!("{}", MAX_RECURSION_DEPTH);
 			}
 		}
+
+		self.metrics.spilled_over_work = self.spilled_over.len();
+		self.metrics.end_tick();
+
+		if !self.spilled_over.is_empty() {
+			// Spilled over work goes first within its priority level on the
+			// next tick, so the same work isn't deferred over and over again.
+			self.active_work.sort_by(|a_id, a, b_id, b| {
+				b.work().priority().cmp(&a.work().priority()).then_with(|| {
+					self.spilled_over
+						.contains(b_id)
+						.cmp(&self.spilled_over.contains(a_id))
+				})
+			});
+		}
 	}
 
 	pub fn tick(&mut self, delta: Duration, context: &mut C::Item<'_>) {
@@ -141,7 +240,7 @@ where
 	fn execute_invoked(&mut self, tick: Tick, context: &mut C::Item<'_>) {
 		for invoked_id in self.invoked.drain(..) {
 			if let Some(invoked_work) = self.invokable_work.get_mut(&invoked_id) {
-				let invoke_result = invoked_work.tick(tick, context);
+				let invoke_result = invoked_work.tick(tick, context, self.track_execution_time);
 				if matches!(invoke_result, WorkResult::Done) {
 					self.invokable_work.remove(&invoked_id);
 				}
@@ -158,14 +257,27 @@ where
 			match action {
 				ScheduledWorkAction::<Tick, C>::Activate((cancellation_id, work)) => {
 					let work_id = self.work_id_generator.get_next();
-					self.active_work.insert(work_id, work);
+					// Active work is kept ordered by priority, and by insertion
+					// order within the same priority.
+					let priority = work.priority();
+					let index = self.active_work.partition_point(|_, active_work| {
+						active_work.work().priority() >= priority
+					});
+					self.active_work.shift_insert(
+						index,
+						work_id,
+						TrackedWork::new(ScheduledWorkId::Cancellation(cancellation_id), work),
+					);
 					self.cancellation_map
 						.entry(cancellation_id)
 						.or_default()
 						.push(work_id);
 				}
 				ScheduledWorkAction::AddInvoked((invoke_id, work)) => {
-					self.invokable_work.insert(invoke_id, work);
+					self.invokable_work.insert(
+						invoke_id,
+						TrackedWork::new(ScheduledWorkId::Invoke(invoke_id), work),
+					);
 				}
 				ScheduledWorkAction::Invoke(invoke_id) => {
 					self.invoked.push(invoke_id);
@@ -188,7 +300,12 @@ where
 		had_actions
 	}
 
-	fn tick_scheduled(&mut self, tick: Tick, context: &mut C::Item<'_>) -> bool {
+	fn tick_scheduled(
+		&mut self,
+		tick: Tick,
+		context: &mut C::Item<'_>,
+		tick_started_at: Option<Instant>,
+	) -> bool {
 		// Each work gets to tick once per tick. This is tracked so that if
 		// new work is scheduled, that also gets ticked in a new loop
 		// without ticking ones already ticked this loop
@@ -200,7 +317,17 @@ where
 			.iter_mut()
 			.filter(|(key, _)| !self.already_ticked.contains(key))
 		{
-			let work_result = work.tick(tick, context);
+			if work.work().priority() < WorkPriority::Critical
+				&& self
+					.tick_budget
+					.is_exhausted(self.metrics.ticked_work, tick_started_at)
+			{
+				self.spilled_over.insert(*work_id);
+				continue;
+			}
+
+			let work_result = work.tick(tick, context, self.track_execution_time);
+			self.metrics.ticked_work += 1;
 
 			if matches!(work_result, WorkResult::Done) {
 				work_finished_this_tick.push(*work_id);
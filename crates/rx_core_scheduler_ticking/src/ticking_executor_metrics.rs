@@ -0,0 +1,32 @@
+/// Counters about the work executed by a
+/// [`TickingSchedulerExecutor`][crate::TickingSchedulerExecutor].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TickingExecutorMetrics {
+	/// The number of work ticked during the last tick.
+	pub ticked_work: usize,
+	/// The number of work deferred to the next tick during the last tick
+	/// because the [`TickBudget`][crate::TickBudget] was exhausted.
+	pub spilled_over_work: usize,
+	/// The number of work ticked since the executor was created.
+	pub total_ticked_work: usize,
+	/// The number of times a work was deferred to a later tick since the
+	/// executor was created.
+	pub total_spilled_over_work: usize,
+	/// The number of ticks that exhausted the budget and deferred work.
+	pub ticks_with_spill_over: usize,
+}
+
+impl TickingExecutorMetrics {
+	pub(crate) fn start_tick(&mut self) {
+		self.ticked_work = 0;
+		self.spilled_over_work = 0;
+	}
+
+	pub(crate) fn end_tick(&mut self) {
+		self.total_ticked_work += self.ticked_work;
+		self.total_spilled_over_work += self.spilled_over_work;
+		if self.spilled_over_work > 0 {
+			self.ticks_with_spill_over += 1;
+		}
+	}
+}
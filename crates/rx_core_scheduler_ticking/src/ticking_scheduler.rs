@@ -1,7 +1,7 @@
 use derive_where::derive_where;
 use rx_core_common::{
-	ScheduledWork, ScheduledWorkAction, Scheduler, WorkCancellationId, WorkCancellationIdGenerator,
-	WorkContextProvider, WorkInvokeId, WorkInvokeIdGenerator,
+	ScheduledWork, ScheduledWorkAction, Scheduler, SchedulerClock, WorkCancellationId,
+	WorkCancellationIdGenerator, WorkContextProvider, WorkInvokeId, WorkInvokeIdGenerator,
 };
 use rx_core_macro_scheduler_derive::RxScheduler;
 
@@ -39,6 +39,11 @@ where
 		!self.action_queue.is_empty()
 	}
 
+	#[inline]
+	fn get_queued_actions(&self) -> &[ScheduledWorkAction<Tick, C>] {
+		&self.action_queue
+	}
+
 	#[inline]
 	fn update_tick(&mut self, tick: Tick) {
 		self.current_tick.update(tick);
@@ -104,3 +109,13 @@ where
 		self.invoke_id_generator.get_next()
 	}
 }
+
+impl<C> SchedulerClock for TickingScheduler<C>
+where
+	C: 'static + WorkContextProvider + Send + Sync,
+{
+	#[inline]
+	fn current_tick(&self) -> Self::Tick {
+		self.current_tick
+	}
+}
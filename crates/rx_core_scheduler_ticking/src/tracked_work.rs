@@ -0,0 +1,60 @@
+use std::time::Instant;
+
+use rx_core_common::{ScheduledWork, WorkContextProvider, WorkResult};
+
+use crate::{ScheduledWorkId, ScheduledWorkInfo, Tick, WorkStats};
+
+/// Work as stored by the executor, along with its statistics.
+pub(crate) struct TrackedWork<C>
+where
+	C: WorkContextProvider,
+{
+	id: ScheduledWorkId,
+	work: Box<dyn ScheduledWork<Tick = Tick, WorkContextProvider = C> + Send + Sync>,
+	stats: WorkStats,
+}
+
+impl<C> TrackedWork<C>
+where
+	C: WorkContextProvider,
+{
+	pub(crate) fn new(
+		id: ScheduledWorkId,
+		work: Box<dyn ScheduledWork<Tick = Tick, WorkContextProvider = C> + Send + Sync>,
+	) -> Self {
+		Self {
+			id,
+			work,
+			stats: WorkStats::default(),
+		}
+	}
+
+	#[inline]
+	pub(crate) fn work(
+		&self,
+	) -> &(dyn ScheduledWork<Tick = Tick, WorkContextProvider = C> + Send + Sync) {
+		self.work.as_ref()
+	}
+
+	pub(crate) fn tick(
+		&mut self,
+		tick: Tick,
+		context: &mut C::Item<'_>,
+		track_execution_time: bool,
+	) -> WorkResult {
+		self.stats.run_count += 1;
+
+		if track_execution_time {
+			let started_at = Instant::now();
+			let work_result = self.work.tick(tick, context);
+			self.stats.execution_time += started_at.elapsed();
+			work_result
+		} else {
+			self.work.tick(tick, context)
+		}
+	}
+
+	pub(crate) fn info(&self) -> ScheduledWorkInfo {
+		ScheduledWorkInfo::new(self.id, self.work.as_ref(), self.stats)
+	}
+}
@@ -3,6 +3,7 @@ use std::{marker::PhantomData, time::Duration};
 use derive_where::derive_where;
 use rx_core_common::{
 	DelayedWork, DelayedWorkFactory, PhantomInvariant, ScheduledOnceWork, WorkContextProvider,
+	WorkKind,
 };
 use rx_core_macro_work_derive::RxWork;
 
@@ -86,4 +87,12 @@ where
 		self.scheduled_on.update(tick_input);
 		self.current_tick.update(tick_input);
 	}
+
+	fn kind(&self) -> WorkKind {
+		WorkKind::Delayed
+	}
+
+	fn next_due(&self) -> Option<Self::Tick> {
+		Some(self.scheduled_on + self.delay)
+	}
 }
@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 use derive_where::derive_where;
 use rx_core_common::{
 	ImmediateTaskFactory, PhantomInvariant, ScheduledOnceWork, ScheduledWork, WorkContextProvider,
-	WorkResult,
+	WorkKind, WorkResult,
 };
 use rx_core_macro_work_derive::RxWork;
 
@@ -63,4 +63,8 @@ where
 	}
 
 	fn on_scheduled_hook(&mut self, _tick_input: Self::Tick) {}
+
+	fn kind(&self) -> WorkKind {
+		WorkKind::Immediate
+	}
 }
@@ -3,7 +3,7 @@ use core::{marker::PhantomData, num::NonZero, time::Duration};
 use derive_where::derive_where;
 use rx_core_common::{
 	PhantomInvariant, RepeatedTaskFactory, ScheduledRepeatedWork, ScheduledWork,
-	WorkContextProvider, WorkResult,
+	WorkContextProvider, WorkKind, WorkResult,
 };
 use rx_core_macro_work_derive::RxWork;
 
@@ -101,4 +101,12 @@ where
 		self.consumed_until.update(tick_input);
 		self.current_tick.update(tick_input);
 	}
+
+	fn kind(&self) -> WorkKind {
+		WorkKind::Repeated
+	}
+
+	fn next_due(&self) -> Option<Self::Tick> {
+		(!self.start_immediately).then(|| self.consumed_until + self.interval)
+	}
 }
@@ -0,0 +1,43 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+use rx_core_common::{Never, PhantomInvariant, Provider, Signal};
+
+use crate::subject::BehaviorSubject;
+
+/// Provides a new [BehaviorSubject] starting with a clone of the initial
+/// value on every call, for use as the connector of a connectable observable.
+#[derive_where(Clone, Debug; In)]
+pub struct BehaviorSubjectProvider<In, InError = Never>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	initial_value: In,
+	_phantom_data: PhantomInvariant<InError>,
+}
+
+impl<In, InError> BehaviorSubjectProvider<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	pub fn new(initial_value: In) -> Self {
+		Self {
+			initial_value,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError> Provider for BehaviorSubjectProvider<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	type Provided = BehaviorSubject<In, InError>;
+
+	fn provide(&self) -> Self::Provided {
+		BehaviorSubject::new(self.initial_value.clone())
+	}
+}
@@ -1,7 +1,9 @@
 mod behavior_subject;
+mod behavior_subject_provider;
 
 pub mod subject {
 	pub use super::behavior_subject::*;
+	pub use super::behavior_subject_provider::*;
 }
 
 pub mod prelude {
@@ -3,6 +3,7 @@ mod multicast_notification;
 mod multicast_notification_errors;
 mod multicast_subscriber_id;
 mod multicast_subscription;
+mod publish_observable;
 mod publish_subject;
 
 pub mod internal {
@@ -14,5 +15,6 @@ pub mod internal {
 }
 
 pub mod subject {
+	pub use super::publish_observable::*;
 	pub use super::publish_subject::*;
 }
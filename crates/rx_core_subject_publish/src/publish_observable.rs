@@ -0,0 +1,71 @@
+use derive_where::derive_where;
+use rx_core_common::{
+	Never, Observable, Signal, Subscriber, SubscriptionLike, UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+
+use crate::{internal::MulticastSubscription, subject::PublishSubject};
+
+/// # [PublishObservable]
+///
+/// A read-only view of a [PublishSubject]. It can only be subscribed to, so
+/// whoever receives it can't push signals into the subject.
+///
+/// Created by [PublishSubject::as_observable].
+#[derive_where(Clone)]
+#[derive(RxObservable, Debug)]
+#[rx_out(Out)]
+#[rx_out_error(OutError)]
+pub struct PublishObservable<Out, OutError = Never>
+where
+	Out: Signal + Clone,
+	OutError: Signal + Clone,
+{
+	subject: PublishSubject<Out, OutError>,
+}
+
+impl<Out, OutError> PublishObservable<Out, OutError>
+where
+	Out: Signal + Clone,
+	OutError: Signal + Clone,
+{
+	/// Whether the underlying subject was already completed, errored or
+	/// unsubscribed.
+	#[inline]
+	pub fn is_closed(&self) -> bool {
+		self.subject.is_closed()
+	}
+}
+
+impl<Out, OutError> From<PublishSubject<Out, OutError>> for PublishObservable<Out, OutError>
+where
+	Out: Signal + Clone,
+	OutError: Signal + Clone,
+{
+	fn from(subject: PublishSubject<Out, OutError>) -> Self {
+		Self { subject }
+	}
+}
+
+impl<Out, OutError> Observable for PublishObservable<Out, OutError>
+where
+	Out: Signal + Clone,
+	OutError: Signal + Clone,
+{
+	type Subscription<Destination>
+		= MulticastSubscription<Out, OutError>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	#[inline]
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		self.subject.subscribe(destination)
+	}
+}
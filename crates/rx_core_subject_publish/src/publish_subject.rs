@@ -7,9 +7,12 @@ use rx_core_common::{
 };
 use rx_core_macro_subject_derive::RxSubject;
 
-use crate::internal::{
-	MulticastDeferredState, MulticastNotification, MulticastSubscriberIdGenerator,
-	MulticastSubscription, SharedSubscribers,
+use crate::{
+	internal::{
+		MulticastDeferredState, MulticastNotification, MulticastSubscriberIdGenerator,
+		MulticastSubscription, SharedSubscribers,
+	},
+	subject::PublishObservable,
 };
 
 /// # [PublishSubject]
@@ -93,6 +96,13 @@ where
 		let state = self.deferred_state.lock_ignore_poison();
 		state.observed_error.is_some()
 	}
+
+	/// Returns a [PublishObservable] that can only subscribe to this subject,
+	/// but can't push signals into it.
+	#[inline]
+	pub fn as_observable(&self) -> PublishObservable<In, InError> {
+		PublishObservable::from(self.clone())
+	}
 }
 
 impl<In, InError> Observable for PublishSubject<In, InError>
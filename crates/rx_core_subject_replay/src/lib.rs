@@ -1,7 +1,22 @@
+mod replay_buffer;
+mod replay_buffer_subject;
 mod replay_subject;
+mod replay_subject_provider;
+mod replay_window_options;
+mod replay_window_subject;
+
+pub use replay_window_options::*;
 
 pub mod subject {
+	pub use super::replay_buffer_subject::*;
 	pub use super::replay_subject::*;
+	pub use super::replay_subject_provider::*;
+	pub use super::replay_window_options::*;
+	pub use super::replay_window_subject::*;
+}
+
+pub(crate) mod internal {
+	pub(crate) use super::replay_buffer::*;
 }
 
 pub mod prelude {
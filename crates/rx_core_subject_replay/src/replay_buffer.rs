@@ -0,0 +1,52 @@
+use std::collections::VecDeque;
+
+/// Storage for the runtime configured replay subjects, evicting the oldest
+/// values when the capacity is reached.
+#[derive(Debug)]
+pub(crate) struct ReplayBuffer<In> {
+	/// `None` means unbounded
+	capacity: Option<usize>,
+	entries: VecDeque<In>,
+}
+
+impl<In> ReplayBuffer<In>
+where
+	In: Clone,
+{
+	pub(crate) fn new(capacity: Option<usize>) -> Self {
+		Self {
+			capacity,
+			entries: VecDeque::new(),
+		}
+	}
+
+	/// Stores a value, evicting the oldest one if the capacity is reached.
+	pub(crate) fn push(&mut self, value: In) {
+		if let Some(capacity) = self.capacity {
+			if capacity == 0 {
+				return;
+			}
+
+			while self.entries.len() >= capacity {
+				self.entries.pop_front();
+			}
+		}
+
+		self.entries.push_back(value);
+	}
+
+	/// Evicts the oldest values for as long as `predicate` holds for them.
+	pub(crate) fn evict_while(&mut self, predicate: impl Fn(&In) -> bool) {
+		while self.entries.front().is_some_and(&predicate) {
+			self.entries.pop_front();
+		}
+	}
+
+	pub(crate) fn values(&self) -> Vec<In> {
+		self.entries.iter().cloned().collect()
+	}
+
+	pub(crate) fn iter(&self) -> impl Iterator<Item = &In> {
+		self.entries.iter()
+	}
+}
@@ -0,0 +1,119 @@
+use std::sync::{Arc, Mutex};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Never, Observable, RxObserver, Signal, Subscriber, UpgradeableObserver,
+};
+use rx_core_macro_subject_derive::RxSubject;
+use rx_core_subject_publish::{internal::MulticastSubscription, subject::PublishSubject};
+
+use crate::internal::ReplayBuffer;
+
+/// A [ReplayBufferSubject] is a [ReplaySubject][crate::subject::ReplaySubject]
+/// whose capacity is decided at runtime, or is not limited at all.
+///
+/// Upon subscription it immediately replays the stored values, at most the
+/// last `capacity` of them.
+#[derive(RxSubject, Clone)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+#[rx_delegate_subscription_like_to_destination]
+pub struct ReplayBufferSubject<In, InError = Never>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	#[destination]
+	subject: PublishSubject<In, InError>,
+	/// Shared data across clones
+	values: Arc<Mutex<ReplayBuffer<In>>>,
+}
+
+impl<In, InError> ReplayBufferSubject<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	/// Creates a subject that replays the last `capacity` values.
+	pub fn new(capacity: usize) -> Self {
+		Self::new_with_capacity(Some(capacity))
+	}
+
+	/// Creates a subject that replays every value it has ever observed.
+	///
+	/// > Memory usage grows with every observed value! Prefer a bounded
+	/// > capacity or a [ReplayWindowSubject][crate::subject::ReplayWindowSubject]
+	/// > for long living subjects.
+	pub fn unbounded() -> Self {
+		Self::new_with_capacity(None)
+	}
+
+	fn new_with_capacity(capacity: Option<usize>) -> Self {
+		Self {
+			subject: PublishSubject::default(),
+			values: Arc::new(Mutex::new(ReplayBuffer::new(capacity))),
+		}
+	}
+
+	/// Returns a clone of the currently stored values
+	/// In case you want to access the current values, prefer using a
+	/// subscription to keep your code reactive; only use this when it is
+	/// absolutely necessary.
+	pub fn values(&self) -> Vec<In> {
+		self.values.lock_ignore_poison().values()
+	}
+}
+
+impl<In, InError> RxObserver for ReplayBufferSubject<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	fn next(&mut self, next: In) {
+		self.values.lock_ignore_poison().push(next.clone());
+
+		self.subject.next(next);
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.subject.error(error);
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.subject.complete();
+	}
+}
+
+impl<In, InError> Observable for ReplayBufferSubject<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	type Subscription<Destination>
+		= MulticastSubscription<In, InError>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+	{
+		let mut destination = destination.upgrade();
+
+		if !self.subject.is_errored() {
+			let values = self.values.lock_ignore_poison().values();
+
+			for value in values {
+				destination.next(value);
+			}
+		}
+
+		self.subject.subscribe(destination)
+	}
+}
@@ -0,0 +1,91 @@
+use std::marker::PhantomData;
+
+use derive_where::derive_where;
+use rx_core_common::{Never, PhantomInvariant, Provider, SchedulerClock, SchedulerHandle, Signal};
+
+use crate::subject::{ReplayBufferSubject, ReplayWindowOptions, ReplayWindowSubject};
+
+/// Provides a new, empty [ReplayBufferSubject] on every call, for use as the
+/// connector of a connectable observable.
+#[derive_where(Clone, Copy, Debug)]
+pub struct ReplayBufferSubjectProvider<In, InError = Never>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	/// `None` means unbounded
+	capacity: Option<usize>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError> ReplayBufferSubjectProvider<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	/// Provides subjects replaying the last `capacity` values, `None` means
+	/// unbounded.
+	pub fn new(capacity: Option<usize>) -> Self {
+		Self {
+			capacity,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError> Provider for ReplayBufferSubjectProvider<In, InError>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+{
+	type Provided = ReplayBufferSubject<In, InError>;
+
+	fn provide(&self) -> Self::Provided {
+		match self.capacity {
+			Some(capacity) => ReplayBufferSubject::new(capacity),
+			None => ReplayBufferSubject::unbounded(),
+		}
+	}
+}
+
+/// Provides a new, empty [ReplayWindowSubject] on every call, for use as the
+/// connector of a connectable observable.
+#[derive_where(Clone)]
+pub struct ReplayWindowSubjectProvider<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + SchedulerClock,
+{
+	options: ReplayWindowOptions,
+	scheduler: SchedulerHandle<S>,
+	_phantom_data: PhantomInvariant<(In, InError)>,
+}
+
+impl<In, InError, S> ReplayWindowSubjectProvider<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + SchedulerClock,
+{
+	pub fn new(options: ReplayWindowOptions, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			options,
+			scheduler,
+			_phantom_data: PhantomData,
+		}
+	}
+}
+
+impl<In, InError, S> Provider for ReplayWindowSubjectProvider<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + SchedulerClock,
+{
+	type Provided = ReplayWindowSubject<In, InError, S>;
+
+	fn provide(&self) -> Self::Provided {
+		ReplayWindowSubject::new(self.options, self.scheduler.clone())
+	}
+}
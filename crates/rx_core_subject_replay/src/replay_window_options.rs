@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+/// Options for configuring a `ReplayWindowSubject`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ReplayWindowOptions {
+	/// How long an observed value is kept around for replaying.
+	/// `Duration::MAX` keeps them forever.
+	/// Default: `1s`.
+	pub window_time: Duration,
+	/// The maximum number of values kept around for replaying, `None` means
+	/// only the age of the values limits it.
+	/// Default: `None`.
+	pub capacity: Option<usize>,
+}
+
+impl ReplayWindowOptions {
+	/// Creates options with the given window time and default capacity.
+	///
+	/// Defaults:
+	/// - `capacity`: `None`
+	pub fn new(window_time: Duration) -> Self {
+		Self {
+			window_time,
+			..Default::default()
+		}
+	}
+
+	#[must_use]
+	pub fn with_capacity(mut self, capacity: usize) -> Self {
+		self.capacity = Some(capacity);
+		self
+	}
+}
+
+impl Default for ReplayWindowOptions {
+	/// Defaults:
+	/// - `window_time`: `1s`
+	/// - `capacity`: `None`
+	fn default() -> Self {
+		Self {
+			window_time: Duration::from_secs(1),
+			capacity: None,
+		}
+	}
+}
@@ -0,0 +1,144 @@
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use derive_where::derive_where;
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, RxObserver, SchedulerClock, SchedulerHandle, Signal,
+	Subscriber, UpgradeableObserver, WorkTick,
+};
+use rx_core_macro_subject_derive::RxSubject;
+use rx_core_subject_publish::{internal::MulticastSubscription, subject::PublishSubject};
+
+use crate::{ReplayWindowOptions, internal::ReplayBuffer};
+
+/// A [ReplayWindowSubject] is a [ReplaySubject][crate::subject::ReplaySubject]
+/// that also evicts values once they get older than `window_time`, as
+/// measured by its scheduler.
+///
+/// Upon subscription it immediately replays the values observed within the
+/// last `window_time`, at most the last `capacity` of them.
+///
+/// > Values are evicted lazily, whenever the subject observes a value, is
+/// > subscribed to, or its values are read. No work is ever scheduled, the
+/// > scheduler is only used as a clock.
+///
+/// A `window_time` of [Duration::MAX] never evicts, only `capacity` limits
+/// the replayed values then.
+#[derive_where(Clone)]
+#[derive(RxSubject)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+#[rx_out(In)]
+#[rx_out_error(InError)]
+#[rx_delegate_subscription_like_to_destination]
+pub struct ReplayWindowSubject<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + SchedulerClock,
+{
+	#[destination]
+	subject: PublishSubject<In, InError>,
+	options: ReplayWindowOptions,
+	scheduler: SchedulerHandle<S>,
+	/// Every value, together with the time it was observed at.
+	/// Shared data across clones
+	values: Arc<Mutex<ReplayBuffer<(Duration, In)>>>,
+}
+
+impl<In, InError, S> ReplayWindowSubject<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + SchedulerClock,
+{
+	pub fn new(options: ReplayWindowOptions, scheduler: SchedulerHandle<S>) -> Self {
+		Self {
+			subject: PublishSubject::default(),
+			values: Arc::new(Mutex::new(ReplayBuffer::new(options.capacity))),
+			options,
+			scheduler,
+		}
+	}
+
+	/// Returns a clone of the currently stored values
+	/// In case you want to access the current values, prefer using a
+	/// subscription to keep your code reactive; only use this when it is
+	/// absolutely necessary.
+	pub fn values(&self) -> Vec<In> {
+		let now = self.now();
+		let mut values = self.values.lock_ignore_poison();
+		self.evict_expired(&mut values, now);
+		values.iter().map(|(_, value)| value.clone()).collect()
+	}
+
+	#[inline]
+	fn now(&self) -> Duration {
+		self.scheduler.lock().current_tick().now()
+	}
+
+	fn evict_expired(&self, values: &mut ReplayBuffer<(Duration, In)>, now: Duration) {
+		let window_time = self.options.window_time;
+		values.evict_while(|(observed_at, _)| observed_at.saturating_add(window_time) <= now);
+	}
+}
+
+impl<In, InError, S> RxObserver for ReplayWindowSubject<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + SchedulerClock,
+{
+	fn next(&mut self, next: In) {
+		let now = self.now();
+		{
+			let mut values = self.values.lock_ignore_poison();
+			self.evict_expired(&mut values, now);
+			values.push((now, next.clone()));
+		}
+
+		self.subject.next(next);
+	}
+
+	#[inline]
+	fn error(&mut self, error: Self::InError) {
+		self.subject.error(error);
+	}
+
+	#[inline]
+	fn complete(&mut self) {
+		self.subject.complete();
+	}
+}
+
+impl<In, InError, S> Observable for ReplayWindowSubject<In, InError, S>
+where
+	In: Signal + Clone,
+	InError: Signal + Clone,
+	S: 'static + SchedulerClock,
+{
+	type Subscription<Destination>
+		= MulticastSubscription<In, InError>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination: 'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError>,
+	{
+		let mut destination = destination.upgrade();
+
+		if !self.subject.is_errored() {
+			for value in self.values() {
+				destination.next(value);
+			}
+		}
+
+		self.subject.subscribe(destination)
+	}
+}
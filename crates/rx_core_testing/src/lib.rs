@@ -1,4 +1,5 @@
 mod harness;
+mod marble;
 mod mock_error;
 mod mock_executor;
 mod mock_observer;
@@ -9,6 +10,7 @@ mod tracked_iterator;
 mod tracked_teardown;
 
 pub use harness::*;
+pub use marble::*;
 pub use mock_error::*;
 pub use mock_executor::*;
 pub use mock_observer::*;
@@ -20,6 +22,7 @@ pub use tracked_teardown::*;
 
 pub mod prelude {
 	pub use super::harness::*;
+	pub use super::marble::*;
 	pub use super::mock_error::*;
 	pub use super::mock_executor::*;
 	pub use super::mock_observer::*;
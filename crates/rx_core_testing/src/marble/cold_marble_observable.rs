@@ -0,0 +1,117 @@
+use std::{sync::Arc, time::Duration};
+
+use derive_where::derive_where;
+use rx_core_common::{
+	ErasedSubscriber, Observable, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension,
+	SharedSubscriber, Signal, Subscriber, SubscriberNotification,
+	SubscriberPushNotificationExtention, SubscriptionLike, Teardown, TeardownCollectionExtension,
+	UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+use rx_core_scheduler_ticking::TickingScheduler;
+
+use crate::{MarbleSubscriptionLog, MockContextProvider, NotifiableSubscription};
+
+/// # [ColdMarbleObservable]
+///
+/// Replays its marble for every subscriber, with frames relative to the
+/// moment of subscription. Created by [MarbleTester::cold][crate::MarbleTester::cold].
+#[derive_where(Clone)]
+#[derive(RxObservable)]
+#[rx_out(Out)]
+#[rx_out_error(OutError)]
+pub struct ColdMarbleObservable<Out, OutError>
+where
+	Out: Signal + Clone,
+	OutError: Signal + Clone,
+{
+	notifications: Arc<Vec<(usize, SubscriberNotification<Out, OutError>)>>,
+	frame_duration: Duration,
+	scheduler: SchedulerHandle<TickingScheduler<MockContextProvider>>,
+	subscription_log: MarbleSubscriptionLog,
+}
+
+impl<Out, OutError> ColdMarbleObservable<Out, OutError>
+where
+	Out: Signal + Clone,
+	OutError: Signal + Clone,
+{
+	pub fn new(
+		notifications: Vec<(usize, SubscriberNotification<Out, OutError>)>,
+		frame_duration: Duration,
+		scheduler: SchedulerHandle<TickingScheduler<MockContextProvider>>,
+		subscription_log: MarbleSubscriptionLog,
+	) -> Self {
+		Self {
+			notifications: Arc::new(notifications),
+			frame_duration,
+			scheduler,
+			subscription_log,
+		}
+	}
+
+	pub fn get_subscription_log(&self) -> MarbleSubscriptionLog {
+		self.subscription_log.clone()
+	}
+
+	/// Asserts every subscription made to this observable, in order.
+	#[track_caller]
+	pub fn assert_subscriptions<const N: usize>(&self, expected: [&str; N]) {
+		self.subscription_log.assert_subscriptions(expected);
+	}
+}
+
+impl<Out, OutError> Observable for ColdMarbleObservable<Out, OutError>
+where
+	Out: Signal + Clone,
+	OutError: Signal + Clone,
+{
+	type Subscription<Destination>
+		= NotifiableSubscription<Out, OutError>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		let mut destination = SharedSubscriber::new(ErasedSubscriber::new(destination.upgrade()));
+
+		let subscription_index = self.subscription_log.record_subscription();
+		let subscription_log = self.subscription_log.clone();
+		destination.add_fn(move || subscription_log.record_unsubscription(subscription_index));
+
+		let cancellation_id = {
+			let mut scheduler = self.scheduler.lock();
+			let cancellation_id = scheduler.generate_cancellation_id();
+
+			for (frame, notification) in self.notifications.iter() {
+				let destination_clone = destination.clone();
+				let notification = notification.clone();
+				scheduler.schedule_delayed_work(
+					move |_, _| {
+						let mut destination = destination_clone.lock();
+						if !destination.is_closed() {
+							destination.push(notification);
+						}
+					},
+					self.frame_duration * *frame as u32,
+					cancellation_id,
+				);
+			}
+
+			cancellation_id
+		};
+
+		destination.add(Teardown::new_work_cancellation(
+			cancellation_id,
+			self.scheduler.clone(),
+		));
+
+		NotifiableSubscription::new(destination)
+	}
+}
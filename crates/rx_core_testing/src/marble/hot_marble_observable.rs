@@ -0,0 +1,162 @@
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+use derive_where::derive_where;
+use rx_core_common::{
+	ErasedSubscriber, LockWithPoisonBehavior, Observable, Scheduler, SchedulerHandle,
+	SchedulerScheduleWorkExtension, SharedSubscriber, Signal, Subscriber, SubscriberNotification,
+	SubscriberPushNotificationExtention, SubscriptionLike, TeardownCollectionExtension,
+	UpgradeableObserver,
+};
+use rx_core_macro_observable_derive::RxObservable;
+use rx_core_scheduler_ticking::TickingScheduler;
+
+use crate::{MarbleSubscriptionLog, MockContextProvider, NotifiableSubscription};
+
+#[derive_where(Default)]
+struct HotMarbleState<Out, OutError>
+where
+	Out: Signal,
+	OutError: Signal,
+{
+	subscribers: Vec<SharedSubscriber<ErasedSubscriber<Out, OutError>>>,
+	terminal_notification: Option<SubscriberNotification<Out, OutError>>,
+}
+
+/// # [HotMarbleObservable]
+///
+/// Emits its marble once, regardless of subscribers, like a subject would.
+/// Frame `0` is the `^` in the marble, or its first character without one,
+/// and everything before the `^` is ignored.
+///
+/// Subscribers that arrive after the marble terminated receive the terminal
+/// notification immediately. Created by [MarbleTester::hot][crate::MarbleTester::hot].
+#[derive_where(Clone)]
+#[derive(RxObservable)]
+#[rx_out(Out)]
+#[rx_out_error(OutError)]
+pub struct HotMarbleObservable<Out, OutError>
+where
+	Out: Signal + Clone,
+	OutError: Signal + Clone,
+{
+	state: Arc<Mutex<HotMarbleState<Out, OutError>>>,
+	subscription_log: MarbleSubscriptionLog,
+}
+
+impl<Out, OutError> HotMarbleObservable<Out, OutError>
+where
+	Out: Signal + Clone,
+	OutError: Signal + Clone,
+{
+	/// Schedules every notification right away, relative to the current tick
+	/// of the scheduler.
+	pub fn new(
+		notifications: Vec<(usize, SubscriberNotification<Out, OutError>)>,
+		frame_duration: Duration,
+		scheduler: SchedulerHandle<TickingScheduler<MockContextProvider>>,
+		subscription_log: MarbleSubscriptionLog,
+	) -> Self {
+		let state = Arc::new(Mutex::new(HotMarbleState::default()));
+
+		let mut scheduler = scheduler.lock();
+		let cancellation_id = scheduler.generate_cancellation_id();
+		for (frame, notification) in notifications {
+			let state_clone = state.clone();
+			scheduler.schedule_delayed_work(
+				move |_, _| Self::emit(&state_clone, notification),
+				frame_duration * frame as u32,
+				cancellation_id,
+			);
+		}
+
+		Self {
+			state,
+			subscription_log,
+		}
+	}
+
+	fn emit(
+		state: &Arc<Mutex<HotMarbleState<Out, OutError>>>,
+		notification: SubscriberNotification<Out, OutError>,
+	) {
+		let subscribers = {
+			let mut state = state.lock_ignore_poison();
+			if state.terminal_notification.is_some() {
+				return;
+			}
+
+			if matches!(
+				notification,
+				SubscriberNotification::Error(_) | SubscriberNotification::Complete
+			) {
+				state.terminal_notification = Some(notification.clone());
+				std::mem::take(&mut state.subscribers)
+			} else {
+				state
+					.subscribers
+					.retain(|subscriber| !subscriber.is_closed());
+				state.subscribers.clone()
+			}
+		};
+
+		// Notified outside of the lock, so subscribers can subscribe again
+		for mut subscriber in subscribers {
+			if !subscriber.is_closed() {
+				subscriber.push(notification.clone());
+			}
+		}
+	}
+
+	pub fn get_subscription_log(&self) -> MarbleSubscriptionLog {
+		self.subscription_log.clone()
+	}
+
+	/// Asserts every subscription made to this observable, in order.
+	#[track_caller]
+	pub fn assert_subscriptions<const N: usize>(&self, expected: [&str; N]) {
+		self.subscription_log.assert_subscriptions(expected);
+	}
+}
+
+impl<Out, OutError> Observable for HotMarbleObservable<Out, OutError>
+where
+	Out: Signal + Clone,
+	OutError: Signal + Clone,
+{
+	type Subscription<Destination>
+		= NotifiableSubscription<Out, OutError>
+	where
+		Destination: 'static + Subscriber<In = Self::Out, InError = Self::OutError>;
+
+	fn subscribe<Destination>(
+		&mut self,
+		destination: Destination,
+	) -> Self::Subscription<Destination::Upgraded>
+	where
+		Destination:
+			'static + UpgradeableObserver<In = Self::Out, InError = Self::OutError> + Send + Sync,
+	{
+		let mut destination = SharedSubscriber::new(ErasedSubscriber::new(destination.upgrade()));
+
+		let subscription_index = self.subscription_log.record_subscription();
+		let subscription_log = self.subscription_log.clone();
+		destination.add_fn(move || subscription_log.record_unsubscription(subscription_index));
+
+		let terminal_notification = {
+			let mut state = self.state.lock_ignore_poison();
+			if state.terminal_notification.is_none() {
+				state.subscribers.push(destination.clone());
+			}
+			state.terminal_notification.clone()
+		};
+
+		if let Some(terminal_notification) = terminal_notification {
+			destination.push(terminal_notification);
+		}
+
+		NotifiableSubscription::new(destination)
+	}
+}
@@ -0,0 +1,272 @@
+use std::fmt::Write;
+
+/// A single signal in a marble diagram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarbleToken {
+	/// Any character that is not reserved by the marble syntax.
+	Next(char),
+	/// `#`
+	Error,
+	/// `|`
+	Complete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarbleEvent {
+	pub frame: usize,
+	pub token: MarbleToken,
+}
+
+/// # [ParsedMarble]
+///
+/// The result of [parse_marble].
+///
+/// ## Syntax
+///
+/// Every character except whitespace takes up one frame of time.
+///
+/// - `-`: Nothing happens in this frame.
+/// - `|`: Complete.
+/// - `#`: Error.
+/// - `^`: The subscription point. In hot observables, this is frame `0`.
+/// - `!`: The unsubscription point.
+/// - `(` and `)`: Everything inside happens in the frame of the opening `(`.
+/// - Whitespace is ignored, it can be used to align diagrams.
+/// - Anything else is a `next` notification, identified by that character.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedMarble {
+	pub events: Vec<MarbleEvent>,
+	pub subscription_frame: Option<usize>,
+	pub unsubscription_frame: Option<usize>,
+}
+
+/// Parses a marble diagram, panics if it's malformed.
+///
+/// See [ParsedMarble] for the syntax.
+#[track_caller]
+pub fn parse_marble(marble: &str) -> ParsedMarble {
+	let mut parsed = ParsedMarble::default();
+	let mut group_frame: Option<usize> = None;
+
+	for (frame, character) in marble.chars().filter(|c| !c.is_whitespace()).enumerate() {
+		let event_frame = group_frame.unwrap_or(frame);
+
+		match character {
+			'-' => {}
+			'(' => {
+				if group_frame.is_some() {
+					panic!("Marble \"{marble}\" has nested groups!");
+				}
+				group_frame = Some(frame);
+			}
+			')' => {
+				if group_frame.take().is_none() {
+					panic!("Marble \"{marble}\" closes a group that was not opened!");
+				}
+			}
+			'^' => {
+				if parsed.subscription_frame.replace(event_frame).is_some() {
+					panic!("Marble \"{marble}\" has more than one subscription point!");
+				}
+			}
+			'!' => {
+				if parsed.unsubscription_frame.replace(event_frame).is_some() {
+					panic!("Marble \"{marble}\" has more than one unsubscription point!");
+				}
+			}
+			'|' => parsed.push(event_frame, MarbleToken::Complete),
+			'#' => parsed.push(event_frame, MarbleToken::Error),
+			next => parsed.push(event_frame, MarbleToken::Next(next)),
+		}
+	}
+
+	if group_frame.is_some() {
+		panic!("Marble \"{marble}\" has an unclosed group!");
+	}
+
+	parsed
+}
+
+impl ParsedMarble {
+	fn push(&mut self, frame: usize, token: MarbleToken) {
+		self.events.push(MarbleEvent { frame, token });
+	}
+
+	/// Drops every event before the subscription point, and makes every
+	/// frame relative to it. Without a subscription point, nothing changes.
+	pub fn relative_to_subscription(mut self) -> Self {
+		if let Some(subscription_frame) = self.subscription_frame {
+			self.events
+				.retain(|event| event.frame >= subscription_frame);
+			for event in self.events.iter_mut() {
+				event.frame -= subscription_frame;
+			}
+			self.unsubscription_frame = self
+				.unsubscription_frame
+				.map(|frame| frame.saturating_sub(subscription_frame));
+			self.subscription_frame = Some(0);
+		}
+		self
+	}
+}
+
+/// Renders events as a marble diagram. Events in the same frame are grouped.
+pub fn render_marble(events: &[MarbleEvent]) -> String {
+	let mut rendered = String::new();
+	let mut frame = 0;
+	let mut index = 0;
+
+	while index < events.len() {
+		let event_frame = events[index].frame;
+		while frame < event_frame {
+			rendered.push('-');
+			frame += 1;
+		}
+
+		let group_length = events[index..]
+			.iter()
+			.take_while(|event| event.frame == event_frame)
+			.count();
+
+		let group = &events[index..index + group_length];
+		if group.len() > 1 {
+			rendered.push('(');
+		}
+		for event in group {
+			rendered.push(match event.token {
+				MarbleToken::Next(next) => next,
+				MarbleToken::Error => '#',
+				MarbleToken::Complete => '|',
+			});
+		}
+		if group.len() > 1 {
+			rendered.push(')');
+			frame += group.len() + 2;
+		} else {
+			frame += 1;
+		}
+
+		index += group_length;
+	}
+
+	rendered
+}
+
+/// Renders a subscription lifetime as a marble diagram.
+pub fn render_subscription_marble(
+	subscription_frame: usize,
+	unsubscription_frame: Option<usize>,
+) -> String {
+	let mut rendered = "-".repeat(subscription_frame);
+	match unsubscription_frame {
+		Some(unsubscription_frame) if unsubscription_frame == subscription_frame => {
+			rendered.push_str("(^!)");
+		}
+		Some(unsubscription_frame) => {
+			let _ = write!(
+				rendered,
+				"^{}!",
+				"-".repeat(unsubscription_frame - subscription_frame - 1)
+			);
+		}
+		None => rendered.push('^'),
+	}
+	rendered
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn event(frame: usize, token: MarbleToken) -> MarbleEvent {
+		MarbleEvent { frame, token }
+	}
+
+	#[test]
+	fn should_parse_nexts_and_completion_in_their_frames() {
+		let parsed = parse_marble("--a--b--|");
+		assert_eq!(
+			parsed.events,
+			vec![
+				event(2, MarbleToken::Next('a')),
+				event(5, MarbleToken::Next('b')),
+				event(8, MarbleToken::Complete),
+			]
+		);
+		assert_eq!(parsed.subscription_frame, None);
+		assert_eq!(parsed.unsubscription_frame, None);
+	}
+
+	#[test]
+	fn should_parse_errors() {
+		let parsed = parse_marble("-a-#");
+		assert_eq!(
+			parsed.events,
+			vec![
+				event(1, MarbleToken::Next('a')),
+				event(3, MarbleToken::Error)
+			]
+		);
+	}
+
+	#[test]
+	fn should_parse_groups_into_the_frame_of_the_group() {
+		let parsed = parse_marble("-(ab|)-c");
+		assert_eq!(
+			parsed.events,
+			vec![
+				event(1, MarbleToken::Next('a')),
+				event(1, MarbleToken::Next('b')),
+				event(1, MarbleToken::Complete),
+				event(7, MarbleToken::Next('c')),
+			]
+		);
+	}
+
+	#[test]
+	fn should_ignore_whitespace() {
+		assert_eq!(parse_marble("  -a -|"), parse_marble("-a-|"));
+	}
+
+	#[test]
+	fn should_parse_subscription_points() {
+		let parsed = parse_marble("--^---!");
+		assert_eq!(parsed.subscription_frame, Some(2));
+		assert_eq!(parsed.unsubscription_frame, Some(6));
+		assert!(parsed.events.is_empty());
+	}
+
+	#[test]
+	fn should_make_frames_relative_to_the_subscription_point() {
+		let parsed = parse_marble("a-^-b-!").relative_to_subscription();
+		assert_eq!(parsed.events, vec![event(2, MarbleToken::Next('b'))]);
+		assert_eq!(parsed.subscription_frame, Some(0));
+		assert_eq!(parsed.unsubscription_frame, Some(4));
+	}
+
+	#[test]
+	#[should_panic]
+	fn should_panic_on_unclosed_groups() {
+		parse_marble("-(a-");
+	}
+
+	#[test]
+	#[should_panic]
+	fn should_panic_on_multiple_subscription_points() {
+		parse_marble("^-^");
+	}
+
+	#[test]
+	fn should_render_events_back_into_a_marble() {
+		for marble in ["--a--b--|", "-(ab)-#", "(a|)"] {
+			assert_eq!(render_marble(&parse_marble(marble).events), marble);
+		}
+	}
+
+	#[test]
+	fn should_render_subscription_marbles() {
+		assert_eq!(render_subscription_marble(2, Some(5)), "--^--!");
+		assert_eq!(render_subscription_marble(0, None), "^");
+		assert_eq!(render_subscription_marble(1, Some(1)), "-(^!)");
+	}
+}
@@ -0,0 +1,142 @@
+use std::{
+	fmt::Debug,
+	sync::{
+		Arc, Mutex,
+		atomic::{AtomicUsize, Ordering},
+	},
+};
+
+use derive_where::derive_where;
+use rx_core_common::{
+	LockWithPoisonBehavior, RxObserver, Signal, SubscriberNotification, SubscriptionLike,
+};
+use rx_core_macro_observer_derive::RxObserver;
+
+use crate::{MarbleValues, parse_marble, render_marble};
+
+type RecordedNotifications<In, InError> =
+	Arc<Mutex<Vec<(usize, SubscriberNotification<In, InError>)>>>;
+
+/// Records every notification it observes along with the frame it was
+/// observed in.
+#[derive_where(Clone)]
+#[derive(RxObserver)]
+#[rx_in(In)]
+#[rx_in_error(InError)]
+pub struct MarbleRecorder<In, InError>
+where
+	In: Signal,
+	InError: Signal,
+{
+	current_frame: Arc<AtomicUsize>,
+	notifications: RecordedNotifications<In, InError>,
+}
+
+impl<In, InError> MarbleRecorder<In, InError>
+where
+	In: Signal,
+	InError: Signal,
+{
+	pub fn new(current_frame: Arc<AtomicUsize>) -> Self {
+		Self {
+			current_frame,
+			notifications: Arc::new(Mutex::new(Vec::new())),
+		}
+	}
+
+	fn record(&mut self, notification: SubscriberNotification<In, InError>) {
+		let frame = self.current_frame.load(Ordering::Relaxed);
+		self.notifications
+			.lock_ignore_poison()
+			.push((frame, notification));
+	}
+}
+
+impl<In, InError> RxObserver for MarbleRecorder<In, InError>
+where
+	In: Signal,
+	InError: Signal,
+{
+	fn next(&mut self, next: Self::In) {
+		self.record(SubscriberNotification::Next(next));
+	}
+
+	fn error(&mut self, error: Self::InError) {
+		self.record(SubscriberNotification::Error(error));
+	}
+
+	fn complete(&mut self) {
+		self.record(SubscriberNotification::Complete);
+	}
+}
+
+/// # [MarbleRecording]
+///
+/// The notifications observed by a subscription made through a
+/// [MarbleTester][crate::MarbleTester]. Keeps the subscription alive until
+/// it's dropped.
+pub struct MarbleRecording<In, InError>
+where
+	In: Signal,
+	InError: Signal,
+{
+	notifications: RecordedNotifications<In, InError>,
+	subscription: Arc<Mutex<Option<Box<dyn SubscriptionLike + Send + Sync>>>>,
+}
+
+impl<In, InError> MarbleRecording<In, InError>
+where
+	In: Signal,
+	InError: Signal,
+{
+	pub fn new(
+		recorder: &MarbleRecorder<In, InError>,
+		subscription: Arc<Mutex<Option<Box<dyn SubscriptionLike + Send + Sync>>>>,
+	) -> Self {
+		Self {
+			notifications: recorder.notifications.clone(),
+			subscription,
+		}
+	}
+
+	pub fn get_notifications(&self) -> Vec<(usize, SubscriberNotification<In, InError>)>
+	where
+		In: Clone,
+		InError: Clone,
+	{
+		self.notifications.lock_ignore_poison().clone()
+	}
+
+	pub fn unsubscribe(&mut self) {
+		if let Some(mut subscription) = self.subscription.lock_ignore_poison().take() {
+			subscription.unsubscribe();
+		}
+	}
+
+	/// Asserts the recorded notifications against a marble, where frame `0`
+	/// is the first frame of the [MarbleTester][crate::MarbleTester].
+	#[track_caller]
+	pub fn assert_marble(&self, expected: &str, values: &MarbleValues<In, InError>)
+	where
+		In: Clone + Debug + PartialEq,
+		InError: Clone + Debug + PartialEq,
+	{
+		let expected_notifications = values.to_notifications(&parse_marble(expected));
+		let notifications = self.get_notifications();
+
+		if notifications != expected_notifications {
+			let actual_events = notifications
+				.iter()
+				.filter_map(|(frame, notification)| values.to_event(*frame, notification))
+				.collect::<Vec<_>>();
+
+			panic!(
+				"Notifications do not match!\nexpected: \"{}\"\nactual:   \"{}\"\n\nexpected: {:?}\nactual:   {:?}",
+				render_marble(&parse_marble(expected).events),
+				render_marble(&actual_events),
+				expected_notifications,
+				notifications
+			);
+		}
+	}
+}
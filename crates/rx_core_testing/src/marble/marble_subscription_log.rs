@@ -0,0 +1,96 @@
+use std::sync::{
+	Arc, Mutex,
+	atomic::{AtomicUsize, Ordering},
+};
+
+use rx_core_common::LockWithPoisonBehavior;
+
+use crate::{parse_marble, render_subscription_marble};
+
+/// The frames a subscription started and ended in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarbleSubscriptionLifetime {
+	pub subscription_frame: usize,
+	pub unsubscription_frame: Option<usize>,
+}
+
+/// # [MarbleSubscriptionLog]
+///
+/// Records when marble observables were subscribed to and unsubscribed from,
+/// so it can be asserted against subscription marbles like `"--^---!"`.
+#[derive(Debug, Clone)]
+pub struct MarbleSubscriptionLog {
+	current_frame: Arc<AtomicUsize>,
+	lifetimes: Arc<Mutex<Vec<MarbleSubscriptionLifetime>>>,
+}
+
+impl MarbleSubscriptionLog {
+	pub fn new(current_frame: Arc<AtomicUsize>) -> Self {
+		Self {
+			current_frame,
+			lifetimes: Arc::new(Mutex::new(Vec::new())),
+		}
+	}
+
+	/// Records a new subscription in the current frame, returns its index
+	/// for [record_unsubscription][MarbleSubscriptionLog::record_unsubscription]
+	pub fn record_subscription(&self) -> usize {
+		let mut lifetimes = self.lifetimes.lock_ignore_poison();
+		lifetimes.push(MarbleSubscriptionLifetime {
+			subscription_frame: self.current_frame.load(Ordering::Relaxed),
+			unsubscription_frame: None,
+		});
+		lifetimes.len() - 1
+	}
+
+	pub fn record_unsubscription(&self, index: usize) {
+		let current_frame = self.current_frame.load(Ordering::Relaxed);
+		if let Some(lifetime) = self.lifetimes.lock_ignore_poison().get_mut(index) {
+			lifetime.unsubscription_frame.get_or_insert(current_frame);
+		}
+	}
+
+	pub fn get_lifetimes(&self) -> Vec<MarbleSubscriptionLifetime> {
+		self.lifetimes.lock_ignore_poison().clone()
+	}
+
+	/// Asserts every subscription made, in order, against subscription
+	/// marbles. A subscription that was never unsubscribed has no `!`.
+	#[track_caller]
+	pub fn assert_subscriptions<const N: usize>(&self, expected: [&str; N]) {
+		let expected_lifetimes = expected
+			.iter()
+			.map(|marble| {
+				let parsed = parse_marble(marble);
+				MarbleSubscriptionLifetime {
+					subscription_frame: parsed.subscription_frame.unwrap_or_else(|| {
+						panic!("Subscription marble \"{marble}\" has no subscription point!")
+					}),
+					unsubscription_frame: parsed.unsubscription_frame,
+				}
+			})
+			.collect::<Vec<_>>();
+
+		let lifetimes = self.get_lifetimes();
+
+		if lifetimes != expected_lifetimes {
+			let render = |lifetimes: &[MarbleSubscriptionLifetime]| {
+				lifetimes
+					.iter()
+					.map(|lifetime| {
+						render_subscription_marble(
+							lifetime.subscription_frame,
+							lifetime.unsubscription_frame,
+						)
+					})
+					.collect::<Vec<_>>()
+			};
+
+			panic!(
+				"Subscriptions do not match!\nexpected: {:?}\nactual:   {:?}",
+				render(&expected_lifetimes),
+				render(&lifetimes)
+			);
+		}
+	}
+}
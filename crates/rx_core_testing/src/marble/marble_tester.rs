@@ -0,0 +1,239 @@
+use std::{
+	fmt::Debug,
+	sync::{
+		Arc, Mutex,
+		atomic::{AtomicUsize, Ordering},
+	},
+	time::Duration,
+};
+
+use rx_core_common::{
+	LockWithPoisonBehavior, Observable, Scheduler, SchedulerHandle, SchedulerScheduleWorkExtension,
+	Signal, SubscriptionLike, WorkExecutor,
+};
+use rx_core_scheduler_ticking::{Tick, TickingScheduler};
+
+use crate::{
+	ColdMarbleObservable, HotMarbleObservable, MarbleRecorder, MarbleRecording,
+	MarbleSubscriptionLog, MarbleValues, MockContextProvider, MockExecutor, parse_marble,
+};
+
+/// After this many frames [flush][MarbleTester::flush] assumes that
+/// something keeps scheduling work forever.
+const MAX_FLUSHED_FRAMES: usize = 10_000;
+
+/// # [MarbleTester]
+///
+/// Runs marble diagrams on the virtual timeline of a [MockExecutor], where
+/// every frame of a marble takes `frame_duration` time.
+///
+/// Operators that need a scheduler should use the one from
+/// [get_scheduler_handle][MarbleTester::get_scheduler_handle] so they share
+/// the same timeline.
+///
+/// ```rust
+/// # use rx_core_common::SubscriptionLike;
+/// # use rx_core_testing::prelude::*;
+/// let mut tester = MarbleTester::default();
+/// let values = MarbleValues::<usize, &'static str>::new([('a', 1), ('b', 2)]);
+///
+/// let source = tester.cold("-a-b-|", &values);
+/// let recording = tester.subscribe(source.clone());
+///
+/// tester.flush();
+///
+/// recording.assert_marble("-a-b-|", &values);
+/// source.assert_subscriptions(["^----!"]);
+/// ```
+pub struct MarbleTester {
+	executor: MockExecutor,
+	frame_duration: Duration,
+	current_frame: Arc<AtomicUsize>,
+	next_frame: usize,
+}
+
+impl MarbleTester {
+	pub fn new(frame_duration: Duration) -> Self {
+		Self {
+			executor: MockExecutor::default(),
+			frame_duration,
+			current_frame: Arc::new(AtomicUsize::new(0)),
+			next_frame: 0,
+		}
+	}
+
+	pub fn get_scheduler_handle(&self) -> SchedulerHandle<TickingScheduler<MockContextProvider>> {
+		self.executor.get_scheduler_handle()
+	}
+
+	/// The duration of `frames` frames, useful to configure time based
+	/// operators.
+	pub fn frames(&self, frames: usize) -> Duration {
+		self.frame_duration * frames as u32
+	}
+
+	pub fn get_current_frame(&self) -> usize {
+		self.current_frame.load(Ordering::Relaxed)
+	}
+
+	/// Creates an observable that emits the marble for each subscriber,
+	/// relative to when it was subscribed to.
+	#[track_caller]
+	pub fn cold<Out, OutError>(
+		&self,
+		marble: &str,
+		values: &MarbleValues<Out, OutError>,
+	) -> ColdMarbleObservable<Out, OutError>
+	where
+		Out: Signal + Clone + Debug,
+		OutError: Signal + Clone + Debug,
+	{
+		let parsed = parse_marble(marble);
+		if parsed.subscription_frame.is_some() || parsed.unsubscription_frame.is_some() {
+			panic!("Cold marble \"{marble}\" can't have subscription points!");
+		}
+
+		ColdMarbleObservable::new(
+			values.to_notifications(&parsed),
+			self.frame_duration,
+			self.get_scheduler_handle(),
+			MarbleSubscriptionLog::new(self.current_frame.clone()),
+		)
+	}
+
+	/// Creates an observable that emits the marble once, starting from now,
+	/// where now is the `^` of the marble.
+	#[track_caller]
+	pub fn hot<Out, OutError>(
+		&self,
+		marble: &str,
+		values: &MarbleValues<Out, OutError>,
+	) -> HotMarbleObservable<Out, OutError>
+	where
+		Out: Signal + Clone + Debug,
+		OutError: Signal + Clone + Debug,
+	{
+		let parsed = parse_marble(marble).relative_to_subscription();
+		if parsed.unsubscription_frame.is_some() {
+			panic!("Hot marble \"{marble}\" can't have an unsubscription point!");
+		}
+
+		HotMarbleObservable::new(
+			values.to_notifications(&parsed),
+			self.frame_duration,
+			self.get_scheduler_handle(),
+			MarbleSubscriptionLog::new(self.current_frame.clone()),
+		)
+	}
+
+	/// Subscribes to the observable right away, recording everything it
+	/// emits.
+	pub fn subscribe<O>(&self, mut observable: O) -> MarbleRecording<O::Out, O::OutError>
+	where
+		O: Observable,
+	{
+		let recorder = MarbleRecorder::new(self.current_frame.clone());
+		let subscription = observable.subscribe(recorder.clone());
+
+		MarbleRecording::new(
+			&recorder,
+			Arc::new(Mutex::new(Some(Box::new(subscription)))),
+		)
+	}
+
+	/// Subscribes to the observable at the `^` of the subscription marble, and
+	/// unsubscribes at its `!`, if it has one.
+	#[track_caller]
+	pub fn subscribe_with<O>(
+		&self,
+		subscription_marble: &str,
+		observable: O,
+	) -> MarbleRecording<O::Out, O::OutError>
+	where
+		O: 'static + Observable + Send + Sync,
+	{
+		let parsed = parse_marble(subscription_marble);
+		let subscription_frame = parsed.subscription_frame.unwrap_or_else(|| {
+			panic!("Subscription marble \"{subscription_marble}\" has no subscription point!")
+		});
+
+		let recorder = MarbleRecorder::new(self.current_frame.clone());
+		let subscription = Arc::new(Mutex::new(None::<Box<dyn SubscriptionLike + Send + Sync>>));
+
+		let now = self.get_current_frame();
+		let scheduler = self.executor.get_scheduler_handle();
+		let mut scheduler = scheduler.lock();
+		let cancellation_id = scheduler.generate_cancellation_id();
+
+		let recorder_clone = recorder.clone();
+		let subscription_clone = subscription.clone();
+		let mut observable = Some(observable);
+		scheduler.schedule_delayed_work(
+			move |_, _| {
+				if let Some(mut observable) = observable.take() {
+					let new_subscription = observable.subscribe(recorder_clone);
+					subscription_clone
+						.lock_ignore_poison()
+						.replace(Box::new(new_subscription));
+				}
+			},
+			self.frames(subscription_frame.saturating_sub(now)),
+			cancellation_id,
+		);
+
+		if let Some(unsubscription_frame) = parsed.unsubscription_frame {
+			let subscription_clone = subscription.clone();
+			scheduler.schedule_delayed_work(
+				move |_, _| {
+					if let Some(mut subscription) = subscription_clone.lock_ignore_poison().take() {
+						subscription.unsubscribe();
+					}
+				},
+				self.frames(unsubscription_frame.saturating_sub(now)),
+				cancellation_id,
+			);
+		}
+
+		MarbleRecording::new(&recorder, subscription)
+	}
+
+	/// Advances the timeline frame by frame, until there is no more work
+	/// scheduled.
+	///
+	/// Panics if work is still scheduled after a very large number of frames,
+	/// use [advance_to_frame][MarbleTester::advance_to_frame] for observables
+	/// that never stop.
+	#[track_caller]
+	pub fn flush(&mut self) {
+		for _ in 0..MAX_FLUSHED_FRAMES {
+			self.tick_next_frame();
+			if self.executor.is_empty() {
+				return;
+			}
+		}
+
+		panic!("MarbleTester was still not flushed after {MAX_FLUSHED_FRAMES} frames!");
+	}
+
+	/// Advances the timeline until `frame` (inclusive), even if there is no
+	/// work left to do.
+	pub fn advance_to_frame(&mut self, frame: usize) {
+		while self.next_frame <= frame {
+			self.tick_next_frame();
+		}
+	}
+
+	fn tick_next_frame(&mut self) {
+		self.current_frame.store(self.next_frame, Ordering::Relaxed);
+		self.executor
+			.tick_to(Tick::new(self.frames(self.next_frame)));
+		self.next_frame += 1;
+	}
+}
+
+impl Default for MarbleTester {
+	/// One millisecond per frame.
+	fn default() -> Self {
+		Self::new(Duration::from_millis(1))
+	}
+}
@@ -0,0 +1,104 @@
+use std::fmt::Debug;
+
+use rx_core_common::{Signal, SubscriberNotification};
+
+use crate::{MarbleEvent, MarbleToken, ParsedMarble};
+
+/// # [MarbleValues]
+///
+/// Maps the characters of a marble diagram to the values they represent, and
+/// `#` to the error.
+#[derive(Debug, Clone)]
+pub struct MarbleValues<In, InError>
+where
+	In: Signal,
+	InError: Signal,
+{
+	values: Vec<(char, In)>,
+	error: Option<InError>,
+}
+
+impl<In, InError> MarbleValues<In, InError>
+where
+	In: Signal + Clone + Debug,
+	InError: Signal + Clone + Debug,
+{
+	pub fn new(values: impl IntoIterator<Item = (char, In)>) -> Self {
+		Self {
+			values: values.into_iter().collect(),
+			error: None,
+		}
+	}
+
+	/// Sets the error `#` represents.
+	pub fn with_error(mut self, error: InError) -> Self {
+		self.error = Some(error);
+		self
+	}
+
+	#[track_caller]
+	pub fn get_value(&self, character: char) -> In {
+		self.values
+			.iter()
+			.find(|(key, _)| *key == character)
+			.map(|(_, value)| value.clone())
+			.unwrap_or_else(|| panic!("No marble value was defined for '{character}'!"))
+	}
+
+	#[track_caller]
+	pub fn get_error(&self) -> InError {
+		self.error
+			.clone()
+			.unwrap_or_else(|| panic!("No marble error was defined for '#'!"))
+	}
+
+	#[track_caller]
+	pub fn to_notification(&self, token: MarbleToken) -> SubscriberNotification<In, InError> {
+		match token {
+			MarbleToken::Next(character) => SubscriberNotification::Next(self.get_value(character)),
+			MarbleToken::Error => SubscriberNotification::Error(self.get_error()),
+			MarbleToken::Complete => SubscriberNotification::Complete,
+		}
+	}
+
+	/// Turns the events of a parsed marble into notifications, keeping their
+	/// frames.
+	#[track_caller]
+	pub fn to_notifications(
+		&self,
+		parsed_marble: &ParsedMarble,
+	) -> Vec<(usize, SubscriberNotification<In, InError>)> {
+		parsed_marble
+			.events
+			.iter()
+			.map(|event| (event.frame, self.to_notification(event.token)))
+			.collect()
+	}
+
+	/// The reverse of [to_notification][MarbleValues::to_notification], used
+	/// to render observed notifications. Values without a character are
+	/// rendered as `?`.
+	pub fn to_event(
+		&self,
+		frame: usize,
+		notification: &SubscriberNotification<In, InError>,
+	) -> Option<MarbleEvent>
+	where
+		In: PartialEq,
+	{
+		let token = match notification {
+			SubscriberNotification::Next(next) => MarbleToken::Next(
+				self.values
+					.iter()
+					.find(|(_, value)| value == next)
+					.map(|(key, _)| *key)
+					.unwrap_or('?'),
+			),
+			SubscriberNotification::Error(_) => MarbleToken::Error,
+			SubscriberNotification::Complete => MarbleToken::Complete,
+			SubscriberNotification::Unsubscribe => return None,
+		};
+
+		Some(MarbleEvent { frame, token })
+	}
+}
@@ -0,0 +1,15 @@
+mod cold_marble_observable;
+mod hot_marble_observable;
+mod marble_parser;
+mod marble_recorder;
+mod marble_subscription_log;
+mod marble_tester;
+mod marble_values;
+
+pub use cold_marble_observable::*;
+pub use hot_marble_observable::*;
+pub use marble_parser::*;
+pub use marble_recorder::*;
+pub use marble_subscription_log::*;
+pub use marble_tester::*;
+pub use marble_values::*;
@@ -49,6 +49,8 @@ impl RxCrateCategory {
 struct RuleIgnores {
 	does_not_have_transitive_features: Vec<String>,
 	does_not_have_an_aggregator_feature: Vec<String>,
+	/// Opt-in features left out of the default feature groups
+	not_part_of_the_feature_group: Vec<String>,
 }
 
 pub fn lint_aggregator_package_features() -> Result<(), RxWorkspaceError> {
@@ -60,6 +62,11 @@ pub fn lint_aggregator_package_features() -> Result<(), RxWorkspaceError> {
 			"rx_core_operator_identity".to_string(),
 		],
 		does_not_have_an_aggregator_feature: vec![],
+		// These pull in serde, which should only be enabled when asked for
+		not_part_of_the_feature_group: vec![
+			"rx_core_observable_replay_file".to_string(),
+			"rx_core_operator_record".to_string(),
+		],
 	};
 
 	let mut workspace_problems = WorkspaceProblems::default();
@@ -321,7 +328,7 @@ fn lint_rx_package(
 
 	if let Err(err) = lint_rx_package_feature_is_part_of_the_feature_group(
 		package,
-		&rule_ignores.does_not_have_an_aggregator_feature,
+		&rule_ignores.not_part_of_the_feature_group,
 		aggregators,
 	) {
 		package_problems.add_problem(err);
@@ -346,7 +353,7 @@ fn lint_rx_package(
 
 		if let Err(err) = lint_rx_package_feature_is_part_of_the_feature_group(
 			&wrapped_package,
-			&rule_ignores.does_not_have_an_aggregator_feature,
+			&rule_ignores.not_part_of_the_feature_group,
 			aggregators,
 		) {
 			package_problems.add_problem(err);